@@ -48,6 +48,29 @@ trait Int8IsoWeek: Datelike {
 impl Int8IsoWeek for chrono::NaiveDateTime {}
 impl<T: chrono::TimeZone> Int8IsoWeek for chrono::DateTime<T> {}
 
+// Create and implement a trait that extracts the year of chrono's `IsoWeek`
+trait IsoYear: Datelike {
+    fn iso_year(&self) -> i32 {
+        self.iso_week().year()
+    }
+}
+
+impl IsoYear for chrono::NaiveDateTime {}
+impl<T: chrono::TimeZone> IsoYear for chrono::DateTime<T> {}
+
+// Create and implement a trait that numbers the weeks of the calendar year, where week 1
+// is the week containing January 1st and weeks start on Monday
+trait Int8WeekOfYear: Datelike {
+    fn i8_week_of_year(&self) -> i8 {
+        let ordinal0 = self.ordinal0();
+        let jan_1_weekday = (self.weekday().num_days_from_monday() + 7 - ordinal0 % 7) % 7;
+        ((ordinal0 + jan_1_weekday) / 7 + 1).try_into().unwrap()
+    }
+}
+
+impl Int8WeekOfYear for chrono::NaiveDateTime {}
+impl<T: chrono::TimeZone> Int8WeekOfYear for chrono::DateTime<T> {}
+
 // Macro to avoid repetition in functions, that apply
 // `chrono::Datelike` methods on Arrays
 macro_rules! date_like {
@@ -108,6 +131,22 @@ pub fn iso_week(array: &dyn Array) -> PolarsResult<PrimitiveArray<i8>> {
     date_like!(i8_iso_week, array, ArrowDataType::Int8)
 }
 
+/// Extracts the week of the calendar year of a temporal array as [`PrimitiveArray<i8>`], where
+/// week 1 is the week containing January 1st and weeks start on Monday.
+/// Value ranges from 1 to 54, and the week is always part of the calendar year.
+/// Use [`can_week_of_year`] to check if this operation is supported for the target [`ArrowDataType`]
+pub fn week_of_year(array: &dyn Array) -> PolarsResult<PrimitiveArray<i8>> {
+    date_like!(i8_week_of_year, array, ArrowDataType::Int8)
+}
+
+/// Extracts the ISO week-based year of a temporal array as [`PrimitiveArray<i32>`].
+/// This differs from the calendar year for the days of ISO week 1 that fall in December,
+/// and for the days of ISO week 52 or 53 that fall in January.
+/// Use [`can_iso_year`] to check if this operation is supported for the target [`ArrowDataType`]
+pub fn iso_year(array: &dyn Array) -> PolarsResult<PrimitiveArray<i32>> {
+    date_like!(iso_year, array, ArrowDataType::Int32)
+}
+
 // Macro to avoid repetition in functions, that apply
 // `chrono::Timelike` methods on Arrays
 macro_rules! time_like {
@@ -374,6 +413,16 @@ pub fn can_iso_week(data_type: &ArrowDataType) -> bool {
     can_date(data_type)
 }
 
+/// Checks if an array of type `data_type` can perform week of year operation
+pub fn can_week_of_year(data_type: &ArrowDataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `data_type` can perform ISO year operation
+pub fn can_iso_year(data_type: &ArrowDataType) -> bool {
+    can_date(data_type)
+}
+
 fn can_date(data_type: &ArrowDataType) -> bool {
     matches!(
         data_type,
@@ -87,3 +87,35 @@ pub struct RollingQuantileParams {
     pub prob: f64,
     pub interpol: QuantileInterpolOptions,
 }
+
+/// Find the position of quantile `prob` in a sorted window of `len > 0` valid values.
+///
+/// Returns the lower and upper index to combine and the fractional position between them. The
+/// positions are the same as for the non-rolling quantile, so both agree for every
+/// interpolation method.
+#[inline]
+pub(super) fn quantile_idx(
+    prob: f64,
+    len: usize,
+    interpol: QuantileInterpolOptions,
+) -> (usize, usize, f64) {
+    let float_idx = (len as f64 - 1.0) * prob;
+    let clamp = |idx: f64| std::cmp::min(idx as usize, len - 1);
+    match interpol {
+        QuantileInterpolOptions::Nearest => {
+            let idx = clamp(float_idx.round());
+            (idx, idx, float_idx)
+        },
+        QuantileInterpolOptions::Lower => {
+            let idx = clamp(float_idx.floor());
+            (idx, idx, float_idx)
+        },
+        QuantileInterpolOptions::Higher => {
+            let idx = clamp(float_idx.ceil());
+            (idx, idx, float_idx)
+        },
+        QuantileInterpolOptions::Midpoint | QuantileInterpolOptions::Linear => {
+            (clamp(float_idx.floor()), clamp(float_idx.ceil()), float_idx)
+        },
+    }
+}
@@ -39,61 +39,26 @@ impl<
         let vals = self.sorted.update(start, end);
         let length = vals.len();
 
-        let idx = match self.interpol {
-            Linear => {
-                // Maybe add a fast path for median case? They could branch depending on odd/even.
-                let length_f = length as f64;
-                let idx = ((length_f - 1.0) * self.prob).floor() as usize;
-
-                let float_idx_top = (length_f - 1.0) * self.prob;
-                let top_idx = float_idx_top.ceil() as usize;
-                return if idx == top_idx {
-                    Some(unsafe { *vals.get_unchecked_release(idx) })
-                } else {
-                    let proportion = T::from(float_idx_top - idx as f64).unwrap();
-                    let vi = unsafe { *vals.get_unchecked_release(idx) };
-                    let vj = unsafe { *vals.get_unchecked_release(top_idx) };
-
-                    Some(proportion * (vj - vi) + vi)
-                };
-            },
-            Midpoint => {
-                let length_f = length as f64;
-                let idx = (length_f * self.prob) as usize;
-                let idx = std::cmp::min(idx, length - 1);
-
-                let top_idx = ((length_f - 1.0) * self.prob).ceil() as usize;
-                return if top_idx == idx {
-                    // SAFETY:
-                    // we are in bounds
-                    Some(unsafe { *vals.get_unchecked_release(idx) })
-                } else {
-                    // SAFETY:
-                    // we are in bounds
-                    let (mid, mid_plus_1) = unsafe {
-                        (
-                            *vals.get_unchecked_release(idx),
-                            *vals.get_unchecked_release(idx + 1),
-                        )
-                    };
-
-                    Some((mid + mid_plus_1) / (T::one() + T::one()))
-                };
-            },
-            Nearest => {
-                let idx = ((length as f64) * self.prob) as usize;
-                std::cmp::min(idx, length - 1)
-            },
-            Lower => ((length as f64 - 1.0) * self.prob).floor() as usize,
-            Higher => {
-                let idx = ((length as f64 - 1.0) * self.prob).ceil() as usize;
-                std::cmp::min(idx, length - 1)
-            },
-        };
-
+        let (idx, top_idx, float_idx) = quantile_idx(self.prob, length, self.interpol);
         // SAFETY:
         // we are in bounds
-        Some(unsafe { *vals.get_unchecked_release(idx) })
+        let (vi, vj) = unsafe {
+            (
+                *vals.get_unchecked_release(idx),
+                *vals.get_unchecked_release(top_idx),
+            )
+        };
+        if idx == top_idx {
+            return Some(vi);
+        }
+        match self.interpol {
+            Midpoint => Some((vi + vj) / (T::one() + T::one())),
+            Linear => {
+                let proportion = T::from(float_idx - idx as f64).unwrap();
+                Some(proportion * (vj - vi) + vi)
+            },
+            _ => Some(vi),
+        }
     }
 }
 
@@ -331,4 +296,32 @@ mod test {
             assert_eq!(out1, out2);
         }
     }
+
+    #[test]
+    fn test_rolling_quantile_interpolation() {
+        let values = &[1.0f64, 2.0, 3.0, 4.0, 5.0];
+        let cases = [
+            (QuantileInterpolOptions::Nearest, [3.0, 4.0]),
+            (QuantileInterpolOptions::Lower, [2.0, 3.0]),
+            (QuantileInterpolOptions::Higher, [3.0, 4.0]),
+            (QuantileInterpolOptions::Midpoint, [2.5, 3.5]),
+            (QuantileInterpolOptions::Linear, [2.5, 3.5]),
+        ];
+
+        for (interpol, [a, b]) in cases {
+            let pars = Some(Arc::new(RollingQuantileParams {
+                prob: 0.5,
+                interpol,
+            }) as Arc<dyn Any + Send + Sync>);
+            let out = rolling_quantile(values, 4, 4, false, None, pars.clone()).unwrap();
+            let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+            let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+            assert_eq!(out, &[None, None, None, Some(a), Some(b)], "{interpol:?}");
+
+            let out = rolling_quantile(values, 4, 4, true, None, pars).unwrap();
+            let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+            let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+            assert_eq!(out, &[None, None, Some(a), Some(b), None], "{interpol:?}");
+        }
+    }
 }
@@ -51,45 +51,20 @@ impl<
         let values = &values[null_count..];
         let length = values.len();
 
-        let mut idx = match self.interpol {
-            QuantileInterpolOptions::Nearest => ((length as f64) * self.prob) as usize,
-            QuantileInterpolOptions::Lower
-            | QuantileInterpolOptions::Midpoint
-            | QuantileInterpolOptions::Linear => {
-                ((length as f64 - 1.0) * self.prob).floor() as usize
-            },
-            QuantileInterpolOptions::Higher => ((length as f64 - 1.0) * self.prob).ceil() as usize,
-        };
-
-        idx = std::cmp::min(idx, length - 1);
-
+        let (idx, top_idx, float_idx) = quantile_idx(self.prob, length, self.interpol);
         // we can unwrap because we sliced of the nulls
+        let vi = values.get_unchecked_release(idx).unwrap();
+        let vj = values.get_unchecked_release(top_idx).unwrap();
+        if idx == top_idx {
+            return Some(vi);
+        }
         match self.interpol {
-            QuantileInterpolOptions::Midpoint => {
-                let top_idx = ((length as f64 - 1.0) * self.prob).ceil() as usize;
-                Some(
-                    (values.get_unchecked_release(idx).unwrap()
-                        + values.get_unchecked_release(top_idx).unwrap())
-                        / T::from::<f64>(2.0f64).unwrap(),
-                )
-            },
+            QuantileInterpolOptions::Midpoint => Some((vi + vj) / T::from::<f64>(2.0f64).unwrap()),
             QuantileInterpolOptions::Linear => {
-                let float_idx = (length as f64 - 1.0) * self.prob;
-                let top_idx = f64::ceil(float_idx) as usize;
-
-                if top_idx == idx {
-                    Some(values.get_unchecked_release(idx).unwrap())
-                } else {
-                    let proportion = T::from(float_idx - idx as f64).unwrap();
-                    Some(
-                        proportion
-                            * (values.get_unchecked_release(top_idx).unwrap()
-                                - values.get_unchecked_release(idx).unwrap())
-                            + values.get_unchecked_release(idx).unwrap(),
-                    )
-                }
+                let proportion = T::from(float_idx - idx as f64).unwrap();
+                Some(proportion * (vj - vi) + vi)
             },
-            _ => Some(values.get_unchecked_release(idx).unwrap()),
+            _ => Some(vi),
         }
     }
 
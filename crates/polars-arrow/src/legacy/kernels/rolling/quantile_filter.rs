@@ -616,57 +616,24 @@ where
         let null_count = self.inner.null_count();
         let valid_length = self.inner.len() - null_count;
 
-        if M::Item::HAS_NULLS && valid_length < self.min_periods {
+        if M::Item::HAS_NULLS && (valid_length < self.min_periods || valid_length == 0) {
             // Default is None
             return M::Item::default();
         }
 
-        let valid_length_f = valid_length as f64;
-
-        use QuantileInterpolOptions::*;
+        let (idx, top_idx, float_idx) =
+            super::quantile_idx(self.quantile, valid_length, self.interpol);
+        let vi = self.inner.get(idx + null_count);
+        if idx == top_idx {
+            return vi;
+        }
+        let vj = self.inner.get(top_idx + null_count);
         match self.interpol {
-            Linear => {
-                let float_idx_top = (valid_length_f - 1.0) * self.quantile;
-                let idx = float_idx_top.floor() as usize;
-                let top_idx = float_idx_top.ceil() as usize;
-
-                if idx == top_idx {
-                    self.inner.get(idx + null_count)
-                } else {
-                    let vi = self.inner.get(idx + null_count);
-                    let vj = self.inner.get(top_idx + null_count);
-                    let proportion = float_idx_top - idx as f64;
-                    <<M as LenGet>::Item>::finish(proportion, vi, vj)
-                }
-            },
-            Nearest => {
-                let idx = (valid_length_f * self.quantile) as usize;
-                let idx = std::cmp::min(idx, valid_length - 1);
-                self.inner.get(idx + null_count)
-            },
-            Midpoint => {
-                let idx = (valid_length_f * self.quantile) as usize;
-                let idx = std::cmp::min(idx, valid_length - 1);
-
-                let top_idx = ((valid_length_f - 1.0) * self.quantile).ceil() as usize;
-                if top_idx == idx {
-                    self.inner.get(idx + null_count)
-                } else {
-                    let mid = self.inner.get(idx + null_count);
-                    let mid_1 = self.inner.get(top_idx + null_count);
-                    <<M as LenGet>::Item>::finish_midpoint(mid, mid_1)
-                }
-            },
-            Lower => {
-                let idx = ((valid_length_f - 1.0) * self.quantile).floor() as usize;
-                let idx = std::cmp::min(idx, valid_length - 1);
-                self.inner.get(idx + null_count)
-            },
-            Higher => {
-                let idx = ((valid_length_f - 1.0) * self.quantile).ceil() as usize;
-                let idx = std::cmp::min(idx, valid_length - 1);
-                self.inner.get(idx + null_count)
+            QuantileInterpolOptions::Midpoint => <<M as LenGet>::Item>::finish_midpoint(vi, vj),
+            QuantileInterpolOptions::Linear => {
+                <<M as LenGet>::Item>::finish(float_idx - idx as f64, vi, vj)
             },
+            _ => vi,
         }
     }
 }
@@ -9,7 +9,8 @@ pub struct RollingOptionsFixedWindow {
     /// An optional slice with the same length as the window that will be multiplied
     ///              elementwise with the values in the window.
     pub weights: Option<Vec<f64>>,
-    /// Set the labels at the center of the window.
+    /// Set the labels at the center of the window. For an even `window_size` the window holds
+    /// one more element before the label than after it.
     pub center: bool,
     pub fn_params: DynArgs,
 }
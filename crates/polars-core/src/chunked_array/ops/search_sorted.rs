@@ -9,9 +9,12 @@ use crate::prelude::*;
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SearchSortedSide {
+    /// Any index at which the value can be inserted.
     #[default]
     Any,
+    /// The first index at which the value can be inserted.
     Left,
+    /// The last index at which the value can be inserted.
     Right,
 }
 
@@ -2,33 +2,19 @@ use polars_utils::iter::EnumerateIdxTrait;
 
 use super::*;
 
-#[derive(Eq)]
+/// A row-encoded sort key, ordered by its bytes and then by its original index, so that
+/// equal keys keep their order without needing a stable sort.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
 struct CompareRow<'a> {
-    idx: IdxSize,
     bytes: &'a [u8],
-}
-
-impl PartialEq for CompareRow<'_> {
-    fn eq(&self, other: &Self) -> bool {
-        self.bytes == other.bytes
-    }
-}
-
-impl Ord for CompareRow<'_> {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.bytes.cmp(other.bytes)
-    }
-}
-
-impl PartialOrd for CompareRow<'_> {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
+    idx: IdxSize,
 }
 
 /// Return the indices of the bottom k elements.
 ///
-/// Similar to .argsort() then .slice(0, k) but with a more efficient implementation.
+/// Similar to .argsort() then .slice(0, k) but with a more efficient implementation: the
+/// k elements are selected in `O(n)` and only those are sorted. Ties are broken by the
+/// original index, so the result is always deterministic and order-maintaining.
 pub fn _arg_bottom_k(
     k: usize,
     by_column: &[Series],
@@ -44,38 +30,20 @@ pub fn _arg_bottom_k(
         .map(|(idx, bytes)| CompareRow { idx, bytes })
         .collect::<Vec<_>>();
 
-    let sorted = if k >= from_n_rows {
-        match (sort_options.multithreaded, sort_options.maintain_order) {
-            (true, true) => POOL.install(|| {
-                rows.par_sort();
-            }),
-            (true, false) => POOL.install(|| {
-                rows.par_sort_unstable();
-            }),
-            (false, true) => rows.sort(),
-            (false, false) => rows.sort_unstable(),
-        }
-        &rows
-    } else if sort_options.maintain_order {
-        // todo: maybe there is some more efficient method, comparable to select_nth_unstable
+    let sort = |rows: &mut [CompareRow]| {
         if sort_options.multithreaded {
-            POOL.install(|| {
-                rows.par_sort();
-            })
+            POOL.install(|| rows.par_sort_unstable())
         } else {
-            rows.sort();
+            rows.sort_unstable()
         }
-        &rows[..k]
+    };
+    let sorted = if k >= from_n_rows {
+        sort(&mut rows);
+        &rows
     } else {
         // todo: possible multi threaded `select_nth_unstable`?
         let (lower, _el, _upper) = rows.select_nth_unstable(k);
-        if sort_options.multithreaded {
-            POOL.install(|| {
-                lower.par_sort_unstable();
-            })
-        } else {
-            lower.sort_unstable();
-        }
+        sort(lower);
         &*lower
     };
 
@@ -185,16 +185,26 @@ pub fn _get_rows_encoded(
     by: &[Series],
     descending: &[bool],
     nulls_last: bool,
+) -> PolarsResult<RowsEncoded> {
+    _get_rows_encoded_per_column(by, descending, &vec![nulls_last; by.len()])
+}
+
+/// Encode the rows with the ordering and null placement given per column.
+pub fn _get_rows_encoded_per_column(
+    by: &[Series],
+    descending: &[bool],
+    nulls_last: &[bool],
 ) -> PolarsResult<RowsEncoded> {
     debug_assert_eq!(by.len(), descending.len());
+    debug_assert_eq!(by.len(), nulls_last.len());
     let mut cols = Vec::with_capacity(by.len());
     let mut fields = Vec::with_capacity(by.len());
-    for (by, descending) in by.iter().zip(descending) {
+    for ((by, descending), nulls_last) in by.iter().zip(descending).zip(nulls_last) {
         let arr = _get_rows_encoded_compat_array(by)?;
 
         let sort_field = EncodingField {
             descending: *descending,
-            nulls_last,
+            nulls_last: *nulls_last,
             no_order: false,
         };
         match arr.data_type() {
@@ -75,7 +75,7 @@ macro_rules! sort_with_fast_path {
                 // if the nulls are already last we can clone
                 if $options.nulls_last && $ca.get($ca.len() - 1).is_none()  ||
                 // if the nulls are already first we can clone
-                $ca.get(0).is_none()
+                !$options.nulls_last && $ca.get(0).is_none()
                 {
                     return $ca.clone();
                 }
@@ -567,36 +567,38 @@ impl StructChunked {
 impl ChunkSort<BooleanType> for BooleanChunked {
     fn sort_with(&self, options: SortOptions) -> ChunkedArray<BooleanType> {
         sort_with_fast_path!(self, options);
-        assert!(
-            !options.nulls_last,
-            "null last not yet supported for bool dtype"
-        );
-        if self.null_count() == 0 {
-            let len = self.len();
-            let n_set = self.sum().unwrap() as usize;
-            let mut bitmap = MutableBitmap::with_capacity(len);
-            let (first, second) = if options.descending {
-                (true, false)
-            } else {
-                (false, true)
-            };
-            bitmap.extend_constant(len - n_set, first);
-            bitmap.extend_constant(n_set, second);
-            let arr = BooleanArray::from_data_default(bitmap.into(), None);
+        let len = self.len();
+        let null_count = self.null_count();
+        let n_set = self.sum().unwrap() as usize;
+        let n_unset = len - null_count - n_set;
+        let (first, second) = if options.descending {
+            ((n_set, true), (n_unset, false))
+        } else {
+            ((n_unset, false), (n_set, true))
+        };
 
-            return unsafe { self.with_chunks(vec![Box::new(arr) as ArrayRef]) };
+        let mut bitmap = MutableBitmap::with_capacity(len);
+        let mut validity = MutableBitmap::with_capacity(len);
+        if !options.nulls_last {
+            bitmap.extend_constant(null_count, false);
+            validity.extend_constant(null_count, false);
         }
-
-        let mut vals = self.into_iter().collect::<Vec<_>>();
-
-        if options.descending {
-            vals.sort_by(|a, b| b.cmp(a))
-        } else {
-            vals.sort()
+        bitmap.extend_constant(first.0, first.1);
+        bitmap.extend_constant(second.0, second.1);
+        validity.extend_constant(len - null_count, true);
+        if options.nulls_last {
+            bitmap.extend_constant(null_count, false);
+            validity.extend_constant(null_count, false);
         }
+        let validity = (null_count > 0).then(|| validity.into());
+        let arr = BooleanArray::new(ArrowDataType::Boolean, bitmap.into(), validity);
 
-        let mut ca: BooleanChunked = vals.into_iter().collect_trusted();
-        ca.rename(self.name());
+        let mut ca = unsafe { self.with_chunks(vec![Box::new(arr) as ArrayRef]) };
+        ca.set_sorted_flag(if options.descending {
+            IsSorted::Descending
+        } else {
+            IsSorted::Ascending
+        });
         ca
     }
 
@@ -902,4 +904,42 @@ mod test {
         let expected = &[Some("c"), Some("b"), Some("a")];
         assert_eq!(Vec::from(&out), expected);
     }
+
+    #[test]
+    fn test_sort_bool() {
+        let ca = BooleanChunked::new("a", &[Some(true), None, Some(false), Some(true)]);
+        let options = SortOptions::default();
+        let out = ca.sort_with(options.with_order_descending(true));
+        assert_eq!(Vec::from(&out), &[None, Some(true), Some(true), Some(false)]);
+        let out = ca.sort_with(options.with_nulls_last(true));
+        assert_eq!(Vec::from(&out), &[Some(false), Some(true), Some(true), None]);
+
+        // no nulls
+        let ca = BooleanChunked::new("a", &[true, false, true]);
+        assert_eq!(Vec::from(&ca.sort(true)), &[Some(true), Some(true), Some(false)]);
+        assert_eq!(Vec::from(&ca.sort(false)), &[Some(false), Some(true), Some(true)]);
+
+        // already sorted, but with the nulls at the wrong end
+        let mut ca = BooleanChunked::new("a", &[None, Some(false), Some(true)]);
+        ca.set_sorted_flag(crate::series::IsSorted::Ascending);
+        let out = ca.sort_with(options.with_nulls_last(true));
+        assert_eq!(Vec::from(&out), &[Some(false), Some(true), None]);
+    }
+
+    #[test]
+    fn test_arg_bottom_k_ties() -> PolarsResult<()> {
+        use super::arg_bottom_k::_arg_bottom_k;
+
+        let by = Series::new("by", &[3, 1, 2, 1, 3, 1, 2]);
+        for multithreaded in [false, true] {
+            let mut options = SortMultipleOptions::default().with_multithreaded(multithreaded);
+            let idx = _arg_bottom_k(4, &[by.clone()], &mut options)?.into_inner();
+            assert_eq!(idx.cont_slice()?, &[1, 3, 5, 2]);
+
+            let mut options = options.with_order_descending(true);
+            let idx = _arg_bottom_k(3, &[by.clone()], &mut options)?.into_inner();
+            assert_eq!(idx.cont_slice()?, &[0, 4, 2]);
+        }
+        Ok(())
+    }
 }
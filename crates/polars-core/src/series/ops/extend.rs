@@ -3,13 +3,46 @@ use crate::prelude::*;
 impl Series {
     /// Extend with a constant value.
     pub fn extend_constant(&self, value: AnyValue, n: usize) -> PolarsResult<Self> {
+        let mut out = self.clone();
+        out.append(&self.constant_like(value, n)?)?;
+        Ok(out)
+    }
+
+    /// Prepend a constant value.
+    pub fn prepend_constant(&self, value: AnyValue, n: usize) -> PolarsResult<Self> {
+        let mut out = self.constant_like(value, n)?;
+        out.append(self)?;
+        Ok(out.with_name(self.name()))
+    }
+
+    /// Create `n` copies of `value` cast to the dtype of `self`, which must be lossless.
+    fn constant_like(&self, value: AnyValue, n: usize) -> PolarsResult<Series> {
         // TODO: Use `from_any_values_and_dtype` here instead of casting afterwards
         let s = Series::from_any_values("", &[value], true).unwrap();
-        let s = s.cast(self.dtype())?;
-        let to_append = s.new_from_index(0, n);
+        let s = s.strict_cast(self.dtype()).map_err(|_| {
+            polars_err!(
+                InvalidOperation: "cannot extend series of dtype {} with value {} of dtype {}",
+                self.dtype(), s.get(0).unwrap(), s.dtype()
+            )
+        })?;
+        Ok(s.new_from_index(0, n))
+    }
+}
 
-        let mut out = self.clone();
-        out.append(&to_append)?;
-        Ok(out)
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_extend_constant() -> PolarsResult<()> {
+        let s = Series::new("a", [1i32, 2]);
+        let out = s.prepend_constant(AnyValue::Int64(0), 2)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(out.i32()?), &[Some(0), Some(0), Some(1), Some(2)]);
+        let out = s.extend_constant(AnyValue::Null, 1)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), Some(2), None]);
+
+        assert!(s.extend_constant(AnyValue::String("a"), 1).is_err());
+        Ok(())
     }
 }
@@ -3,7 +3,6 @@ use std::borrow::Cow;
 use arrow::legacy::kernels::list::array_to_unit_list;
 use arrow::offset::Offsets;
 
-use crate::chunked_array::builder::get_list_builder;
 use crate::prelude::*;
 
 fn reshape_fast_path(name: &str, s: &Series) -> Series {
@@ -50,82 +49,124 @@ impl Series {
         Ok(ca)
     }
 
+    /// Reshape the values of this Series into a Series of (nested) lists.
+    ///
+    /// A single `-1` dimension is inferred from the length. `List` input is exploded first.
     pub fn reshape(&self, dimensions: &[i64]) -> PolarsResult<Series> {
+        self.reshape_impl(dimensions, false)
+    }
+
+    /// Reshape the values of this Series into a Series of (nested) fixed-size arrays.
+    ///
+    /// Only the first dimension, the number of rows, can be inferred with `-1`, as the other
+    /// dimensions determine the widths of the `Array` data types.
+    #[cfg(feature = "dtype-array")]
+    pub fn reshape_array(&self, dimensions: &[i64]) -> PolarsResult<Series> {
+        polars_ensure!(
+            dimensions.iter().skip(1).all(|d| *d > 0),
+            ComputeError: "reshape into an array needs positive inner dimensions, got {:?}", dimensions
+        );
+        self.reshape_impl(dimensions, true)
+    }
+
+    fn reshape_impl(&self, dimensions: &[i64], as_array: bool) -> PolarsResult<Series> {
         if dimensions.is_empty() {
             polars_bail!(ComputeError: "reshape `dimensions` cannot be empty")
         }
-        let s = if let DataType::List(_) = self.dtype() {
-            Cow::Owned(self.explode()?)
-        } else {
-            Cow::Borrowed(self)
+        let s = match self.dtype() {
+            DataType::List(_) => Cow::Owned(self.explode()?),
+            #[cfg(feature = "dtype-array")]
+            DataType::Array(_, _) => Cow::Owned(self.explode()?),
+            _ => Cow::Borrowed(self),
         };
 
         // No rows.
-        if dimensions[0] == 0 {
+        if dimensions[0] == 0 && !as_array {
             let s = reshape_fast_path(self.name(), &s);
             return Ok(s);
         }
 
         let s_ref = s.as_ref();
+        let len = s_ref.len() as i64;
 
-        let mut dimensions = dimensions.to_vec();
+        polars_ensure!(
+            dimensions.iter().all(|d| *d >= -1) && dimensions.iter().filter(|d| **d == -1).count() <= 1,
+            ComputeError: "invalid reshape dimensions {:?}: only one dimension can be inferred with -1 and the others must be non-negative", dimensions
+        );
+        let mut inferred = dimensions.to_vec();
         if let Some(idx) = dimensions.iter().position(|i| *i == -1) {
-            let mut product = 1;
-
-            for (cnt, dim) in dimensions.iter().enumerate() {
-                if cnt != idx {
-                    product *= *dim
-                }
-            }
-            dimensions[idx] = s_ref.len() as i64 / product;
+            let product = dimensions
+                .iter()
+                .enumerate()
+                .filter(|(cnt, _)| *cnt != idx)
+                .map(|(_, dim)| *dim)
+                .product::<i64>();
+            polars_ensure!(
+                product > 0 && len % product == 0,
+                ComputeError: "cannot reshape len {} into shape {:?}", len, dimensions,
+            );
+            inferred[idx] = len / product;
         }
 
-        let prod = dimensions.iter().product::<i64>() as usize;
+        let prod = inferred.iter().product::<i64>();
         polars_ensure!(
-            prod == s_ref.len(),
-            ComputeError: "cannot reshape len {} into shape {:?}", s_ref.len(), dimensions,
+            prod == len,
+            ComputeError: "cannot reshape len {} into shape {:?}", len, dimensions,
         );
-        match dimensions.len() {
-            1 => Ok(s_ref.slice(0, dimensions[0] as usize)),
-            2 => {
-                let mut rows = dimensions[0];
-                let mut cols = dimensions[1];
-
-                // Infer dimension.
-                if rows == -1 {
-                    rows = cols / s_ref.len() as i64
-                }
-                if cols == -1 {
-                    cols = rows / s_ref.len() as i64
-                }
-
-                // Fast path, we can create a unit list so we only allocate offsets.
-                if rows as usize == s_ref.len() && cols == 1 {
-                    let s = reshape_fast_path(self.name(), s_ref);
-                    return Ok(s);
-                }
-
-                let mut builder =
-                    get_list_builder(s_ref.dtype(), s_ref.len(), rows as usize, self.name())?;
-
-                let mut offset = 0i64;
-                for _ in 0..rows {
-                    let row = s_ref.slice(offset, cols as usize);
-                    builder.append_series(&row).unwrap();
-                    offset += cols;
-                }
-                Ok(builder.finish().into_series())
-            },
-            _ => {
-                panic!("more than two dimensions not yet supported");
-            },
+        if inferred.len() == 1 {
+            return Ok(s_ref.slice(0, inferred[0] as usize));
         }
+
+        // Group the values into lists or arrays, from the innermost dimension outwards.
+        let mut out = s_ref.rechunk();
+        for (i, width) in inferred.iter().enumerate().skip(1).rev() {
+            let rows = inferred[..i].iter().product::<i64>() as usize;
+            out = group_values(self.name(), &out, rows, *width as usize, as_array);
+        }
+        Ok(out)
+    }
+}
+
+/// Group the values of the single-chunk `s` into `rows` consecutive lists or arrays of `width`
+/// elements.
+fn group_values(name: &str, s: &Series, rows: usize, width: usize, as_array: bool) -> Series {
+    let values = s.array_ref(0).clone();
+    #[cfg(feature = "dtype-array")]
+    if as_array {
+        let data_type = FixedSizeListArray::default_datatype(values.data_type().clone(), width);
+        let mut ca =
+            ArrayChunked::with_chunk(name, FixedSizeListArray::new(data_type, values, None));
+        unsafe { ca.to_logical(s.dtype().clone()) };
+        return ca.into_series();
+    }
+    #[cfg(not(feature = "dtype-array"))]
+    let _ = as_array;
+
+    let offsets = (0..=rows as i64)
+        .map(|row| row * width as i64)
+        .collect::<Vec<_>>();
+    let data_type = ListArray::<i64>::default_datatype(values.data_type().clone());
+    // SAFETY: offsets are monotonically increasing and end at the length of the values.
+    let arr = unsafe {
+        ListArray::new(
+            data_type,
+            Offsets::new_unchecked(offsets).into(),
+            values,
+            None,
+        )
+    };
+    let mut ca = ListChunked::with_chunk(name, arr);
+    unsafe { ca.to_logical(s.dtype().clone()) };
+    if width > 0 {
+        ca.set_fast_explode();
     }
+    ca.into_series()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::chunked_array::builder::get_list_builder;
 
     #[test]
     fn test_to_list() -> PolarsResult<()> {
@@ -160,4 +201,55 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_reshape_nested() -> PolarsResult<()> {
+        let s = Series::new("a", (0..12).collect::<Vec<i32>>());
+
+        let out = s.reshape(&[2, -1, 3])?;
+        assert_eq!(out.len(), 2);
+        assert_eq!(
+            out.dtype(),
+            &DataType::List(Box::new(DataType::List(Box::new(DataType::Int32))))
+        );
+        let first = out.list()?.get_as_series(1).unwrap();
+        assert_eq!(
+            Vec::from(first.list()?.get_as_series(0).unwrap().i32()?),
+            &[Some(6), Some(7), Some(8)]
+        );
+
+        assert!(s.reshape(&[5, -1]).is_err());
+        assert!(s.reshape(&[-1, -1]).is_err());
+        assert!(s.reshape(&[3, 0, -1]).is_err());
+        assert!(s.reshape(&[3, -2]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-array")]
+    fn test_reshape_array() -> PolarsResult<()> {
+        let s = Series::new("a", (0..12).collect::<Vec<i32>>());
+
+        let out = s.reshape_array(&[-1, 4])?;
+        assert_eq!(out.len(), 3);
+        assert_eq!(out.dtype(), &DataType::Array(Box::new(DataType::Int32), 4));
+        assert_eq!(
+            Vec::from(out.array()?.get_as_series(2).unwrap().i32()?),
+            &[Some(8), Some(9), Some(10), Some(11)]
+        );
+
+        let out = s.reshape_array(&[2, 3, 2])?;
+        assert_eq!(out.len(), 2);
+        assert_eq!(
+            out.dtype(),
+            &DataType::Array(Box::new(DataType::Array(Box::new(DataType::Int32), 2)), 3)
+        );
+        assert!(out.explode()?.explode()?.equals(&s));
+
+        assert!(s.reshape_array(&[3, -1]).is_err());
+        assert!(s.reshape_array(&[0, 0]).is_err());
+        let empty = Series::new_empty("a", &DataType::Int32);
+        assert_eq!(empty.reshape_array(&[-1, 2])?.len(), 0);
+        Ok(())
+    }
 }
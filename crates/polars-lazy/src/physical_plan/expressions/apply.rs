@@ -410,7 +410,7 @@ impl PhysicalExpr for ApplyExpr {
         match function {
             FunctionExpr::Boolean(BooleanFunction::IsNull) => Some(self),
             #[cfg(feature = "is_in")]
-            FunctionExpr::Boolean(BooleanFunction::IsIn) => Some(self),
+            FunctionExpr::Boolean(BooleanFunction::IsIn { .. }) => Some(self),
             #[cfg(feature = "is_between")]
             FunctionExpr::Boolean(BooleanFunction::IsBetween { closed: _ }) => Some(self),
             FunctionExpr::Boolean(BooleanFunction::IsNotNull) => Some(self),
@@ -545,7 +545,7 @@ impl ApplyExpr {
                 }
             },
             #[cfg(feature = "is_in")]
-            FunctionExpr::Boolean(BooleanFunction::IsIn) => {
+            FunctionExpr::Boolean(BooleanFunction::IsIn { nulls_equal }) => {
                 let should_read = || -> Option<bool> {
                     let root = expr_to_leaf_column_name(&input[0]).ok()?;
                     let Expr::Literal(LiteralValue::Series(input)) = &input[1] else {
@@ -553,6 +553,10 @@ impl ApplyExpr {
                     };
                     #[allow(clippy::explicit_auto_deref)]
                     let input: &Series = &**input;
+                    // Null values may match, but they are not covered by min/max.
+                    if *nulls_equal && input.null_count() > 0 {
+                        return None;
+                    }
                     let st = stats.get_stats(&root).ok()?;
                     let min = st.to_min()?;
                     let max = st.to_max()?;
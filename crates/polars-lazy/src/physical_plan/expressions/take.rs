@@ -2,7 +2,7 @@ use arrow::legacy::utils::CustomIterTools;
 use polars_core::chunked_array::builder::get_list_builder;
 use polars_core::prelude::*;
 use polars_core::utils::NoNull;
-use polars_ops::prelude::{convert_to_unsigned_index, is_positive_idx_uncertain};
+use polars_ops::prelude::{convert_to_unsigned_index_with, is_positive_idx_uncertain, OutOfBounds};
 
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
@@ -12,6 +12,7 @@ pub struct TakeExpr {
     pub(crate) idx: Arc<dyn PhysicalExpr>,
     pub(crate) expr: Expr,
     pub(crate) returns_scalar: bool,
+    pub(crate) oob: OutOfBounds,
 }
 
 impl PhysicalExpr for TakeExpr {
@@ -39,8 +40,9 @@ impl PhysicalExpr for TakeExpr {
                 polars_ensure!(inner.is_integer(), InvalidOperation: "expected numeric dtype as index, got {:?}", inner)
             },
             dt if dt.is_integer() => {
-                // Unsigned integers will fall through and will use faster paths.
-                if !is_positive_idx_uncertain(s_idx) {
+                // Unsigned integers will fall through and will use faster paths, which
+                // raise on out of bounds indices.
+                if self.oob != OutOfBounds::Raise || !is_positive_idx_uncertain(s_idx) {
                     return self.process_negative_indices_agg(ac, idx, groups);
                 }
             },
@@ -68,16 +70,20 @@ impl PhysicalExpr for TakeExpr {
             },
         };
 
-        let s = idx.cast(&DataType::List(Box::new(IDX_DTYPE)))?;
-        let idx = s.list().unwrap();
-
         let taken = unsafe {
             ac.aggregated()
                 .list()
                 .unwrap()
                 .amortized_iter()
                 .zip(idx.amortized_iter())
-                .map(|(s, idx)| Some(s?.as_ref().take(idx?.as_ref().idx().unwrap())))
+                .map(|(s, idx)| {
+                    let s = s?;
+                    let s = s.as_ref();
+                    Some(
+                        convert_to_unsigned_index_with(idx?.as_ref(), s.len(), self.oob)
+                            .and_then(|idx| s.take(&idx)),
+                    )
+                })
                 .map(|opt_res| opt_res.transpose())
                 .collect::<PolarsResult<ListChunked>>()?
                 .with_name(ac.series().name())
@@ -101,7 +107,7 @@ impl TakeExpr {
         series: Series,
     ) -> PolarsResult<Series> {
         let idx = self.idx.evaluate(df, state)?;
-        let idx = convert_to_unsigned_index(&idx, series.len())?;
+        let idx = convert_to_unsigned_index_with(&idx, series.len(), self.oob)?;
         series.take(&idx)
     }
 
@@ -243,7 +249,11 @@ impl TakeExpr {
             for (s, idx) in iter {
                 match (s, idx) {
                     (Some(s), Some(idx)) => {
-                        let idx = convert_to_unsigned_index(idx.as_ref(), s.as_ref().len())?;
+                        let idx = convert_to_unsigned_index_with(
+                            idx.as_ref(),
+                            s.as_ref().len(),
+                            self.oob,
+                        )?;
                         let out = s.as_ref().take(&idx)?;
                         builder.append_series(&out)?;
                     },
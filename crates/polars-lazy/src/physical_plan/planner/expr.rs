@@ -259,6 +259,7 @@ fn create_physical_expr_inner(
             expr,
             idx,
             returns_scalar,
+            oob,
         } => {
             let phys_expr = create_physical_expr_inner(expr, ctxt, expr_arena, schema, state)?;
             let phys_idx = create_physical_expr_inner(idx, ctxt, expr_arena, schema, state)?;
@@ -267,6 +268,7 @@ fn create_physical_expr_inner(
                 idx: phys_idx,
                 expr: node_to_expr(expression, expr_arena),
                 returns_scalar,
+                oob,
             }))
         },
         SortBy {
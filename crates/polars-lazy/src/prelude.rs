@@ -6,7 +6,7 @@ pub use polars_io::ipc::IpcWriterOptions;
 pub use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 pub use polars_io::parquet::write::ParquetWriteOptions;
-pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
+pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation, OutOfBounds};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
 pub use polars_plan::logical_plan::{
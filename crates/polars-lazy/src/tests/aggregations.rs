@@ -477,6 +477,53 @@ fn take_aggregations() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_take_out_of_bounds_in_group_by() -> PolarsResult<()> {
+    let df = df!["a" => [1i64, 2, 3, 4, 5, 6]]?;
+    let idx = || lit(Series::new("", &[-1i64, 3]));
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([(col("a") % lit(2)).alias("key")])
+        .agg([
+            col("a").gather_with(idx(), OutOfBounds::Null).alias("null"),
+            col("a").gather_with(idx(), OutOfBounds::Wrap).alias("wrap"),
+            col("a")
+                .gather_with(lit(Series::new("", &[5u32, 1])), OutOfBounds::Null)
+                .alias("unsigned"),
+        ])
+        .collect()?;
+
+    let null = out.column("null")?.explode()?;
+    assert_eq!(Vec::from(null.i64()?), &[Some(5), None, Some(6), None]);
+    let wrap = out.column("wrap")?.explode()?;
+    assert_eq!(
+        Vec::from(wrap.i64()?),
+        &[Some(5), Some(1), Some(6), Some(2)]
+    );
+    let unsigned = out.column("unsigned")?.explode()?;
+    assert_eq!(Vec::from(unsigned.i64()?), &[None, Some(3), None, Some(4)]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([(col("a") % lit(2)).alias("key")])
+        .agg([col("a").gather(idx())])
+        .collect();
+    assert!(out.is_err());
+
+    let out = df
+        .lazy()
+        .select([col("a").gather_with(lit(Series::new("", &[-7i64, 0, 9])), OutOfBounds::Wrap)])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.i64()?),
+        &[Some(6), Some(1), Some(4)]
+    );
+    Ok(())
+}
 #[test]
 fn test_take_consistency() -> PolarsResult<()> {
     let df = fruits_cars();
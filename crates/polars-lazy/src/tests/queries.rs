@@ -342,7 +342,7 @@ fn test_lazy_query_8() -> PolarsResult<()> {
     let mut selection = vec![];
 
     for c in &["A", "B", "C", "D", "E"] {
-        let e = when(col(c).is_in(col("E")))
+        let e = when(col(c).is_in(col("E"), false))
             .then(col("A"))
             .otherwise(Null {}.lit())
             .alias(c);
@@ -1689,7 +1689,7 @@ fn empty_df() -> PolarsResult<()> {
             col("A").shift_and_fill(lit(1), lit(1)).alias("2"),
             col("A").shift_and_fill(lit(-1), lit(1)).alias("3"),
             col("A").fill_null(lit(1)).alias("4"),
-            col("A").cum_count(false).alias("5"),
+            col("A").cum_count(false, false).alias("5"),
             col("A").diff(1, NullBehavior::Ignore).alias("6"),
             col("A").cum_max(false).alias("7"),
             col("A").cum_min(false).alias("8"),
@@ -1730,7 +1730,7 @@ fn test_is_in() -> PolarsResult<()> {
         .clone()
         .lazy()
         .group_by_stable([col("fruits")])
-        .agg([col("cars").is_in(col("cars").filter(col("cars").eq(lit("beetle"))))])
+        .agg([col("cars").is_in(col("cars").filter(col("cars").eq(lit("beetle"))), false)])
         .collect()?;
     let out = out.column("cars").unwrap();
     let out = out.explode()?;
@@ -1744,7 +1744,7 @@ fn test_is_in() -> PolarsResult<()> {
     let out = df
         .lazy()
         .group_by_stable([col("fruits")])
-        .agg([col("cars").is_in(lit(Series::new("a", ["beetle", "vw"])))])
+        .agg([col("cars").is_in(lit(Series::new("a", ["beetle", "vw"])), false)])
         .collect()?;
 
     let out = out.column("cars").unwrap();
@@ -0,0 +1,44 @@
+use argminmax::ArgMinMax;
+use arrow::array::{FixedSizeListArray, PrimitiveArray};
+use arrow::legacy::utils::CustomIterTools;
+use polars_core::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
+
+use super::min_max::AggType;
+
+fn dispatch_arg_agg<T>(arr: &FixedSizeListArray, width: usize, agg_type: &AggType) -> ArrayRef
+where
+    T: NumericNative,
+    for<'a> &'a [T]: ArgMinMax,
+{
+    let values = arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap();
+    let values = values.values().as_slice();
+
+    let idx: Vec<IdxSize> = values
+        .chunks_exact(width)
+        .map(|sl| match agg_type {
+            AggType::Min => sl.argmin() as IdxSize,
+            AggType::Max => sl.argmax() as IdxSize,
+        })
+        .collect_trusted();
+
+    Box::new(PrimitiveArray::from_data_default(
+        idx.into(),
+        arr.validity().cloned(),
+    ))
+}
+
+/// Fast path for the arg min/max of arrays with a numeric inner type and no inner nulls.
+pub(super) fn arg_agg_numerical(ca: &ArrayChunked, agg_type: AggType) -> IdxCa {
+    let width = ca.width();
+    let chunks: Vec<ArrayRef> = with_match_physical_numeric_polars_type!(ca.inner_dtype(), |$T| {
+        ca.downcast_iter()
+            .map(|arr| dispatch_arg_agg::<<$T as PolarsNumericType>::Native>(arr, width, &agg_type))
+            .collect()
+    });
+    unsafe { IdxCa::from_chunks(ca.name(), chunks) }
+}
@@ -0,0 +1,177 @@
+use arrow::array::FixedSizeListArray;
+use polars_core::frame::NullStrategy;
+use polars_core::utils::try_get_supertype;
+
+use super::*;
+
+/// Reduce equal-width array columns element-wise with a horizontal reduction over their inner
+/// values.
+///
+/// The inner values of null rows are ignored, so a row of the output is only null if it is null
+/// in all inputs.
+fn reduce_arrays_horizontal(
+    s: &[Series],
+    name: &str,
+    reduce: impl Fn(DataFrame) -> PolarsResult<Option<Series>>,
+) -> PolarsResult<Series> {
+    polars_ensure!(!s.is_empty(), ComputeError: "`{}` needs at least one input", name);
+    let mut dtype = s[0].dtype().clone();
+    for other in &s[1..] {
+        dtype = try_get_supertype(&dtype, other.dtype())?;
+    }
+    let DataType::Array(inner_dtype, width) = &dtype else {
+        polars_bail!(
+            InvalidOperation: "`{}` expects equal-width array columns, got dtype `{}`", name, dtype
+        )
+    };
+
+    let len = s[0].len();
+    let mut validity: Option<BooleanChunked> = None;
+    let inner = s
+        .iter()
+        .map(|s| {
+            polars_ensure!(
+                s.len() == len,
+                ShapeMismatch: "`{}` expects all inputs to have the same length, got {} and {}",
+                name, len, s.len()
+            );
+            let s = s.cast(&dtype)?;
+            let ca = s.array()?.rechunk();
+            let values = ca.get_inner();
+
+            let row_valid = ca.is_not_null();
+            validity = Some(match validity.take() {
+                Some(acc) => &acc | &row_valid,
+                None => row_valid.clone(),
+            });
+            if ca.null_count() == 0 {
+                return Ok(values);
+            }
+            // The inner values of null rows are not meaningful.
+            let mask: BooleanChunked = row_valid
+                .into_no_null_iter()
+                .flat_map(|valid| std::iter::repeat(valid).take(*width))
+                .collect();
+            values.zip_with(&mask, &Series::full_null("", values.len(), inner_dtype))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let df = unsafe { DataFrame::new_no_checks(inner) };
+    let values = reduce(df)?.unwrap().cast(inner_dtype)?.rechunk();
+    let validity = validity.unwrap().rechunk();
+    let validity = validity.downcast_iter().next().unwrap().values().clone();
+
+    let data_type =
+        FixedSizeListArray::default_datatype(values.array_ref(0).data_type().clone(), *width);
+    let arr = FixedSizeListArray::new(
+        data_type,
+        values.array_ref(0).clone(),
+        Some(validity).filter(|v| v.unset_bits() > 0),
+    );
+    let mut ca = ArrayChunked::with_chunk(s[0].name(), arr);
+    unsafe { ca.to_logical(*inner_dtype.clone()) };
+    Ok(ca.into_series())
+}
+
+/// Element-wise maximum of equal-width array columns, ignoring null values.
+pub fn arr_max_horizontal(s: &[Series]) -> PolarsResult<Series> {
+    reduce_arrays_horizontal(s, "arr_max_horizontal", |df| df.max_horizontal())
+}
+
+/// Element-wise minimum of equal-width array columns, ignoring null values.
+pub fn arr_min_horizontal(s: &[Series]) -> PolarsResult<Series> {
+    reduce_arrays_horizontal(s, "arr_min_horizontal", |df| df.min_horizontal())
+}
+
+/// Element-wise sum of equal-width numeric array columns, ignoring null values.
+pub fn arr_sum_horizontal(s: &[Series]) -> PolarsResult<Series> {
+    if let Some(DataType::Array(inner, _)) = s.first().map(|s| s.dtype()) {
+        polars_ensure!(
+            inner.is_numeric(),
+            InvalidOperation: "`arr_sum_horizontal` operation not supported for dtype `{}`", s[0].dtype()
+        );
+    }
+    reduce_arrays_horizontal(s, "arr_sum_horizontal", |df| {
+        df.sum_horizontal(NullStrategy::Ignore)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn array(name: &str, values: &[Option<i32>], validity: Option<&[bool]>) -> Series {
+        let values = Series::new(name, values);
+        let mut out = values.reshape_array(&[-1, 2]).unwrap();
+        if let Some(validity) = validity {
+            let mask = BooleanChunked::from_slice("", validity);
+            let nulls = Series::full_null(name, out.len(), out.dtype());
+            out = out.zip_with(&mask, &nulls).unwrap();
+        }
+        out
+    }
+
+    #[test]
+    fn test_arr_max_min_horizontal() -> PolarsResult<()> {
+        let a = array(
+            "a",
+            &[Some(1), None, Some(5), Some(6), Some(0), Some(0)],
+            Some(&[true, true, false]),
+        );
+        let b = array(
+            "b",
+            &[Some(3), None, Some(4), Some(2), Some(9), Some(9)],
+            Some(&[true, true, false]),
+        );
+
+        let out = arr_max_horizontal(&[a.clone(), b.clone()])?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(out.dtype(), &DataType::Array(Box::new(DataType::Int32), 2));
+        let expected = array(
+            "a",
+            &[Some(3), None, Some(5), Some(6), None, None],
+            Some(&[true, true, false]),
+        );
+        assert!(out.equals_missing(&expected));
+
+        let out = arr_min_horizontal(&[a.clone(), b.clone()])?;
+        let expected = array(
+            "a",
+            &[Some(1), None, Some(4), Some(2), None, None],
+            Some(&[true, true, false]),
+        );
+        assert!(out.equals_missing(&expected));
+
+        let c = array(
+            "c",
+            &[Some(3), None, Some(4), Some(2), Some(9), Some(9)],
+            Some(&[true, false, true]),
+        );
+        let out = arr_max_horizontal(&[a.clone(), c])?;
+        let expected = array(
+            "a",
+            &[Some(3), None, Some(5), Some(6), Some(9), Some(9)],
+            None,
+        );
+        assert!(out.equals_missing(&expected));
+
+        let wide = Series::new("w", &[1i32, 2, 3]).reshape_array(&[-1, 3])?;
+        assert!(arr_max_horizontal(&[a, wide]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_arr_sum_horizontal() -> PolarsResult<()> {
+        let a = array("a", &[Some(1), None, Some(5), Some(6)], None);
+        let b = array(
+            "b",
+            &[Some(3), Some(2), None, Some(2)],
+            Some(&[true, false]),
+        );
+
+        let out = arr_sum_horizontal(&[a, b])?;
+        let expected = array("a", &[Some(4), Some(2), Some(5), Some(6)], None);
+        assert!(out.equals_missing(&expected));
+        Ok(())
+    }
+}
@@ -1,8 +1,10 @@
 #[cfg(feature = "array_any_all")]
 mod any_all;
+mod arg_min_max;
 mod count;
 mod dispersion;
 mod get;
+mod horizontal;
 mod join;
 mod min_max;
 mod namespace;
@@ -10,6 +12,7 @@ mod sum_mean;
 #[cfg(feature = "array_to_struct")]
 mod to_struct;
 
+pub use horizontal::*;
 pub use namespace::ArrayNameSpace;
 use polars_core::prelude::*;
 #[cfg(feature = "array_to_struct")]
@@ -1,14 +1,15 @@
+use super::arg_min_max::arg_agg_numerical;
 use super::min_max::AggType;
 use super::*;
 #[cfg(feature = "array_count")]
 use crate::chunked_array::array::count::array_count_matches;
 use crate::chunked_array::array::count::count_boolean_bits;
-use crate::chunked_array::array::sum_mean::sum_with_nulls;
+use crate::chunked_array::array::sum_mean::{mean_with_nulls, sum_with_nulls};
 #[cfg(feature = "array_any_all")]
 use crate::prelude::array::any_all::{array_all, array_any};
 use crate::prelude::array::get::array_get;
 use crate::prelude::array::join::array_join;
-use crate::prelude::array::sum_mean::sum_array_numerical;
+use crate::prelude::array::sum_mean::{mean_array_numerical, sum_array_numerical};
 use crate::series::ArgAgg;
 
 pub fn has_inner_nulls(ca: &ArrayChunked) -> bool {
@@ -51,6 +52,17 @@ pub trait ArrayNameSpace: AsArray {
         }
     }
 
+    fn array_mean(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+
+        match ca.inner_dtype() {
+            dt if dt.is_numeric() && ca.width() > 0 && !has_inner_nulls(ca) => {
+                Ok(mean_array_numerical(ca, &dt))
+            },
+            _ => mean_with_nulls(ca),
+        }
+    }
+
     fn array_median(&self) -> PolarsResult<Series> {
         let ca = self.as_array();
         dispersion::median_with_nulls(ca)
@@ -110,6 +122,9 @@ pub trait ArrayNameSpace: AsArray {
 
     fn array_arg_min(&self) -> IdxCa {
         let ca = self.as_array();
+        if ca.inner_dtype().is_numeric() && ca.width() > 0 && !has_inner_nulls(ca) {
+            return arg_agg_numerical(ca, AggType::Min);
+        }
         ca.apply_amortized_generic(|opt_s| {
             opt_s.and_then(|s| s.as_ref().arg_min().map(|idx| idx as IdxSize))
         })
@@ -117,6 +132,9 @@ pub trait ArrayNameSpace: AsArray {
 
     fn array_arg_max(&self) -> IdxCa {
         let ca = self.as_array();
+        if ca.inner_dtype().is_numeric() && ca.width() > 0 && !has_inner_nulls(ca) {
+            return arg_agg_numerical(ca, AggType::Max);
+        }
         ca.apply_amortized_generic(|opt_s| {
             opt_s.and_then(|s| s.as_ref().arg_max().map(|idx| idx as IdxSize))
         })
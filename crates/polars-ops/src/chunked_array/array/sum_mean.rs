@@ -116,3 +116,75 @@ pub(super) fn sum_with_nulls(ca: &ArrayChunked, inner_dtype: &DataType) -> Polar
     out.rename(ca.name());
     Ok(out)
 }
+
+fn dispatch_mean<T, S>(arr: &dyn Array, width: usize, validity: Option<&Bitmap>) -> ArrayRef
+where
+    T: NativeType + ToPrimitive,
+    S: NativeType + NumCast + std::iter::Sum + std::ops::Div<Output = S>,
+{
+    let values = arr.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let values = values.values().as_slice();
+    let n: S = NumCast::from(width).unwrap();
+
+    let means: Vec<_> = (0..values.len())
+        .step_by(width)
+        .map(|start| {
+            let slice = unsafe { values.get_unchecked(start..start + width) };
+            sum_slice::<T, S>(slice) / n
+        })
+        .collect_trusted();
+
+    Box::new(PrimitiveArray::from_data_default(
+        means.into(),
+        validity.cloned(),
+    )) as ArrayRef
+}
+
+pub(super) fn mean_array_numerical(ca: &ArrayChunked, inner_type: &DataType) -> Series {
+    let width = ca.width();
+    use DataType::*;
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| {
+            let values = arr.values().as_ref();
+
+            match inner_type {
+                Int8 => dispatch_mean::<i8, f64>(values, width, arr.validity()),
+                Int16 => dispatch_mean::<i16, f64>(values, width, arr.validity()),
+                Int32 => dispatch_mean::<i32, f64>(values, width, arr.validity()),
+                Int64 => dispatch_mean::<i64, f64>(values, width, arr.validity()),
+                UInt8 => dispatch_mean::<u8, f64>(values, width, arr.validity()),
+                UInt16 => dispatch_mean::<u16, f64>(values, width, arr.validity()),
+                UInt32 => dispatch_mean::<u32, f64>(values, width, arr.validity()),
+                UInt64 => dispatch_mean::<u64, f64>(values, width, arr.validity()),
+                Float32 => dispatch_mean::<f32, f32>(values, width, arr.validity()),
+                Float64 => dispatch_mean::<f64, f64>(values, width, arr.validity()),
+                _ => unimplemented!(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Series::try_from((ca.name(), chunks)).unwrap()
+}
+
+pub(super) fn mean_with_nulls(ca: &ArrayChunked) -> PolarsResult<Series> {
+    let mut out = match ca.inner_dtype() {
+        DataType::Float32 => {
+            let out: Float32Chunked = ca
+                .apply_amortized_generic(|s| s.and_then(|s| s.as_ref().mean().map(|v| v as f32)))
+                .with_name(ca.name());
+            out.into_series()
+        },
+        dt if dt.is_numeric() || matches!(dt, DataType::Boolean) => {
+            let out: Float64Chunked = ca
+                .apply_amortized_generic(|s| s.and_then(|s| s.as_ref().mean()))
+                .with_name(ca.name());
+            out.into_series()
+        },
+        dt => {
+            polars_bail!(InvalidOperation: "`mean` operation not supported for array with inner dtype {}", dt)
+        },
+    };
+    out.rename(ca.name());
+    Ok(out)
+}
@@ -12,12 +12,14 @@ fn compute_hist<T>(
     bins: Option<&[f64]>,
     include_category: bool,
     include_breakpoint: bool,
+    density: bool,
 ) -> Series
 where
     T: PolarsNumericType,
     ChunkedArray<T>: ChunkAgg<T::Native>,
 {
-    let (breaks, count) = if let Some(bins) = bins {
+    // `widths` holds the width of the interval each bin actually counts.
+    let (breaks, count, widths) = if let Some(bins) = bins {
         let mut breaks = Vec::with_capacity(bins.len() + 1);
         breaks.extend_from_slice(bins);
         breaks.sort_unstable_by_key(|k| k.to_total_ord());
@@ -60,11 +62,16 @@ where
         while count.len() < breaks.len() {
             count.push(0)
         }
-        (breaks, count)
+        let widths = std::iter::once(f64::NEG_INFINITY)
+            .chain(breaks.iter().copied())
+            .zip(breaks.iter())
+            .map(|(lower, upper)| upper - lower)
+            .collect();
+        (breaks, count, widths)
     } else if ca.null_count() == ca.len() {
         let breaks: Vec<f64> = vec![f64::INFINITY];
         let count: Vec<IdxSize> = vec![0];
-        (breaks, count)
+        (breaks, count, vec![f64::INFINITY])
     } else {
         let min = ChunkAgg::min(ca).unwrap().to_f64().unwrap();
         let max = ChunkAgg::max(ca).unwrap().to_f64().unwrap();
@@ -112,9 +119,11 @@ where
                 count[idx] += 1;
             }
         }
-        (breaks, count)
+        // The outer bins only count values within one interval of the data range.
+        let widths = vec![interval; breaks.len()];
+        (breaks, count, widths)
     };
-    let mut fields = Vec::with_capacity(3);
+    let mut fields = Vec::with_capacity(4);
     if include_category {
         // Use AnyValue for formatting.
         let mut lower = AnyValue::Float64(f64::NEG_INFINITY);
@@ -138,8 +147,24 @@ where
         fields.insert(0, Series::new("break_point", breaks))
     }
 
+    let density = density.then(|| {
+        let total = count.iter().sum::<IdxSize>() as f64;
+        count
+            .iter()
+            .zip(widths)
+            .map(|(&c, width)| {
+                let norm = total * width;
+                // Zero-width and unbounded bins have no meaningful density.
+                (norm.is_finite() && norm > 0.0).then(|| c as f64 / norm)
+            })
+            .collect::<Float64Chunked>()
+            .with_name("density")
+            .into_series()
+    });
+
     let count = Series::new("count", count);
     fields.push(count);
+    fields.extend(density);
 
     if fields.len() == 1 {
         let out = fields.pop().unwrap();
@@ -151,12 +176,19 @@ where
     }
 }
 
+/// Compute the histogram of `s`, either over the given `bins` or over `bin_count` equal-width
+/// bins spanning the data.
+///
+/// If `density` is set, a `Float64` `density` field is added after `count`, holding
+/// `count / (total_count * bin_width)`. Bins with zero width (e.g. duplicate `bins`) or an
+/// unbounded width (the outer bins when `bins` is given) have a null density.
 pub fn hist_series(
     s: &Series,
     bin_count: Option<usize>,
     bins: Option<Series>,
     include_category: bool,
     include_breakpoint: bool,
+    density: bool,
 ) -> PolarsResult<Series> {
     let mut bins_arg = None;
 
@@ -174,7 +206,7 @@ pub fn hist_series(
 
     let out = with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
          let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
-         compute_hist(ca, bin_count, bins_arg, include_category, include_breakpoint)
+         compute_hist(ca, bin_count, bins_arg, include_category, include_breakpoint, density)
     });
     Ok(out)
 }
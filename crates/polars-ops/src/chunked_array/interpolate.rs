@@ -19,10 +19,11 @@ fn nearest_itp<T>(low: T, step: T, diff: T, steps_n: T) -> T
 where
     T: Sub<Output = T> + Mul<Output = T> + Add<Output = T> + Div<Output = T> + PartialOrd + Copy,
 {
-    // 5 - 1 = 5 -> low
+    // 5 - 1 = 4 -> low
     // 5 - 2 = 3 -> low
     // 5 - 3 = 2 -> high
-    if (steps_n - step) > step {
+    // Ties are broken toward the earlier point.
+    if (steps_n - step) >= step {
         low
     } else {
         low + diff
@@ -227,13 +228,52 @@ where
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum InterpolationMethod {
     Linear,
+    /// Fill with the closest non-null neighbour, preferring the earlier one on ties. The
+    /// dtype is preserved.
     Nearest,
 }
 
-pub fn interpolate(s: &Series, method: InterpolationMethod) -> Series {
-    match method {
+/// Set the filled values of every gap for which `is_too_long(low, high)` holds back to null,
+/// with `low` and `high` the indices of the non-null values around the gap.
+pub(crate) fn null_long_gaps(
+    out: &Series,
+    is_valid: &BooleanChunked,
+    is_too_long: impl Fn(usize, usize) -> bool,
+) -> Series {
+    let mut keep = vec![true; is_valid.len()];
+    let mut low = None;
+    for (i, valid) in is_valid.into_no_null_iter().enumerate() {
+        if valid {
+            if let Some(low) = low.filter(|low| i - low > 1 && is_too_long(*low, i)) {
+                keep[low + 1..i].fill(false);
+            }
+            low = Some(i);
+        }
+    }
+    if keep.iter().all(|keep| *keep) {
+        return out.clone();
+    }
+    let keep = BooleanChunked::from_slice("", &keep);
+    out.zip_with(&keep, &Series::full_null("", out.len(), out.dtype()))
+        .unwrap()
+}
+
+/// Fill the null values of `s` by interpolating between their non-null neighbours.
+///
+/// If `max_gap` is given, runs of more than `max_gap` consecutive nulls are not filled and
+/// remain null.
+pub fn interpolate(s: &Series, method: InterpolationMethod, max_gap: Option<IdxSize>) -> Series {
+    let out = match method {
         InterpolationMethod::Linear => interpolate_linear(s),
         InterpolationMethod::Nearest => interpolate_nearest(s),
+    };
+    match max_gap {
+        Some(max_gap) if s.null_count() > 0 => {
+            null_long_gaps(&out, &s.is_not_null(), |low, high| {
+                high - low - 1 > max_gap as usize
+            })
+        },
+        _ => out,
     }
 }
 
@@ -244,7 +284,7 @@ mod test {
     #[test]
     fn test_interpolate() {
         let ca = UInt32Chunked::new("", &[Some(1), None, None, Some(4), Some(5)]);
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear, None);
         let out = out.f64().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -252,7 +292,7 @@ mod test {
         );
 
         let ca = UInt32Chunked::new("", &[None, Some(1), None, None, Some(4), Some(5)]);
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear, None);
         let out = out.f64().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -260,7 +300,7 @@ mod test {
         );
 
         let ca = UInt32Chunked::new("", &[None, Some(1), None, None, Some(4), Some(5), None]);
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear, None);
         let out = out.f64().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -275,7 +315,7 @@ mod test {
             ]
         );
         let ca = UInt32Chunked::new("", &[None, Some(1), None, None, Some(4), Some(5), None]);
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Nearest);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Nearest, None);
         let out = out.u32().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -283,10 +323,83 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_interpolate_max_gap() {
+        let ca = Int32Chunked::new(
+            "a",
+            &[
+                None,
+                Some(1),
+                None,
+                Some(3),
+                None,
+                None,
+                None,
+                Some(7),
+                None,
+            ],
+        );
+        let s = ca.into_series();
+        let out = interpolate(&s, InterpolationMethod::Linear, Some(2));
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[
+                None,
+                Some(1.0),
+                Some(2.0),
+                Some(3.0),
+                None,
+                None,
+                None,
+                Some(7.0),
+                None
+            ]
+        );
+
+        let out = interpolate(&s, InterpolationMethod::Nearest, Some(3));
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[
+                None,
+                Some(1),
+                Some(1),
+                Some(3),
+                Some(3),
+                Some(3),
+                Some(7),
+                Some(7),
+                None
+            ]
+        );
+
+        let out = interpolate(&s, InterpolationMethod::Nearest, Some(0));
+        assert!(out.equals_missing(&s));
+    }
+
+    #[test]
+    fn test_interpolate_nearest_ties() {
+        let ca = Int32Chunked::new("", &[Some(1), None, Some(3), None, None, None, Some(7)]);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Nearest, None);
+        let out = out.i32().unwrap();
+        assert_eq!(
+            Vec::from(out),
+            &[
+                Some(1),
+                Some(1),
+                Some(3),
+                Some(3),
+                Some(3),
+                Some(7),
+                Some(7)
+            ]
+        );
+    }
+
     #[test]
     fn test_interpolate_decreasing_unsigned() {
         let ca = UInt32Chunked::new("", &[Some(4), None, None, Some(1)]);
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear, None);
         let out = out.f64().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -314,7 +427,7 @@ mod test {
                 Some(4660f32),
             ],
         );
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear, None);
         let out = out.f32().unwrap();
 
         assert_eq!(
@@ -40,12 +40,39 @@ fn count_bits_set_by_offsets(values: &Bitmap, offset: &[i64]) -> Vec<IdxSize> {
         .collect_trusted()
 }
 
+/// Count how often `element` occurs in each sublist, where nulls match nulls.
+///
+/// `element` is either broadcast if it has length 1, or matched per row.
 #[cfg(feature = "list_count")]
-pub fn list_count_matches(ca: &ListChunked, value: AnyValue) -> PolarsResult<Series> {
-    let value = Series::new("", [value]);
+pub fn list_count_matches(ca: &ListChunked, element: &Series) -> PolarsResult<Series> {
+    let element = match element.len() {
+        1 => element.clone(),
+        len if len == ca.len() => {
+            // Repeat each row's element for every value in that row's sublist, so that it
+            // can be compared against the inner values directly.
+            let ca = ca.rechunk();
+            let Some(arr) = ca.downcast_iter().next() else {
+                return Ok(IdxCa::full_null(ca.name(), 0).into_series());
+            };
+            let offsets = arr.offsets().as_slice();
+            let mut idx = Vec::with_capacity(arr.values().len());
+            // Values outside the offsets are never counted, any index will do.
+            idx.resize(offsets[0] as usize, 0);
+            for (i, w) in offsets.windows(2).enumerate() {
+                idx.extend(std::iter::repeat(i as IdxSize).take((w[1] - w[0]) as usize));
+            }
+            idx.resize(arr.values().len(), 0);
+            // SAFETY: all indices are smaller than `len`.
+            unsafe { element.take_unchecked(&IdxCa::from_vec("", idx)) }
+        },
+        len => polars_bail!(
+            ShapeMismatch: "`element` in `list.count_matches` must have length 1 or {}, got {}",
+            ca.len(), len
+        ),
+    };
 
     let ca = ca.apply_to_inner(&|s| {
-        ChunkCompare::<&Series>::equal_missing(&s, &value).map(|ca| ca.into_series())
+        ChunkCompare::<&Series>::equal_missing(&s, &element).map(|ca| ca.into_series())
     })?;
     let out = count_boolean_bits(&ca);
     Ok(out.into_series())
@@ -1,4 +1,151 @@
+use arrow::array::{Array, PrimitiveArray};
+use arrow::bitmap::Bitmap;
+use arrow::types::NativeType;
+use polars_core::export::num::{NumCast, ToPrimitive};
+use polars_utils::total_ord::TotalOrd;
+
 use super::*;
+use crate::chunked_array::sum::sum_slice;
+
+#[derive(Copy, Clone)]
+enum Dispersion {
+    Median,
+    Std(u8),
+    Var(u8),
+}
+
+fn var_slice<T>(values: &[T], ddof: u8) -> Option<f64>
+where
+    T: NativeType + ToPrimitive,
+{
+    let n_values = values.len();
+    if n_values <= ddof as usize {
+        return None;
+    }
+    let mean = sum_slice::<_, f64>(values) / n_values as f64;
+    let squared: f64 = values
+        .iter()
+        .map(|value| {
+            let tmp = value.to_f64().unwrap() - mean;
+            tmp * tmp
+        })
+        .sum();
+    Some(squared / (n_values as f64 - ddof as f64))
+}
+
+/// `buf` is scratch space that is reused between calls to avoid allocating per sublist.
+fn median_slice<T>(values: &[T], buf: &mut Vec<f64>) -> Option<f64>
+where
+    T: NativeType + ToPrimitive,
+{
+    let n_values = values.len();
+    if n_values == 0 {
+        return None;
+    }
+    buf.clear();
+    buf.extend(values.iter().map(|value| value.to_f64().unwrap()));
+    let (lower, upper, _) = buf.select_nth_unstable_by(n_values / 2, |a, b| a.tot_cmp(b));
+    let upper = *upper;
+    if n_values % 2 == 1 {
+        Some(upper)
+    } else {
+        let lower = lower.iter().copied().max_by(|a, b| a.tot_cmp(b)).unwrap();
+        Some((lower + upper) / 2.0)
+    }
+}
+
+fn dispersion_between_offsets<T, S>(
+    values: &[T],
+    offset: &[i64],
+    dispersion: Dispersion,
+) -> PrimitiveArray<S>
+where
+    T: NativeType + ToPrimitive,
+    S: NativeType + NumCast,
+{
+    let mut buf = vec![];
+    offset
+        .windows(2)
+        .map(|w| {
+            let values = values.get(w[0] as usize..w[1] as usize)?;
+            let out = match dispersion {
+                Dispersion::Median => median_slice(values, &mut buf),
+                Dispersion::Std(ddof) => var_slice(values, ddof).map(|var| var.sqrt()),
+                Dispersion::Var(ddof) => var_slice(values, ddof),
+            };
+            out.and_then(NumCast::from)
+        })
+        .collect()
+}
+
+fn dispatch_dispersion<T, S>(
+    arr: &dyn Array,
+    offsets: &[i64],
+    validity: Option<&Bitmap>,
+    dispersion: Dispersion,
+) -> ArrayRef
+where
+    T: NativeType + ToPrimitive,
+    S: NativeType + NumCast,
+{
+    let values = arr.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let values = values.values().as_slice();
+    let mut out = dispersion_between_offsets::<_, S>(values, offsets, dispersion);
+    if let Some(validity) = validity {
+        if out.has_validity() {
+            out.apply_validity(|other_validity| validity & &other_validity)
+        } else {
+            out = out.with_validity(Some(validity.clone()));
+        }
+    }
+    Box::new(out)
+}
+
+/// Compute `dispersion` directly on the values of a list of numbers without nulls, instead of
+/// going through a [`Series`] per sublist.
+fn dispersion_list_numerical(
+    ca: &ListChunked,
+    inner_type: &DataType,
+    dispersion: Dispersion,
+) -> Series {
+    use DataType::*;
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| {
+            let offsets = arr.offsets().as_slice();
+            let values = arr.values().as_ref();
+            let validity = arr.validity();
+
+            match inner_type {
+                Int8 => dispatch_dispersion::<i8, f64>(values, offsets, validity, dispersion),
+                Int16 => dispatch_dispersion::<i16, f64>(values, offsets, validity, dispersion),
+                Int32 => dispatch_dispersion::<i32, f64>(values, offsets, validity, dispersion),
+                Int64 => dispatch_dispersion::<i64, f64>(values, offsets, validity, dispersion),
+                UInt8 => dispatch_dispersion::<u8, f64>(values, offsets, validity, dispersion),
+                UInt16 => dispatch_dispersion::<u16, f64>(values, offsets, validity, dispersion),
+                UInt32 => dispatch_dispersion::<u32, f64>(values, offsets, validity, dispersion),
+                UInt64 => dispatch_dispersion::<u64, f64>(values, offsets, validity, dispersion),
+                Float32 => dispatch_dispersion::<f32, f32>(values, offsets, validity, dispersion),
+                Float64 => dispatch_dispersion::<f64, f64>(values, offsets, validity, dispersion),
+                _ => unimplemented!(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Series::try_from((ca.name(), chunks)).unwrap()
+}
+
+pub(super) fn median_list_numerical(ca: &ListChunked, inner_type: &DataType) -> Series {
+    dispersion_list_numerical(ca, inner_type, Dispersion::Median)
+}
+
+pub(super) fn std_list_numerical(ca: &ListChunked, inner_type: &DataType, ddof: u8) -> Series {
+    dispersion_list_numerical(ca, inner_type, Dispersion::Std(ddof))
+}
+
+pub(super) fn var_list_numerical(ca: &ListChunked, inner_type: &DataType, ddof: u8) -> Series {
+    dispersion_list_numerical(ca, inner_type, Dispersion::Var(ddof))
+}
 
 pub(super) fn median_with_nulls(ca: &ListChunked) -> Series {
     return match ca.inner_dtype() {
@@ -7,6 +7,7 @@ mod dispersion;
 #[cfg(feature = "hash")]
 pub(crate) mod hash;
 mod min_max;
+mod n_unique;
 mod namespace;
 #[cfg(feature = "list_sets")]
 mod sets;
@@ -0,0 +1,80 @@
+use std::hash::Hash;
+
+use arrow::array::{Array, PrimitiveArray};
+use arrow::bitmap::Bitmap;
+use arrow::types::NativeType;
+use polars_core::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::total_ord::ToTotalOrd;
+
+/// Count the distinct values of every sublist. A single hash set is reused for all
+/// sublists, so the unique values are never materialized.
+fn n_unique_between_offsets<T>(
+    arr: &dyn Array,
+    offsets: &[i64],
+    validity: Option<&Bitmap>,
+) -> PrimitiveArray<IdxSize>
+where
+    T: NativeType + ToTotalOrd,
+    <T as ToTotalOrd>::TotalOrdItem: Hash + Eq,
+{
+    let arr = arr.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let values = arr.values().as_slice();
+    let inner_validity = arr.validity();
+    let mut set = PlHashSet::new();
+
+    offsets
+        .windows(2)
+        .enumerate()
+        .map(|(i, w)| {
+            if !validity.map_or(true, |v| v.get_bit(i)) {
+                return None;
+            }
+            let (start, end) = (w[0] as usize, w[1] as usize);
+            set.clear();
+            set.reserve(end - start);
+
+            let mut has_null = false;
+            match inner_validity {
+                None => set.extend(values[start..end].iter().map(|v| v.to_total_ord())),
+                Some(inner_validity) => {
+                    for (idx, v) in (start..end).zip(&values[start..end]) {
+                        if inner_validity.get_bit(idx) {
+                            set.insert(v.to_total_ord());
+                        } else {
+                            has_null = true;
+                        }
+                    }
+                },
+            }
+            Some((set.len() + has_null as usize) as IdxSize)
+        })
+        .collect()
+}
+
+pub(super) fn list_n_unique(ca: &ListChunked) -> PolarsResult<IdxCa> {
+    let inner_dtype = ca.inner_dtype();
+    let physical = inner_dtype.to_physical();
+    if !(inner_dtype.is_numeric() || inner_dtype.is_temporal()) {
+        return ca.try_apply_amortized_generic(|s| {
+            let opt_v = s.map(|s| s.as_ref().n_unique()).transpose()?;
+            Ok(opt_v.map(|idx| idx as IdxSize))
+        });
+    }
+
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| {
+            let offsets = arr.offsets().as_slice();
+            let values = arr.values().as_ref();
+            with_match_physical_numeric_polars_type!(&physical, |$T| {
+                n_unique_between_offsets::<<$T as PolarsNumericType>::Native>(
+                    values,
+                    offsets,
+                    arr.validity(),
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    Ok(IdxCa::from_chunk_iter(ca.name(), chunks))
+}
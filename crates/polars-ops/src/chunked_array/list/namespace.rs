@@ -1,5 +1,3 @@
-use std::fmt::Write;
-
 use arrow::array::ValueSize;
 use arrow::legacy::kernels::list::{index_is_oob, sublist_get};
 use polars_core::chunked_array::builder::get_list_builder;
@@ -15,10 +13,11 @@ use super::*;
 #[cfg(feature = "list_any_all")]
 use crate::chunked_array::list::any_all::*;
 use crate::chunked_array::list::min_max::{list_max_function, list_min_function};
+use crate::chunked_array::list::n_unique::list_n_unique;
 use crate::chunked_array::list::sum_mean::sum_with_nulls;
-#[cfg(feature = "diff")]
-use crate::prelude::diff;
 use crate::prelude::list::sum_mean::{mean_list_numerical, sum_list_numerical};
+#[cfg(feature = "diff")]
+use crate::prelude::{diff, diff_signed_dtype};
 use crate::series::ArgAgg;
 
 pub(super) fn has_inner_nulls(ca: &ListChunked) -> bool {
@@ -103,24 +102,7 @@ pub trait ListNameSpaceImpl: AsList {
 
         ca.for_each_amortized(|opt_s| {
             let opt_val = opt_s.and_then(|s| {
-                // make sure that we don't write values of previous iteration
-                buf.clear();
-                let ca = s.as_ref().str().unwrap();
-
-                if ca.null_count() != 0 && !ignore_nulls {
-                    return None;
-                }
-
-                for arr in ca.downcast_iter() {
-                    for val in arr.non_null_values_iter() {
-                        buf.write_str(val).unwrap();
-                        buf.write_str(separator).unwrap();
-                    }
-                }
-
-                // last value should not have a separator, so slice that off
-                // saturating sub because there might have been nothing written.
-                Some(&buf[..buf.len().saturating_sub(separator.len())])
+                join_sublist(&mut buf, s.as_ref().str().unwrap(), separator, ignore_nulls)
             });
             builder.append_option(opt_val)
         });
@@ -143,24 +125,8 @@ pub trait ListNameSpaceImpl: AsList {
                 .for_each(|(opt_s, opt_sep)| match opt_sep {
                     Some(separator) => {
                         let opt_val = opt_s.and_then(|s| {
-                            // make sure that we don't write values of previous iteration
-                            buf.clear();
                             let ca = s.as_ref().str().unwrap();
-
-                            if ca.null_count() != 0 && !ignore_nulls {
-                                return None;
-                            }
-
-                            for arr in ca.downcast_iter() {
-                                for val in arr.non_null_values_iter() {
-                                    buf.write_str(val).unwrap();
-                                    buf.write_str(separator).unwrap();
-                                }
-                            }
-
-                            // last value should not have a separator, so slice that off
-                            // saturating sub because there might have been nothing written.
-                            Some(&buf[..buf.len().saturating_sub(separator.len())])
+                            join_sublist(&mut buf, ca, separator, ignore_nulls)
                         });
                         builder.append_option(opt_val)
                     },
@@ -219,17 +185,41 @@ pub trait ListNameSpaceImpl: AsList {
 
     fn lst_median(&self) -> Series {
         let ca = self.as_list();
-        dispersion::median_with_nulls(ca)
+
+        if has_inner_nulls(ca) {
+            return dispersion::median_with_nulls(ca);
+        };
+
+        match ca.inner_dtype() {
+            dt if dt.is_numeric() => dispersion::median_list_numerical(ca, &dt),
+            _ => dispersion::median_with_nulls(ca),
+        }
     }
 
     fn lst_std(&self, ddof: u8) -> Series {
         let ca = self.as_list();
-        dispersion::std_with_nulls(ca, ddof)
+
+        if has_inner_nulls(ca) {
+            return dispersion::std_with_nulls(ca, ddof);
+        };
+
+        match ca.inner_dtype() {
+            dt if dt.is_numeric() => dispersion::std_list_numerical(ca, &dt, ddof),
+            _ => dispersion::std_with_nulls(ca, ddof),
+        }
     }
 
     fn lst_var(&self, ddof: u8) -> Series {
         let ca = self.as_list();
-        dispersion::var_with_nulls(ca, ddof)
+
+        if has_inner_nulls(ca) {
+            return dispersion::var_with_nulls(ca, ddof);
+        };
+
+        match ca.inner_dtype() {
+            dt if dt.is_numeric() => dispersion::var_list_numerical(ca, &dt, ddof),
+            _ => dispersion::var_with_nulls(ca, ddof),
+        }
     }
 
     fn same_type(&self, out: ListChunked) -> ListChunked {
@@ -244,7 +234,7 @@ pub trait ListNameSpaceImpl: AsList {
 
     fn lst_sort(&self, options: SortOptions) -> PolarsResult<ListChunked> {
         let ca = self.as_list();
-        let out = ca.try_apply_amortized(|s| s.as_ref().sort_with(options))?;
+        let out = ca.try_apply_amortized(|s| sort_sublist(s.as_ref(), options))?;
         Ok(self.same_type(out))
     }
 
@@ -256,11 +246,7 @@ pub trait ListNameSpaceImpl: AsList {
     }
 
     fn lst_n_unique(&self) -> PolarsResult<IdxCa> {
-        let ca = self.as_list();
-        ca.try_apply_amortized_generic(|s| {
-            let opt_v = s.map(|s| s.as_ref().n_unique()).transpose()?;
-            Ok(opt_v.map(|idx| idx as IdxSize))
-        })
+        list_n_unique(self.as_list())
     }
 
     fn lst_unique(&self) -> PolarsResult<ListChunked> {
@@ -292,6 +278,18 @@ pub trait ListNameSpaceImpl: AsList {
     #[cfg(feature = "diff")]
     fn lst_diff(&self, n: i64, null_behavior: NullBehavior) -> PolarsResult<ListChunked> {
         let ca = self.as_list();
+        // Widen up front, so empty columns and all-null sublists get the output dtype too.
+        let widened;
+        let ca = match diff_signed_dtype(&ca.inner_dtype()) {
+            Some(dtype) => {
+                widened = ca.cast(&DataType::List(Box::new(dtype)))?;
+                widened.list().unwrap()
+            },
+            None => ca,
+        };
+        if ca.null_count() == ca.len() {
+            return Ok(ca.clone());
+        }
         ca.try_apply_amortized(|s| diff(s.as_ref(), n, null_behavior))
     }
 
@@ -307,13 +305,62 @@ pub trait ListNameSpaceImpl: AsList {
                     ListChunked::full_null_with_dtype(ca.name(), ca.len(), &ca.inner_dtype())
                 }
             },
-            _ => ca.zip_and_apply_amortized(periods, |opt_s, opt_periods| {
-                match (opt_s, opt_periods) {
-                    (Some(s), Some(periods)) => Some(s.as_ref().shift(periods)),
-                    _ => None,
-                }
-            }),
+            n => {
+                polars_ensure!(
+                    n == ca.len(),
+                    ShapeMismatch: "shift periods length {} does not match list length of {}",
+                    n, ca.len()
+                );
+                ca.zip_and_apply_amortized(periods, |opt_s, opt_periods| {
+                    match (opt_s, opt_periods) {
+                        (Some(s), Some(periods)) => Some(s.as_ref().shift(periods)),
+                        _ => None,
+                    }
+                })
+            },
+        };
+        Ok(self.same_type(out))
+    }
+
+    /// Keep the elements of every sublist for which the matching element of the `mask`
+    /// sublist is `true`. Null mask elements drop the element.
+    fn lst_filter(&self, mask: &ListChunked) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        polars_ensure!(
+            mask.inner_dtype() == DataType::Boolean,
+            ComputeError: "list.filter mask must be of type list[bool], got {}", mask.dtype()
+        );
+        let mask = match mask.len() {
+            1 if ca.len() != 1 => mask.new_from_index(0, ca.len()),
+            n => {
+                polars_ensure!(
+                    n == ca.len(),
+                    ShapeMismatch: "mask length {} does not match list length of {}",
+                    n, ca.len()
+                );
+                mask.clone()
+            },
+        };
+
+        // SAFETY: unstable series never lives longer than the iterator.
+        let mut out: ListChunked = unsafe {
+            ca.amortized_iter()
+                .zip(mask.amortized_iter())
+                .map(|(opt_s, opt_mask)| match (opt_s, opt_mask) {
+                    (Some(s), Some(mask)) => {
+                        let (s, mask) = (s.as_ref(), mask.as_ref());
+                        polars_ensure!(
+                            s.len() == mask.len(),
+                            ComputeError: "list.filter mask sublist of length {} does not match \
+                            sublist of length {}", mask.len(), s.len()
+                        );
+                        s.filter(mask.bool()?).map(Some)
+                    },
+                    _ => Ok(None),
+                })
+                .collect::<PolarsResult<_>>()?
         };
+        out.rename(ca.name());
         Ok(self.same_type(out))
     }
 
@@ -362,6 +409,10 @@ pub trait ListNameSpaceImpl: AsList {
     #[cfg(feature = "list_gather")]
     fn lst_gather_every(&self, n: &IdxCa, offset: &IdxCa) -> PolarsResult<Series> {
         let list_ca = self.as_list();
+        polars_ensure!(
+            n.min() != Some(0),
+            ComputeError: "`n` should be positive in `gather_every`"
+        );
         let out = match (n.len(), offset.len()) {
             (1, 1) => match (n.get(0), offset.get(0)) {
                 (Some(n), Some(offset)) => list_ca
@@ -508,6 +559,11 @@ pub trait ListNameSpaceImpl: AsList {
         list_ca.apply_amortized(|s| s.as_ref().drop_nulls())
     }
 
+    /// Sample `n` elements from each sublist.
+    ///
+    /// If `seed` is given, the sublist at row `i` is sampled with seed `seed + i`. If `clamp`
+    /// is set, `n` is capped at the sublist length when sampling without replacement,
+    /// instead of raising an error.
     #[cfg(feature = "list_sample")]
     fn lst_sample_n(
         &self,
@@ -515,38 +571,28 @@ pub trait ListNameSpaceImpl: AsList {
         with_replacement: bool,
         shuffle: bool,
         seed: Option<u64>,
+        clamp: bool,
     ) -> PolarsResult<ListChunked> {
         let ca = self.as_list();
 
         let n_s = n.cast(&IDX_DTYPE)?;
         let n = n_s.idx()?;
 
-        let out = match n.len() {
-            1 => {
-                if let Some(n) = n.get(0) {
-                    ca.try_apply_amortized(|s| {
-                        s.as_ref()
-                            .sample_n(n as usize, with_replacement, shuffle, seed)
-                    })
-                } else {
-                    Ok(ListChunked::full_null_with_dtype(
-                        ca.name(),
-                        ca.len(),
-                        &ca.inner_dtype(),
-                    ))
-                }
-            },
-            _ => ca.try_zip_and_apply_amortized(n, |opt_s, opt_n| match (opt_s, opt_n) {
-                (Some(s), Some(n)) => s
-                    .as_ref()
-                    .sample_n(n as usize, with_replacement, shuffle, seed)
-                    .map(Some),
-                _ => Ok(None),
-            }),
-        };
+        let out = sample_sublists(
+            ca,
+            n,
+            |n, _| n as usize,
+            with_replacement,
+            shuffle,
+            seed,
+            clamp,
+        );
         out.map(|ok| self.same_type(ok))
     }
 
+    /// Sample a `fraction` of the elements of each sublist.
+    ///
+    /// See [`ListNameSpaceImpl::lst_sample_n`] for how `seed` and `clamp` are used.
     #[cfg(feature = "list_sample")]
     fn lst_sample_fraction(
         &self,
@@ -554,37 +600,22 @@ pub trait ListNameSpaceImpl: AsList {
         with_replacement: bool,
         shuffle: bool,
         seed: Option<u64>,
+        clamp: bool,
     ) -> PolarsResult<ListChunked> {
         let ca = self.as_list();
 
         let fraction_s = fraction.cast(&DataType::Float64)?;
         let fraction = fraction_s.f64()?;
 
-        let out = match fraction.len() {
-            1 => {
-                if let Some(fraction) = fraction.get(0) {
-                    ca.try_apply_amortized(|s| {
-                        let n = (s.as_ref().len() as f64 * fraction) as usize;
-                        s.as_ref().sample_n(n, with_replacement, shuffle, seed)
-                    })
-                } else {
-                    Ok(ListChunked::full_null_with_dtype(
-                        ca.name(),
-                        ca.len(),
-                        &ca.inner_dtype(),
-                    ))
-                }
-            },
-            _ => ca.try_zip_and_apply_amortized(fraction, |opt_s, opt_n| match (opt_s, opt_n) {
-                (Some(s), Some(fraction)) => {
-                    let n = (s.as_ref().len() as f64 * fraction) as usize;
-                    s.as_ref()
-                        .sample_n(n, with_replacement, shuffle, seed)
-                        .map(Some)
-                },
-                _ => Ok(None),
-            }),
-        };
+        let out = sample_sublists(
+            ca,
+            fraction,
+            |fraction, len| (len as f64 * fraction) as usize,
+            with_replacement,
+            shuffle,
+            seed,
+            clamp,
+        );
         out.map(|ok| self.same_type(ok))
     }
 
@@ -743,6 +774,108 @@ pub trait ListNameSpaceImpl: AsList {
 
 impl ListNameSpaceImpl for ListChunked {}
 
+/// Sort a sublist. The nulls are split off and placed explicitly, so that `nulls_last` means
+/// the same for every inner dtype.
+/// Join the strings of a single sublist with `separator` into `buf`.
+///
+/// The joined length is computed up front so that `buf` grows at most once per sublist, however
+/// wide it is. Returns `None` if the sublist contains nulls and `ignore_nulls` is not set.
+fn join_sublist<'a>(
+    buf: &'a mut String,
+    ca: &StringChunked,
+    separator: &str,
+    ignore_nulls: bool,
+) -> Option<&'a str> {
+    // Make sure that we don't write values of the previous iteration.
+    buf.clear();
+    if ca.null_count() != 0 && !ignore_nulls {
+        return None;
+    }
+
+    let n_values = ca.len() - ca.null_count();
+    let values_len = ca
+        .downcast_iter()
+        .flat_map(|arr| arr.non_null_values_iter())
+        .map(str::len)
+        .sum::<usize>();
+    buf.reserve(values_len + separator.len() * n_values.saturating_sub(1));
+
+    let values = ca
+        .downcast_iter()
+        .flat_map(|arr| arr.non_null_values_iter());
+    for (i, val) in values.enumerate() {
+        if i > 0 {
+            buf.push_str(separator);
+        }
+        buf.push_str(val);
+    }
+    Some(buf.as_str())
+}
+
+fn sort_sublist(s: &Series, options: SortOptions) -> PolarsResult<Series> {
+    if s.len() <= 1 {
+        return Ok(s.clone());
+    }
+    let nulls_last = options.nulls_last;
+    let options = options.with_nulls_last(false);
+    if s.null_count() == 0 {
+        return s.sort_with(options);
+    }
+
+    let values = s.drop_nulls().sort_with(options)?;
+    let nulls = s.filter(&s.is_null())?;
+    let (mut out, tail) = if nulls_last {
+        (values, nulls)
+    } else {
+        (nulls, values)
+    };
+    out.append(&tail)?;
+    Ok(out)
+}
+
+/// Sample each sublist, where `sample_size(size, len)` gives the number of elements to take
+/// from a sublist of length `len`. `sizes` is broadcast if it has length 1.
+///
+/// Every row gets its own seed derived from `seed` and the row index, so that sublists of
+/// the same length aren't all sampled identically, and the result doesn't depend on how
+/// `ca` is chunked.
+#[cfg(feature = "list_sample")]
+fn sample_sublists<T, F>(
+    ca: &ListChunked,
+    sizes: &ChunkedArray<T>,
+    sample_size: F,
+    with_replacement: bool,
+    shuffle: bool,
+    seed: Option<u64>,
+    clamp: bool,
+) -> PolarsResult<ListChunked>
+where
+    T: PolarsNumericType,
+    F: Fn(T::Native, usize) -> usize,
+{
+    let sizes = if sizes.len() == 1 && ca.len() != 1 {
+        sizes.new_from_index(0, ca.len())
+    } else {
+        sizes.clone()
+    };
+    let mut row_idx = 0u64;
+    ca.try_zip_and_apply_amortized(&sizes, |opt_s, opt_size| {
+        let row_seed = seed.map(|seed| seed.wrapping_add(row_idx));
+        row_idx += 1;
+        match (opt_s, opt_size) {
+            (Some(s), Some(size)) => {
+                let s = s.as_ref();
+                let mut n = sample_size(size, s.len());
+                if clamp && !with_replacement {
+                    n = n.min(s.len());
+                }
+                s.sample_n(n, with_replacement, shuffle, row_seed).map(Some)
+            },
+            _ => Ok(None),
+        }
+    })
+}
+
 #[cfg(feature = "list_gather")]
 fn take_series(s: &Series, idx: Series, null_on_oob: bool) -> PolarsResult<Series> {
     let len = s.len();
@@ -852,3 +985,157 @@ fn cast_index(idx: Series, len: usize, null_on_oob: bool) -> PolarsResult<Series
 }
 
 // TODO: implement the above for ArrayChunked as well?
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sort_one(s: Series, options: SortOptions) -> PolarsResult<Series> {
+        let ca = ListChunked::full(s.name(), &s, 1);
+        Ok(ca.lst_sort(options)?.get_as_series(0).unwrap())
+    }
+
+    #[test]
+    fn test_lst_sort_nulls() -> PolarsResult<()> {
+        let ints = Series::new("", [Some(3i32), None, Some(1), None, Some(2)]);
+        let bools = Series::new("", [Some(true), None, Some(false), Some(true)]);
+        let strs = Series::new("", [Some("b"), None, Some("a")]);
+
+        let options = SortOptions::default()
+            .with_order_descending(true)
+            .with_nulls_last(true);
+        let out = sort_one(ints.clone(), options)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(3), Some(2), Some(1), None, None]
+        );
+        let out = sort_one(bools.clone(), options)?;
+        assert_eq!(
+            Vec::from(out.bool()?),
+            &[Some(true), Some(true), Some(false), None]
+        );
+        let out = sort_one(strs, options)?;
+        assert_eq!(Vec::from(out.str()?), &[Some("b"), Some("a"), None]);
+
+        let options = SortOptions::default().with_order_descending(true);
+        let out = sort_one(bools, options)?;
+        assert_eq!(
+            Vec::from(out.bool()?),
+            &[None, Some(true), Some(true), Some(false)]
+        );
+        let out = sort_one(Series::new("", [None::<i32>]), options)?;
+        assert_eq!(Vec::from(out.i32()?), &[None]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "diff")]
+    fn test_lst_diff_unsigned() -> PolarsResult<()> {
+        let s = Series::new("", [Some(5u32), Some(2), None, Some(4)]);
+        let ca = ListChunked::full("a", &s, 1);
+
+        let out = ca.lst_diff(1, NullBehavior::Ignore)?;
+        assert_eq!(out.inner_dtype(), DataType::Int64);
+        let out = out.get_as_series(0).unwrap();
+        assert_eq!(Vec::from(out.i64()?), &[None, Some(-3), None, None]);
+
+        let out = ca.lst_diff(2, NullBehavior::Drop)?;
+        let out = out.get_as_series(0).unwrap();
+        assert_eq!(Vec::from(out.i64()?), &[None, Some(2)]);
+
+        let empty = ListChunked::full_null_with_dtype("a", 2, &DataType::UInt32);
+        let out = empty.lst_diff(1, NullBehavior::Ignore)?;
+        assert_eq!(out.inner_dtype(), DataType::Int64);
+        assert_eq!(out.null_count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lst_n_unique() -> PolarsResult<()> {
+        let floats = Series::new("", [Some(1.0f64), None, Some(f64::NAN), Some(1.0), None]);
+        let strs = Series::new("", [Some("a"), None, Some("a"), None]);
+        let mut ca = ListChunked::full("a", &floats, 2);
+        ca.append(&ListChunked::full_null_with_dtype(
+            "a",
+            1,
+            &DataType::Float64,
+        ))?;
+        ca.append(&ListChunked::full("a", &floats.slice(0, 0), 1))?;
+
+        let out = ca.lst_n_unique()?;
+        assert_eq!(Vec::from(&out), &[Some(3), Some(3), None, Some(0)]);
+        let out = ListChunked::full("a", &strs, 1).lst_n_unique()?;
+        assert_eq!(Vec::from(&out), &[Some(2)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lst_shift() -> PolarsResult<()> {
+        let s = Series::new("", [Some(1i32), Some(2), None]);
+        let ca = ListChunked::full("a", &s, 3);
+
+        let out = ca.lst_shift(&Series::new("", [1i64, -2, 5]))?;
+        let expected: [&[Option<i32>]; 3] = [
+            &[None, Some(1), Some(2)],
+            &[None, None, None],
+            &[None, None, None],
+        ];
+        for (i, expected) in expected.iter().enumerate() {
+            assert_eq!(Vec::from(out.get_as_series(i).unwrap().i32()?), *expected);
+        }
+        let out = ca.lst_shift(&Series::new("", [-1i64]))?;
+        let out = out.get_as_series(0).unwrap();
+        assert_eq!(Vec::from(out.i32()?), &[Some(2), None, None]);
+
+        assert!(ca.lst_shift(&Series::new("", [1i64, 2])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lst_filter() -> PolarsResult<()> {
+        let s = Series::new("", [Some(1i32), None, Some(3)]);
+        let ca = ListChunked::full("a", &s, 2);
+        let mask = Series::new("", [Some(true), Some(true), None]);
+        let mut masks = ListChunked::full("mask", &mask, 1);
+        masks.append(&ListChunked::full_null_with_dtype(
+            "mask",
+            1,
+            &DataType::Boolean,
+        ))?;
+
+        let out = ca.lst_filter(&masks)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.get_as_series(0).unwrap().i32()?),
+            &[Some(1), None]
+        );
+        assert!(out.get_as_series(1).is_none());
+
+        let short = ListChunked::full("mask", &mask.slice(0, 2), 2);
+        assert!(ca.lst_filter(&short).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lst_join() -> PolarsResult<()> {
+        let s = Series::new("", [Some("a"), None, Some("bc")]);
+        let mut ca = ListChunked::full("a", &s, 1);
+        ca.append(&ListChunked::full("a", &s.slice(0, 0), 1))?;
+        ca.append(&ListChunked::full_null_with_dtype(
+            "a",
+            1,
+            &DataType::String,
+        ))?;
+        let separator = StringChunked::new("", &["--"]);
+
+        let out = ca.lst_join(&separator, true)?;
+        assert_eq!(Vec::from(&out), &[Some("a--bc"), Some(""), None]);
+        let out = ca.lst_join(&separator, false)?;
+        assert_eq!(Vec::from(&out), &[None, Some(""), None]);
+
+        let separators = StringChunked::new("", &[Some(","), Some(","), None]);
+        let out = ca.lst_join(&separators, true)?;
+        assert_eq!(Vec::from(&out), &[Some("a,bc"), Some(""), None]);
+        Ok(())
+    }
+}
@@ -82,10 +82,13 @@ where
             out.extend_buf(set.drain(..))
         },
         SetOperation::Difference => {
-            set.extend(a);
-            for v in b {
-                set.swap_remove(&v);
+            // If broadcast `set2` should already be filled.
+            if !broadcast_rhs {
+                set2.clear();
+                set2.extend(b);
             }
+            // Filter instead of removing from `set`, which would not preserve the order of `a`.
+            set.extend(a.filter(|v| !set2.contains(v)));
             out.extend_buf(set.drain(..))
         },
         SetOperation::SymmetricDifference => {
@@ -7,12 +7,17 @@ use super::*;
 
 #[derive(Copy, Clone, Debug)]
 pub enum ListToStructWidthStrategy {
+    /// The length of the first non-empty sublist.
     FirstNonNull,
+    /// The length of the longest sublist.
     MaxWidth,
+    /// A fixed number of fields; longer sublists are truncated.
+    Fixed(usize),
 }
 
 fn det_n_fields(ca: &ListChunked, n_fields: ListToStructWidthStrategy) -> usize {
     match n_fields {
+        ListToStructWidthStrategy::Fixed(n) => n,
         ListToStructWidthStrategy::MaxWidth => {
             let mut max = 0;
 
@@ -54,6 +59,15 @@ pub fn _default_struct_name_gen(idx: usize) -> SmartString {
     format_smartstring!("field_{idx}")
 }
 
+/// Name the fields after `names` by index. Fields beyond the given names fall back to the
+/// default `field_{idx}` names.
+pub fn names_struct_name_gen(names: Vec<String>) -> NameGenerator {
+    Arc::new(move |idx| match names.get(idx) {
+        Some(name) => name.as_str().into(),
+        None => _default_struct_name_gen(idx),
+    })
+}
+
 pub trait ToStruct: AsList {
     fn to_struct(
         &self,
@@ -85,3 +99,26 @@ pub trait ToStruct: AsList {
 }
 
 impl ToStruct for ListChunked {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_to_struct_names_and_width() -> PolarsResult<()> {
+        let mut ca = ListChunked::full("a", &Series::new("", [1i32, 2]), 1);
+        ca.append(&ListChunked::full("a", &Series::new("", [3i32, 4, 5]), 1))?;
+        let names = names_struct_name_gen(vec!["x".into()]);
+
+        let out = ca.to_struct(ListToStructWidthStrategy::MaxWidth, Some(names.clone()))?;
+        let fields = out.fields();
+        let field_names = fields.iter().map(|s| s.name()).collect::<Vec<_>>();
+        assert_eq!(field_names, &["x", "field_1", "field_2"]);
+        assert_eq!(Vec::from(fields[2].i32()?), &[None, Some(5)]);
+
+        let out = ca.to_struct(ListToStructWidthStrategy::Fixed(1), Some(names))?;
+        assert_eq!(out.fields().len(), 1);
+        assert_eq!(Vec::from(out.fields()[0].i32()?), &[Some(1), Some(3)]);
+        Ok(())
+    }
+}
@@ -39,6 +39,8 @@ pub use hist::*;
 #[cfg(feature = "interpolate")]
 pub use interpolate::*;
 pub use list::*;
+#[cfg(feature = "peaks")]
+pub use peaks::{PeakOptions, PlateauBehavior};
 #[allow(unused_imports)]
 use polars_core::prelude::*;
 #[cfg(feature = "repeat_by")]
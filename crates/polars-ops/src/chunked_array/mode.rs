@@ -2,7 +2,10 @@ use arrow::legacy::utils::CustomIterTools;
 use polars_core::prelude::*;
 use polars_core::{with_match_physical_integer_polars_type, POOL};
 
-fn mode_primitive<T: PolarsDataType>(ca: &ChunkedArray<T>) -> PolarsResult<ChunkedArray<T>>
+fn mode_primitive<T: PolarsDataType>(
+    ca: &ChunkedArray<T>,
+    maintain_order: bool,
+) -> PolarsResult<ChunkedArray<T>>
 where
     ChunkedArray<T>: IntoGroupsProxy + ChunkTake<[IdxSize]>,
 {
@@ -11,25 +14,30 @@ where
     }
     let parallel = !POOL.current_thread_has_pending_tasks().unwrap_or(false);
     let groups = ca.group_tuples(parallel, false).unwrap();
-    let idx = mode_indices(groups);
+    let mut idx = mode_indices(groups);
+    if maintain_order {
+        // The first index of a group is the first appearance of its value.
+        idx.sort_unstable();
+    }
 
     // SAFETY:
     // group indices are in bounds
     Ok(unsafe { ca.take_unchecked(idx.as_slice()) })
 }
 
-fn mode_f32(ca: &Float32Chunked) -> PolarsResult<Float32Chunked> {
-    let s = ca.apply_as_ints(|v| mode(v).unwrap());
+fn mode_f32(ca: &Float32Chunked, maintain_order: bool) -> PolarsResult<Float32Chunked> {
+    let s = ca.apply_as_ints(|v| mode_impl(v, maintain_order).unwrap());
     let ca = s.f32().unwrap().clone();
     Ok(ca)
 }
 
-fn mode_64(ca: &Float64Chunked) -> PolarsResult<Float64Chunked> {
-    let s = ca.apply_as_ints(|v| mode(v).unwrap());
+fn mode_64(ca: &Float64Chunked, maintain_order: bool) -> PolarsResult<Float64Chunked> {
+    let s = ca.apply_as_ints(|v| mode_impl(v, maintain_order).unwrap());
     let ca = s.f64().unwrap().clone();
     Ok(ca)
 }
 
+/// Get the first index of every group that occurs the maximum number of times.
 fn mode_indices(groups: GroupsProxy) -> Vec<IdxSize> {
     match groups {
         GroupsProxy::Idx(groups) => {
@@ -45,34 +53,32 @@ fn mode_indices(groups: GroupsProxy) -> Vec<IdxSize> {
                 .collect()
         },
         GroupsProxy::Slice { groups, .. } => {
-            let last = groups.last().unwrap();
-            let max_occur = last[1];
+            let max_occur = groups.iter().map(|v| v[1]).max().unwrap();
 
             groups
                 .iter()
                 .rev()
-                .take_while(|v| {
-                    let len = v[1];
-                    len == max_occur
-                })
+                .filter(|v| v[1] == max_occur)
                 .map(|v| v[0])
                 .collect()
         },
     }
 }
 
-pub fn mode(s: &Series) -> PolarsResult<Series> {
+fn mode_impl(s: &Series, maintain_order: bool) -> PolarsResult<Series> {
     let s_phys = s.to_physical_repr();
     let out = match s_phys.dtype() {
-        DataType::Binary => mode_primitive(s_phys.binary().unwrap())?.into_series(),
-        DataType::Boolean => mode_primitive(s_phys.bool().unwrap())?.into_series(),
-        DataType::Float32 => mode_f32(s_phys.f32().unwrap())?.into_series(),
-        DataType::Float64 => mode_64(s_phys.f64().unwrap())?.into_series(),
-        DataType::String => mode_primitive(&s_phys.str().unwrap().as_binary())?.into_series(),
+        DataType::Binary => mode_primitive(s_phys.binary().unwrap(), maintain_order)?.into_series(),
+        DataType::Boolean => mode_primitive(s_phys.bool().unwrap(), maintain_order)?.into_series(),
+        DataType::Float32 => mode_f32(s_phys.f32().unwrap(), maintain_order)?.into_series(),
+        DataType::Float64 => mode_64(s_phys.f64().unwrap(), maintain_order)?.into_series(),
+        DataType::String => {
+            mode_primitive(&s_phys.str().unwrap().as_binary(), maintain_order)?.into_series()
+        },
         dt if dt.is_integer() => {
             with_match_physical_integer_polars_type!(dt, |$T| {
                 let ca: &ChunkedArray<$T> = s_phys.as_ref().as_ref().as_ref();
-                mode_primitive(ca)?.into_series()
+                mode_primitive(ca, maintain_order)?.into_series()
             })
         },
         _ => polars_bail!(opq = mode, s.dtype()),
@@ -81,35 +87,60 @@ pub fn mode(s: &Series) -> PolarsResult<Series> {
     unsafe { out.cast_unchecked(s.dtype()) }
 }
 
+/// Compute the most occurring value(s).
+///
+/// All values that are tied for the highest count are returned. If `maintain_order` is set they
+/// are returned in order of first appearance, otherwise the order is unspecified. Nulls are
+/// counted as a value unless `ignore_nulls` is set.
+pub fn mode(s: &Series, maintain_order: bool, ignore_nulls: bool) -> PolarsResult<Series> {
+    if ignore_nulls && s.null_count() > 0 {
+        mode_impl(&s.drop_nulls(), maintain_order)
+    } else {
+        mode_impl(s, maintain_order)
+    }
+}
+
+/// Compute the single most occurring value, where ties are broken by first appearance.
+///
+/// Always returns a `Series` of length 1, which is null if there are no values.
+pub fn mode_first(s: &Series, ignore_nulls: bool) -> PolarsResult<Series> {
+    let out = mode(s, true, ignore_nulls)?;
+    if out.is_empty() {
+        Ok(Series::full_null(s.name(), 1, s.dtype()))
+    } else {
+        Ok(out.head(Some(1)))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use polars_core::prelude::*;
 
-    use super::{mode, mode_primitive};
+    use super::{mode, mode_first, mode_primitive};
 
     #[test]
     fn mode_test() {
         let ca = Int32Chunked::from_slice("test", &[0, 1, 2, 3, 4, 4, 5, 6, 5, 0]);
-        let mut result = mode_primitive(&ca).unwrap().to_vec();
+        let mut result = mode_primitive(&ca, false).unwrap().to_vec();
         result.sort_by_key(|a| a.unwrap());
         assert_eq!(&result, &[Some(0), Some(4), Some(5)]);
 
         let ca = Int32Chunked::from_slice("test", &[1, 1]);
-        let mut result = mode_primitive(&ca).unwrap().to_vec();
+        let mut result = mode_primitive(&ca, false).unwrap().to_vec();
         result.sort_by_key(|a| a.unwrap());
         assert_eq!(&result, &[Some(1)]);
 
         let ca = Int32Chunked::from_slice("test", &[]);
-        let mut result = mode_primitive(&ca).unwrap().to_vec();
+        let mut result = mode_primitive(&ca, false).unwrap().to_vec();
         result.sort_by_key(|a| a.unwrap());
         assert_eq!(result, &[]);
 
         let ca = Float32Chunked::from_slice("test", &[1.0f32, 2.0, 2.0, 3.0, 3.0, 3.0]);
-        let result = mode_primitive(&ca).unwrap().to_vec();
+        let result = mode_primitive(&ca, false).unwrap().to_vec();
         assert_eq!(result, &[Some(3.0f32)]);
 
         let ca = StringChunked::from_slice("test", &["test", "test", "test", "another test"]);
-        let result = mode_primitive(&ca).unwrap();
+        let result = mode_primitive(&ca, false).unwrap();
         let vec_result4: Vec<Option<&str>> = result.into_iter().collect();
         assert_eq!(vec_result4, &[Some("test")]);
 
@@ -120,8 +151,30 @@ mod test {
         ca_builder.append_value("test2");
         ca_builder.append_value("test2");
         let s = ca_builder.finish().into_series();
-        let result = mode(&s).unwrap();
+        let result = mode(&s, false, false).unwrap();
         assert_eq!(result.str_value(0).unwrap(), "test2");
         assert_eq!(result.len(), 1);
     }
+
+    #[test]
+    fn mode_order_and_nulls_test() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            [Some(3i32), None, Some(1), None, Some(1), Some(3), Some(2)],
+        );
+        let result = mode(&s, true, false)?;
+        assert_eq!(Vec::from(result.i32()?), &[Some(3), None, Some(1)]);
+        let result = mode(&s, true, true)?;
+        assert_eq!(Vec::from(result.i32()?), &[Some(3), Some(1)]);
+        let result = mode_first(&s, true)?;
+        assert_eq!(Vec::from(result.i32()?), &[Some(3)]);
+
+        // Sorted input is grouped by slices; the longest run is not necessarily the last one.
+        let s = Series::new("a", [1i32, 1, 2]).sort(Default::default())?;
+        assert_eq!(Vec::from(mode(&s, false, false)?.i32()?), &[Some(1)]);
+
+        let s = Series::new("a", [None::<i32>, None]);
+        assert_eq!(Vec::from(mode_first(&s, true)?.i32()?), &[None]);
+        Ok(())
+    }
 }
@@ -1,22 +1,249 @@
 use num_traits::Zero;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Which elements of a plateau, a run of equal values that forms a peak, are marked.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PlateauBehavior {
+    /// Mark every element of the plateau.
+    MarkAll,
+    /// Mark only the first element of the plateau.
+    MarkFirst,
+    /// Mark only the last element of the plateau.
+    MarkLast,
+    /// Don't mark plateaus, only peaks consisting of a single element.
+    #[default]
+    MarkNone,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PeakOptions {
+    pub plateau: PlateauBehavior,
+    /// Only keep peaks with at least this prominence: the vertical distance between the
+    /// peak and the highest of the two lowest points (for maxima) that have to be passed
+    /// on either side before reaching a value beyond the peak or the end of the data.
+    pub min_prominence: Option<f64>,
+}
+
+/// Whether `a` lies strictly beyond `b`, i.e. above it for maxima and below it for minima.
+#[inline]
+fn beyond<T: PartialOrd>(a: T, b: T, is_max: bool) -> bool {
+    if is_max {
+        a > b
+    } else {
+        a < b
+    }
+}
+
+/// The most extreme value that is passed on one side of a peak of value `v` before reaching
+/// a value beyond the peak.
+fn side_base<T: PartialOrd + Copy>(v: T, values: impl Iterator<Item = T>, is_max: bool) -> T {
+    let mut base = v;
+    for x in values {
+        if beyond(x, v, is_max) {
+            break;
+        }
+        if beyond(base, x, is_max) {
+            base = x;
+        }
+    }
+    base
+}
+
+fn prominence<T: NumericNative>(
+    values: &[Option<T>],
+    start: usize,
+    end: usize,
+    is_max: bool,
+) -> f64 {
+    let v = values[start].unwrap();
+    // Like the neighbours, the data is padded with a zero on both ends.
+    let outside = std::iter::once(T::zero());
+    let left = values[..start]
+        .iter()
+        .rev()
+        .flatten()
+        .copied()
+        .chain(outside.clone());
+    let right = values[end + 1..].iter().flatten().copied().chain(outside);
+    let left = side_base(v, left, is_max);
+    let right = side_base(v, right, is_max);
+    let base = if beyond(left, right, is_max) {
+        left
+    } else {
+        right
+    };
+    (v.to_f64().unwrap() - base.to_f64().unwrap()).abs()
+}
+
+fn find_peaks<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    options: PeakOptions,
+    is_max: bool,
+) -> BooleanChunked {
+    let values: Vec<Option<T::Native>> = ca.iter().collect();
+    let n = values.len();
+    let mut out = vec![Some(false); n];
+
+    let mut start = 0;
+    while start < n {
+        let Some(v) = values[start] else {
+            out[start] = None;
+            start += 1;
+            continue;
+        };
+        let mut end = start;
+        while end + 1 < n && values[end + 1] == Some(v) {
+            end += 1;
+        }
+
+        // Neighbours outside of the data are zero; a null neighbour makes the result unknown.
+        let side = |neighbour: Option<Option<T::Native>>| match neighbour {
+            None => Some(beyond(v, T::Native::zero(), is_max)),
+            Some(neighbour) => neighbour.map(|x| beyond(v, x, is_max)),
+        };
+        let left = side(start.checked_sub(1).map(|i| values[i]));
+        let right = side(values.get(end + 1).copied());
+        let is_peak = match (left, right) {
+            (Some(false), _) | (_, Some(false)) => Some(false),
+            (Some(true), Some(true)) => match options.min_prominence {
+                Some(min) => Some(prominence(&values, start, end, is_max) >= min),
+                None => Some(true),
+            },
+            _ => None,
+        };
+
+        if is_peak != Some(false) {
+            let marked = match options.plateau {
+                _ if start == end => Some((start, end)),
+                PlateauBehavior::MarkAll => Some((start, end)),
+                PlateauBehavior::MarkFirst => Some((start, start)),
+                PlateauBehavior::MarkLast => Some((end, end)),
+                PlateauBehavior::MarkNone => None,
+            };
+            if let Some((first, last)) = marked {
+                out[first..=last].fill(is_peak);
+            }
+        }
+        start = end + 1;
+    }
+    BooleanChunked::from_iter_options(ca.name(), out.into_iter())
+}
 
 /// Get a boolean mask of the local maximum peaks.
-pub fn peak_max<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> BooleanChunked
+///
+/// Values outside of the data are considered to be zero. A peak next to a null value is
+/// null, unless the value on its other side already rules it out.
+pub fn peak_max<T: PolarsNumericType>(ca: &ChunkedArray<T>, options: PeakOptions) -> BooleanChunked
 where
     ChunkedArray<T>: for<'a> ChunkCompare<&'a ChunkedArray<T>, Item = BooleanChunked>,
 {
+    if options != PeakOptions::default() {
+        return find_peaks(ca, options, true);
+    }
     let shift_left = ca.shift_and_fill(1, Some(Zero::zero()));
     let shift_right = ca.shift_and_fill(-1, Some(Zero::zero()));
     ChunkedArray::lt(&shift_left, ca) & ChunkedArray::lt(&shift_right, ca)
 }
 
 /// Get a boolean mask of the local minimum peaks.
-pub fn peak_min<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> BooleanChunked
+///
+/// Values outside of the data are considered to be zero. A peak next to a null value is
+/// null, unless the value on its other side already rules it out.
+pub fn peak_min<T: PolarsNumericType>(ca: &ChunkedArray<T>, options: PeakOptions) -> BooleanChunked
 where
     ChunkedArray<T>: for<'a> ChunkCompare<&'a ChunkedArray<T>, Item = BooleanChunked>,
 {
+    if options != PeakOptions::default() {
+        return find_peaks(ca, options, false);
+    }
     let shift_left = ca.shift_and_fill(1, Some(Zero::zero()));
     let shift_right = ca.shift_and_fill(-1, Some(Zero::zero()));
     ChunkedArray::gt(&shift_left, ca) & ChunkedArray::gt(&shift_right, ca)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn options(plateau: PlateauBehavior, min_prominence: Option<f64>) -> PeakOptions {
+        PeakOptions {
+            plateau,
+            min_prominence,
+        }
+    }
+
+    #[test]
+    fn test_peak_plateau() {
+        let ca = Int32Chunked::new("a", &[1, 3, 3, 3, 1, 2, 1]);
+        let check = |plateau, expected: &[bool]| {
+            let out = peak_max(&ca, options(plateau, None));
+            assert_eq!(
+                Vec::from(&out),
+                expected.iter().map(|&b| Some(b)).collect::<Vec<_>>()
+            );
+        };
+        check(
+            PlateauBehavior::MarkAll,
+            &[false, true, true, true, false, true, false],
+        );
+        check(
+            PlateauBehavior::MarkFirst,
+            &[false, true, false, false, false, true, false],
+        );
+        check(
+            PlateauBehavior::MarkLast,
+            &[false, false, false, true, false, true, false],
+        );
+        check(
+            PlateauBehavior::MarkNone,
+            &[false, false, false, false, false, true, false],
+        );
+
+        // A run that is followed by a lower value is not a plateau minimum.
+        let ca = Int32Chunked::new("a", &[5, 4, 4, 3, 6]);
+        let out = peak_min(&ca, options(PlateauBehavior::MarkAll, None));
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(true),
+                Some(false)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_peak_prominence() {
+        let ca = Float64Chunked::new("a", &[0.0, 5.0, 4.5, 4.8, 1.0, 3.0, 0.0]);
+        let out = peak_max(&ca, options(PlateauBehavior::MarkNone, Some(1.0)));
+        // 4.8 only rises 0.3 above the dip towards the higher peak at 5.0.
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some(false),
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(true),
+                Some(false)
+            ]
+        );
+        let out = peak_max(&ca, options(PlateauBehavior::MarkNone, Some(3.5)));
+        assert_eq!(out.sum(), Some(1));
+    }
+
+    #[test]
+    fn test_peak_nulls_match_default() {
+        let ca = Int32Chunked::new("a", &[Some(1), Some(3), None, Some(0), Some(2), Some(1)]);
+        let default = peak_max(&ca, PeakOptions::default());
+        let generic = find_peaks(&ca, PeakOptions::default(), true);
+        assert_eq!(Vec::from(&default), Vec::from(&generic));
+    }
+}
@@ -1,4 +1,5 @@
 use polars_core::prelude::StringChunked;
+use unicode_segmentation::UnicodeSegmentation;
 
 // Inlined from std.
 fn convert_while_ascii(b: &[u8], convert: fn(&u8) -> u8, out: &mut Vec<u8>) {
@@ -131,7 +132,39 @@ pub(super) fn to_uppercase<'a>(ca: &'a StringChunked) -> StringChunked {
     ca.apply_mut(f)
 }
 
-#[cfg(feature = "nightly")]
+/// Push `word`, which is assumed to be lowercase, with its first character uppercased.
+///
+/// An apostrophe after a single letter, as in "o'brien" or "l'été", starts a new word, so
+/// these become "O'Brien" and "L'Été". Other apostrophes stay within the word: "there's"
+/// becomes "There's".
+fn push_capitalized(word: &str, out: &mut String) {
+    let mut chars = word.chars();
+    let Some(first) = chars.next() else {
+        return;
+    };
+    out.extend(first.to_uppercase());
+    let rest = chars.as_str();
+
+    if first.is_alphabetic() {
+        let mut rest_chars = rest.chars();
+        if let Some(apostrophe @ ('\'' | '\u{2019}')) = rest_chars.next() {
+            let after = rest_chars.as_str();
+            if after.starts_with(char::is_alphabetic) {
+                out.push(apostrophe);
+                push_capitalized(after, out);
+                return;
+            }
+        }
+    }
+    out.push_str(rest);
+}
+
+/// Uppercase the first character of every word and lowercase the rest.
+///
+/// Words are determined by the Unicode word boundary rules of
+/// [UAX #29](https://www.unicode.org/reports/tr29/), so words separated by punctuation
+/// are capitalized separately ("jean-luc" becomes "Jean-Luc") and combining characters
+/// stay attached to the character they modify.
 pub(super) fn to_titlecase<'a>(ca: &'a StringChunked) -> StringChunked {
     // Amortize allocation.
     let mut buf = Vec::new();
@@ -147,14 +180,8 @@ pub(super) fn to_titlecase<'a>(ca: &'a StringChunked) -> StringChunked {
         buf.clear();
         let mut s = unsafe { String::from_utf8_unchecked(std::mem::take(&mut buf)) };
 
-        let mut next_is_upper = true;
-        for c in lowercased.chars() {
-            if next_is_upper {
-                s.extend(c.to_uppercase());
-            } else {
-                s.push(c);
-            }
-            next_is_upper = c.is_whitespace();
+        for word in lowercased.split_word_bounds() {
+            push_capitalized(word, &mut s);
         }
 
         // Put buf back for next iteration.
@@ -166,3 +193,38 @@ pub(super) fn to_titlecase<'a>(ca: &'a StringChunked) -> StringChunked {
     };
     ca.apply_mut(f)
 }
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_titlecase() {
+        let ca = StringChunked::new(
+            "a",
+            &[
+                Some("welcome to my world"),
+                Some("THERE'S NO TURNING BACK"),
+                Some("o'brien and jean-luc"),
+                Some("l\u{2019}\u{e9}t\u{e9} in 1st place"),
+                Some("e\u{301}cole (nouvelle)"),
+                Some(""),
+                None,
+            ],
+        );
+        assert_eq!(
+            Vec::from(&to_titlecase(&ca)),
+            &[
+                Some("Welcome To My World"),
+                Some("There's No Turning Back"),
+                Some("O'Brien And Jean-Luc"),
+                Some("L\u{2019}\u{c9}t\u{e9} In 1st Place"),
+                Some("E\u{301}cole (Nouvelle)"),
+                Some(""),
+                None,
+            ]
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use polars_core::prelude::arity::broadcast_binary_elementwise_values;
+use polars_core::prelude::*;
+
+/// Edit distance between `a` and `b` in Unicode scalar values.
+///
+/// `chars` and `row` are scratch buffers that are reused between calls.
+fn levenshtein(a: &str, b: &str, chars: &mut Vec<char>, row: &mut Vec<u32>) -> u32 {
+    chars.clear();
+    chars.extend(b.chars());
+    row.clear();
+    row.extend(0..=chars.len() as u32);
+
+    for (i, ca) in a.chars().enumerate() {
+        // `diag` holds the distance of the prefixes without their last characters.
+        let mut diag = row[0];
+        row[0] = i as u32 + 1;
+        for (j, cb) in chars.iter().enumerate() {
+            let substitution = diag + (ca != *cb) as u32;
+            diag = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diag + 1);
+        }
+    }
+    row[chars.len()]
+}
+
+/// Jaro similarity between `a` and `b`, which are given as Unicode scalar values.
+///
+/// `matched` is a scratch buffer that is reused between calls.
+fn jaro(a: &[char], b: &[char], matched: &mut Vec<bool>) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    matched.clear();
+    matched.resize(b.len(), false);
+
+    let mut a_matches = Vec::with_capacity(a.len().min(b.len()));
+    for (i, ca) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        if let Some(j) = (start..end).find(|&j| !matched[j] && b[j] == *ca) {
+            matched[j] = true;
+            a_matches.push(*ca);
+        }
+    }
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+
+    let b_matches = b
+        .iter()
+        .zip(matched.iter())
+        .filter_map(|(cb, m)| m.then_some(cb));
+    let transpositions = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|(ca, cb)| ca != cb)
+        .count()
+        / 2;
+
+    let m = a_matches.len() as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+/// Jaro-Winkler similarity between `a` and `b` in `[0, 1]`, using the standard prefix scale of
+/// `0.1` for a common prefix of up to four characters.
+fn jaro_winkler(a: &str, b: &str, buf: &mut (Vec<char>, Vec<char>, Vec<bool>)) -> f64 {
+    let (a_chars, b_chars, matched) = buf;
+    a_chars.clear();
+    a_chars.extend(a.chars());
+    b_chars.clear();
+    b_chars.extend(b.chars());
+
+    let sim = jaro(a_chars, b_chars, matched);
+    let prefix = a_chars
+        .iter()
+        .zip(b_chars.iter())
+        .take(4)
+        .take_while(|(ca, cb)| ca == cb)
+        .count();
+    sim + prefix as f64 * 0.1 * (1.0 - sim)
+}
+
+/// Levenshtein edit distance between the strings of `a` and `b`, counted in Unicode scalar
+/// values. Either side may be a scalar, nulls propagate.
+pub fn str_levenshtein(a: &StringChunked, b: &StringChunked) -> PolarsResult<UInt32Chunked> {
+    polars_ensure!(
+        a.len() == b.len() || a.len() == 1 || b.len() == 1,
+        ShapeMismatch: "`levenshtein` expects inputs of equal length or a scalar, got {} and {}",
+        a.len(), b.len()
+    );
+    let mut chars = Vec::new();
+    let mut row = Vec::new();
+    Ok(broadcast_binary_elementwise_values(a, b, |a, b| {
+        levenshtein(a, b, &mut chars, &mut row)
+    }))
+}
+
+/// Jaro-Winkler similarity in `[0, 1]` between the strings of `a` and `b`, computed on Unicode
+/// scalar values. Either side may be a scalar, nulls propagate.
+pub fn str_jaro_winkler(a: &StringChunked, b: &StringChunked) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(
+        a.len() == b.len() || a.len() == 1 || b.len() == 1,
+        ShapeMismatch: "`jaro_winkler` expects inputs of equal length or a scalar, got {} and {}",
+        a.len(), b.len()
+    );
+    let mut buf = Default::default();
+    Ok(broadcast_binary_elementwise_values(a, b, |a, b| {
+        jaro_winkler(a, b, &mut buf)
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein() {
+        let mut buf = (Vec::new(), Vec::new());
+        let mut dist = |a, b| levenshtein(a, b, &mut buf.0, &mut buf.1);
+        assert_eq!(dist("kitten", "sitting"), 3);
+        assert_eq!(dist("", "abc"), 3);
+        assert_eq!(dist("abc", ""), 3);
+        assert_eq!(dist("flaw", "lawn"), 2);
+        assert_eq!(dist("same", "same"), 0);
+        // Unicode scalar values, not bytes.
+        assert_eq!(dist("café", "cafe"), 1);
+    }
+
+    #[test]
+    fn test_jaro_winkler() {
+        let mut buf = Default::default();
+        let mut sim = |a, b| jaro_winkler(a, b, &mut buf);
+        assert!((sim("MARTHA", "MARHTA") - 0.961111).abs() < 1e-6);
+        assert!((sim("DWAYNE", "DUANE") - 0.84).abs() < 1e-6);
+        assert!((sim("DIXON", "DICKSONX") - 0.813333).abs() < 1e-6);
+        assert_eq!(sim("", ""), 1.0);
+        assert_eq!(sim("abc", ""), 0.0);
+        assert_eq!(sim("abc", "xyz"), 0.0);
+        assert_eq!(sim("é", "é"), 1.0);
+    }
+
+    #[test]
+    fn test_str_distance_broadcast() -> PolarsResult<()> {
+        let a = StringChunked::new("a", &[Some("kitten"), None, Some("")]);
+        let b = StringChunked::new("b", &["sitting"]);
+        let out = str_levenshtein(&a, &b)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[Some(3), None, Some(7)]);
+
+        let out = str_jaro_winkler(&b, &a)?;
+        assert_eq!(out.null_count(), 1);
+        assert_eq!(out.get(2), Some(0.0));
+
+        let c = StringChunked::new("c", &["a", "b"]);
+        assert!(str_levenshtein(&a, &c).is_err());
+        Ok(())
+    }
+}
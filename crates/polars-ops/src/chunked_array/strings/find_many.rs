@@ -8,6 +8,11 @@ fn build_ac(patterns: &StringChunked, ascii_case_insensitive: bool) -> PolarsRes
         .map_err(|e| polars_err!(ComputeError: "could not build aho corasick automaton {}", e))
 }
 
+/// Check whether the strings contain any of the `patterns`.
+///
+/// The patterns are compiled once into an Aho-Corasick automaton, so the cost per string
+/// doesn't grow with the number of patterns. Null patterns are ignored and null strings
+/// propagate.
 pub fn contains_any(
     ca: &StringChunked,
     patterns: &StringChunked,
@@ -18,6 +23,10 @@ pub fn contains_any(
     Ok(ca.apply_generic(|opt_val| opt_val.map(|val| ac.find(val).is_some())))
 }
 
+/// Replace all non-overlapping matches of the `patterns` by the corresponding value of
+/// `replace_with`, which may also be a single value used for all patterns.
+///
+/// Like [`contains_any`], this compiles the patterns once into an Aho-Corasick automaton.
 pub fn replace_all(
     ca: &StringChunked,
     patterns: &StringChunked,
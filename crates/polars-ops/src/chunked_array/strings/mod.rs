@@ -2,6 +2,8 @@
 mod case;
 #[cfg(feature = "strings")]
 mod concat;
+#[cfg(feature = "string_distance")]
+mod distance;
 #[cfg(feature = "strings")]
 mod extract;
 #[cfg(feature = "find_many")]
@@ -10,6 +12,8 @@ mod find_many;
 mod json_path;
 #[cfg(feature = "strings")]
 mod namespace;
+#[cfg(feature = "string_normalize")]
+mod normalize;
 #[cfg(feature = "string_pad")]
 mod pad;
 #[cfg(feature = "string_reverse")]
@@ -26,12 +30,16 @@ mod unicode_internals;
 
 #[cfg(feature = "strings")]
 pub use concat::*;
+#[cfg(feature = "string_distance")]
+pub use distance::*;
 #[cfg(feature = "find_many")]
 pub use find_many::*;
 #[cfg(feature = "extract_jsonpath")]
 pub use json_path::*;
 #[cfg(feature = "strings")]
 pub use namespace::*;
+#[cfg(feature = "string_normalize")]
+pub use normalize::UnicodeForm;
 use polars_core::prelude::*;
 #[cfg(feature = "strings")]
 pub use split::*;
@@ -24,6 +24,12 @@ where
     f
 }
 
+/// Convert the byte offset of a match in `s` to an offset in characters.
+#[inline]
+fn char_offset(s: &str, byte_offset: usize) -> u32 {
+    s[..byte_offset].chars().count() as u32
+}
+
 pub trait StringNameSpaceImpl: AsString {
     #[cfg(not(feature = "binary_encoding"))]
     fn hex_decode(&self) -> PolarsResult<StringChunked> {
@@ -117,6 +123,13 @@ pub trait StringNameSpaceImpl: AsString {
         Ok(out)
     }
 
+    /// Escape all regular expression meta characters in the string values, so that they
+    /// match literally when embedded in a pattern.
+    fn str_escape_regex(&self) -> StringChunked {
+        let ca = self.as_string();
+        ca.apply_to_buffer(|s, buf| buf.push_str(&escape(s)))
+    }
+
     fn contains_chunked(
         &self,
         pat: &StringChunked,
@@ -197,7 +210,10 @@ pub trait StringNameSpaceImpl: AsString {
             Ok(broadcast_binary_elementwise(
                 ca,
                 pat,
-                |src: Option<&str>, pat: Option<&str>| src?.find(pat?).map(|idx| idx as u32),
+                |src: Option<&str>, pat: Option<&str>| {
+                    let src = src?;
+                    src.find(pat?).map(|idx| char_offset(src, idx))
+                },
             ))
         } else {
             // note: sqrt(n) regex cache is not too small, not too large.
@@ -205,7 +221,7 @@ pub trait StringNameSpaceImpl: AsString {
             let matcher = |src: Option<&str>, pat: Option<&str>| -> PolarsResult<Option<u32>> {
                 if let (Some(src), Some(pat)) = (src, pat) {
                     let rx = rx_cache.try_get_or_insert_with(pat, |p| Regex::new(p))?;
-                    return Ok(rx.find(src).map(|m| m.start() as u32));
+                    return Ok(rx.find(src).map(|m| char_offset(src, m.start())));
                 }
                 Ok(None)
             };
@@ -249,10 +265,11 @@ pub trait StringNameSpaceImpl: AsString {
 
     /// Pad the start of the string with zeros until it reaches the given length.
     ///
-    /// A sign prefix (`-`) is handled by inserting the padding after the sign
+    /// A sign prefix (`-` or `+`) is handled by inserting the padding after the sign
     /// character rather than before.
     /// Strings with length equal to or greater than the given length are
-    /// returned as-is.
+    /// returned as-is. The length may differ per row, or be a single value that is
+    /// broadcast to all rows.
     #[cfg(feature = "string_pad")]
     fn zfill(&self, length: &UInt64Chunked) -> StringChunked {
         let ca = self.as_string();
@@ -280,20 +297,21 @@ pub trait StringNameSpaceImpl: AsString {
         self.contains(regex::escape(lit).as_str(), true)
     }
 
-    /// Return the index position of a literal substring in the target string.
+    /// Return the index position of a literal substring in the target string, counted in
+    /// characters.
     fn find_literal(&self, lit: &str) -> PolarsResult<UInt32Chunked> {
         self.find(regex::escape(lit).as_str(), true)
     }
 
-    /// Return the index position of a regular expression substring in the target string.
+    /// Return the index position of a regular expression substring in the target string,
+    /// counted in characters like the offsets of [`str_slice`](Self::str_slice).
     fn find(&self, pat: &str, strict: bool) -> PolarsResult<UInt32Chunked> {
         let ca = self.as_string();
         match Regex::new(pat) {
-            Ok(rx) => {
-                Ok(ca.apply_generic(|opt_s| {
-                    opt_s.and_then(|s| rx.find(s)).map(|m| m.start() as u32)
-                }))
-            },
+            Ok(rx) => Ok(ca.apply_generic(|opt_s| {
+                let s = opt_s?;
+                rx.find(s).map(|m| char_offset(s, m.start()))
+            })),
             Err(_) if !strict => Ok(UInt32Chunked::full_null(ca.name(), ca.len())),
             Err(e) => Err(PolarsError::ComputeError(
                 format!("Invalid regular expression: {}", e).into(),
@@ -486,6 +504,15 @@ pub trait StringNameSpaceImpl: AsString {
         split_helper(ca, by, str::split_inclusive)
     }
 
+    /// Split the strings at every match of the regex patterns in `by`.
+    ///
+    /// If `inclusive`, the matched separators are kept at the end of the preceding piece.
+    fn split_regex(&self, by: &StringChunked, inclusive: bool) -> PolarsResult<ListChunked> {
+        let ca = self.as_string();
+
+        split_regex_helper(ca, by, inclusive)
+    }
+
     /// Extract each successive non-overlapping regex match in an individual string as an array.
     fn extract_all_many(&self, pat: &StringChunked) -> PolarsResult<ListChunked> {
         let ca = self.as_string();
@@ -578,8 +605,11 @@ pub trait StringNameSpaceImpl: AsString {
     }
 
     /// Modify the strings to their titlecase equivalent.
+    ///
+    /// The first character of every word is uppercased and the rest lowercased, where words
+    /// are determined by the Unicode word boundary rules. An apostrophe after a single letter
+    /// also starts a new word, so "o'brien" becomes "O'Brien".
     #[must_use]
-    #[cfg(feature = "nightly")]
     fn to_titlecase(&self) -> StringChunked {
         let ca = self.as_string();
         case::to_titlecase(ca)
@@ -600,10 +630,20 @@ pub trait StringNameSpaceImpl: AsString {
         reverse::reverse(ca)
     }
 
+    /// Normalize the string values to a Unicode normalization form.
+    #[must_use]
+    #[cfg(feature = "string_normalize")]
+    fn str_normalize(&self, form: UnicodeForm) -> StringChunked {
+        let ca = self.as_string();
+        normalize::normalize(ca, form)
+    }
+
     /// Slice the string values.
     ///
     /// Determines a substring starting from `offset` and with length `length` of each of the elements in `array`.
     /// `offset` can be negative, in which case the start counts from the end of the string.
+    /// Both are counted in characters (Unicode scalar values), see
+    /// [`str_slice_bytes`](Self::str_slice_bytes) to slice by bytes.
     fn str_slice(&self, offset: &Series, length: &Series) -> PolarsResult<StringChunked> {
         let ca = self.as_string();
         let offset = offset.cast(&DataType::Int64)?;
@@ -613,6 +653,20 @@ pub trait StringNameSpaceImpl: AsString {
         Ok(substring::substring(ca, offset.i64()?, length.u64()?))
     }
 
+    /// Slice the string values by byte offsets.
+    ///
+    /// Like [`str_slice`](Self::str_slice), but `offset` and `length` are counted in bytes.
+    /// Errors if a slice doesn't fall on character boundaries, as it would cut a multibyte
+    /// character in two.
+    fn str_slice_bytes(&self, offset: &Series, length: &Series) -> PolarsResult<StringChunked> {
+        let ca = self.as_string();
+        let offset = offset.cast(&DataType::Int64)?;
+        // We strict cast, otherwise negative value will be treated as a valid length.
+        let length = length.strict_cast(&DataType::UInt64)?;
+
+        substring::substring_bytes(ca, offset.i64()?, length.u64()?)
+    }
+
     /// Slice the first `n` values of the string.
     ///
     /// Determines a substring starting at the beginning of the string up to offset `n` of each
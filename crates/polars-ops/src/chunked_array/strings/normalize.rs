@@ -0,0 +1,32 @@
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+
+/// A Unicode normalization form, see [Unicode Standard Annex #15](https://www.unicode.org/reports/tr15/).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UnicodeForm {
+    /// Canonical decomposition, followed by canonical composition.
+    NFC,
+    /// Canonical decomposition.
+    NFD,
+    /// Compatibility decomposition, followed by canonical composition.
+    NFKC,
+    /// Compatibility decomposition.
+    NFKD,
+}
+
+fn normalize_with(ca: &StringChunked, normalizer: impl Fn(&str, &mut String)) -> StringChunked {
+    ca.apply_to_buffer(|s, buf| normalizer(s, buf))
+}
+
+/// Normalize the string values to the given Unicode normalization `form`.
+pub fn normalize(ca: &StringChunked, form: UnicodeForm) -> StringChunked {
+    match form {
+        UnicodeForm::NFC => normalize_with(ca, |s, buf| buf.extend(s.nfc())),
+        UnicodeForm::NFD => normalize_with(ca, |s, buf| buf.extend(s.nfd())),
+        UnicodeForm::NFKC => normalize_with(ca, |s, buf| buf.extend(s.nfkc())),
+        UnicodeForm::NFKD => normalize_with(ca, |s, buf| buf.extend(s.nfkd())),
+    }
+}
@@ -59,11 +59,13 @@ fn zfill_fn<'a>(s: Option<&'a str>, len: Option<u64>, buf: &mut String) -> Optio
                 return Some(s);
             }
             buf.clear();
-            if let Some(stripped) = s.strip_prefix('-') {
+            // Like Python's `str.zfill`, the padding goes after a leading sign.
+            if let Some(stripped) = s.strip_prefix(['-', '+']) {
                 write!(
                     buf,
-                    "-{:0length$}{value}",
+                    "{sign}{:0length$}{value}",
                     0,
+                    sign = &s[..1],
                     length = length as usize,
                     value = stripped
                 )
@@ -2,6 +2,8 @@ use arrow::array::ValueSize;
 #[cfg(feature = "dtype-struct")]
 use arrow::array::{MutableArray, MutableUtf8Array};
 use polars_core::chunked_array::ops::arity::binary_elementwise_for_each;
+use polars_core::export::regex::Regex;
+use polars_utils::cache::FastFixedCache;
 
 use super::*;
 
@@ -112,3 +114,134 @@ where
         builder.finish()
     }
 }
+
+/// Split `s` at every match of `reg`, keeping each match at the end of the preceding piece.
+fn split_regex_inclusive<'a>(reg: &'a Regex, s: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    let mut matches = reg.find_iter(s);
+    let mut last = 0;
+    std::iter::from_fn(move || match matches.next() {
+        Some(m) => {
+            let piece = &s[last..m.end()];
+            last = m.end();
+            Some(piece)
+        },
+        None if last < s.len() => {
+            let piece = &s[last..];
+            last = s.len();
+            Some(piece)
+        },
+        None => None,
+    })
+}
+
+fn append_split_regex(
+    builder: &mut ListStringChunkedBuilder,
+    reg: &Regex,
+    s: &str,
+    inclusive: bool,
+) {
+    if inclusive {
+        builder.append_values_iter(split_regex_inclusive(reg, s))
+    } else {
+        builder.append_values_iter(reg.split(s))
+    }
+}
+
+/// Split every string at the matches of the regex patterns in `by`.
+///
+/// A single pattern is compiled once for all rows, per-row patterns go through a regex cache.
+/// Consecutive matches produce empty strings, unless `inclusive` is set, in which case the
+/// matches are kept at the end of the preceding piece.
+pub fn split_regex_helper(
+    ca: &StringChunked,
+    by: &StringChunked,
+    inclusive: bool,
+) -> PolarsResult<ListChunked> {
+    let mut builder = ListStringChunkedBuilder::new(ca.name(), ca.len(), ca.get_values_size());
+
+    if by.len() == 1 {
+        let Some(by) = by.get(0) else {
+            return Ok(ListChunked::full_null_with_dtype(
+                ca.name(),
+                ca.len(),
+                &DataType::String,
+            ));
+        };
+        let reg = Regex::new(by)?;
+        ca.for_each(|opt_s| match opt_s {
+            Some(s) => append_split_regex(&mut builder, &reg, s, inclusive),
+            None => builder.append_null(),
+        });
+    } else {
+        polars_ensure!(
+            ca.len() == by.len(),
+            ShapeMismatch: "pattern's length: {} does not match that of the argument series: {}",
+            by.len(), ca.len(),
+        );
+        // A sqrt(n) regex cache is not too small, not too large.
+        let mut reg_cache = FastFixedCache::new((ca.len() as f64).sqrt() as usize);
+        for (opt_s, opt_by) in ca.into_iter().zip(by) {
+            match (opt_s, opt_by) {
+                (Some(s), Some(by)) => {
+                    let reg = reg_cache.try_get_or_insert_with(by, |p| Regex::new(p))?;
+                    append_split_regex(&mut builder, reg, s, inclusive)
+                },
+                _ => builder.append_null(),
+            }
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn to_vecs(ca: &ListChunked) -> Vec<Option<Vec<String>>> {
+        ca.into_iter()
+            .map(|opt_s| {
+                opt_s.map(|s| {
+                    let ca = s.str().unwrap();
+                    ca.into_no_null_iter().map(|s| s.to_string()).collect()
+                })
+            })
+            .collect()
+    }
+
+    fn strings(values: &[&str]) -> Option<Vec<String>> {
+        Some(values.iter().map(|s| s.to_string()).collect())
+    }
+
+    #[test]
+    fn test_split_regex() -> PolarsResult<()> {
+        let ca = StringChunked::new("a", &[Some("a  b\tc"), Some("a,;b"), None, Some("")]);
+
+        let by = StringChunked::new("", &[r"[\s,;]"]);
+        let out = split_regex_helper(&ca, &by, false)?;
+        assert_eq!(
+            to_vecs(&out),
+            [
+                strings(&["a", "", "b", "c"]),
+                strings(&["a", "", "b"]),
+                None,
+                strings(&[""]),
+            ]
+        );
+
+        let by = StringChunked::new("", &[r"\s+", ",", ";", "x"]);
+        let out = split_regex_helper(&ca, &by, true)?;
+        assert_eq!(
+            to_vecs(&out),
+            [
+                strings(&["a  ", "b\t", "c"]),
+                strings(&["a,", ";b"]),
+                None,
+                strings(&[]),
+            ]
+        );
+
+        let by = StringChunked::new("", &["("]);
+        assert!(split_regex_helper(&ca, &by, false).is_err());
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use polars_core::prelude::arity::{binary_elementwise, ternary_elementwise, unary_elementwise};
-use polars_core::prelude::{Int64Chunked, StringChunked, UInt64Chunked};
+use polars_core::prelude::*;
 
 fn head_binary(opt_str_val: Option<&str>, opt_n: Option<i64>) -> Option<&str> {
     if let (Some(str_val), Some(n)) = (opt_str_val, opt_n) {
@@ -179,6 +179,71 @@ pub(super) fn substring(
     }
 }
 
+fn substring_bytes_ternary(
+    str_val: &str,
+    offset: i64,
+    opt_length: Option<u64>,
+) -> PolarsResult<&str> {
+    let len = str_val.len() as i64;
+    // Like the character based slice, a negative offset before the start of the string
+    // reduces the length.
+    let (start, length_reduction) = if offset >= 0 {
+        (offset.min(len), 0)
+    } else if -offset <= len {
+        (len + offset, 0)
+    } else {
+        (0, (-offset - len) as u64)
+    };
+    let start = start as usize;
+    let end = match opt_length {
+        Some(length) => start
+            .saturating_add(length.saturating_sub(length_reduction) as usize)
+            .min(str_val.len()),
+        None => str_val.len(),
+    };
+    polars_ensure!(
+        str_val.is_char_boundary(start) && str_val.is_char_boundary(end),
+        ComputeError: "byte slice {}..{} of {:?} does not fall on character boundaries",
+        start, end, str_val
+    );
+    Ok(&str_val[start..end])
+}
+
+pub(super) fn substring_bytes(
+    ca: &StringChunked,
+    offset: &Int64Chunked,
+    length: &UInt64Chunked,
+) -> PolarsResult<StringChunked> {
+    let len = ca.len().max(offset.len()).max(length.len());
+    let broadcast = |s_len: usize| s_len == 1 && len != 1;
+    let ca = if broadcast(ca.len()) {
+        ca.new_from_index(0, len)
+    } else {
+        ca.clone()
+    };
+    let offset = if broadcast(offset.len()) {
+        offset.new_from_index(0, len)
+    } else {
+        offset.clone()
+    };
+    let length = if broadcast(length.len()) {
+        length.new_from_index(0, len)
+    } else {
+        length.clone()
+    };
+
+    let mut builder = StringChunkedBuilder::new(ca.name(), len);
+    for ((opt_str_val, opt_offset), opt_length) in ca.iter().zip(offset.iter()).zip(length.iter()) {
+        match (opt_str_val, opt_offset) {
+            (Some(str_val), Some(offset)) => {
+                builder.append_value(substring_bytes_ternary(str_val, offset, opt_length)?)
+            },
+            _ => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
 pub(super) fn head(ca: &StringChunked, n: &Int64Chunked) -> StringChunked {
     match (ca.len(), n.len()) {
         (_, 1) => {
@@ -22,20 +22,24 @@ use std::marker::PhantomData;
 
 use polars_core::export::ahash::RandomState;
 
-/// The greater is P, the smaller the error.
-const HLL_P: usize = 14_usize;
-/// The number of bits of the hash value used determining the number of leading zeros
-const HLL_Q: usize = 64_usize - HLL_P;
-const NUM_REGISTERS: usize = 1_usize << HLL_P;
-/// Mask to obtain index into the registers
-const HLL_P_MASK: u64 = (NUM_REGISTERS as u64) - 1;
+/// The default number of bits of the hash value used to select a register. The greater it
+/// is, the smaller the error.
+pub const HLL_DEFAULT_PRECISION: u8 = 14;
+/// The smallest supported precision.
+pub const HLL_MIN_PRECISION: u8 = 4;
+/// The largest supported precision.
+pub const HLL_MAX_PRECISION: u8 = 18;
+/// The largest number of bits of the hash value used determining the number of leading zeros
+const HLL_MAX_Q: usize = 64 - HLL_MIN_PRECISION as usize;
 
 #[derive(Clone, Debug)]
 pub struct HyperLogLog<T>
 where
     T: Hash + ?Sized,
 {
-    registers: [u8; NUM_REGISTERS],
+    /// Number of bits of the hash used to select a register, there are `2^p` registers.
+    p: u8,
+    registers: Vec<u8>,
     phantom: PhantomData<T>,
 }
 
@@ -65,22 +69,34 @@ impl<T> HyperLogLog<T>
 where
     T: Hash + ?Sized,
 {
-    /// Creates a new, empty HyperLogLog.
+    /// Creates a new, empty HyperLogLog with the default precision.
     pub fn new() -> Self {
-        let registers = [0; NUM_REGISTERS];
-        Self::new_with_registers(registers)
+        Self::with_precision(HLL_DEFAULT_PRECISION)
     }
 
-    /// Creates a HyperLogLog from already populated registers
-    /// note that this method should not be invoked in untrusted environment
-    /// because the internal structure of registers are not examined.
-    pub(crate) fn new_with_registers(registers: [u8; NUM_REGISTERS]) -> Self {
+    /// Creates a new, empty HyperLogLog with `2^precision` registers.
+    ///
+    /// The standard error of the estimate is about `1.04 / sqrt(2^precision)`.
+    ///
+    /// # Panics
+    /// Panics if `precision` is not within [`HLL_MIN_PRECISION`] and [`HLL_MAX_PRECISION`].
+    pub fn with_precision(precision: u8) -> Self {
+        assert!(
+            (HLL_MIN_PRECISION..=HLL_MAX_PRECISION).contains(&precision),
+            "precision must be between {HLL_MIN_PRECISION} and {HLL_MAX_PRECISION}, got {precision}"
+        );
         Self {
-            registers,
+            p: precision,
+            registers: vec![0; 1 << precision],
             phantom: PhantomData,
         }
     }
 
+    #[inline]
+    fn q(&self) -> usize {
+        64 - self.p as usize
+    }
+
     /// choice of hash function: ahash is already an dependency
     /// and it fits the requirements of being a 64bit hash with
     /// reasonable performance.
@@ -92,18 +108,19 @@ where
     /// Adds an element to the HyperLogLog.
     pub fn add(&mut self, obj: &T) {
         let hash = self.hash_value(obj);
-        let index = (hash & HLL_P_MASK) as usize;
-        let p = ((hash >> HLL_P) | (1_u64 << HLL_Q)).trailing_zeros() + 1;
+        let index = (hash & (self.registers.len() as u64 - 1)) as usize;
+        let p = ((hash >> self.p) | (1_u64 << self.q())).trailing_zeros() + 1;
         self.registers[index] = self.registers[index].max(p as u8);
     }
 
     /// Get the register histogram (each value in register index into
-    /// the histogram; u32 is enough because we only have 2**14=16384 registers
+    /// the histogram; u32 is enough because we have at most 2**18 registers.
+    /// Only the first `q + 2` entries are used.
     #[inline]
-    fn get_histogram(&self) -> [u32; HLL_Q + 2] {
-        let mut histogram = [0; HLL_Q + 2];
+    fn get_histogram(&self) -> [u32; HLL_MAX_Q + 2] {
+        let mut histogram = [0; HLL_MAX_Q + 2];
         // hopefully this can be unrolled
-        for r in self.registers {
+        for &r in &self.registers {
             histogram[r as usize] += 1;
         }
         histogram
@@ -125,9 +142,10 @@ where
     /// Guess the number of unique elements seen by the HyperLogLog.
     pub fn count(&self) -> usize {
         let histogram = self.get_histogram();
-        let m = NUM_REGISTERS as f64;
-        let mut z = m * hll_tau((m - histogram[HLL_Q + 1] as f64) / m);
-        for i in histogram[1..=HLL_Q].iter().rev() {
+        let q = self.q();
+        let m = self.registers.len() as f64;
+        let mut z = m * hll_tau((m - histogram[q + 1] as f64) / m);
+        for i in histogram[1..=q].iter().rev() {
             z += *i as f64;
             z *= 0.5;
         }
@@ -217,16 +235,20 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::{HyperLogLog, NUM_REGISTERS};
+    use super::*;
 
     fn compare_with_delta(got: usize, expected: usize) {
+        compare_with_delta_precision(got, expected, HLL_DEFAULT_PRECISION)
+    }
+
+    fn compare_with_delta_precision(got: usize, expected: usize, precision: u8) {
         let expected = expected as f64;
         let diff = (got as f64) - expected;
         let diff = diff.abs() / expected;
         // times 6 because we want the tests to be stable
         // so we allow a rather large margin of error
         // this is adopted from redis's unit test version as well
-        let margin = 1.04 / ((NUM_REGISTERS as f64).sqrt()) * 6.0;
+        let margin = 1.04 / (((1usize << precision) as f64).sqrt()) * 6.0;
         assert!(
             diff <= margin,
             "{} is not near {} percent of {} which is ({}, {})",
@@ -350,4 +372,13 @@ mod tests {
         }
         compare_with_delta(hll.count(), 1000);
     }
+
+    #[test]
+    fn test_precision() {
+        for precision in [HLL_MIN_PRECISION, 8, 12, HLL_MAX_PRECISION] {
+            let mut hll = HyperLogLog::<u64>::with_precision(precision);
+            hll.extend(0..50_000u64);
+            compare_with_delta_precision(hll.count(), 50_000, precision);
+        }
+    }
 }
@@ -5,41 +5,62 @@ use polars_core::with_match_physical_integer_polars_type;
 use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
 
 #[cfg(feature = "approx_unique")]
-use crate::series::ops::approx_algo::HyperLogLog;
+use crate::series::ops::approx_algo::{
+    HyperLogLog, HLL_DEFAULT_PRECISION, HLL_MAX_PRECISION, HLL_MIN_PRECISION,
+};
 
-fn approx_n_unique_ca<'a, T>(ca: &'a ChunkedArray<T>) -> PolarsResult<Series>
+fn approx_n_unique_ca<'a, T>(ca: &'a ChunkedArray<T>, precision: u8) -> PolarsResult<Series>
 where
     T: PolarsDataType,
     T::Physical<'a>: TotalHash + TotalEq + Copy + ToTotalOrd,
     <Option<T::Physical<'a>> as ToTotalOrd>::TotalOrdItem: Hash + Eq,
 {
-    let mut hllp = HyperLogLog::new();
-    ca.iter().for_each(|item| hllp.add(&item.to_total_ord()));
-    let c = hllp.count() as IdxSize;
+    // Count exactly as long as there are at most as many unique values as registers, which avoids
+    // the bias of the estimator at small cardinalities.
+    let exact_limit = 1usize << precision;
+    let mut exact = PlHashSet::new();
+    let mut iter = ca.iter().map(|item| item.to_total_ord());
+    for item in iter.by_ref() {
+        exact.insert(item);
+        if exact.len() > exact_limit {
+            break;
+        }
+    }
+    let c = if exact.len() <= exact_limit {
+        exact.len()
+    } else {
+        let mut hllp = HyperLogLog::with_precision(precision);
+        hllp.extend(exact.iter());
+        drop(exact);
+        iter.for_each(|item| hllp.add(&item));
+        hllp.count()
+    };
 
-    Ok(Series::new(ca.name(), &[c]))
+    Ok(Series::new(ca.name(), &[c as IdxSize]))
 }
 
-fn dispatcher(s: &Series) -> PolarsResult<Series> {
+fn dispatcher(s: &Series, precision: u8) -> PolarsResult<Series> {
     let s = s.to_physical_repr();
     use DataType::*;
     match s.dtype() {
-        Boolean => s.bool().and_then(approx_n_unique_ca),
-        Binary => s.binary().and_then(approx_n_unique_ca),
+        Boolean => s.bool().and_then(|ca| approx_n_unique_ca(ca, precision)),
+        Binary => s.binary().and_then(|ca| approx_n_unique_ca(ca, precision)),
         String => {
             let ca = s.str().unwrap().as_binary();
-            approx_n_unique_ca(&ca)
+            approx_n_unique_ca(&ca, precision)
         },
-        Float32 => approx_n_unique_ca(AsRef::<ChunkedArray<Float32Type>>::as_ref(
-            s.as_ref().as_ref(),
-        )),
-        Float64 => approx_n_unique_ca(AsRef::<ChunkedArray<Float64Type>>::as_ref(
-            s.as_ref().as_ref(),
-        )),
+        Float32 => approx_n_unique_ca(
+            AsRef::<ChunkedArray<Float32Type>>::as_ref(s.as_ref().as_ref()),
+            precision,
+        ),
+        Float64 => approx_n_unique_ca(
+            AsRef::<ChunkedArray<Float64Type>>::as_ref(s.as_ref().as_ref()),
+            precision,
+        ),
         dt if dt.is_numeric() => {
             with_match_physical_integer_polars_type!(s.dtype(), |$T| {
                 let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
-                approx_n_unique_ca(ca)
+                approx_n_unique_ca(ca, precision)
             })
         },
         dt => polars_bail!(opq = approx_n_unique, dt),
@@ -48,7 +69,8 @@ fn dispatcher(s: &Series) -> PolarsResult<Series> {
 
 /// Approx count unique values.
 ///
-/// This is done using the HyperLogLog++ algorithm for cardinality estimation.
+/// This is done using the HyperLogLog++ algorithm for cardinality estimation, with the
+/// default precision of 14. See [`approx_n_unique_with_precision`] to tune it.
 ///
 /// # Example
 ///
@@ -74,5 +96,51 @@ fn dispatcher(s: &Series) -> PolarsResult<Series> {
 /// ]
 /// ```
 pub fn approx_n_unique(s: &Series) -> PolarsResult<Series> {
-    dispatcher(s)
+    dispatcher(s, HLL_DEFAULT_PRECISION)
+}
+
+/// Approx count unique values, using `2^precision` registers.
+///
+/// A higher precision uses more memory, one byte per register, but gives a more accurate
+/// estimate. The relative standard error is about `1.04 / sqrt(2^precision)`:
+///
+/// | precision | registers | standard error |
+/// |-----------|-----------|----------------|
+/// | 4         | 16        | 26%            |
+/// | 8         | 256       | 6.5%           |
+/// | 10        | 1024      | 3.3%           |
+/// | 12        | 4096      | 1.6%           |
+/// | 14        | 16384     | 0.81%          |
+/// | 16        | 65536     | 0.41%          |
+/// | 18        | 262144    | 0.20%          |
+///
+/// As long as there are at most `2^precision` unique values they are counted exactly.
+pub fn approx_n_unique_with_precision(s: &Series, precision: u8) -> PolarsResult<Series> {
+    polars_ensure!(
+        (HLL_MIN_PRECISION..=HLL_MAX_PRECISION).contains(&precision),
+        ComputeError: "`precision` of `approx_n_unique` must be between {} and {}, got {}",
+        HLL_MIN_PRECISION, HLL_MAX_PRECISION, precision
+    );
+    dispatcher(s, precision)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_approx_n_unique_with_precision() -> PolarsResult<()> {
+        let s = Series::new("a", (0..100i32).map(|i| i % 10).collect::<Vec<_>>());
+        let out = approx_n_unique_with_precision(&s, 4)?;
+        assert_eq!(out.get(0)?, AnyValue::from(10 as IdxSize));
+
+        let s = Series::new("a", (0..20_000i64).collect::<Vec<_>>());
+        let out = approx_n_unique_with_precision(&s, 12)?;
+        let count = out.idx()?.get(0).unwrap() as f64;
+        assert!((count - 20_000.0).abs() / 20_000.0 < 0.1);
+
+        assert!(approx_n_unique_with_precision(&s, 3).is_err());
+        assert!(approx_n_unique_with_precision(&s, 19).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,94 @@
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_per_column;
+use polars_core::prelude::*;
+use polars_core::POOL;
+use polars_utils::iter::EnumerateIdxTrait;
+use rayon::prelude::*;
+
+fn broadcast_flags(flags: &[bool], n_keys: usize, name: &str) -> PolarsResult<Vec<bool>> {
+    match flags.len() {
+        1 => Ok(vec![flags[0]; n_keys]),
+        n if n == n_keys => Ok(flags.to_vec()),
+        n => polars_bail!(
+            ComputeError: "the number of `{}` flags ({}) does not match the number of sort keys ({})",
+            name, n, n_keys
+        ),
+    }
+}
+
+/// Get the permutation that sorts the rows by the first key, breaking ties with the next keys.
+///
+/// Every key has its own sort direction and null placement; a single flag is broadcast to all
+/// keys. Rows that are equal on all keys keep their original order.
+pub fn arg_sort_by(
+    keys: &[Series],
+    descending: &[bool],
+    nulls_last: &[bool],
+    parallel: bool,
+) -> PolarsResult<IdxCa> {
+    polars_ensure!(!keys.is_empty(), ComputeError: "`arg_sort_by` needs at least one sort key");
+    let len = keys[0].len();
+    for s in &keys[1..] {
+        polars_ensure!(
+            s.len() == len,
+            ShapeMismatch: "sort keys must have equal lengths, got {} and {}", len, s.len()
+        );
+    }
+    let descending = broadcast_flags(descending, keys.len(), "descending")?;
+    let nulls_last = broadcast_flags(nulls_last, keys.len(), "nulls_last")?;
+
+    let name = keys[0].name();
+    if len == 0 {
+        return Ok(IdxCa::from_vec(name, vec![]));
+    }
+
+    let rows = _get_rows_encoded_per_column(keys, &descending, &nulls_last)?;
+    let mut items: Vec<_> = rows.iter().enumerate_idx().collect();
+    if parallel {
+        POOL.install(|| items.par_sort_by(|a, b| a.1.cmp(b.1)));
+    } else {
+        items.sort_by(|a, b| a.1.cmp(b.1));
+    }
+    Ok(IdxCa::from_vec(
+        name,
+        items.into_iter().map(|(idx, _)| idx).collect(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_arg_sort_by() -> PolarsResult<()> {
+        let a = Series::new("a", [Some(1i32), None, Some(1), Some(2), None]);
+        let b = Series::new("b", [Some("x"), Some("y"), None, Some("z"), Some("x")]);
+
+        let out = arg_sort_by(
+            &[a.clone(), b.clone()],
+            &[false, true],
+            &[true, false],
+            false,
+        )?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(2), Some(0), Some(3), Some(1), Some(4)]
+        );
+
+        let out = arg_sort_by(&[a.clone(), b.clone()], &[true], &[false, true], true)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(1), Some(4), Some(3), Some(0), Some(2)]
+        );
+
+        assert!(arg_sort_by(
+            &[a.clone(), b.clone()],
+            &[true, false, true],
+            &[false],
+            false
+        )
+        .is_err());
+        assert!(arg_sort_by(&[a.clone(), b.head(Some(2))], &[false], &[false], false).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use num_traits::PrimInt;
+use polars_core::prelude::*;
+use polars_core::with_match_physical_integer_polars_type;
+
+#[derive(Copy, Clone)]
+enum BitOp {
+    CountOnes,
+    LeadingZeros,
+    TrailingZeros,
+}
+
+impl BitOp {
+    fn name(self) -> &'static str {
+        match self {
+            BitOp::CountOnes => "bit_count",
+            BitOp::LeadingZeros => "leading_zeros",
+            BitOp::TrailingZeros => "trailing_zeros",
+        }
+    }
+}
+
+fn apply_bit_op(s: &Series, op: BitOp) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.dtype().is_integer(),
+        InvalidOperation: "`{}` only supports integer dtypes, got {}", op.name(), s.dtype()
+    );
+    with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+        let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+        Ok(apply_bit_op_ca(ca, op).with_name(s.name()).into_series())
+    })
+}
+
+fn apply_bit_op_ca<T>(ca: &ChunkedArray<T>, op: BitOp) -> UInt32Chunked
+where
+    T: PolarsIntegerType,
+    T::Native: PrimInt,
+{
+    match op {
+        BitOp::CountOnes => ca.apply_values_generic(|v| v.count_ones()),
+        BitOp::LeadingZeros => ca.apply_values_generic(|v| v.leading_zeros()),
+        BitOp::TrailingZeros => ca.apply_values_generic(|v| v.trailing_zeros()),
+    }
+}
+
+/// Count the number of set bits of every integer, a.k.a. the population count.
+pub fn bit_count(s: &Series) -> PolarsResult<Series> {
+    apply_bit_op(s, BitOp::CountOnes)
+}
+
+/// Count the number of leading zero bits of every integer, within the width of its dtype.
+pub fn leading_zeros(s: &Series) -> PolarsResult<Series> {
+    apply_bit_op(s, BitOp::LeadingZeros)
+}
+
+/// Count the number of trailing zero bits of every integer. Zero has as many trailing zeros
+/// as the width of its dtype.
+pub fn trailing_zeros(s: &Series) -> PolarsResult<Series> {
+    apply_bit_op(s, BitOp::TrailingZeros)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bit_counts() -> PolarsResult<()> {
+        let s = Series::new("a", [Some(0i32), Some(1), Some(-1), Some(i32::MIN), None]);
+        assert_eq!(
+            Vec::from(bit_count(&s)?.u32()?),
+            &[Some(0), Some(1), Some(32), Some(1), None]
+        );
+        assert_eq!(
+            Vec::from(leading_zeros(&s)?.u32()?),
+            &[Some(32), Some(31), Some(0), Some(0), None]
+        );
+        assert_eq!(
+            Vec::from(trailing_zeros(&s)?.u32()?),
+            &[Some(32), Some(0), Some(0), Some(31), None]
+        );
+
+        let s = Series::new("a", [u64::MAX, 1 << 40]);
+        assert_eq!(Vec::from(bit_count(&s)?.u32()?), &[Some(64), Some(1)]);
+        assert_eq!(Vec::from(leading_zeros(&s)?.u32()?), &[Some(0), Some(23)]);
+        assert_eq!(Vec::from(trailing_zeros(&s)?.u32()?), &[Some(0), Some(40)]);
+
+        assert!(bit_count(&Series::new("a", [1.0f64])).is_err());
+        Ok(())
+    }
+}
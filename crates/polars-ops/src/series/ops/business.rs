@@ -1,106 +1,737 @@
+use std::sync::{Mutex, OnceLock};
+
 #[cfg(feature = "dtype-date")]
-use chrono::DateTime;
-use polars_core::prelude::arity::{binary_elementwise_values, try_binary_elementwise};
+use chrono::{DateTime, Datelike, Months, NaiveDate};
+use polars_core::prelude::arity::{
+    binary_elementwise, binary_elementwise_values, broadcast_try_binary_elementwise,
+    try_unary_elementwise, unary_elementwise, unary_elementwise_values,
+};
 use polars_core::prelude::*;
+use polars_core::utils::_split_offsets;
 #[cfg(feature = "dtype-date")]
-use polars_core::utils::arrow::temporal_conversions::SECONDS_IN_DAY;
+use polars_core::utils::arrow::temporal_conversions::{
+    date32_to_date_opt, EPOCH_DAYS_FROM_CE, SECONDS_IN_DAY,
+};
+use polars_core::POOL;
 use polars_utils::binary_search::{find_first_ge_index, find_first_gt_index};
+use polars_utils::cache::FastFixedCache;
+use rayon::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "timezones")]
 use crate::prelude::replace_time_zone;
+use crate::prelude::ClosedInterval;
+
+/// Inputs shorter than this are not worth splitting over the thread pool.
+const PARALLEL_THRESHOLD: usize = 1000;
+
+/// The number of calendars kept by [`BusinessDayCalendar::cached`].
+const CALENDAR_CACHE_SIZE: usize = 16;
+
+/// Calendars keyed by their week mask and the content hash of the raw holidays. The raw
+/// holidays are kept next to the calendar, so a hash collision is detected rather than
+/// returning the wrong calendar.
+type CalendarCache = FastFixedCache<([bool; 7], u64), (Arc<[i32]>, BusinessDayCalendar)>;
+
+fn calendar_cache() -> &'static Mutex<CalendarCache> {
+    static CACHE: OnceLock<Mutex<CalendarCache>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(FastFixedCache::new(CALENDAR_CACHE_SIZE)))
+}
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Roll {
+    /// Roll forward to the next business day, also known as "following".
     Forward,
+    /// Roll backward to the previous business day, also known as "preceding".
     Backward,
+    /// Raise an error.
     Raise,
+    /// Roll forward, unless that would cross into the next month, in which case roll backward.
+    ModifiedFollowing,
+    /// Roll backward, unless that would cross into the previous month, in which case roll
+    /// forward.
+    ModifiedPreceding,
+    /// Return null.
+    Null,
 }
 
-/// Count the number of business days between `start` and `end`, excluding `end`.
+/// A validated week mask together with its normalised holidays.
+///
+/// Building a calendar once and reusing it avoids re-validating the week mask and
+/// re-sorting the holidays on every call, e.g. when counting business days per group.
+/// The holidays are shared, so cloning a calendar is cheap.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BusinessDayCalendar {
+    week_mask: [bool; 7],
+    n_business_days_in_week_mask: i32,
+    holidays: Arc<[i32]>,
+}
+
+impl BusinessDayCalendar {
+    /// # Arguments
+    /// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+    /// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+    ///   days since the UNIX epoch.
+    pub fn new(week_mask: [bool; 7], holidays: &[i32]) -> PolarsResult<Self> {
+        Self::new_with_validation(week_mask, holidays, true)
+    }
+
+    /// Same as [`BusinessDayCalendar::new`], but allows skipping the check that all
+    /// `holidays` lie within the range of valid dates.
+    ///
+    /// The check guards against holidays accidentally given as timestamps in another
+    /// unit, e.g. seconds or milliseconds since the UNIX epoch. It only costs a single
+    /// scan over `holidays`, but can be disabled with `validate_holidays = false`.
+    pub fn new_with_validation(
+        week_mask: [bool; 7],
+        holidays: &[i32],
+        validate_holidays: bool,
+    ) -> PolarsResult<Self> {
+        if !week_mask.iter().any(|&x| x) {
+            polars_bail!(
+                ComputeError: "`week_mask` must have at least one business day, got {:?}", week_mask
+            );
+        }
+        if validate_holidays {
+            check_holidays_in_date_range(holidays)?;
+        }
+        Ok(Self {
+            week_mask,
+            n_business_days_in_week_mask: week_mask.iter().filter(|&x| *x).count() as i32,
+            holidays: normalise_holidays(holidays, &week_mask).into(),
+        })
+    }
+
+    /// Same as [`BusinessDayCalendar::new`], but reuses the calendar built by an earlier call
+    /// with the same week mask and holidays.
+    ///
+    /// The lazy engine evaluates a business day expression once per partition or group, and
+    /// several expressions often share one holiday list. With the cache, the holidays are
+    /// validated and normalised only once.
+    pub fn cached(week_mask: [bool; 7], holidays: &[i32]) -> PolarsResult<Self> {
+        if holidays.is_empty() {
+            return Self::new(week_mask, holidays);
+        }
+        let hash = ahash::RandomState::with_seeds(0, 0, 0, 0).hash_one(holidays);
+        let key = (week_mask, hash);
+        if let Some((raw, calendar)) = calendar_cache().lock().unwrap().get(&key) {
+            if **raw == *holidays {
+                return Ok(calendar.clone());
+            }
+        }
+
+        // Build outside of the lock, so concurrent callers aren't blocked on the sort.
+        let calendar = Self::new(week_mask, holidays)?;
+        calendar_cache()
+            .lock()
+            .unwrap()
+            .insert(key, (holidays.into(), calendar.clone()));
+        Ok(calendar)
+    }
+
+    pub fn week_mask(&self) -> &[bool; 7] {
+        &self.week_mask
+    }
+
+    /// The holidays that fall on business days, sorted and deduplicated.
+    pub fn holidays(&self) -> &[i32] {
+        &self.holidays
+    }
+
+    /// See [`business_day_count`].
+    pub fn business_day_count(
+        &self,
+        start: &Series,
+        end: &Series,
+        closed: ClosedInterval,
+        output_dtype: Option<DataType>,
+    ) -> PolarsResult<Series> {
+        polars_ensure!(
+            start.dtype() == end.dtype(),
+            ComputeError: "`start` and `end` must have the same dtype, got {} and {}", start.dtype(), end.dtype()
+        );
+
+        let start = to_local_date(start)?;
+        let end = to_local_date(end)?;
+        let start_dates = start.date()?;
+        let end_dates = end.date()?;
+
+        let out = match output_dtype.unwrap_or(DataType::Int32) {
+            DataType::Int32 => self
+                .business_day_count_dates::<Int32Type>(start_dates, end_dates, closed)
+                .into_series(),
+            DataType::Int64 => self
+                .business_day_count_dates::<Int64Type>(start_dates, end_dates, closed)
+                .into_series(),
+            dt => polars_bail!(ComputeError: "`output_dtype` must be Int32 or Int64, got {}", dt),
+        };
+        Ok(out)
+    }
+
+    /// Count the business days between the physical dates, widening each count to `T`.
+    fn business_day_count_dates<T>(
+        &self,
+        start_dates: &Int32Chunked,
+        end_dates: &Int32Chunked,
+        closed: ClosedInterval,
+    ) -> ChunkedArray<T>
+    where
+        T: PolarsNumericType,
+        T::Native: From<i32>,
+    {
+        if self.holidays.is_empty() {
+            apply_business_day_count(start_dates, end_dates, |start_date, end_date| {
+                business_day_count_no_holidays_impl(
+                    start_date,
+                    end_date,
+                    &self.week_mask,
+                    self.n_business_days_in_week_mask,
+                    closed,
+                )
+                .into()
+            })
+        } else {
+            apply_business_day_count(start_dates, end_dates, |start_date, end_date| {
+                business_day_count_impl(
+                    start_date,
+                    end_date,
+                    &self.week_mask,
+                    self.n_business_days_in_week_mask,
+                    &self.holidays,
+                    closed,
+                )
+                .into()
+            })
+        }
+    }
+
+    /// See [`add_business_days`].
+    pub fn add_business_days(
+        &self,
+        start: &Series,
+        n: &Series,
+        roll: Roll,
+    ) -> PolarsResult<Series> {
+        match start.dtype() {
+            DataType::Date => {},
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(time_unit, None) => {
+                let result_date = self.add_business_days(&start.cast(&DataType::Date)?, n, roll)?;
+                let start_time = start
+                    .cast(&DataType::Time)?
+                    .cast(&DataType::Duration(*time_unit))?;
+                return Ok(result_date.cast(&DataType::Datetime(*time_unit, None))? + start_time);
+            },
+            #[cfg(feature = "timezones")]
+            DataType::Datetime(time_unit, Some(time_zone)) => {
+                let start_naive = replace_time_zone(
+                    start.datetime().unwrap(),
+                    None,
+                    &StringChunked::from_iter(std::iter::once("raise")),
+                    NonExistent::Raise,
+                )?;
+                let result_date =
+                    self.add_business_days(&start_naive.cast(&DataType::Date)?, n, roll)?;
+                let start_time = start_naive
+                    .cast(&DataType::Time)?
+                    .cast(&DataType::Duration(*time_unit))?;
+                let result_naive =
+                    result_date.cast(&DataType::Datetime(*time_unit, None))? + start_time;
+                let result_tz_aware = replace_time_zone(
+                    result_naive.datetime().unwrap(),
+                    Some(time_zone),
+                    &StringChunked::from_iter(std::iter::once("raise")),
+                    NonExistent::Raise,
+                )?;
+                return Ok(result_tz_aware.into_series());
+            },
+            _ => polars_bail!(InvalidOperation: "expected date or datetime, got {}", start.dtype()),
+        }
+
+        let start_dates = start.date()?;
+        let n = cast_n_to_i32(n)?;
+        let n = n.i32()?;
+
+        let add = |start_date, n| {
+            let rolled = roll_start_date(start_date, roll, &self.week_mask, &self.holidays)?;
+            Ok::<Option<i32>, PolarsError>(rolled.map(|(start_date, day_of_week)| {
+                add_business_days_impl(
+                    start_date,
+                    day_of_week,
+                    n,
+                    &self.week_mask,
+                    self.n_business_days_in_week_mask,
+                    &self.holidays,
+                )
+            }))
+        };
+        let out: Int32Chunked = match (start_dates.len(), n.len()) {
+            (1, n_len) if n_len != 1 => {
+                // Only roll the start date once.
+                let rolled = match start_dates.get(0) {
+                    Some(start_date) => {
+                        roll_start_date(start_date, roll, &self.week_mask, &self.holidays)?
+                    },
+                    None => None,
+                };
+                if let Some((start_date, day_of_week)) = rolled {
+                    n.apply_values(|n| {
+                        add_business_days_impl(
+                            start_date,
+                            day_of_week,
+                            n,
+                            &self.week_mask,
+                            self.n_business_days_in_week_mask,
+                            &self.holidays,
+                        )
+                    })
+                } else {
+                    Int32Chunked::full_null(start_dates.name(), n_len)
+                }
+            },
+            _ => {
+                broadcast_try_binary_elementwise(start_dates, n, |opt_start_date, opt_n| {
+                    match (opt_start_date, opt_n) {
+                        (Some(start_date), Some(n)) => add(start_date, n),
+                        _ => Ok(None),
+                    }
+                })?
+            },
+        };
+        Ok(out.into_date().into_series())
+    }
+
+    /// See [`nth_business_day`].
+    pub fn nth_business_day(&self, start: &Series, n: &Series) -> PolarsResult<Series> {
+        let len = start.len().max(n.len());
+        let start = broadcast(&to_local_date(start)?, len)?;
+        let n = broadcast(&cast_n_to_i32(n)?, len)?;
+
+        let out: Int32Chunked =
+            binary_elementwise(start.date()?, n.i32()?, |opt_start_date, opt_n| {
+                let (start_date, n) = (opt_start_date?, opt_n?);
+                if n == 0 {
+                    return None;
+                }
+                // Rolling and offsetting moves at most this many days away from `start`.
+                let max_days =
+                    (n.unsigned_abs() as i64 / self.n_business_days_in_week_mask as i64 + 2) * 7
+                        + self.holidays.len() as i64;
+                let horizon = start_date as i64 + n.signum() as i64 * max_days;
+                if i32::try_from(horizon).is_err() {
+                    return None;
+                }
+                let roll = if n > 0 { Roll::Forward } else { Roll::Backward };
+                // Rolling forwards or backwards can't fail.
+                let (date, day_of_week) =
+                    roll_start_date(start_date, roll, &self.week_mask, &self.holidays)
+                        .ok()
+                        .flatten()?;
+                Some(add_business_days_impl(
+                    date,
+                    day_of_week,
+                    n - n.signum(),
+                    &self.week_mask,
+                    self.n_business_days_in_week_mask,
+                    &self.holidays,
+                ))
+            });
+        Ok(out.into_date().into_series())
+    }
+
+    /// See [`roll_business_day`].
+    pub fn roll_business_day(&self, dates: &Series, roll: Roll) -> PolarsResult<Series> {
+        let dates = to_local_date(dates)?;
+        let dates = dates.date()?;
+        let out: Int32Chunked = try_unary_elementwise(dates, |opt_date| match opt_date {
+            Some(date) => Ok::<_, PolarsError>(
+                roll_start_date(date, roll, &self.week_mask, &self.holidays)?.map(|(date, _)| date),
+            ),
+            None => Ok(None),
+        })?;
+        Ok(out.into_date().into_series())
+    }
+
+    /// See [`business_days_in_month`].
+    pub fn business_days_in_month(&self, dates: &Series) -> PolarsResult<Series> {
+        let dates = to_local_date(dates)?;
+        let out: Int32Chunked = unary_elementwise(dates.date()?, |opt_date| {
+            let (first_day, last_day) = month_bounds(opt_date?)?;
+            Some(business_day_count_impl(
+                first_day,
+                last_day,
+                &self.week_mask,
+                self.n_business_days_in_week_mask,
+                &self.holidays,
+                ClosedInterval::Both,
+            ))
+        });
+        Ok(out.into_series())
+    }
+
+    /// See [`rolling_business_day_count`].
+    pub fn rolling_business_day_count(
+        &self,
+        dates: &Series,
+        window_days: i32,
+    ) -> PolarsResult<Series> {
+        polars_ensure!(
+            window_days > 0,
+            ComputeError: "`window` must be a positive number of days, got {}", window_days
+        );
+        let dates = to_local_date(dates)?;
+        let dates = dates.date()?;
+        let Some(first_date) = dates.min() else {
+            return Ok(Int32Chunked::full_null(dates.name(), dates.len()).into_series());
+        };
+        // Counting the days in `(start, date]`, so the earliest `start` that still counts
+        // `first_date` is the day before it.
+        let out = par_split_apply(dates.len(), |offset, len| {
+            dates.slice(offset as i64, len).apply_values(|date| {
+                let start = date.saturating_sub(window_days).max(first_date - 1);
+                business_day_count_impl(
+                    start,
+                    date,
+                    &self.week_mask,
+                    self.n_business_days_in_week_mask,
+                    &self.holidays,
+                    ClosedInterval::Right,
+                )
+            })
+        });
+        Ok(out.into_series())
+    }
+
+    /// See [`business_date_range`].
+    pub fn business_date_range(
+        &self,
+        start: &Series,
+        end: &Series,
+        interval: i32,
+    ) -> PolarsResult<Series> {
+        polars_ensure!(
+            interval > 0,
+            ComputeError: "`interval` must be a positive number of business days, got {}", interval
+        );
+        polars_ensure!(
+            start.len() == 1 && end.len() == 1,
+            ComputeError: "`start` and `end` must contain exactly one value, got {} and {} values",
+            start.len(), end.len()
+        );
+        let name = start.name();
+        let (Some(start), Some(end)) = (
+            to_local_date(start)?.date()?.get(0),
+            to_local_date(end)?.date()?.get(0),
+        ) else {
+            polars_bail!(ComputeError: "`start` and `end` of a business date range must not be null")
+        };
+
+        let n_business_days = business_day_count_impl(
+            start,
+            end,
+            &self.week_mask,
+            self.n_business_days_in_week_mask,
+            &self.holidays,
+            ClosedInterval::Both,
+        )
+        .max(0) as usize;
+        let mut out = Vec::with_capacity(n_business_days.div_ceil(interval as usize));
+        let (mut date, mut day_of_week) = roll_forward(
+            start,
+            get_day_of_week(start),
+            &self.week_mask,
+            &self.holidays,
+        );
+        while date <= end {
+            out.push(date);
+            date = add_business_days_impl(
+                date,
+                day_of_week,
+                interval,
+                &self.week_mask,
+                self.n_business_days_in_week_mask,
+                &self.holidays,
+            );
+            day_of_week = get_day_of_week(date);
+        }
+        Ok(Int32Chunked::from_vec(name, out).into_date().into_series())
+    }
+
+    /// See [`is_business_day`].
+    pub fn is_business_day(&self, dates: &Series) -> PolarsResult<Series> {
+        let dates = to_local_date(dates)?;
+        let dates = dates.date()?;
+        let out: BooleanChunked = dates.apply_nonnull_values_generic(DataType::Boolean, |date| {
+            is_business_day_impl(date, get_day_of_week(date), &self.week_mask, &self.holidays)
+        });
+        Ok(out.into_series())
+    }
+}
+
+/// Count the number of business days between `start` and `end`.
+///
+/// If `start` is after `end`, the count is negative. `closed` decides whether `start`
+/// and `end` are counted when they're business days, regardless of which of the two comes
+/// first. Use `ClosedInterval::Left` to count `start` but not `end`, like NumPy's `busday_count`.
 ///
 /// # Arguments
-/// - `start`: Series holding start dates.
-/// - `end`: Series holding end dates.
+/// - `start`: Series holding start dates or datetimes. Datetimes are converted to their
+///   calendar date in their own time zone.
+/// - `end`: Series holding end dates or datetimes, of the same dtype as `start`.
 /// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
 /// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
 ///   days since the UNIX epoch.
+/// - `closed`: which of `start` and `end` to count if they're business days.
+/// - `output_dtype`: the dtype of the result, either `Int32` (the default) or `Int64`.
 pub fn business_day_count(
     start: &Series,
     end: &Series,
     week_mask: [bool; 7],
     holidays: &[i32],
+    closed: ClosedInterval,
+    output_dtype: Option<DataType>,
 ) -> PolarsResult<Series> {
-    if !week_mask.iter().any(|&x| x) {
-        polars_bail!(ComputeError:"`week_mask` must have at least one business day");
-    }
+    BusinessDayCalendar::cached(week_mask, holidays)?.business_day_count(
+        start,
+        end,
+        closed,
+        output_dtype,
+    )
+}
 
-    let holidays = normalise_holidays(holidays, &week_mask);
-    let start_dates = start.date()?;
-    let end_dates = end.date()?;
-    let n_business_days_in_week_mask = week_mask.iter().filter(|&x| *x).count() as i32;
+/// Count the business days between `start` and `end` like Excel's `NETWORKDAYS`.
+///
+/// Both `start` and `end` are counted if they're business days. If `start` is after `end`,
+/// the count is negative, e.g. `networkdays(friday, monday)` within one week is `-5`, as in
+/// Excel. This is `business_day_count` with `ClosedInterval::Both`.
+pub fn networkdays(
+    start: &Series,
+    end: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    business_day_count(start, end, week_mask, holidays, ClosedInterval::Both, None)
+}
 
-    let out = match (start_dates.len(), end_dates.len()) {
+/// Apply `count` to each pair of start and end dates, broadcasting either if it has length 1.
+fn apply_business_day_count<T, F>(
+    start_dates: &Int32Chunked,
+    end_dates: &Int32Chunked,
+    count: F,
+) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    F: Fn(i32, i32) -> T::Native + Copy + Send + Sync,
+{
+    match (start_dates.len(), end_dates.len()) {
         (_, 1) => {
             if let Some(end_date) = end_dates.get(0) {
-                start_dates.apply_values(|start_date| {
-                    business_day_count_impl(
-                        start_date,
-                        end_date,
-                        &week_mask,
-                        n_business_days_in_week_mask,
-                        &holidays,
-                    )
+                par_split_apply(start_dates.len(), |offset, len| {
+                    unary_elementwise_values(&start_dates.slice(offset as i64, len), |start_date| {
+                        count(start_date, end_date)
+                    })
                 })
             } else {
-                Int32Chunked::full_null(start_dates.name(), start_dates.len())
+                ChunkedArray::full_null(start_dates.name(), start_dates.len())
             }
         },
         (1, _) => {
             if let Some(start_date) = start_dates.get(0) {
-                end_dates.apply_values(|end_date| {
-                    business_day_count_impl(
-                        start_date,
-                        end_date,
-                        &week_mask,
-                        n_business_days_in_week_mask,
-                        &holidays,
-                    )
+                par_split_apply(end_dates.len(), |offset, len| {
+                    unary_elementwise_values(&end_dates.slice(offset as i64, len), |end_date| {
+                        count(start_date, end_date)
+                    })
                 })
             } else {
-                Int32Chunked::full_null(start_dates.name(), end_dates.len())
+                ChunkedArray::full_null(start_dates.name(), end_dates.len())
             }
         },
-        _ => binary_elementwise_values(start_dates, end_dates, |start_date, end_date| {
-            business_day_count_impl(
+        (start_len, end_len) if start_len == end_len => {
+            par_split_apply(start_len, |offset, len| {
+                binary_elementwise_values(
+                    &start_dates.slice(offset as i64, len),
+                    &end_dates.slice(offset as i64, len),
+                    count,
+                )
+            })
+        },
+        _ => binary_elementwise_values(start_dates, end_dates, count),
+    }
+}
+
+/// Count the number of business days between `start` and `end`, with `holidays` given as
+/// a Series of dtype `Date`.
+///
+/// Null holidays are ignored. See [`business_day_count`] for the other arguments.
+pub fn business_day_count_from_series(
+    start: &Series,
+    end: &Series,
+    week_mask: [bool; 7],
+    holidays: &Series,
+    closed: ClosedInterval,
+) -> PolarsResult<Series> {
+    let holidays = holidays_from_series(holidays)?;
+    business_day_count(start, end, week_mask, &holidays, closed, None)
+}
+
+/// Count the number of business days between `start` and `end`, with a `week_mask` that
+/// can differ per row.
+///
+/// `week_mask` must be a List or Array column of booleans, where each non-null entry has
+/// exactly 7 values, at least one of which is `true`. Rows where `start`, `end` or
+/// `week_mask` is null yield null. Any of the three inputs may have length 1, in which
+/// case it's broadcast. See [`business_day_count`] for the other arguments.
+pub fn business_day_count_masked(
+    start: &Series,
+    end: &Series,
+    week_mask: &Series,
+    holidays: &[i32],
+    closed: ClosedInterval,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        start.dtype() == end.dtype(),
+        ComputeError: "`start` and `end` must have the same dtype, got {} and {}", start.dtype(), end.dtype()
+    );
+    let len = [start.len(), end.len(), week_mask.len()]
+        .into_iter()
+        .max()
+        .unwrap();
+    let start = broadcast(&to_local_date(start)?, len)?;
+    let end = broadcast(&to_local_date(end)?, len)?;
+    let week_masks = week_masks_from_series(&broadcast(week_mask, len)?)?;
+
+    check_holidays_in_date_range(holidays)?;
+
+    // There are only 2^7 possible week masks, so normalise the holidays once per distinct
+    // mask, indexed by the mask's bit pattern.
+    let mut holidays_per_mask: Vec<Option<Vec<i32>>> = vec![None; 1 << 7];
+    let start_dates: &Int32Chunked = start.date()?;
+    let end_dates: &Int32Chunked = end.date()?;
+    let out: Int32Chunked = start_dates
+        .into_iter()
+        .zip(end_dates)
+        .zip(week_masks)
+        .map(|((opt_start_date, opt_end_date), opt_week_mask)| {
+            let start_date = opt_start_date?;
+            let end_date = opt_end_date?;
+            let week_mask = opt_week_mask?;
+            let key = week_mask
+                .iter()
+                .enumerate()
+                .fold(0, |acc, (i, &b)| acc | ((b as usize) << i));
+            let holidays = holidays_per_mask[key]
+                .get_or_insert_with(|| normalise_holidays(holidays, &week_mask));
+            Some(business_day_count_impl(
                 start_date,
                 end_date,
                 &week_mask,
-                n_business_days_in_week_mask,
-                &holidays,
-            )
-        }),
+                week_mask.iter().filter(|&x| *x).count() as i32,
+                holidays,
+                closed,
+            ))
+        })
+        .collect();
+    Ok(out.with_name(start.name()).into_series())
+}
+
+/// Same as [`business_day_count_impl`], for when there are no holidays.
+fn business_day_count_no_holidays_impl(
+    start_date: i32,
+    end_date: i32,
+    week_mask: &[bool; 7],
+    n_business_days_in_week_mask: i32,
+    closed: ClosedInterval,
+) -> i32 {
+    let (mut start_date, end_date, swapped) =
+        normalise_business_day_range(start_date, end_date, closed);
+
+    let mut start_day_of_week = get_day_of_week(start_date);
+    let whole_weeks = (end_date - start_date) / 7;
+    let mut count = whole_weeks * n_business_days_in_week_mask;
+    start_date += whole_weeks * 7;
+    while start_date < end_date {
+        // SAFETY: week_mask is length 7, start_day_of_week is between 0 and 6
+        if unsafe { *week_mask.get_unchecked(start_day_of_week) } {
+            count += 1;
+        }
+        start_date += 1;
+        start_day_of_week = increment_day_of_week(start_day_of_week);
+    }
+    if swapped {
+        -count
+    } else {
+        count
+    }
+}
+
+/// Turn `start` and `end` into an ascending half-open range `[start, end)` of the days to
+/// count, and whether they were swapped to get there.
+///
+/// `closed` refers to `start` and `end` as given, so e.g. `ClosedInterval::Left` always
+/// includes `start`, even if it comes after `end`.
+fn normalise_business_day_range(
+    start_date: i32,
+    end_date: i32,
+    closed: ClosedInterval,
+) -> (i32, i32, bool) {
+    let (include_start, include_end) = match closed {
+        ClosedInterval::Both => (true, true),
+        ClosedInterval::Left => (true, false),
+        ClosedInterval::Right => (false, true),
+        ClosedInterval::None => (false, false),
     };
-    Ok(out.into_series())
+    let swapped = start_date > end_date;
+    let (lower, upper, include_lower, include_upper) = if swapped {
+        (end_date, start_date, include_end, include_start)
+    } else {
+        (start_date, end_date, include_start, include_end)
+    };
+    let lower = lower + !include_lower as i32;
+    let upper = upper + include_upper as i32;
+    // With `ClosedInterval::None` and `start == end`, the range would be inverted.
+    (lower, upper.max(lower), swapped)
+}
+
+/// Split `0..len` into contiguous slices, apply `f(offset, len)` to each on the thread pool,
+/// and concatenate the results in order. Small inputs are processed on the current thread.
+fn par_split_apply<T, F>(len: usize, f: F) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    F: Fn(usize, usize) -> ChunkedArray<T> + Send + Sync,
+{
+    let n_threads = POOL.current_num_threads();
+    if len < PARALLEL_THRESHOLD || n_threads == 1 {
+        return f(0, len);
+    }
+    let offsets = _split_offsets(len, n_threads);
+    let chunks = POOL.install(|| {
+        offsets
+            .into_par_iter()
+            .map(|(offset, len)| f(offset, len))
+            .collect::<Vec<_>>()
+    });
+    let mut chunks = chunks.into_iter();
+    let mut out = chunks.next().unwrap();
+    for ca in chunks {
+        out.append(&ca);
+    }
+    out
 }
 
 /// Ported from:
 /// https://github.com/numpy/numpy/blob/e59c074842e3f73483afa5ddef031e856b9fd313/numpy/_core/src/multiarray/datetime_busday.c#L355-L433
 fn business_day_count_impl(
-    mut start_date: i32,
-    mut end_date: i32,
+    start_date: i32,
+    end_date: i32,
     week_mask: &[bool; 7],
     n_business_days_in_week_mask: i32,
     holidays: &[i32],
+    closed: ClosedInterval,
 ) -> i32 {
-    let swapped = start_date > end_date;
-    if swapped {
-        (start_date, end_date) = (end_date, start_date);
-        start_date += 1;
-        end_date += 1;
-    }
+    let (mut start_date, end_date, swapped) =
+        normalise_business_day_range(start_date, end_date, closed);
 
     let holidays_begin = find_first_ge_index(holidays, start_date);
     let holidays_end = find_first_ge_index(&holidays[holidays_begin..], end_date) + holidays_begin;
@@ -133,10 +764,7 @@ fn business_day_count_impl(
 /// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
 /// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
 ///   days since the UNIX epoch.
-/// - `roll`: what to do when the start date doesn't land on a business day:
-///   - `Roll::Forward`: roll forward to the next business day.
-///   - `Roll::Backward`: roll backward to the previous business day.
-///   - `Roll::Raise`: raise an error.
+/// - `roll`: what to do when the start date doesn't land on a business day, see [`Roll`].
 pub fn add_business_days(
     start: &Series,
     n: &Series,
@@ -144,120 +772,31 @@ pub fn add_business_days(
     holidays: &[i32],
     roll: Roll,
 ) -> PolarsResult<Series> {
-    if !week_mask.iter().any(|&x| x) {
-        polars_bail!(ComputeError:"`week_mask` must have at least one business day");
-    }
-
-    match start.dtype() {
-        DataType::Date => {},
-        #[cfg(feature = "dtype-datetime")]
-        DataType::Datetime(time_unit, None) => {
-            let result_date =
-                add_business_days(&start.cast(&DataType::Date)?, n, week_mask, holidays, roll)?;
-            let start_time = start
-                .cast(&DataType::Time)?
-                .cast(&DataType::Duration(*time_unit))?;
-            return Ok(result_date.cast(&DataType::Datetime(*time_unit, None))? + start_time);
-        },
-        #[cfg(feature = "timezones")]
-        DataType::Datetime(time_unit, Some(time_zone)) => {
-            let start_naive = replace_time_zone(
-                start.datetime().unwrap(),
-                None,
-                &StringChunked::from_iter(std::iter::once("raise")),
-                NonExistent::Raise,
-            )?;
-            let result_date = add_business_days(
-                &start_naive.cast(&DataType::Date)?,
-                n,
-                week_mask,
-                holidays,
-                roll,
-            )?;
-            let start_time = start_naive
-                .cast(&DataType::Time)?
-                .cast(&DataType::Duration(*time_unit))?;
-            let result_naive =
-                result_date.cast(&DataType::Datetime(*time_unit, None))? + start_time;
-            let result_tz_aware = replace_time_zone(
-                result_naive.datetime().unwrap(),
-                Some(time_zone),
-                &StringChunked::from_iter(std::iter::once("raise")),
-                NonExistent::Raise,
-            )?;
-            return Ok(result_tz_aware.into_series());
-        },
-        _ => polars_bail!(InvalidOperation: "expected date or datetime, got {}", start.dtype()),
-    }
-
-    let holidays = normalise_holidays(holidays, &week_mask);
-    let start_dates = start.date()?;
-    let n = match &n.dtype() {
-        DataType::Int64 | DataType::UInt64 | DataType::UInt32 => n.cast(&DataType::Int32)?,
-        DataType::Int32 => n.clone(),
-        _ => {
-            polars_bail!(InvalidOperation: "expected Int64, Int32, UInt64, or UInt32, got {}", n.dtype())
-        },
-    };
-    let n = n.i32()?;
-    let n_business_days_in_week_mask = week_mask.iter().filter(|&x| *x).count() as i32;
+    BusinessDayCalendar::cached(week_mask, holidays)?.add_business_days(start, n, roll)
+}
 
-    let out: Int32Chunked = match (start_dates.len(), n.len()) {
-        (_, 1) => {
-            if let Some(n) = n.get(0) {
-                start_dates.try_apply_nonnull_values_generic(|start_date| {
-                    let (start_date, day_of_week) =
-                        roll_start_date(start_date, roll, &week_mask, &holidays)?;
-                    Ok::<i32, PolarsError>(add_business_days_impl(
-                        start_date,
-                        day_of_week,
-                        n,
-                        &week_mask,
-                        n_business_days_in_week_mask,
-                        &holidays,
-                    ))
-                })?
-            } else {
-                Int32Chunked::full_null(start_dates.name(), start_dates.len())
-            }
-        },
-        (1, _) => {
-            if let Some(start_date) = start_dates.get(0) {
-                let (start_date, day_of_week) =
-                    roll_start_date(start_date, roll, &week_mask, &holidays)?;
-                n.apply_values(|n| {
-                    add_business_days_impl(
-                        start_date,
-                        day_of_week,
-                        n,
-                        &week_mask,
-                        n_business_days_in_week_mask,
-                        &holidays,
-                    )
-                })
-            } else {
-                Int32Chunked::full_null(start_dates.name(), n.len())
-            }
-        },
-        _ => try_binary_elementwise(start_dates, n, |opt_start_date, opt_n| {
-            match (opt_start_date, opt_n) {
-                (Some(start_date), Some(n)) => {
-                    let (start_date, day_of_week) =
-                        roll_start_date(start_date, roll, &week_mask, &holidays)?;
-                    Ok::<Option<i32>, PolarsError>(Some(add_business_days_impl(
-                        start_date,
-                        day_of_week,
-                        n,
-                        &week_mask,
-                        n_business_days_in_week_mask,
-                        &holidays,
-                    )))
-                },
-                _ => Ok(None),
-            }
-        })?,
-    };
-    Ok(out.into_date().into_series())
+/// Get the `n`-th business day on or after `start`, counting from 1.
+///
+/// If `start` is a business day, it's the first one, so `n = 1` returns `start` itself;
+/// otherwise `n = 1` returns the first business day after `start`. A negative `n` counts
+/// backwards, so `n = -1` returns the last business day on or before `start`. There is no
+/// 0th business day, so `n = 0` yields null, and so does a result that falls outside of
+/// the range of representable dates.
+///
+/// # Arguments
+/// - `start`: Series holding start dates or datetimes. Datetimes are converted to their
+///   calendar date in their own time zone.
+/// - `n`: Which business day to get.
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+pub fn nth_business_day(
+    start: &Series,
+    n: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    BusinessDayCalendar::cached(week_mask, holidays)?.nth_business_day(start, n)
 }
 
 /// Ported from:
@@ -311,41 +850,321 @@ fn add_business_days_impl(
     }
 }
 
+/// Roll `date` to a business day according to `roll`, returning the rolled date and its
+/// day of the week, or `None` if `roll` is [`Roll::Null`] and `date` isn't a business day.
 fn roll_start_date(
-    mut date: i32,
+    date: i32,
     roll: Roll,
     week_mask: &[bool; 7],
     holidays: &[i32],
-) -> PolarsResult<(i32, usize)> {
-    let mut day_of_week = get_day_of_week(date);
-    match roll {
+) -> PolarsResult<Option<(i32, usize)>> {
+    let day_of_week = get_day_of_week(date);
+    if is_business_day_impl(date, day_of_week, week_mask, holidays) {
+        return Ok(Some((date, day_of_week)));
+    }
+    let rolled = match roll {
         Roll::Raise => {
-            // SAFETY: week_mask is length 7, day_of_week is between 0 and 6
-            if holidays.contains(&date) | unsafe { !*week_mask.get_unchecked(day_of_week) } {
-                let date = DateTime::from_timestamp(date as i64 * SECONDS_IN_DAY, 0)
-                    .unwrap()
-                    .format("%Y-%m-%d");
-                polars_bail!(ComputeError:
-                    "date {} is not a business date; use `roll` to roll forwards (or backwards) to the next (or previous) valid date.", date
-                )
-            };
+            let date = DateTime::from_timestamp(date as i64 * SECONDS_IN_DAY, 0)
+                .unwrap()
+                .format("%Y-%m-%d");
+            polars_bail!(ComputeError:
+                "date {} is not a business date; use `roll` to roll forwards (or backwards) to the next (or previous) valid date.", date
+            )
         },
-        Roll::Forward => {
-            // SAFETY: week_mask is length 7, day_of_week is between 0 and 6
-            while holidays.contains(&date) | unsafe { !*week_mask.get_unchecked(day_of_week) } {
-                date += 1;
-                day_of_week = increment_day_of_week(day_of_week);
+        Roll::Null => return Ok(None),
+        Roll::Forward => roll_forward(date, day_of_week, week_mask, holidays),
+        Roll::Backward => roll_backward(date, day_of_week, week_mask, holidays),
+        Roll::ModifiedFollowing => {
+            let rolled = roll_forward(date, day_of_week, week_mask, holidays);
+            if is_same_month(date, rolled.0) {
+                rolled
+            } else {
+                roll_backward(date, day_of_week, week_mask, holidays)
             }
         },
-        Roll::Backward => {
-            // SAFETY: week_mask is length 7, day_of_week is between 0 and 6
-            while holidays.contains(&date) | unsafe { !*week_mask.get_unchecked(day_of_week) } {
-                date -= 1;
-                day_of_week = decrement_day_of_week(day_of_week);
+        Roll::ModifiedPreceding => {
+            let rolled = roll_backward(date, day_of_week, week_mask, holidays);
+            if is_same_month(date, rolled.0) {
+                rolled
+            } else {
+                roll_forward(date, day_of_week, week_mask, holidays)
             }
         },
+    };
+    Ok(Some(rolled))
+}
+
+fn roll_forward(
+    mut date: i32,
+    mut day_of_week: usize,
+    week_mask: &[bool; 7],
+    holidays: &[i32],
+) -> (i32, usize) {
+    while !is_business_day_impl(date, day_of_week, week_mask, holidays) {
+        date += 1;
+        day_of_week = increment_day_of_week(day_of_week);
+    }
+    (date, day_of_week)
+}
+
+fn roll_backward(
+    mut date: i32,
+    mut day_of_week: usize,
+    week_mask: &[bool; 7],
+    holidays: &[i32],
+) -> (i32, usize) {
+    while !is_business_day_impl(date, day_of_week, week_mask, holidays) {
+        date -= 1;
+        day_of_week = decrement_day_of_week(day_of_week);
+    }
+    (date, day_of_week)
+}
+
+fn is_same_month(a: i32, b: i32) -> bool {
+    let year_month = |x| date32_to_date_opt(x).map(|date| (date.year(), date.month()));
+    year_month(a) == year_month(b)
+}
+
+/// Roll each date to a business day, leaving dates that already are business days as-is.
+///
+/// # Arguments
+/// - `dates`: Series holding dates or datetimes. Datetimes are converted to their calendar
+///   date in their own time zone, and the result is always of dtype `Date`.
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+/// - `roll`: which convention to roll non-business days by. The `Modified*` conventions
+///   never move a date into a different month, rolling in the opposite direction instead
+///   when they otherwise would.
+pub fn roll_business_day(
+    dates: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    roll: Roll,
+) -> PolarsResult<Series> {
+    BusinessDayCalendar::cached(week_mask, holidays)?.roll_business_day(dates, roll)
+}
+
+/// Count the number of business days in the calendar month of each date.
+///
+/// # Arguments
+/// - `dates`: Series holding dates or datetimes. Datetimes are converted to their calendar
+///   date in their own time zone.
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+pub fn business_days_in_month(
+    dates: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    BusinessDayCalendar::cached(week_mask, holidays)?.business_days_in_month(dates)
+}
+
+/// Determine whether each date is a business day.
+///
+/// # Arguments
+/// - `dates`: Series holding dates or datetimes. Datetimes are converted to their calendar
+///   date in their own time zone.
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+pub fn is_business_day(
+    dates: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    BusinessDayCalendar::cached(week_mask, holidays)?.is_business_day(dates)
+}
+
+/// Get every `interval`-th business day from `start` through `end`, both inclusive.
+///
+/// The range starts at the first business day on or after `start`. If there is no business
+/// day in `[start, end]`, the result is empty.
+///
+/// # Arguments
+/// - `start`: Series holding a single start date or datetime. Datetimes are converted to
+///   their calendar date in their own time zone.
+/// - `end`: Series holding a single end date or datetime.
+/// - `interval`: The number of business days between consecutive dates, must be positive.
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+pub fn business_date_range(
+    start: &Series,
+    end: &Series,
+    interval: i32,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    BusinessDayCalendar::cached(week_mask, holidays)?.business_date_range(start, end, interval)
+}
+
+/// Count the number of business days in the trailing window of `window_days` calendar
+/// days ending at, and including, each date.
+///
+/// Where the full window would reach back before the earliest date in `dates`, only the
+/// available days from the earliest date onwards are counted. Null dates yield null.
+///
+/// # Arguments
+/// - `dates`: Series holding dates or datetimes. Datetimes are converted to their
+///   calendar date in their own time zone.
+/// - `window_days`: The length of the window in days, must be positive.
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+pub fn rolling_business_day_count(
+    dates: &Series,
+    window_days: i32,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    BusinessDayCalendar::cached(week_mask, holidays)?.rolling_business_day_count(dates, window_days)
+}
+
+/// Get the day of the week of each date, from 0 (Monday) to 6 (Sunday).
+///
+/// This matches the indexing of `week_mask`, so it can be used to build custom masks.
+pub fn weekday_of_date(dates: &Series) -> PolarsResult<Series> {
+    polars_ensure!(
+        dates.dtype() == &DataType::Date,
+        ComputeError: "expected dtype Date, got {}", dates.dtype()
+    );
+    let out: UInt8Chunked =
+        unary_elementwise_values(dates.date()?, |date| get_day_of_week(date) as u8);
+    Ok(out.into_series())
+}
+
+/// The first and last day of the month that `date` falls in.
+fn month_bounds(date: i32) -> Option<(i32, i32)> {
+    let first_day = date32_to_date_opt(date)?.with_day(1)?;
+    let next_first_day = first_day.checked_add_months(Months::new(1))?;
+    let to_date32 = |date: NaiveDate| date.num_days_from_ce() - EPOCH_DAYS_FROM_CE;
+    Some((to_date32(first_day), to_date32(next_first_day) - 1))
+}
+
+/// `holidays` must be sorted, as returned by [`normalise_holidays`].
+fn is_business_day_impl(
+    date: i32,
+    day_of_week: usize,
+    week_mask: &[bool; 7],
+    holidays: &[i32],
+) -> bool {
+    // SAFETY: week_mask is length 7, day_of_week is between 0 and 6
+    (unsafe { *week_mask.get_unchecked(day_of_week) }) && holidays.binary_search(&date).is_err()
+}
+
+/// Broadcast `s` to length `len` if it has length 1.
+fn broadcast(s: &Series, len: usize) -> PolarsResult<Series> {
+    match s.len() {
+        1 if len != 1 => Ok(s.new_from_index(0, len)),
+        n if n == len => Ok(s.clone()),
+        n => polars_bail!(
+            ShapeMismatch: "`{}` has length {}, expected {} or 1", s.name(), n, len
+        ),
+    }
+}
+
+fn cast_n_to_i32(n: &Series) -> PolarsResult<Series> {
+    match n.dtype() {
+        DataType::Int64 | DataType::UInt64 | DataType::UInt32 => n.cast(&DataType::Int32),
+        DataType::Int32 => Ok(n.clone()),
+        dt => {
+            polars_bail!(InvalidOperation: "expected Int64, Int32, UInt64, or UInt32, got {}", dt)
+        },
+    }
+}
+
+/// Convert dates or datetimes to dates. Time zone aware datetimes are converted to the
+/// calendar date in their time zone, rather than in UTC.
+fn to_local_date(s: &Series) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Date => Ok(s.clone()),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(_, None) => s.cast(&DataType::Date),
+        #[cfg(feature = "timezones")]
+        DataType::Datetime(_, Some(_)) => {
+            let s_naive = replace_time_zone(
+                s.datetime().unwrap(),
+                None,
+                &StringChunked::from_iter(std::iter::once("raise")),
+                NonExistent::Raise,
+            )?;
+            s_naive.cast(&DataType::Date)
+        },
+        dt => polars_bail!(InvalidOperation: "expected date or datetime, got {}", dt),
     }
-    Ok((date, day_of_week))
+}
+
+/// Extract per-row week masks from a List or Array column of booleans.
+fn week_masks_from_series(week_mask: &Series) -> PolarsResult<Vec<Option<[bool; 7]>>> {
+    let week_mask = match week_mask.dtype() {
+        DataType::List(inner) if inner.as_ref() == &DataType::Boolean => week_mask.clone(),
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(inner, _) if inner.as_ref() == &DataType::Boolean => {
+            week_mask.cast(&DataType::List(Box::new(DataType::Boolean)))?
+        },
+        dt => polars_bail!(
+            ComputeError: "expected `week_mask` to be a List or Array of Boolean, got {}", dt
+        ),
+    };
+    let week_mask = week_mask.list()?;
+    // SAFETY: unstable series never lives longer than the iterator.
+    unsafe { week_mask.amortized_iter() }
+        .enumerate()
+        .map(|(row, opt_mask)| {
+            let Some(mask) = opt_mask else {
+                return Ok(None);
+            };
+            let mask = mask.as_ref().bool()?;
+            polars_ensure!(
+                mask.len() == 7 && mask.null_count() == 0,
+                ComputeError: "`week_mask` must have exactly 7 non-null values, got {:?} in row {}",
+                mask.into_iter().collect::<Vec<_>>(), row
+            );
+            let mut out = [false; 7];
+            for (out, value) in out.iter_mut().zip(mask.into_no_null_iter()) {
+                *out = value;
+            }
+            polars_ensure!(
+                out.iter().any(|&x| x),
+                ComputeError: "`week_mask` must have at least one business day, got {:?} in row {}",
+                out, row
+            );
+            Ok(Some(out))
+        })
+        .collect()
+}
+
+/// Extract the non-null holidays from a Series of dtype `Date` as days since the UNIX epoch.
+fn holidays_from_series(holidays: &Series) -> PolarsResult<Vec<i32>> {
+    polars_ensure!(
+        holidays.dtype() == &DataType::Date,
+        ComputeError: "expected `holidays` to be of dtype Date, got {}", holidays.dtype()
+    );
+    Ok(holidays.date()?.into_iter().flatten().collect())
+}
+
+/// Check that all holidays lie within the range of dates that can be represented.
+///
+/// Anything outside of it is most likely a timestamp in another unit that was cast to i32.
+fn check_holidays_in_date_range(holidays: &[i32]) -> PolarsResult<()> {
+    let Some((min, max)) = holidays.iter().fold(None, |acc, &x| match acc {
+        None => Some((x, x)),
+        Some((min, max)) => Some((i32::min(min, x), i32::max(max, x))),
+    }) else {
+        return Ok(());
+    };
+    let lower = NaiveDate::MIN.num_days_from_ce() - EPOCH_DAYS_FROM_CE;
+    let upper = NaiveDate::MAX.num_days_from_ce() - EPOCH_DAYS_FROM_CE;
+    for x in [min, max] {
+        polars_ensure!(
+            (lower..=upper).contains(&x),
+            ComputeError: "holiday {} is outside the range of valid dates ({} to {}); holidays must be \
+            given as the number of days since the UNIX epoch, not as a timestamp", x, lower, upper
+        );
+    }
+    Ok(())
 }
 
 /// Sort and deduplicate holidays and remove holidays that are not business days.
@@ -387,3 +1206,624 @@ fn decrement_day_of_week(x: usize) -> usize {
         x - 1
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // 2024-01-01 is a Monday.
+    const MONDAY: i32 = 19723;
+    const WEEK_MASK: [bool; 7] = [true, true, true, true, true, false, false];
+
+    fn dates(values: &[Option<i32>]) -> Series {
+        Int32Chunked::from_slice_options("dates", values)
+            .into_date()
+            .into_series()
+    }
+
+    fn physical(s: &Series) -> Vec<Option<i32>> {
+        s.date().unwrap().into_iter().collect()
+    }
+
+    #[test]
+    fn test_add_business_days() -> PolarsResult<()> {
+        let start = dates(&[Some(MONDAY), Some(MONDAY), Some(MONDAY)]);
+        let n = Series::new("n", &[5, -1, 1]);
+        let out = add_business_days(&start, &n, WEEK_MASK, &[MONDAY + 1], Roll::Raise)?;
+        assert_eq!(
+            physical(&out),
+            &[Some(MONDAY + 8), Some(MONDAY - 3), Some(MONDAY + 2)]
+        );
+
+        // Broadcast `start` and `n`.
+        let start = dates(&[Some(MONDAY)]);
+        let n = Series::new("n", &[0, 1, 4]);
+        let out = add_business_days(&start, &n, WEEK_MASK, &[], Roll::Raise)?;
+        assert_eq!(
+            physical(&out),
+            &[Some(MONDAY), Some(MONDAY + 1), Some(MONDAY + 4)]
+        );
+        let start = dates(&[Some(MONDAY), Some(MONDAY + 1)]);
+        let out = add_business_days(&start, &Series::new("n", &[4]), WEEK_MASK, &[], Roll::Raise)?;
+        assert_eq!(physical(&out), &[Some(MONDAY + 4), Some(MONDAY + 7)]);
+
+        // Nulls in either input propagate.
+        let start = dates(&[Some(MONDAY), None, Some(MONDAY)]);
+        let n = Series::new("n", &[Some(1), Some(1), None]);
+        let out = add_business_days(&start, &n, WEEK_MASK, &[], Roll::Raise)?;
+        assert_eq!(physical(&out), &[Some(MONDAY + 1), None, None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_business_days_roll() -> PolarsResult<()> {
+        let saturday = dates(&[Some(MONDAY + 5)]);
+        let n = Series::new("n", &[0]);
+        assert!(add_business_days(&saturday, &n, WEEK_MASK, &[], Roll::Raise).is_err());
+        let out = add_business_days(&saturday, &n, WEEK_MASK, &[], Roll::Forward)?;
+        assert_eq!(physical(&out), &[Some(MONDAY + 7)]);
+        let out = add_business_days(&saturday, &n, WEEK_MASK, &[], Roll::Backward)?;
+        assert_eq!(physical(&out), &[Some(MONDAY + 4)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_business_day() -> PolarsResult<()> {
+        let s = dates(&[Some(MONDAY), Some(MONDAY + 1), None, Some(MONDAY + 5)]);
+        let out = is_business_day(&s, WEEK_MASK, &[MONDAY + 1])?;
+        let out = out.bool()?.into_iter().collect::<Vec<_>>();
+        assert_eq!(out, &[Some(true), Some(false), None, Some(false)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rolling_business_day_count() -> PolarsResult<()> {
+        let s = dates(&[
+            Some(MONDAY + 2),
+            Some(MONDAY + 7),
+            None,
+            Some(MONDAY + 14),
+            Some(MONDAY + 3),
+        ]);
+        let out = rolling_business_day_count(&s, 7, WEEK_MASK, &[MONDAY + 8])?;
+        let out = out.i32()?.into_iter().collect::<Vec<_>>();
+        // The first and last windows are cut off at the earliest date, Wednesday.
+        assert_eq!(out, &[Some(1), Some(4), None, Some(4), Some(2)]);
+        assert!(rolling_business_day_count(&s, 0, WEEK_MASK, &[]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_weekday_of_date() -> PolarsResult<()> {
+        let s = dates(&[
+            Some(MONDAY),
+            Some(MONDAY + 6),
+            None,
+            Some(MONDAY - 1),
+            Some(0),
+        ]);
+        let out = weekday_of_date(&s)?;
+        assert_eq!(out.dtype(), &DataType::UInt8);
+        let out = out.u8()?.into_iter().collect::<Vec<_>>();
+        // 1970-01-01 was a Thursday.
+        assert_eq!(out, &[Some(0), Some(6), None, Some(6), Some(3)]);
+        assert!(weekday_of_date(&Series::new("a", &[1i32])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_business_day_count_closed() -> PolarsResult<()> {
+        let wednesday = MONDAY + 2;
+        let holidays = [wednesday];
+        // (start, end, expected count for [Left, Right, Both, None])
+        let cases = [
+            (MONDAY, MONDAY + 4, [3, 3, 4, 2]),
+            (MONDAY + 4, MONDAY, [-3, -3, -4, -2]),
+            (MONDAY, wednesday, [2, 1, 2, 1]),
+            (wednesday, MONDAY, [-1, -2, -2, -1]),
+            (MONDAY, MONDAY, [0, 0, 1, 0]),
+            (wednesday, wednesday, [0, 0, 0, 0]),
+        ];
+        for (start, end, expected) in cases {
+            let closed = [
+                ClosedInterval::Left,
+                ClosedInterval::Right,
+                ClosedInterval::Both,
+                ClosedInterval::None,
+            ];
+            for (closed, expected) in closed.into_iter().zip(expected) {
+                let out = business_day_count(
+                    &dates(&[Some(start)]),
+                    &dates(&[Some(end)]),
+                    WEEK_MASK,
+                    &holidays,
+                    closed,
+                    None,
+                )?;
+                assert_eq!(
+                    out.i32()?.get(0),
+                    Some(expected),
+                    "start={start}, end={end}, closed={closed:?}"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_networkdays() -> PolarsResult<()> {
+        // Expected values are Excel's `NETWORKDAYS` with the same arguments.
+        let new_year = MONDAY;
+        let good_friday = MONDAY + 88;
+        let easter_monday = MONDAY + 91;
+        let holidays = [new_year, good_friday, easter_monday];
+        let cases = [
+            // 2024-01-01 to 2024-01-31, New Year's Day is a holiday.
+            (MONDAY, MONDAY + 30, &holidays[..], 22),
+            (MONDAY, MONDAY + 30, &[][..], 23),
+            // 2024-03-25 to 2024-04-05 spans Good Friday and Easter Monday.
+            (MONDAY + 84, MONDAY + 95, &holidays[..], 8),
+            // Reversed arguments count both endpoints and negate.
+            (MONDAY + 95, MONDAY + 84, &holidays[..], -8),
+            (MONDAY + 4, MONDAY, &[][..], -5),
+            // A single day, and a weekend.
+            (MONDAY, MONDAY, &[][..], 1),
+            (MONDAY, MONDAY, &holidays[..], 0),
+            (MONDAY + 5, MONDAY + 6, &[][..], 0),
+            // 2024-01-01 to 2024-12-31.
+            (MONDAY, MONDAY + 365, &holidays[..], 259),
+        ];
+        for (start, end, holidays, expected) in cases {
+            let out = networkdays(
+                &dates(&[Some(start)]),
+                &dates(&[Some(end)]),
+                WEEK_MASK,
+                holidays,
+            )?;
+            assert_eq!(
+                out.i32()?.get(0),
+                Some(expected),
+                "start={start}, end={end}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_business_day_count_from_series() -> PolarsResult<()> {
+        let start = dates(&[Some(MONDAY)]);
+        let end = dates(&[Some(MONDAY + 7)]);
+        let holidays = dates(&[Some(MONDAY + 1), None, Some(MONDAY + 2)]);
+        let out = business_day_count_from_series(
+            &start,
+            &end,
+            WEEK_MASK,
+            &holidays,
+            ClosedInterval::Left,
+        )?;
+        assert_eq!(out.i32()?.get(0), Some(3));
+
+        let holidays = Series::new("holidays", &[MONDAY + 1]);
+        assert!(business_day_count_from_series(
+            &start,
+            &end,
+            WEEK_MASK,
+            &holidays,
+            ClosedInterval::Left
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn test_business_day_count_datetime() -> PolarsResult<()> {
+        const MS_IN_DAY: i64 = SECONDS_IN_DAY * 1000;
+        let datetimes = |values: &[i64], tz: Option<TimeZone>| {
+            Int64Chunked::from_slice("datetimes", values)
+                .into_datetime(TimeUnit::Milliseconds, tz)
+                .into_series()
+        };
+        let monday = MONDAY as i64 * MS_IN_DAY;
+        // Friday, 23:30 in UTC.
+        let friday_late = monday + 4 * MS_IN_DAY + 23 * 3_600_000 + 1_800_000;
+
+        let start = datetimes(&[monday], None);
+        let end = datetimes(&[friday_late], None);
+        let out = business_day_count(&start, &end, WEEK_MASK, &[], ClosedInterval::Left, None)?;
+        assert_eq!(out.i32()?.get(0), Some(4));
+
+        // Mixing dates and datetimes is not allowed.
+        assert!(business_day_count(
+            &start,
+            &dates(&[Some(MONDAY)]),
+            WEEK_MASK,
+            &[],
+            ClosedInterval::Both,
+            None,
+        )
+        .is_err());
+
+        // Friday 23:30 in UTC is already Saturday in Tokyo.
+        #[cfg(feature = "timezones")]
+        {
+            let tz = Some("Asia/Tokyo".to_string());
+            let start = datetimes(&[monday], tz.clone());
+            let end = datetimes(&[friday_late], tz);
+            let out = business_day_count(&start, &end, WEEK_MASK, &[], ClosedInterval::Left, None)?;
+            assert_eq!(out.i32()?.get(0), Some(5));
+            let out = is_business_day(&end, WEEK_MASK, &[])?;
+            assert_eq!(out.bool()?.get(0), Some(false));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_business_day_count_large() -> PolarsResult<()> {
+        let len = 3 * PARALLEL_THRESHOLD as i32;
+        let start_values = (0..len).map(|i| MONDAY + i % 50).collect::<Vec<_>>();
+        let end_values = (0..len).map(|i| MONDAY + i % 37).collect::<Vec<_>>();
+        let start = Int32Chunked::from_vec("start", start_values.clone())
+            .into_date()
+            .into_series();
+        let end = Int32Chunked::from_vec("end", end_values.clone())
+            .into_date()
+            .into_series();
+        let holidays = [MONDAY + 3, MONDAY + 17];
+        let out = business_day_count(
+            &start,
+            &end,
+            WEEK_MASK,
+            &holidays,
+            ClosedInterval::Left,
+            None,
+        )?;
+        let expected = start_values
+            .iter()
+            .zip(&end_values)
+            .map(|(&start, &end)| {
+                Some(business_day_count_impl(
+                    start,
+                    end,
+                    &WEEK_MASK,
+                    5,
+                    &holidays,
+                    ClosedInterval::Left,
+                ))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(out.i32()?.into_iter().collect::<Vec<_>>(), expected);
+
+        let out_i64 = business_day_count(
+            &start,
+            &end,
+            WEEK_MASK,
+            &holidays,
+            ClosedInterval::Left,
+            Some(DataType::Int64),
+        )?;
+        assert_eq!(out_i64.dtype(), &DataType::Int64);
+        assert!(out_i64.equals(&out.cast(&DataType::Int64)?));
+        assert!(business_day_count(
+            &start,
+            &end,
+            WEEK_MASK,
+            &holidays,
+            ClosedInterval::Left,
+            Some(DataType::UInt32),
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_business_day_count_masked() -> PolarsResult<()> {
+        let start = dates(&[Some(MONDAY), Some(MONDAY), Some(MONDAY), None]);
+        let end = dates(&[Some(MONDAY + 7)]);
+        let sunday_to_thursday = [true, true, true, true, false, false, true];
+        let week_mask = Series::new(
+            "week_mask",
+            [
+                Series::new("", WEEK_MASK),
+                Series::new("", sunday_to_thursday),
+                Series::new("", [true; 7]),
+                Series::new("", WEEK_MASK),
+            ],
+        );
+        // Friday is a holiday, but only counts as such where it's a business day.
+        let out = business_day_count_masked(
+            &start,
+            &end,
+            &week_mask,
+            &[MONDAY + 4],
+            ClosedInterval::Left,
+        )?;
+        assert_eq!(
+            out.i32()?.into_iter().collect::<Vec<_>>(),
+            &[Some(4), Some(5), Some(6), None]
+        );
+
+        let err_msg = |week_mask: &[Series]| {
+            let week_mask = Series::new("week_mask", week_mask);
+            business_day_count_masked(&start, &end, &week_mask, &[], ClosedInterval::Left)
+                .unwrap_err()
+                .to_string()
+        };
+        let valid = Series::new("", WEEK_MASK);
+        let msg = err_msg(&[
+            valid.clone(),
+            Series::new("", [true; 6]),
+            valid.clone(),
+            valid.clone(),
+        ]);
+        assert!(msg.contains("exactly 7 non-null values"), "{msg}");
+        assert!(msg.contains("in row 1"), "{msg}");
+        let msg = err_msg(&[
+            valid.clone(),
+            valid.clone(),
+            valid,
+            Series::new("", [false; 7]),
+        ]);
+        assert!(msg.contains("at least one business day"), "{msg}");
+        assert!(
+            msg.contains("[false, false, false, false, false, false, false] in row 3"),
+            "{msg}"
+        );
+
+        let msg = BusinessDayCalendar::new([false; 7], &[])
+            .unwrap_err()
+            .to_string();
+        assert!(
+            msg.contains("got [false, false, false, false, false, false, false]"),
+            "{msg}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_nth_business_day() -> PolarsResult<()> {
+        let saturday = MONDAY + 5;
+        let start = dates(&[
+            Some(MONDAY),
+            Some(MONDAY),
+            Some(saturday),
+            Some(saturday),
+            None,
+        ]);
+        let n = Series::new("n", &[1, 3, 1, -1, 1]);
+        // The Tuesday is a holiday.
+        let out = nth_business_day(&start, &n, WEEK_MASK, &[MONDAY + 1])?;
+        assert_eq!(
+            physical(&out),
+            &[
+                Some(MONDAY),
+                Some(MONDAY + 3),
+                Some(MONDAY + 7),
+                Some(MONDAY + 4),
+                None
+            ]
+        );
+
+        let out = nth_business_day(
+            &dates(&[Some(MONDAY)]),
+            &Series::new("n", &[0, -1]),
+            WEEK_MASK,
+            &[],
+        )?;
+        assert_eq!(physical(&out), &[None, Some(MONDAY)]);
+
+        let out = nth_business_day(&start, &Series::new("n", &[i32::MAX]), WEEK_MASK, &[])?;
+        assert_eq!(out.null_count(), out.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_business_day_count_no_holidays_parity() {
+        use rand::rngs::SmallRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..10_000 {
+            let start_date = rng.gen_range(-50_000..50_000);
+            let end_date = start_date + rng.gen_range(-1_000..1_000);
+            let mut week_mask: [bool; 7] = rng.gen();
+            week_mask[rng.gen_range(0..7)] = true;
+            let n_business_days_in_week_mask = week_mask.iter().filter(|&x| *x).count() as i32;
+            let closed = [
+                ClosedInterval::Left,
+                ClosedInterval::Right,
+                ClosedInterval::Both,
+                ClosedInterval::None,
+            ][rng.gen_range(0..4)];
+            assert_eq!(
+                business_day_count_no_holidays_impl(
+                    start_date,
+                    end_date,
+                    &week_mask,
+                    n_business_days_in_week_mask,
+                    closed
+                ),
+                business_day_count_impl(
+                    start_date,
+                    end_date,
+                    &week_mask,
+                    n_business_days_in_week_mask,
+                    &[],
+                    closed
+                ),
+            );
+        }
+    }
+
+    #[test]
+    fn test_business_day_calendar() -> PolarsResult<()> {
+        // Unsorted, duplicated, and with a holiday on a Saturday.
+        let holidays = [MONDAY + 2, MONDAY + 5, MONDAY + 1, MONDAY + 2];
+        let calendar = BusinessDayCalendar::new(WEEK_MASK, &holidays)?;
+        assert_eq!(calendar.holidays(), &[MONDAY + 1, MONDAY + 2]);
+        assert!(BusinessDayCalendar::new([false; 7], &holidays).is_err());
+
+        // 2024-01-01 in seconds since the UNIX epoch.
+        let timestamps = [MONDAY * 86_400];
+        assert!(BusinessDayCalendar::new(WEEK_MASK, &timestamps).is_err());
+        assert!(business_day_count(
+            &dates(&[Some(MONDAY)]),
+            &dates(&[Some(MONDAY + 7)]),
+            WEEK_MASK,
+            &timestamps,
+            ClosedInterval::Left,
+            None,
+        )
+        .is_err());
+        let unchecked = BusinessDayCalendar::new_with_validation([true; 7], &timestamps, false)?;
+        assert_eq!(unchecked.holidays(), &timestamps);
+
+        let start = dates(&[Some(MONDAY)]);
+        let end = dates(&[Some(MONDAY + 7)]);
+        let out = calendar.business_day_count(&start, &end, ClosedInterval::Left, None)?;
+        assert!(out.equals(&business_day_count(
+            &start,
+            &end,
+            WEEK_MASK,
+            &holidays,
+            ClosedInterval::Left,
+            None
+        )?));
+        assert_eq!(out.i32()?.get(0), Some(3));
+        let out = calendar.is_business_day(&start)?;
+        assert_eq!(out.bool()?.get(0), Some(true));
+        let out = calendar.add_business_days(&start, &Series::new("n", &[1]), Roll::Raise)?;
+        assert_eq!(physical(&out), &[Some(MONDAY + 3)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_business_day_calendar_cached() -> PolarsResult<()> {
+        let holidays = [MONDAY + 9, MONDAY + 8, MONDAY + 12];
+        let calendar = BusinessDayCalendar::cached(WEEK_MASK, &holidays)?;
+        assert_eq!(calendar, BusinessDayCalendar::new(WEEK_MASK, &holidays)?);
+        // The second lookup shares the normalised holidays of the first.
+        let again = BusinessDayCalendar::cached(WEEK_MASK, &holidays)?;
+        assert_eq!(calendar.holidays().as_ptr(), again.holidays().as_ptr());
+
+        // The week mask is part of the key: Saturday is a business day here.
+        let week_mask = [true, true, true, true, true, true, false];
+        let other = BusinessDayCalendar::cached(week_mask, &holidays)?;
+        assert_eq!(other.holidays(), &[MONDAY + 8, MONDAY + 9, MONDAY + 12]);
+        assert_eq!(calendar.holidays(), &[MONDAY + 8, MONDAY + 9]);
+
+        // Invalid holidays still raise on every call.
+        let timestamps = [MONDAY * 86_400];
+        assert!(BusinessDayCalendar::cached(WEEK_MASK, &timestamps).is_err());
+        assert!(BusinessDayCalendar::cached(WEEK_MASK, &timestamps).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_roll_business_day() -> PolarsResult<()> {
+        // 2024-03-30 is a Saturday, and 2024-03-31 a Sunday. 2024-04-01 is a Monday, and
+        // 2024-04-06 a Saturday.
+        let saturday_end_of_month = MONDAY + 89;
+        let saturday = MONDAY + 96;
+        let s = dates(&[
+            Some(saturday_end_of_month),
+            Some(saturday),
+            Some(MONDAY),
+            None,
+        ]);
+        let roll = |roll| roll_business_day(&s, WEEK_MASK, &[], roll).map(|out| physical(&out));
+        let (friday_end_of_month, monday_next_month) = (MONDAY + 88, MONDAY + 91);
+        assert_eq!(
+            roll(Roll::Forward)?,
+            &[
+                Some(monday_next_month),
+                Some(saturday + 2),
+                Some(MONDAY),
+                None
+            ]
+        );
+        assert_eq!(
+            roll(Roll::Backward)?,
+            &[
+                Some(friday_end_of_month),
+                Some(saturday - 1),
+                Some(MONDAY),
+                None
+            ]
+        );
+        assert_eq!(
+            roll(Roll::ModifiedFollowing)?,
+            &[
+                Some(friday_end_of_month),
+                Some(saturday + 2),
+                Some(MONDAY),
+                None
+            ]
+        );
+        assert_eq!(roll(Roll::Null)?, &[None, None, Some(MONDAY), None]);
+        assert!(roll(Roll::Raise).is_err());
+
+        // Sunday 2024-09-01 would roll back into August.
+        let sunday_start_of_month = dates(&[Some(MONDAY + 244)]);
+        let out = roll_business_day(
+            &sunday_start_of_month,
+            WEEK_MASK,
+            &[],
+            Roll::ModifiedPreceding,
+        )?;
+        assert_eq!(physical(&out), &[Some(MONDAY + 245)]);
+        let out = roll_business_day(&sunday_start_of_month, WEEK_MASK, &[], Roll::Backward)?;
+        assert_eq!(physical(&out), &[Some(MONDAY + 242)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_business_date_range() -> PolarsResult<()> {
+        let wednesday = MONDAY + 2;
+        let range = |start, end, interval, holidays: &[i32]| {
+            business_date_range(
+                &dates(&[Some(start)]),
+                &dates(&[Some(end)]),
+                interval,
+                WEEK_MASK,
+                holidays,
+            )
+        };
+
+        // Saturday to the Tuesday a week later, skipping the weekend and a holiday.
+        let out = range(MONDAY - 2, MONDAY + 8, 1, &[wednesday])?;
+        let expected = [0, 1, 3, 4, 7, 8].map(|d| Some(MONDAY + d));
+        assert_eq!(physical(&out), expected);
+
+        let out = range(MONDAY, MONDAY + 14, 2, &[wednesday])?;
+        let expected = [0, 3, 7, 9, 11].map(|d| Some(MONDAY + d));
+        assert_eq!(physical(&out), expected);
+
+        // Starting at a non-business day counts from the next business day.
+        let out = range(wednesday, MONDAY + 9, 3, &[wednesday])?;
+        let expected = [3, 8].map(|d| Some(MONDAY + d));
+        assert_eq!(physical(&out), expected);
+
+        assert!(range(MONDAY + 5, MONDAY + 6, 1, &[])?.is_empty());
+        assert!(range(MONDAY + 7, MONDAY, 1, &[])?.is_empty());
+        assert!(range(MONDAY, MONDAY + 7, 0, &[]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_business_days_in_month() -> PolarsResult<()> {
+        // 2024-02-15 (leap year), 2023-02-15, and 2024-01-31.
+        let s = dates(&[
+            Some(MONDAY + 45),
+            Some(MONDAY - 320),
+            Some(MONDAY + 30),
+            None,
+        ]);
+        let out = business_days_in_month(&s, WEEK_MASK, &[MONDAY])?;
+        assert_eq!(
+            out.i32()?.into_iter().collect::<Vec<_>>(),
+            &[Some(21), Some(20), Some(22), None]
+        );
+        Ok(())
+    }
+}
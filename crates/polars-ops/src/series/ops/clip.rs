@@ -1,17 +1,46 @@
 use num_traits::{clamp, clamp_max, clamp_min};
 use polars_core::prelude::arity::{binary_elementwise, ternary_elementwise};
 use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
 use polars_core::with_match_physical_numeric_polars_type;
 
+/// Cast a bound to the dtype of `s`, checking that both have a numeric supertype.
+fn cast_bound(s: &Series, bound: &Series) -> PolarsResult<Series> {
+    polars_ensure!(
+        bound.len() == 1 || bound.len() == s.len(),
+        ShapeMismatch: "`clip` bound has length {}, expected 1 or {}", bound.len(), s.len()
+    );
+    let supertype = try_get_supertype(s.dtype(), bound.dtype());
+    polars_ensure!(
+        matches!(supertype, Ok(dt) if dt.to_physical().is_numeric() || dt.is_decimal()),
+        ComputeError: "cannot clip values of dtype {} with a bound of dtype {}",
+        s.dtype(), bound.dtype()
+    );
+    bound.strict_cast(s.dtype())
+}
+
 /// Set values outside the given boundaries to the boundary value.
+///
+/// The bounds are either scalars or have one value per element. A null bound means the
+/// element is not bounded on that side. Decimals are compared in fixed point, with the
+/// bounds cast to the scale of `s`.
 pub fn clip(s: &Series, min: &Series, max: &Series) -> PolarsResult<Series> {
+    #[cfg(feature = "dtype-decimal")]
+    if let Ok(ca) = s.decimal() {
+        let (min, max) = (cast_bound(s, min)?, cast_bound(s, max)?);
+        let (min, max) = (min.decimal()?, max.decimal()?);
+        let out = clip_helper(ca.as_ref(), min.as_ref(), max.as_ref());
+        return Ok(out
+            .into_decimal_unchecked(ca.precision(), ca.scale())
+            .into_series());
+    }
     polars_ensure!(
         s.dtype().to_physical().is_numeric(),
         InvalidOperation: "`clip` only supports physical numeric types"
     );
 
     let original_type = s.dtype();
-    let (min, max) = (min.strict_cast(s.dtype())?, max.strict_cast(s.dtype())?);
+    let (min, max) = (cast_bound(s, min)?, cast_bound(s, max)?);
 
     let (s, min, max) = (
         s.to_physical_repr(),
@@ -39,13 +68,21 @@ pub fn clip(s: &Series, min: &Series, max: &Series) -> PolarsResult<Series> {
 
 /// Set values above the given maximum to the maximum value.
 pub fn clip_max(s: &Series, max: &Series) -> PolarsResult<Series> {
+    #[cfg(feature = "dtype-decimal")]
+    if let Ok(ca) = s.decimal() {
+        let max = cast_bound(s, max)?;
+        let out = clip_min_max_helper(ca.as_ref(), max.decimal()?.as_ref(), clamp_max);
+        return Ok(out
+            .into_decimal_unchecked(ca.precision(), ca.scale())
+            .into_series());
+    }
     polars_ensure!(
         s.dtype().to_physical().is_numeric(),
         InvalidOperation: "`clip` only supports physical numeric types"
     );
 
     let original_type = s.dtype();
-    let max = max.strict_cast(s.dtype())?;
+    let max = cast_bound(s, max)?;
 
     let (s, max) = (s.to_physical_repr(), max.to_physical_repr());
 
@@ -68,13 +105,21 @@ pub fn clip_max(s: &Series, max: &Series) -> PolarsResult<Series> {
 
 /// Set values below the given minimum to the minimum value.
 pub fn clip_min(s: &Series, min: &Series) -> PolarsResult<Series> {
+    #[cfg(feature = "dtype-decimal")]
+    if let Ok(ca) = s.decimal() {
+        let min = cast_bound(s, min)?;
+        let out = clip_min_max_helper(ca.as_ref(), min.decimal()?.as_ref(), clamp_min);
+        return Ok(out
+            .into_decimal_unchecked(ca.precision(), ca.scale())
+            .into_series());
+    }
     polars_ensure!(
         s.dtype().to_physical().is_numeric(),
         InvalidOperation: "`clip` only supports physical numeric types"
     );
 
     let original_type = s.dtype();
-    let min = min.strict_cast(s.dtype())?;
+    let min = cast_bound(s, min)?;
 
     let (s, min) = (s.to_physical_repr(), min.to_physical_repr());
 
@@ -95,6 +140,16 @@ pub fn clip_min(s: &Series, min: &Series) -> PolarsResult<Series> {
     }
 }
 
+#[inline]
+fn clamp_opt<T: PartialOrd>(s: T, min: Option<T>, max: Option<T>) -> T {
+    match (min, max) {
+        (Some(min), Some(max)) => clamp(s, min, max),
+        (Some(min), None) => clamp_min(s, min),
+        (None, Some(max)) => clamp_max(s, max),
+        (None, None) => s,
+    }
+}
+
 fn clip_helper<T>(
     ca: &ChunkedArray<T>,
     min: &ChunkedArray<T>,
@@ -105,31 +160,24 @@ where
     T::Native: PartialOrd,
 {
     match (min.len(), max.len()) {
-        (1, 1) => match (min.get(0), max.get(0)) {
-            (Some(min), Some(max)) => {
-                ca.apply_generic(|s| s.map(|s| num_traits::clamp(s, min, max)))
-            },
-            _ => ChunkedArray::<T>::full_null(ca.name(), ca.len()),
+        (1, 1) => {
+            let (min, max) = (min.get(0), max.get(0));
+            ca.apply_generic(|s| s.map(|s| clamp_opt(s, min, max)))
         },
-        (1, _) => match min.get(0) {
-            Some(min) => binary_elementwise(ca, max, |opt_s, opt_max| match (opt_s, opt_max) {
-                (Some(s), Some(max)) => Some(clamp(s, min, max)),
-                _ => None,
-            }),
-            _ => ChunkedArray::<T>::full_null(ca.name(), ca.len()),
+        (1, _) => {
+            let min = min.get(0);
+            binary_elementwise(ca, max, |opt_s, opt_max| {
+                opt_s.map(|s| clamp_opt(s, min, opt_max))
+            })
         },
-        (_, 1) => match max.get(0) {
-            Some(max) => binary_elementwise(ca, min, |opt_s, opt_min| match (opt_s, opt_min) {
-                (Some(s), Some(min)) => Some(clamp(s, min, max)),
-                _ => None,
-            }),
-            _ => ChunkedArray::<T>::full_null(ca.name(), ca.len()),
+        (_, 1) => {
+            let max = max.get(0);
+            binary_elementwise(ca, min, |opt_s, opt_min| {
+                opt_s.map(|s| clamp_opt(s, opt_min, max))
+            })
         },
         _ => ternary_elementwise(ca, min, max, |opt_s, opt_min, opt_max| {
-            match (opt_s, opt_min, opt_max) {
-                (Some(s), Some(min), Some(max)) => Some(clamp(s, min, max)),
-                _ => None,
-            }
+            opt_s.map(|s| clamp_opt(s, opt_min, opt_max))
         }),
     }
 }
@@ -147,11 +195,10 @@ where
     match bound.len() {
         1 => match bound.get(0) {
             Some(bound) => ca.apply_generic(|s| s.map(|s| op(s, bound))),
-            _ => ChunkedArray::<T>::full_null(ca.name(), ca.len()),
+            _ => ca.clone(),
         },
-        _ => binary_elementwise(ca, bound, |opt_s, opt_bound| match (opt_s, opt_bound) {
-            (Some(s), Some(bound)) => Some(op(s, bound)),
-            _ => None,
+        _ => binary_elementwise(ca, bound, |opt_s, opt_bound| {
+            opt_s.map(|s| opt_bound.map_or(s, |bound| op(s, bound)))
         }),
     }
 }
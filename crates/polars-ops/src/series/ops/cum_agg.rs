@@ -1,36 +1,53 @@
 use std::ops::{Add, AddAssign, Mul};
 
-use num_traits::{Bounded, One, Zero};
+use num_traits::{CheckedMul, One, Zero};
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::utils::{CustomIterTools, NoNull};
 use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::float::IsFloat;
 
-fn det_max<T>(state: &mut T, v: Option<T>) -> Option<Option<T>>
+/// Whether the running extremum `state` should be replaced by `v`.
+///
+/// The first non-null value always seeds the state, so infinities are kept as is. NaN values are
+/// skipped once a non-NaN value has been seen, which keeps the result independent of the
+/// direction the values are visited in.
+fn replaces_state<T: Copy + IsFloat>(
+    state: Option<T>,
+    v: T,
+    better: impl Fn(T, T) -> bool,
+) -> bool {
+    match state {
+        None => true,
+        Some(state) => (state.is_nan() && !v.is_nan()) || better(v, state),
+    }
+}
+
+fn det_max<T>(state: &mut Option<T>, v: Option<T>) -> Option<Option<T>>
 where
-    T: Copy + PartialOrd + AddAssign + Add<Output = T>,
+    T: Copy + PartialOrd + IsFloat,
 {
     match v {
         Some(v) => {
-            if v > *state {
-                *state = v
+            if replaces_state(*state, v, |v, state| v > state) {
+                *state = Some(v)
             }
-            Some(Some(*state))
+            Some(*state)
         },
         None => Some(None),
     }
 }
 
-fn det_min<T>(state: &mut T, v: Option<T>) -> Option<Option<T>>
+fn det_min<T>(state: &mut Option<T>, v: Option<T>) -> Option<Option<T>>
 where
-    T: Copy + PartialOrd + AddAssign + Add<Output = T>,
+    T: Copy + PartialOrd + IsFloat,
 {
     match v {
         Some(v) => {
-            if v < *state {
-                *state = v
+            if replaces_state(*state, v, |v, state| v < state) {
+                *state = Some(v)
             }
-            Some(Some(*state))
+            Some(*state)
         },
         None => Some(None),
     }
@@ -67,11 +84,9 @@ where
     T: PolarsNumericType,
     ChunkedArray<T>: FromIterator<Option<T::Native>>,
 {
-    let init = Bounded::min_value();
-
     let out: ChunkedArray<T> = match reverse {
-        false => ca.iter().scan(init, det_max).collect_trusted(),
-        true => ca.iter().rev().scan(init, det_max).collect_reversed(),
+        false => ca.iter().scan(None, det_max).collect_trusted(),
+        true => ca.iter().rev().scan(None, det_max).collect_reversed(),
     };
     out.with_name(ca.name())
 }
@@ -81,10 +96,9 @@ where
     T: PolarsNumericType,
     ChunkedArray<T>: FromIterator<Option<T::Native>>,
 {
-    let init = Bounded::max_value();
     let out: ChunkedArray<T> = match reverse {
-        false => ca.iter().scan(init, det_min).collect_trusted(),
-        true => ca.iter().rev().scan(init, det_min).collect_reversed(),
+        false => ca.iter().scan(None, det_min).collect_trusted(),
+        true => ca.iter().rev().scan(None, det_min).collect_reversed(),
     };
     out.with_name(ca.name())
 }
@@ -115,19 +129,77 @@ where
     out.with_name(ca.name())
 }
 
+fn cum_prod_checked<T>(ca: &ChunkedArray<T>, reverse: bool) -> PolarsResult<ChunkedArray<T>>
+where
+    T: PolarsIntegerType,
+    T::Native: CheckedMul,
+{
+    let mut state = T::Native::one();
+    let mut det_prod_checked = |idx: usize, v: Option<T::Native>| -> PolarsResult<_> {
+        match v {
+            Some(v) => {
+                state = state.checked_mul(&v).ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "cumulative product overflowed {} at index {}",
+                        T::get_dtype(), idx
+                    )
+                })?;
+                Ok(Some(state))
+            },
+            None => Ok(None),
+        }
+    };
+    let out = match reverse {
+        false => ca
+            .iter()
+            .enumerate()
+            .map(|(idx, v)| det_prod_checked(idx, v))
+            .collect::<PolarsResult<Vec<_>>>()?,
+        true => {
+            let len = ca.len();
+            let mut out = ca
+                .iter()
+                .rev()
+                .enumerate()
+                .map(|(i, v)| det_prod_checked(len - 1 - i, v))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            out.reverse();
+            out
+        },
+    };
+    Ok(ChunkedArray::from_iter_options(ca.name(), out.into_iter()))
+}
+
+fn cum_prod_integer<T>(ca: &ChunkedArray<T>, reverse: bool, strict: bool) -> PolarsResult<Series>
+where
+    T: PolarsIntegerType,
+    T::Native: CheckedMul,
+    ChunkedArray<T>: FromIterator<Option<T::Native>> + IntoSeries,
+{
+    if strict {
+        cum_prod_checked(ca, reverse).map(|ca| ca.into_series())
+    } else {
+        Ok(cum_prod_numeric(ca, reverse).into_series())
+    }
+}
+
 /// Get an array with the cumulative product computed at every element.
 ///
 /// If the [`DataType`] is one of `{Int8, UInt8, Int16, UInt16, Int32, UInt32}` the `Series` is
 /// first cast to `Int64` to prevent overflow issues.
-pub fn cum_prod(s: &Series, reverse: bool) -> PolarsResult<Series> {
+///
+/// Integer products wrap around on overflow, unless `strict` is set, in which case an error
+/// naming the index of the first overflow is raised instead. Floats are not affected by
+/// `strict`.
+pub fn cum_prod(s: &Series, reverse: bool, strict: bool) -> PolarsResult<Series> {
     use DataType::*;
     let out = match s.dtype() {
         Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 => {
             let s = s.cast(&Int64)?;
-            cum_prod_numeric(s.i64()?, reverse).into_series()
+            cum_prod_integer(s.i64()?, reverse, strict)?
         },
-        Int64 => cum_prod_numeric(s.i64()?, reverse).into_series(),
-        UInt64 => cum_prod_numeric(s.u64()?, reverse).into_series(),
+        Int64 => cum_prod_integer(s.i64()?, reverse, strict)?,
+        UInt64 => cum_prod_integer(s.u64()?, reverse, strict)?,
         Float32 => cum_prod_numeric(s.f32()?, reverse).into_series(),
         Float64 => cum_prod_numeric(s.f64()?, reverse).into_series(),
         dt => polars_bail!(opq = cum_prod, dt),
@@ -207,8 +279,10 @@ pub fn cum_max(s: &Series, reverse: bool) -> PolarsResult<Series> {
     }
 }
 
-pub fn cum_count(s: &Series, reverse: bool) -> PolarsResult<Series> {
-    let mut out = if s.null_count() == 0 {
+/// Count the values up to and including each position, or from each position to the end if
+/// `reverse`. Nulls are only counted if `count_nulls` is set.
+pub fn cum_count(s: &Series, reverse: bool, count_nulls: bool) -> PolarsResult<Series> {
+    let mut out = if count_nulls || s.null_count() == 0 {
         // Fast paths for no nulls
         cum_count_no_nulls(s.name(), s.len(), reverse)
     } else {
@@ -253,3 +327,87 @@ fn cum_count_no_nulls(name: &str, len: usize, reverse: bool) -> Series {
     ca.rename(name);
     ca.into_series()
 }
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    fn random_series(rng: &mut SmallRng, dtype: &DataType) -> Series {
+        let len = rng.gen_range(0..40);
+        let values: Vec<Option<i32>> = (0..len)
+            .map(|_| rng.gen_bool(0.7).then(|| rng.gen_range(-5..5)))
+            .collect();
+        // Split into chunks so that reverse iteration crosses chunk boundaries.
+        let mid = rng.gen_range(0..=len);
+        let mut s = Series::new("a", &values[..mid]);
+        s.append(&Series::new("a", &values[mid..])).unwrap();
+        s.cast(dtype).unwrap()
+    }
+
+    #[test]
+    fn test_cum_agg_reverse_identity() -> PolarsResult<()> {
+        type CumAgg = fn(&Series, bool) -> PolarsResult<Series>;
+        let ops: [(&str, CumAgg); 4] = [
+            ("cum_sum", cum_sum),
+            ("cum_min", cum_min),
+            ("cum_max", cum_max),
+            ("cum_prod", |s, reverse| cum_prod(s, reverse, false)),
+        ];
+        let dtypes = [
+            DataType::Int32,
+            DataType::Int64,
+            DataType::UInt32,
+            DataType::UInt64,
+            DataType::Float32,
+            DataType::Float64,
+        ];
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..200 {
+            for dtype in &dtypes {
+                let s = random_series(&mut rng, dtype);
+                for (name, op) in ops {
+                    let reversed = op(&s, true)?;
+                    let reflected = op(&s.reverse(), false)?.reverse();
+                    assert_eq!(reversed.dtype(), reflected.dtype());
+                    assert!(
+                        reversed.equals_missing(&reflected),
+                        "{name} on {dtype}: {s:?} gave {reversed:?}, expected {reflected:?}"
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_cum_min_max_float_edges() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            &[None, Some(f64::NAN), Some(f64::NEG_INFINITY), Some(1.0)],
+        );
+
+        let out = cum_max(&s, false)?;
+        let expected = Series::new(
+            "a",
+            &[None, Some(f64::NAN), Some(f64::NEG_INFINITY), Some(1.0)],
+        );
+        assert!(out.equals_missing(&expected));
+
+        let out = cum_min(&s, true)?;
+        let expected = Series::new(
+            "a",
+            &[
+                None,
+                Some(f64::NEG_INFINITY),
+                Some(f64::NEG_INFINITY),
+                Some(1.0),
+            ],
+        );
+        assert!(out.equals_missing(&expected));
+        Ok(())
+    }
+}
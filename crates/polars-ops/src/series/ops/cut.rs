@@ -1,4 +1,17 @@
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How [`qcut`] handles quantiles that fall on the same edge.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum QuantileDuplicates {
+    /// Raise an error naming the offending quantile.
+    #[default]
+    Raise,
+    /// Collapse duplicate edges, which may produce fewer bins than requested.
+    Drop,
+}
 
 fn map_cats(
     s: &Series,
@@ -9,8 +22,9 @@ fn map_cats(
 ) -> PolarsResult<Series> {
     let out_name = format!("{}_bin", s.name());
 
-    // Create new categorical and pre-register labels for consistent categorical indexes.
-    let mut bld = CategoricalChunkedBuilder::new(&out_name, s.len(), Default::default());
+    // Create new categorical and pre-register labels in bin order, so that the physical
+    // ordering sorts by bin rather than by label.
+    let mut bld = CategoricalChunkedBuilder::new(&out_name, s.len(), CategoricalOrdering::Physical);
     for label in labels {
         bld.register_value(label);
     }
@@ -79,6 +93,15 @@ pub fn compute_labels(breaks: &[f64], left_closed: bool) -> PolarsResult<Vec<Str
     Ok(ret)
 }
 
+/// Bin the values of `s` into the intervals defined by `breaks`.
+///
+/// By default a physically ordered `Categorical` with one category per interval is returned,
+/// so sorting it follows the order of the intervals. Under a global string cache this only holds
+/// for labels that were not cached before in a different order.
+///
+/// If `include_breaks` is set, the output is a `Struct` instead, with a `Float64` field `brk`
+/// holding the right edge of the assigned interval (`inf` for the last interval), followed by
+/// the categorical field named `"{name}_bin"`.
 pub fn cut(
     s: &Series,
     mut breaks: Vec<f64>,
@@ -105,15 +128,25 @@ pub fn cut(
     map_cats(s, &cut_labels, &breaks, left_closed, include_breaks)
 }
 
+/// Bin the values of `s` into intervals whose edges are the quantiles `probs` of `s`.
+///
+/// Heavily tied data can map several quantiles to the same edge; `duplicates` decides whether
+/// that raises or whether the duplicate edges are dropped. When dropping, the labels are
+/// computed from the remaining edges, so user provided `labels` must match the reduced number of
+/// bins.
+///
+/// See [`cut`] for the output when `include_breaks` is set.
 pub fn qcut(
     s: &Series,
-    probs: Vec<f64>,
+    mut probs: Vec<f64>,
     labels: Option<Vec<String>>,
     left_closed: bool,
-    allow_duplicates: bool,
+    duplicates: QuantileDuplicates,
     include_breaks: bool,
 ) -> PolarsResult<Series> {
     polars_ensure!(!probs.iter().any(|x| x.is_nan()), ComputeError: "quantiles cannot be NaN");
+    // Quantiles are monotonic in the probability, so sorting the probabilities sorts the breaks.
+    probs.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
 
     let s = s.cast(&DataType::Float64)?;
     let s2 = s.sort(SortOptions::default())?;
@@ -130,10 +163,18 @@ pub fn qcut(
             .unwrap()
     };
     let mut qbreaks: Vec<_> = probs.iter().map(f).collect();
-    qbreaks.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
 
-    if !allow_duplicates {
-        polars_ensure!(qbreaks.windows(2).all(|x| x[0] != x[1]), Duplicate: "quantiles are not unique while allow_duplicates=False");
+    match duplicates {
+        QuantileDuplicates::Raise => {
+            if let Some(i) = qbreaks.windows(2).position(|x| x[0] == x[1]) {
+                polars_bail!(
+                    Duplicate: "quantile {} has the same edge {} as quantile {}; \
+                    consider dropping duplicate edges",
+                    probs[i + 1], qbreaks[i + 1], probs[i]
+                );
+            }
+        },
+        QuantileDuplicates::Drop => qbreaks.dedup(),
     }
 
     let cut_labels = if let Some(l) = labels {
@@ -1,22 +1,40 @@
 use polars_core::prelude::*;
 use polars_core::series::ops::NullBehavior;
 
+/// Compute the difference between every value and the value `n` rows before it.
+///
+/// A negative `n` takes the difference with the value `-n` rows after it instead. With
+/// [`NullBehavior::Ignore`] the positions without a counterpart are null, with
+/// [`NullBehavior::Drop`] they are removed. Unsigned integers are widened to a signed
+/// type, so that negative differences can be represented.
 pub fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsResult<Series> {
-    use DataType::*;
-    let s = match s.dtype() {
-        UInt8 => s.cast(&Int16)?,
-        UInt16 => s.cast(&Int32)?,
-        UInt32 | UInt64 => s.cast(&Int64)?,
-        _ => s.clone(),
+    let s = match diff_signed_dtype(s.dtype()) {
+        Some(dtype) => s.cast(&dtype)?,
+        None => s.clone(),
     };
 
     match null_behavior {
         NullBehavior::Ignore => Ok(&s - &s.shift(n)),
         NullBehavior::Drop => {
-            polars_ensure!(n > 0, InvalidOperation: "only positive integer allowed if nulls are dropped in 'diff' operation");
-            let n = n as usize;
-            let len = s.len() - n;
-            Ok(&s.slice(n as i64, len) - &s.slice(0, len))
+            let offset = n.unsigned_abs().min(s.len() as u64) as usize;
+            let len = s.len() - offset;
+            let (lhs, rhs) = if n >= 0 {
+                (s.slice(offset as i64, len), s.slice(0, len))
+            } else {
+                (s.slice(0, len), s.slice(offset as i64, len))
+            };
+            Ok(&lhs - &rhs)
         },
     }
 }
+
+/// The signed integer type [`diff`] widens an unsigned integer type to, if any.
+pub fn diff_signed_dtype(dtype: &DataType) -> Option<DataType> {
+    use DataType::*;
+    match dtype {
+        UInt8 => Some(Int16),
+        UInt16 => Some(Int32),
+        UInt32 | UInt64 => Some(Int64),
+        _ => None,
+    }
+}
@@ -1,23 +1,51 @@
 use num_traits::{Float, FromPrimitive, One, Zero};
 use polars_core::prelude::*;
 
+/// Compute the exponentially-weighted moving average of `s`, where each step decays the
+/// previous average by `0.5^(dt / half_life)` and `dt` is the distance in `times` to the
+/// previous observation.
+///
+/// `half_life` is expressed in nanoseconds for temporal `times` and in the units of `times`
+/// for integer `times`. Rows where either the value or the time is null produce null and
+/// leave the running average untouched. If `assume_sorted` is `false`, the values are sorted
+/// by `times` first and the output is returned in the original order.
 pub fn ewm_mean_by(
     s: &Series,
     times: &Series,
     half_life: i64,
     assume_sorted: bool,
 ) -> PolarsResult<Series> {
-    let func = match assume_sorted {
-        true => ewm_mean_by_impl_sorted,
-        false => ewm_mean_by_impl,
-    };
+    fn func<T>(
+        values: &ChunkedArray<T>,
+        times: &Int64Chunked,
+        half_life: i64,
+        assume_sorted: bool,
+    ) -> PolarsResult<Series>
+    where
+        T: PolarsFloatType,
+        T::Native: Float + Zero + One,
+        ChunkedArray<T>: ChunkTakeUnchecked<IdxCa> + IntoSeries,
+    {
+        if assume_sorted {
+            Ok(ewm_mean_by_impl_sorted(values, times, half_life).into_series())
+        } else {
+            Ok(ewm_mean_by_impl(values, times, half_life).into_series())
+        }
+    }
+
     match (s.dtype(), times.dtype()) {
-        (DataType::Float64, DataType::Int64) => {
-            Ok(func(s.f64().unwrap(), times.i64().unwrap(), half_life).into_series())
-        },
-        (DataType::Float32, DataType::Int64) => {
-            Ok(ewm_mean_by_impl(s.f32().unwrap(), times.i64().unwrap(), half_life).into_series())
-        },
+        (DataType::Float64, DataType::Int64) => func(
+            s.f64().unwrap(),
+            times.i64().unwrap(),
+            half_life,
+            assume_sorted,
+        ),
+        (DataType::Float32, DataType::Int64) => func(
+            s.f32().unwrap(),
+            times.i64().unwrap(),
+            half_life,
+            assume_sorted,
+        ),
         #[cfg(feature = "dtype-datetime")]
         (_, DataType::Datetime(time_unit, _)) => {
             let half_life = adjust_half_life_to_time_unit(half_life, time_unit);
@@ -30,21 +58,18 @@ pub fn ewm_mean_by(
             half_life,
             assume_sorted,
         ),
-        (_, DataType::UInt64 | DataType::UInt32 | DataType::Int32) => {
+        (_, dt) if dt.is_integer() && dt != &DataType::Int64 => {
             ewm_mean_by(s, &times.cast(&DataType::Int64)?, half_life, assume_sorted)
         },
-        (DataType::UInt64 | DataType::UInt32 | DataType::Int64 | DataType::Int32, _) => {
-            ewm_mean_by(
-                &s.cast(&DataType::Float64)?,
-                times,
-                half_life,
-                assume_sorted,
-            )
-        },
+        (dt, DataType::Int64) if dt.is_integer() => ewm_mean_by(
+            &s.cast(&DataType::Float64)?,
+            times,
+            half_life,
+            assume_sorted,
+        ),
         _ => {
-            polars_bail!(InvalidOperation: "expected series to be Float64, Float32, \
-                Int64, Int32, UInt64, UInt32, and `by` to be Date, Datetime, Int64, Int32, \
-                UInt64, or UInt32")
+            polars_bail!(InvalidOperation: "expected series to be a float or integer, and `by` \
+                to be Date, Datetime, or an integer, got {} and {}", s.dtype(), times.dtype())
         },
     }
 }
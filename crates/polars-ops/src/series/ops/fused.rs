@@ -1,7 +1,9 @@
 use arrow::array::PrimitiveArray;
+use arrow::bitmap::Bitmap;
 use arrow::compute::utils::combine_validities_and3;
+use num_traits::Float;
 use polars_core::prelude::*;
-use polars_core::utils::align_chunks_ternary;
+use polars_core::utils::{align_chunks_ternary, try_get_supertype};
 use polars_core::with_match_physical_numeric_polars_type;
 
 // a + (b * c)
@@ -155,3 +157,133 @@ pub fn fms_series(a: &Series, b: &Series, c: &Series) -> Series {
         &(a * b) - c
     }
 }
+
+fn full_length_validity<T: NumericNative>(arr: &PrimitiveArray<T>, len: usize) -> Option<&Bitmap> {
+    if arr.len() == len {
+        arr.validity()
+    } else {
+        None
+    }
+}
+
+fn stride<T: NumericNative>(arr: &PrimitiveArray<T>, len: usize) -> usize {
+    (arr.len() == len) as usize
+}
+
+// (a * b) + c, rounded once. Operands of length 1 are broadcast.
+fn mul_add_ca<T>(
+    a: &ChunkedArray<T>,
+    b: &ChunkedArray<T>,
+    c: &ChunkedArray<T>,
+    len: usize,
+) -> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+{
+    let operands = [a, b, c];
+    if len != 1
+        && operands
+            .iter()
+            .any(|ca| ca.len() == 1 && ca.null_count() == 1)
+    {
+        return ChunkedArray::full_null(a.name(), len);
+    }
+    let name = a.name();
+    let [a, b, c] = operands.map(|ca| ca.rechunk());
+    let [a, b, c] = [&a, &b, &c].map(|ca| ca.downcast_iter().next().unwrap());
+
+    // A broadcast operand is valid here, so only full-length operands contribute validity.
+    let validity = combine_validities_and3(
+        full_length_validity(a, len),
+        full_length_validity(b, len),
+        full_length_validity(c, len),
+    );
+    // The stride is zero for a broadcast operand, so it always reads its single value.
+    let (sa, sb, sc) = (stride(a, len), stride(b, len), stride(c, len));
+    let (a, b, c) = (a.values(), b.values(), c.values());
+
+    let out = (0..len)
+        .map(|i| unsafe {
+            a.get_unchecked(i * sa)
+                .mul_add(*b.get_unchecked(i * sb), *c.get_unchecked(i * sc))
+        })
+        .collect::<Vec<_>>();
+    let arr = PrimitiveArray::from_data_default(out.into(), validity);
+    ChunkedArray::with_chunk(name, arr)
+}
+
+/// Compute `a * b + c`.
+///
+/// Floats use a fused multiply-add, which rounds once and doesn't materialize `a * b`.
+/// Other numeric types fall back to a separate multiplication and addition. The operands
+/// are cast to their supertype and are either scalars or have the same length.
+pub fn mul_add(a: &Series, b: &Series, c: &Series) -> PolarsResult<Series> {
+    let len = [a.len(), b.len(), c.len()]
+        .into_iter()
+        .find(|len| *len != 1)
+        .unwrap_or(1);
+    polars_ensure!(
+        [a.len(), b.len(), c.len()].iter().all(|l| *l == 1 || *l == len),
+        ShapeMismatch: "`mul_add` operands have lengths {}, {} and {}; expected 1 or {}",
+        a.len(), b.len(), c.len(), len
+    );
+    let dtype = try_get_supertype(a.dtype(), b.dtype())?;
+    let dtype = try_get_supertype(&dtype, c.dtype())?;
+    let (a, b, c) = (a.cast(&dtype)?, b.cast(&dtype)?, c.cast(&dtype)?);
+
+    let mut out = match dtype {
+        DataType::Float32 => mul_add_ca(a.f32()?, b.f32()?, c.f32()?, len).into_series(),
+        DataType::Float64 => mul_add_ca(a.f64()?, b.f64()?, c.f64()?, len).into_series(),
+        _ => &(&a * &b) + &c,
+    };
+    out.rename(a.name());
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mul_add() -> PolarsResult<()> {
+        let a = Series::new("a", [Some(1.5f64), None, Some(0.1)]);
+        let b = Series::new("b", [2.0f64]);
+        let c = Series::new("c", [Some(1.0f64), Some(1.0), None]);
+
+        let out = mul_add(&a, &b, &c)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(out.f64()?), &[Some(4.0), None, None]);
+
+        // A single rounding step: `0.1 * 3.0 + c` would round the product to `-c` first.
+        let a = Series::new("a", [0.1f64]);
+        let c = Series::new("c", [-(0.1f64 * 3.0)]);
+        let out = mul_add(&a, &Series::new("b", [3.0f64]), &c)?;
+        let out = out.f64()?.get(0).unwrap();
+        assert_ne!(out, 0.0);
+        assert_eq!(out, 0.1f64.mul_add(3.0, -(0.1f64 * 3.0)));
+
+        let out = mul_add(
+            &a,
+            &Series::new("b", [Some(3i32), None]),
+            &Series::new("c", [1i32]),
+        )?;
+        assert_eq!(out.dtype(), &DataType::Float64);
+        assert_eq!(out.null_count(), 1);
+
+        let out = mul_add(
+            &Series::new("a", [2i32, 3]),
+            &Series::new("b", [4i32]),
+            &Series::new("c", [1i32, 2]),
+        )?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(9), Some(14)]);
+
+        assert!(mul_add(
+            &a,
+            &Series::new("b", [1.0f64, 2.0]),
+            &Series::new("c", [1.0f64; 3])
+        )
+        .is_err());
+        Ok(())
+    }
+}
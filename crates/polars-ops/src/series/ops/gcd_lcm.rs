@@ -0,0 +1,154 @@
+use num_traits::{Bounded, NumCast, ToPrimitive, Zero};
+use polars_core::prelude::arity::broadcast_try_binary_elementwise;
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+use polars_core::with_match_physical_integer_polars_type;
+
+/// Absolute value of any integer native, which always fits in an `u64`.
+#[inline]
+fn abs_u64<T: ToPrimitive>(v: T) -> u64 {
+    v.to_i128().unwrap().unsigned_abs() as u64
+}
+
+/// Binary GCD (Stein's algorithm).
+fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    if a == 0 {
+        return b;
+    }
+    if b == 0 {
+        return a;
+    }
+    let shift = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
+    loop {
+        b >>= b.trailing_zeros();
+        if a > b {
+            std::mem::swap(&mut a, &mut b);
+        }
+        b -= a;
+        if b == 0 {
+            return a << shift;
+        }
+    }
+}
+
+fn cast_to_integer_supertype(
+    a: &Series,
+    b: &Series,
+    name: &str,
+) -> PolarsResult<(Series, Series, DataType)> {
+    polars_ensure!(
+        a.len() == b.len() || a.len() == 1 || b.len() == 1,
+        ShapeMismatch: "cannot compute `{}` of series with lengths {} and {}", name, a.len(), b.len()
+    );
+    let dtype = try_get_supertype(a.dtype(), b.dtype())?;
+    polars_ensure!(
+        a.dtype().is_integer() && b.dtype().is_integer() && dtype.is_integer(),
+        InvalidOperation: "`{}` only supports integer dtypes, got {} and {}", name, a.dtype(), b.dtype()
+    );
+    Ok((a.cast(&dtype)?, b.cast(&dtype)?, dtype))
+}
+
+/// Element-wise greatest common divisor of two integer series.
+///
+/// Either side may be a scalar. The result is non-negative, with `gcd(0, 0) == 0`, and has
+/// the supertype of both inputs.
+pub fn gcd(a: &Series, b: &Series) -> PolarsResult<Series> {
+    let (a, b, dtype) = cast_to_integer_supertype(a, b, "gcd")?;
+    with_match_physical_integer_polars_type!(dtype, |$T| {
+        let a: &ChunkedArray<$T> = a.as_ref().as_ref().as_ref();
+        let b: &ChunkedArray<$T> = b.as_ref().as_ref().as_ref();
+        let out: ChunkedArray<$T> = broadcast_try_binary_elementwise(a, b, |x, y| match (x, y) {
+            (Some(x), Some(y)) => {
+                let out: Option<<$T as PolarsNumericType>::Native> =
+                    NumCast::from(gcd_u64(abs_u64(x), abs_u64(y)));
+                polars_ensure!(
+                    out.is_some(),
+                    ComputeError: "`gcd` of {} and {} does not fit in {}", x, y, dtype
+                );
+                Ok(out)
+            },
+            _ => Ok(None),
+        })?;
+        Ok(out.into_series())
+    })
+}
+
+/// Element-wise least common multiple of two integer series.
+///
+/// Either side may be a scalar. The result is non-negative, zero if either value is zero,
+/// and has the supertype of both inputs. If the result does not fit in that dtype, this
+/// raises an error, or returns the dtype's maximum value if `saturate` is set.
+pub fn lcm(a: &Series, b: &Series, saturate: bool) -> PolarsResult<Series> {
+    let (a, b, dtype) = cast_to_integer_supertype(a, b, "lcm")?;
+    with_match_physical_integer_polars_type!(dtype, |$T| {
+        let a: &ChunkedArray<$T> = a.as_ref().as_ref().as_ref();
+        let b: &ChunkedArray<$T> = b.as_ref().as_ref().as_ref();
+        let out: ChunkedArray<$T> = broadcast_try_binary_elementwise(a, b, |x, y| match (x, y) {
+            (Some(x), Some(y)) => {
+                let (ux, uy) = (abs_u64(x), abs_u64(y));
+                if ux == 0 || uy == 0 {
+                    return Ok(Some(Zero::zero()));
+                }
+                let out: Option<<$T as PolarsNumericType>::Native> = (ux / gcd_u64(ux, uy))
+                    .checked_mul(uy)
+                    .and_then(NumCast::from);
+                match out {
+                    Some(out) => Ok(Some(out)),
+                    None if saturate => Ok(Some(Bounded::max_value())),
+                    None => polars_bail!(
+                        ComputeError: "`lcm` of {} and {} overflows {}; set `saturate` to clamp to its maximum value instead",
+                        x, y, dtype
+                    ),
+                }
+            },
+            _ => Ok(None),
+        })?;
+        Ok(out.into_series())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() -> PolarsResult<()> {
+        let a = Series::new("a", [Some(12i64), Some(-18), Some(0), None, Some(7)]);
+        let b = Series::new("b", [Some(18i64), Some(12), Some(0), Some(3), Some(-21)]);
+
+        let out = gcd(&a, &b)?;
+        assert_eq!(
+            Vec::from(out.i64()?),
+            &[Some(6), Some(6), Some(0), None, Some(7)]
+        );
+        let out = lcm(&a, &b, false)?;
+        assert_eq!(
+            Vec::from(out.i64()?),
+            &[Some(36), Some(36), Some(0), None, Some(21)]
+        );
+
+        let four = Series::new("", [4i32]);
+        let out = gcd(&a, &four)?;
+        assert_eq!(out.dtype(), &DataType::Int64);
+        assert_eq!(
+            Vec::from(out.i64()?),
+            &[Some(4), Some(2), Some(4), None, Some(1)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lcm_overflow() -> PolarsResult<()> {
+        let a = Series::new("a", [i32::MAX, 6]);
+        let b = Series::new("b", [i32::MAX - 1, 4]);
+        assert!(lcm(&a, &b, false).is_err());
+        let out = lcm(&a, &b, true)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(i32::MAX), Some(12)]);
+
+        let a = Series::new("a", [i64::MIN]);
+        let b = Series::new("b", [0i64]);
+        assert!(gcd(&a, &b).is_err());
+        Ok(())
+    }
+}
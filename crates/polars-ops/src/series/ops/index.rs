@@ -1,47 +1,82 @@
-use num_traits::{Signed, Zero};
-use polars_core::error::{polars_ensure, PolarsResult};
-use polars_core::prelude::{ChunkedArray, DataType, IdxCa, PolarsIntegerType, Series, IDX_DTYPE};
-use polars_utils::index::ToIdx;
+use num_traits::{Signed, ToPrimitive, Zero};
+use polars_core::chunked_array::ops::gather::check_bounds_ca;
+use polars_core::error::{polars_ensure, polars_err, PolarsResult};
+use polars_core::prelude::*;
+use polars_core::with_match_physical_integer_polars_type;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-fn convert<T>(ca: &ChunkedArray<T>, target_len: usize) -> PolarsResult<IdxCa>
+/// What to do with an index that is out of bounds after negative indices have been counted
+/// from the end.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OutOfBounds {
+    /// Raise an error.
+    #[default]
+    Raise,
+    /// Yield a null.
+    Null,
+    /// Wrap the index around, modulo the length. Indexing into an empty sequence raises.
+    Wrap,
+}
+
+fn normalize<T>(ca: &ChunkedArray<T>, target_len: usize, oob: OutOfBounds) -> PolarsResult<IdxCa>
 where
     T: PolarsIntegerType,
-    T::Native: ToIdx,
 {
-    let target_len = target_len as u64;
-    Ok(ca.apply_values_generic(|v| v.to_idx(target_len)))
+    let len = target_len as i128;
+    let mut first_oob = None;
+    let out = IdxCa::from_iter_options(
+        ca.name(),
+        ca.iter().map(|opt_v| {
+            let v = opt_v?.to_i128().unwrap();
+            let idx = if v < 0 { v + len } else { v };
+            if (0..len).contains(&idx) {
+                return Some(idx as IdxSize);
+            }
+            match oob {
+                OutOfBounds::Null => None,
+                OutOfBounds::Wrap if len > 0 => Some(v.rem_euclid(len) as IdxSize),
+                _ => {
+                    first_oob.get_or_insert(v);
+                    None
+                },
+            }
+        }),
+    );
+    match first_oob {
+        Some(idx) => Err(polars_err!(
+            OutOfBounds: "gather indices are out of bounds: index {} for a sequence of length {}",
+            idx, target_len
+        )),
+        None => Ok(out),
+    }
 }
 
+/// Convert integer indices into a sequence of length `target_len` to `IdxSize`, counting
+/// negative indices from the end. Out of bounds indices raise an error.
 pub fn convert_to_unsigned_index(s: &Series, target_len: usize) -> PolarsResult<IdxCa> {
+    convert_to_unsigned_index_with(s, target_len, OutOfBounds::Raise)
+}
+
+/// Convert integer indices into a sequence of length `target_len` to `IdxSize`, counting
+/// negative indices from the end and treating out of bounds indices according to `oob`.
+///
+/// The result is always in bounds, so it can be fed to the gather kernels directly.
+pub fn convert_to_unsigned_index_with(
+    s: &Series,
+    target_len: usize,
+    oob: OutOfBounds,
+) -> PolarsResult<IdxCa> {
     let dtype = s.dtype();
     polars_ensure!(dtype.is_integer(), InvalidOperation: "expected integers as index");
-    if dtype.is_unsigned_integer() {
-        let nulls_before_cast = s.null_count();
-        let out = s.cast(&IDX_DTYPE).unwrap();
-        polars_ensure!(out.null_count() == nulls_before_cast, OutOfBounds: "some integers did not fit polars' index size");
-        return Ok(out.idx().unwrap().clone());
-    }
-    match dtype {
-        DataType::Int64 => {
-            let ca = s.i64().unwrap();
-            convert(ca, target_len)
-        },
-        DataType::Int32 => {
-            let ca = s.i32().unwrap();
-            convert(ca, target_len)
-        },
-        #[cfg(feature = "dtype-i16")]
-        DataType::Int16 => {
-            let ca = s.i16().unwrap();
-            convert(ca, target_len)
-        },
-        #[cfg(feature = "dtype-i8")]
-        DataType::Int8 => {
-            let ca = s.i8().unwrap();
-            convert(ca, target_len)
-        },
-        _ => unreachable!(),
+    if dtype == &IDX_DTYPE && check_bounds_ca(s.idx().unwrap(), target_len as IdxSize).is_ok() {
+        return Ok(s.idx().unwrap().clone());
     }
+    with_match_physical_integer_polars_type!(dtype, |$T| {
+        let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+        normalize(ca, target_len, oob)
+    })
 }
 
 /// May give false negatives because it ignores the null values.
@@ -96,3 +131,49 @@ pub fn is_positive_idx_uncertain(s: &Series) -> bool {
         _ => unreachable!(),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn convert(
+        idx: &[Option<i64>],
+        len: usize,
+        oob: OutOfBounds,
+    ) -> PolarsResult<Vec<Option<IdxSize>>> {
+        let s = Series::new("", idx);
+        Ok(Vec::from(&convert_to_unsigned_index_with(&s, len, oob)?))
+    }
+
+    #[test]
+    fn test_convert_index_out_of_bounds() -> PolarsResult<()> {
+        let idx = [Some(-1), Some(-4), Some(3), None, Some(-5), Some(7)];
+        assert!(convert(&idx, 4, OutOfBounds::Raise).is_err());
+        assert_eq!(
+            convert(&idx, 4, OutOfBounds::Null)?,
+            &[Some(3), Some(0), Some(3), None, None, None]
+        );
+        assert_eq!(
+            convert(&idx, 4, OutOfBounds::Wrap)?,
+            &[Some(3), Some(0), Some(3), None, Some(3), Some(3)]
+        );
+
+        // All negative and in bounds.
+        let idx = [Some(-1), Some(-2), Some(-3)];
+        assert_eq!(
+            convert(&idx, 3, OutOfBounds::Raise)?,
+            &[Some(2), Some(1), Some(0)]
+        );
+
+        // Every index is out of bounds of an empty sequence, even when wrapping.
+        assert!(convert(&[Some(0)], 0, OutOfBounds::Wrap).is_err());
+        assert_eq!(convert(&[Some(0)], 0, OutOfBounds::Null)?, &[None]);
+        assert_eq!(convert(&[], 0, OutOfBounds::Raise)?, &[]);
+
+        // Indices beyond `IdxSize` don't wrap around silently.
+        assert!(convert(&[Some(1 << 32)], 4, OutOfBounds::Raise).is_err());
+        let s = Series::new("", &[1u64 << 32]);
+        assert!(convert_to_unsigned_index(&s, 4).is_err());
+        Ok(())
+    }
+}
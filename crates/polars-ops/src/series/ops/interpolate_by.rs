@@ -0,0 +1,261 @@
+use arrow::temporal_conversions::SECONDS_IN_DAY;
+use polars_core::prelude::*;
+
+use crate::chunked_array::{null_long_gaps, InterpolationMethod};
+
+/// Call `f(idx, low, high)` for every null that has a non-null value on both sides, where
+/// `low` and `high` are the indices of those neighbours.
+fn for_each_inner_null(
+    is_valid: impl Iterator<Item = bool>,
+    mut f: impl FnMut(usize, usize, usize),
+) {
+    let mut low = None;
+    for (i, valid) in is_valid.enumerate() {
+        if valid {
+            if let Some(low) = low {
+                for idx in low + 1..i {
+                    f(idx, low, i)
+                }
+            }
+            low = Some(i);
+        }
+    }
+}
+
+/// Relative position of `x[idx]` between `x[low]` and `x[high]`.
+#[inline]
+fn weight(x: &[f64], idx: usize, low: usize, high: usize) -> f64 {
+    let dx = x[high] - x[low];
+    if dx == 0.0 {
+        0.0
+    } else {
+        (x[idx] - x[low]) / dx
+    }
+}
+
+fn interpolate_by_linear(values: &Series, x: &[f64]) -> PolarsResult<Series> {
+    let logical = values.dtype();
+    let ca = values.to_physical_repr().cast(&DataType::Float64)?;
+    let ca = ca.f64().unwrap().rechunk();
+
+    let is_temporal = logical.is_temporal();
+
+    let mut out: Vec<Option<f64>> = ca.iter().collect();
+    for_each_inner_null(ca.iter().map(|v| v.is_some()), |idx, low, high| {
+        let (y_low, y_high) = (out[low].unwrap(), out[high].unwrap());
+        let y = y_low + (y_high - y_low) * weight(x, idx, low, high);
+        out[idx] = Some(if is_temporal { y.round() } else { y });
+    });
+    let out = Float64Chunked::from_iter_options(ca.name(), out.into_iter()).into_series();
+
+    match logical {
+        DataType::Float32 => out.cast(logical),
+        dt if is_temporal => out.cast(&dt.to_physical())?.cast(logical),
+        _ => Ok(out),
+    }
+}
+
+fn interpolate_by_nearest(values: &Series, x: &[f64]) -> PolarsResult<Series> {
+    let is_valid = values.is_not_null();
+
+    let mut idx: Vec<Option<IdxSize>> = is_valid
+        .into_no_null_iter()
+        .enumerate()
+        .map(|(i, valid)| valid.then_some(i as IdxSize))
+        .collect();
+    for_each_inner_null(is_valid.into_no_null_iter(), |i, low, high| {
+        // Ties go to the lower neighbour, as in `interpolate`.
+        let nearest = if weight(x, i, low, high) <= 0.5 {
+            low
+        } else {
+            high
+        };
+        idx[i] = Some(nearest as IdxSize);
+    });
+    let idx = IdxCa::from_iter_options("", idx.into_iter());
+    values.take(&idx)
+}
+
+fn nanoseconds_per_unit(tu: TimeUnit) -> f64 {
+    match tu {
+        TimeUnit::Nanoseconds => 1.0,
+        TimeUnit::Microseconds => 1_000.0,
+        TimeUnit::Milliseconds => 1_000_000.0,
+    }
+}
+
+/// Convert the scalar `max_gap` to the physical unit of `by`. Temporal x-coordinates need a
+/// duration, numeric ones a number.
+fn max_gap_in_units_of(max_gap: &Series, by: &DataType) -> PolarsResult<Option<f64>> {
+    polars_ensure!(
+        max_gap.len() == 1,
+        ComputeError: "`max_gap` must be a single value in `interpolate_by`, got {} values",
+        max_gap.len()
+    );
+    let gap = max_gap.to_physical_repr().cast(&DataType::Float64)?;
+    let Some(gap) = gap.f64().unwrap().get(0) else {
+        return Ok(None);
+    };
+    let gap = match (max_gap.dtype(), by) {
+        (DataType::Duration(tu), _) if by.is_temporal() => {
+            let ns = gap * nanoseconds_per_unit(*tu);
+            match by {
+                DataType::Date => ns / (SECONDS_IN_DAY as f64 * 1e9),
+                DataType::Datetime(tu, _) | DataType::Duration(tu) => {
+                    ns / nanoseconds_per_unit(*tu)
+                },
+                _ => ns,
+            }
+        },
+        (dt, _) if dt.is_numeric() && by.is_numeric() => gap,
+        (dt, _) => polars_bail!(
+            InvalidOperation: "`max_gap` of dtype {} cannot be used with `by` of dtype {} in \
+            `interpolate_by`; temporal `by` needs a duration", dt, by
+        ),
+    };
+    Ok(Some(gap))
+}
+
+/// Fill the null values of `values` by interpolating against the x-coordinates in `by`, so
+/// that unevenly spaced points are weighted by their actual distance.
+///
+/// `by` must be numeric or temporal, without nulls, and monotonic (either ascending or
+/// descending). Nulls before the first and after the last non-null value are not
+/// extrapolated and remain null.
+///
+/// If `max_gap` is given, a gap is only filled if the x-coordinates of its non-null neighbours
+/// are at most `max_gap` apart, otherwise it remains null. For temporal `by` this must be a
+/// duration.
+pub fn interpolate_by(
+    values: &Series,
+    by: &Series,
+    method: InterpolationMethod,
+    max_gap: Option<&Series>,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        values.len() == by.len(),
+        ShapeMismatch: "`by` has length {} while the values have length {} in `interpolate_by`",
+        by.len(), values.len()
+    );
+    polars_ensure!(
+        values.dtype().is_numeric() || values.dtype().is_temporal(),
+        InvalidOperation: "`interpolate_by` is not supported for values of dtype {}", values.dtype()
+    );
+    polars_ensure!(
+        by.dtype().is_numeric() || by.dtype().is_temporal(),
+        InvalidOperation: "`by` must be numeric or temporal in `interpolate_by`, got {}", by.dtype()
+    );
+    polars_ensure!(
+        by.null_count() == 0,
+        ComputeError: "`by` cannot contain nulls in `interpolate_by`"
+    );
+
+    let by_dtype = by.dtype();
+    let by = by.to_physical_repr().cast(&DataType::Float64)?;
+    let by = by.f64().unwrap().rechunk();
+    let x = by.cont_slice().unwrap();
+    let ascending = x.windows(2).all(|w| w[0] <= w[1]);
+    polars_ensure!(
+        ascending || x.windows(2).all(|w| w[0] >= w[1]),
+        ComputeError: "`by` must be monotonic in `interpolate_by`"
+    );
+
+    let max_gap = match max_gap {
+        Some(max_gap) => max_gap_in_units_of(max_gap, by_dtype)?,
+        None => None,
+    };
+
+    if values.null_count() == 0 || values.null_count() == values.len() {
+        return match method {
+            InterpolationMethod::Linear if values.dtype().is_integer() => {
+                values.cast(&DataType::Float64)
+            },
+            _ => Ok(values.clone()),
+        };
+    }
+
+    let out = match method {
+        InterpolationMethod::Linear => interpolate_by_linear(values, x)?,
+        InterpolationMethod::Nearest => interpolate_by_nearest(values, x)?,
+    };
+    Ok(match max_gap {
+        Some(max_gap) => null_long_gaps(&out, &values.is_not_null(), |low, high| {
+            (x[high] - x[low]).abs() > max_gap
+        }),
+        None => out,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_interpolate_by_linear() {
+        let values = Series::new("a", [Some(1.0), None, None, Some(4.0), None]);
+        let by = Series::new("by", [0i64, 1, 3, 6, 7]);
+        let out = interpolate_by(&values, &by, InterpolationMethod::Linear, None).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(1.0), Some(1.5), Some(2.5), Some(4.0), None]
+        );
+
+        let values = Series::new("a", [None, Some(10i32), None, Some(40)]);
+        let by = Series::new("by", [9.0, 8.0, 7.0, 5.0]);
+        let out = interpolate_by(&values, &by, InterpolationMethod::Linear, None).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[None, Some(10.0), Some(20.0), Some(40.0)]
+        );
+    }
+
+    #[test]
+    fn test_interpolate_by_nearest() {
+        let values = Series::new("a", [Some(1i32), None, None, None, Some(5)]);
+        let by = Series::new("by", [0i32, 1, 2, 5, 6]);
+        let out = interpolate_by(&values, &by, InterpolationMethod::Nearest, None).unwrap();
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[Some(1), Some(1), Some(1), Some(5), Some(5)]
+        );
+    }
+
+    #[test]
+    fn test_interpolate_by_max_gap() -> PolarsResult<()> {
+        let values = Series::new("a", [Some(1.0), None, Some(3.0), None, Some(5.0)]);
+        let by = Series::new("by", [0i64, 1, 2, 10, 12]);
+        let max_gap = Series::new("", [2i64]);
+        let out = interpolate_by(&values, &by, InterpolationMethod::Linear, Some(&max_gap))?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(1.0), Some(2.0), Some(3.0), None, Some(5.0)]
+        );
+
+        // Durations are converted to the unit of the x-coordinates.
+        let by = by.cast(&DataType::Date)?;
+        let max_gap = Series::new("", [2 * 86_400_000_000i64])
+            .cast(&DataType::Duration(TimeUnit::Microseconds))?;
+        let out = interpolate_by(&values, &by, InterpolationMethod::Nearest, Some(&max_gap))?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(1.0), Some(1.0), Some(3.0), None, Some(5.0)]
+        );
+
+        let max_gap = Series::new("", [2i64]);
+        assert!(interpolate_by(&values, &by, InterpolationMethod::Linear, Some(&max_gap)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_interpolate_by_invalid() {
+        let values = Series::new("a", [Some(1.0), None, Some(3.0)]);
+        let by = Series::new("by", [0i32, 2, 1]);
+        assert!(interpolate_by(&values, &by, InterpolationMethod::Linear, None).is_err());
+
+        let by = Series::new("by", [Some(0i32), None, Some(2)]);
+        assert!(interpolate_by(&values, &by, InterpolationMethod::Linear, None).is_err());
+
+        let by = Series::new("by", [0i32, 1]);
+        assert!(interpolate_by(&values, &by, InterpolationMethod::Linear, None).is_err());
+    }
+}
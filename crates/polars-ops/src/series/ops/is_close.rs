@@ -0,0 +1,119 @@
+use polars_core::prelude::arity::broadcast_binary_elementwise;
+use polars_core::prelude::*;
+
+#[inline]
+fn is_close_f64(a: f64, b: f64, rel_tol: f64, abs_tol: f64, nans_equal: bool) -> bool {
+    if a == b {
+        return true;
+    }
+    if a.is_nan() || b.is_nan() {
+        return nans_equal && a.is_nan() && b.is_nan();
+    }
+    // Infinities are only close to themselves, which is covered above.
+    if a.is_infinite() || b.is_infinite() {
+        return false;
+    }
+    (a - b).abs() <= f64::max(rel_tol * f64::max(a.abs(), b.abs()), abs_tol)
+}
+
+/// Check element-wise whether `a` and `b` are approximately equal, i.e. whether
+/// `|a - b| <= max(rel_tol * max(|a|, |b|), abs_tol)`.
+///
+/// Either side may be a scalar. NaN is only close to NaN if `nans_equal` is set, and
+/// nulls propagate.
+pub fn is_close(
+    a: &Series,
+    b: &Series,
+    rel_tol: f64,
+    abs_tol: f64,
+    nans_equal: bool,
+) -> PolarsResult<BooleanChunked> {
+    polars_ensure!(
+        rel_tol >= 0.0 && abs_tol >= 0.0,
+        ComputeError: "tolerances of `is_close` must be non-negative, got rel_tol={} and abs_tol={}",
+        rel_tol, abs_tol
+    );
+    polars_ensure!(
+        a.len() == b.len() || a.len() == 1 || b.len() == 1,
+        ShapeMismatch: "cannot compare series of lengths {} and {} in `is_close`", a.len(), b.len()
+    );
+    polars_ensure!(
+        a.dtype().is_numeric() && b.dtype().is_numeric(),
+        InvalidOperation: "`is_close` only supports numeric dtypes, got {} and {}", a.dtype(), b.dtype()
+    );
+
+    let a_f64 = a.cast(&DataType::Float64)?;
+    let b_f64 = b.cast(&DataType::Float64)?;
+    let out: BooleanChunked =
+        broadcast_binary_elementwise(a_f64.f64()?, b_f64.f64()?, |x, y| match (x, y) {
+            (Some(x), Some(y)) => Some(is_close_f64(x, y, rel_tol, abs_tol, nans_equal)),
+            _ => None,
+        });
+    Ok(out.with_name(a.name()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_close() -> PolarsResult<()> {
+        let a = Series::new(
+            "a",
+            [
+                Some(1.0),
+                Some(1.0),
+                Some(f64::NAN),
+                Some(f64::INFINITY),
+                Some(f64::INFINITY),
+                None,
+                Some(0.0),
+            ],
+        );
+        let b = Series::new(
+            "b",
+            [
+                Some(1.0 + 1e-10),
+                Some(1.1),
+                Some(f64::NAN),
+                Some(f64::INFINITY),
+                Some(1e308),
+                Some(1.0),
+                Some(1e-12),
+            ],
+        );
+        let out = is_close(&a, &b, 1e-9, 0.0, false)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(true),
+                Some(false),
+                None,
+                Some(false)
+            ]
+        );
+        let out = is_close(&a, &b, 1e-9, 1e-9, true)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some(true),
+                Some(false),
+                Some(true),
+                Some(true),
+                Some(false),
+                None,
+                Some(true)
+            ]
+        );
+
+        let scalar = Series::new("", [1i32]);
+        let out = is_close(&a, &scalar, 0.2, 0.0, false)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(out.get(1), Some(true));
+        assert!(is_close(&a, &b, -1.0, 0.0, false).is_err());
+        Ok(())
+    }
+}
@@ -124,7 +124,7 @@ where
             if &st != ca_in.dtype() || **dt != st {
                 let left = ca_in.cast(&st)?;
                 let right = other.cast(&DataType::List(Box::new(st)))?;
-                return is_in(&left, &right);
+                return is_in_impl(&left, &right);
             };
             is_in_numeric_list(ca_in, other)
         },
@@ -134,7 +134,7 @@ where
             if &st != ca_in.dtype() || **dt != st {
                 let left = ca_in.cast(&st)?;
                 let right = other.cast(&DataType::Array(Box::new(st), *width))?;
-                return is_in(&left, &right);
+                return is_in_impl(&left, &right);
             };
             is_in_numeric_array(ca_in, other)
         },
@@ -144,7 +144,7 @@ where
                 let st = try_get_supertype(ca_in.dtype(), other.dtype())?;
                 let left = ca_in.cast(&st)?;
                 let right = other.cast(&st)?;
-                return is_in(&left, &right);
+                return is_in_impl(&left, &right);
             }
             is_in_helper(ca_in, other)
         },
@@ -524,7 +524,7 @@ fn is_in_struct(ca_in: &StructChunked, other: &Series) -> PolarsResult<BooleanCh
                     .map(|(name, st)| Field::new(name, st.clone()))
                     .collect();
                 let other_super = other.cast(&DataType::Struct(other_supertype_fields))?;
-                return is_in(&ca_in_super, &other_super);
+                return is_in_impl(&ca_in_super, &other_super);
             }
 
             let mut any_values = Vec::with_capacity(other.len() * other.fields().len());
@@ -691,7 +691,7 @@ fn is_in_cat_list(ca_in: &CategoricalChunked, other: &Series) -> PolarsResult<Bo
     Ok(ca)
 }
 
-pub fn is_in(s: &Series, other: &Series) -> PolarsResult<BooleanChunked> {
+fn is_in_impl(s: &Series, other: &Series) -> PolarsResult<BooleanChunked> {
     match s.dtype() {
         #[cfg(feature = "dtype-categorical")]
         DataType::Categorical(_, _) | DataType::Enum(_, _) => {
@@ -730,3 +730,115 @@ pub fn is_in(s: &Series, other: &Series) -> PolarsResult<BooleanChunked> {
         dt => polars_bail!(opq = is_in, dt),
     }
 }
+
+/// Check for every value of `s` whether it is contained in `other`.
+///
+/// If `other` is a `List` or `Array` column, every value is looked up in the sublist of the same
+/// row (or in every sublist if `s` is a scalar), otherwise all values of `other` form one set.
+///
+/// A null value results in null, or, if `nulls_equal` is set, in whether the set (or sublist)
+/// contains a null. A null sublist always results in null.
+pub fn is_in(s: &Series, other: &Series, nulls_equal: bool) -> PolarsResult<BooleanChunked> {
+    let out = is_in_impl(s, other)?;
+    let per_row = match other.dtype() {
+        DataType::List(_) => true,
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(_, _) => true,
+        _ => false,
+    };
+    if s.null_count() == 0 && !(per_row && other.null_count() > 0) {
+        return Ok(out);
+    }
+
+    let is_null = s.is_null();
+    let is_null = if is_null.len() == 1 && out.len() != 1 {
+        is_null.new_from_index(0, out.len())
+    } else {
+        is_null
+    };
+    let mut fixed: BooleanChunked = if per_row {
+        let has_null: BooleanChunked = match other.dtype() {
+            #[cfg(feature = "dtype-array")]
+            DataType::Array(_, _) => other
+                .array()?
+                .apply_amortized_generic(|opt_s| opt_s.map(|s| s.as_ref().null_count() > 0)),
+            _ => other
+                .list()?
+                .apply_amortized_generic(|opt_s| opt_s.map(|s| s.as_ref().null_count() > 0)),
+        };
+        out.iter()
+            .zip(has_null.iter())
+            .zip(is_null.into_no_null_iter())
+            .map(|((out, has_null), is_null)| {
+                let has_null = has_null?;
+                if is_null {
+                    nulls_equal.then_some(has_null)
+                } else {
+                    out
+                }
+            })
+            .collect_trusted()
+    } else {
+        let null_out = nulls_equal.then(|| other.null_count() > 0);
+        out.iter()
+            .zip(is_null.into_no_null_iter())
+            .map(|(out, is_null)| if is_null { null_out } else { out })
+            .collect_trusted()
+    };
+    fixed.rename(out.name());
+    Ok(fixed)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_in_nulls_equal() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(1i32), None, Some(3)]);
+        let set = Series::new("set", &[Some(1i32), None]);
+
+        let out = is_in(&s, &set, false)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[Some(true), None, Some(false)]);
+        let out = is_in(&s, &set, true)?;
+        assert_eq!(Vec::from(&out), &[Some(true), Some(true), Some(false)]);
+
+        let set = Series::new("set", &[1i32, 2]);
+        let out = is_in(&s, &set, true)?;
+        assert_eq!(Vec::from(&out), &[Some(true), Some(false), Some(false)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_in_list_nulls_equal() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(1i32), None, None, Some(3)]);
+        let lists = [
+            Some(Series::new("", &[Some(1i32), None])),
+            Some(Series::new("", &[Some(2i32), None])),
+            Some(Series::new("", &[2i32])),
+            None,
+        ];
+        let other = Series::new("l", &lists);
+
+        let out = is_in(&s, &other, false)?;
+        assert_eq!(Vec::from(&out), &[Some(true), None, None, None]);
+        let out = is_in(&s, &other, true)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(true), Some(true), Some(false), None]
+        );
+
+        // A scalar value is checked against every sublist.
+        let s = Series::new("a", &[None::<i32>]);
+        let out = is_in(&s, &other, true)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(true), Some(true), Some(false), None]
+        );
+        let out = is_in(&s, &other, false)?;
+        assert_eq!(out.len(), 4);
+        assert_eq!(out.null_count(), 4);
+        Ok(())
+    }
+}
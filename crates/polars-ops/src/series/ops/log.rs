@@ -1,3 +1,4 @@
+use polars_core::prelude::arity::broadcast_binary_elementwise_values;
 use polars_core::prelude::*;
 use polars_core::with_match_physical_integer_polars_type;
 
@@ -39,6 +40,29 @@ pub trait LogSeries: SeriesSealed {
         }
     }
 
+    /// Compute the logarithm of every element to the base of the corresponding element in
+    /// `base`, which may also be a scalar. The result is always `Float64`.
+    ///
+    /// Following IEEE 754, negative values yield NaN and zero yields `-inf` (for bases
+    /// greater than one).
+    fn log_with_base(&self, base: &Series) -> PolarsResult<Series> {
+        let s = self.as_series();
+        polars_ensure!(
+            s.len() == base.len() || s.len() == 1 || base.len() == 1,
+            ShapeMismatch: "cannot compute `log` of series with length {} and base of length {}",
+            s.len(), base.len()
+        );
+        polars_ensure!(
+            s.dtype().is_numeric() && base.dtype().is_numeric(),
+            InvalidOperation: "`log` expects numeric input, got {} and base {}", s.dtype(), base.dtype()
+        );
+        let s = s.cast(&DataType::Float64)?;
+        let base = base.cast(&DataType::Float64)?;
+        let out: Float64Chunked =
+            broadcast_binary_elementwise_values(s.f64()?, base.f64()?, |v: f64, b: f64| v.log(b));
+        Ok(out.with_name(self.as_series().name()).into_series())
+    }
+
     /// Compute the natural logarithm of all elements plus one in the input array
     fn log1p(&self) -> Series {
         let s = self.as_series().to_physical_repr();
@@ -79,38 +103,57 @@ pub trait LogSeries: SeriesSealed {
 
     /// Compute the entropy as `-sum(pk * log(pk)`.
     /// where `pk` are discrete probabilities.
+    ///
+    /// Null and zero probabilities don't contribute to the entropy. To get the entropy of
+    /// the distribution of values in a column, compute it over the counts of its unique
+    /// values with `normalize` set.
     fn entropy(&self, base: f64, normalize: bool) -> PolarsResult<f64> {
         let s = self.as_series().to_physical_repr();
         polars_ensure!(s.dtype().is_numeric(), InvalidOperation: "expected numerical input for 'entropy'");
-        // if there is only one value in the series, return 0.0 to prevent the
-        // function from returning -0.0
-        if s.len() == 1 {
-            return Ok(0.0);
-        }
-        match s.dtype() {
-            DataType::Float32 | DataType::Float64 => {
-                let pk = s.as_ref();
-
-                let pk = if normalize {
-                    let sum = pk.sum_as_series().unwrap();
-
-                    if sum.get(0).unwrap().extract::<f64>().unwrap() != 1.0 {
-                        pk / &sum
-                    } else {
-                        pk.clone()
-                    }
-                } else {
-                    pk.clone()
-                };
-
-                let log_pk = pk.log(base);
-                (&pk * &log_pk).sum::<f64>().map(|v| -v)
-            },
-            _ => s
-                .cast(&DataType::Float64)
-                .map(|s| s.entropy(base, normalize))?,
-        }
+        let pk = s.cast(&DataType::Float64)?;
+        let pk = pk.f64()?;
+
+        let sum = if normalize {
+            pk.sum().unwrap_or(1.0)
+        } else {
+            1.0
+        };
+        let h: f64 = pk
+            .into_iter()
+            .flatten()
+            .filter(|&p| p != 0.0)
+            .map(|p| {
+                let p = p / sum;
+                p * p.log(base)
+            })
+            .sum();
+        // Subtract from zero rather than negate, so that no entropy is 0.0 rather than -0.0.
+        Ok(0.0 - h)
     }
 }
 
 impl LogSeries for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_log_with_base() -> PolarsResult<()> {
+        let s = Series::new("a", [Some(8i32), Some(100), Some(0), Some(-1), None]);
+        let base = Series::new("base", [2.0, 10.0, 10.0, 10.0, 3.0]);
+        let out = s.log_with_base(&base)?;
+        assert_eq!(out.name(), "a");
+        let out = Vec::from(out.f64()?);
+        assert_eq!(&out[..3], &[Some(3.0), Some(2.0), Some(f64::NEG_INFINITY)]);
+        assert!(out[3].unwrap().is_nan());
+        assert_eq!(out[4], None);
+
+        let out = s.log_with_base(&Series::new("base", [2.0f32]))?;
+        assert_eq!(out.dtype(), &DataType::Float64);
+        assert_eq!(out.f64()?.get(0), Some(3.0));
+
+        assert!(s.log_with_base(&Series::new("base", [2.0, 3.0])).is_err());
+        Ok(())
+    }
+}
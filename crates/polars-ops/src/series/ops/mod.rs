@@ -5,6 +5,9 @@ mod approx_algo;
 #[cfg(feature = "approx_unique")]
 mod approx_unique;
 mod arg_min_max;
+mod arg_sort_by;
+#[cfg(feature = "bitwise")]
+mod bitwise;
 #[cfg(feature = "business")]
 mod business;
 mod clip;
@@ -22,11 +25,17 @@ mod ewm_by;
 mod floor_divide;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(feature = "gcd_lcm")]
+mod gcd_lcm;
 mod horizontal;
 mod index;
 mod int_range;
+#[cfg(feature = "interpolate_by")]
+mod interpolate_by;
 #[cfg(feature = "is_between")]
 mod is_between;
+#[cfg(feature = "is_close")]
+mod is_close;
 #[cfg(feature = "is_first_distinct")]
 mod is_first_distinct;
 #[cfg(feature = "is_in")]
@@ -69,6 +78,9 @@ pub use approx_algo::*;
 #[cfg(feature = "approx_unique")]
 pub use approx_unique::*;
 pub use arg_min_max::ArgAgg;
+pub use arg_sort_by::*;
+#[cfg(feature = "bitwise")]
+pub use bitwise::*;
 #[cfg(feature = "business")]
 pub use business::*;
 pub use clip::*;
@@ -86,11 +98,17 @@ pub use ewm_by::*;
 pub use floor_divide::*;
 #[cfg(feature = "fused")]
 pub use fused::*;
+#[cfg(feature = "gcd_lcm")]
+pub use gcd_lcm::*;
 pub use horizontal::*;
 pub use index::*;
 pub use int_range::*;
+#[cfg(feature = "interpolate_by")]
+pub use interpolate_by::*;
 #[cfg(feature = "is_between")]
 pub use is_between::*;
+#[cfg(feature = "is_close")]
+pub use is_close::*;
 #[cfg(feature = "is_first_distinct")]
 pub use is_first_distinct::*;
 #[cfg(feature = "is_in")]
@@ -41,7 +41,83 @@ fn moment_precomputed_mean(s: &Series, moment: usize, mean: f64) -> PolarsResult
     Ok(out)
 }
 
+/// Central moments of order 2, 3 and 4 in a single pass over the values, using the update
+/// formulas of Terriberry. Returns the number of values and the moments.
+fn central_moments_single_pass(ca: &Float64Chunked) -> (f64, [f64; 3]) {
+    let mut n = 0.0;
+    let mut mean = 0.0;
+    let (mut m2, mut m3, mut m4) = (0.0, 0.0, 0.0);
+    for x in ca.into_iter().flatten() {
+        let n1 = n;
+        n += 1.0;
+        let delta = x - mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term1 = delta * delta_n * n1;
+        mean += delta_n;
+        m4 += term1 * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * m2 - 4.0 * delta_n * m3;
+        m3 += term1 * delta_n * (n - 2.0) - 3.0 * delta_n * m2;
+        m2 += term1;
+    }
+    (n, [m2 / n, m3 / n, m4 / n])
+}
+
 pub trait MomentSeries: SeriesSealed {
+    /// Compute the `order`-th moment of the non-null values.
+    ///
+    /// If `central` is set the moment is taken about the mean, otherwise about zero. The moment of
+    /// order `0` is always `1`. The first central moment is `0`, the first raw moment is the mean.
+    ///
+    /// If `bias` is `false`, central moments of order 2, 3 and 4 are replaced by their unbiased
+    /// estimators (h-statistics), so that the second central moment equals the sample variance.
+    /// There is no such correction for higher central orders, which raise an error. Raw moments
+    /// are unbiased already and don't depend on `bias`.
+    ///
+    /// Central moments up to order 4 are accumulated in a single numerically stable pass. Higher
+    /// orders compute the mean first and the moment of the centered values in a second pass.
+    ///
+    /// Returns `None` if there are no non-null values or, for the unbiased estimators, if there
+    /// are not more values than the order.
+    fn moment(&self, order: usize, central: bool, bias: bool) -> PolarsResult<Option<f64>> {
+        let s = self.as_series();
+        polars_ensure!(
+            bias || !central || order <= 4,
+            InvalidOperation: "unbiased central moments are only supported up to order 4, got {}", order
+        );
+        let n = s.len() - s.null_count();
+        if n == 0 {
+            return Ok(None);
+        }
+        let s = s.cast(&DataType::Float64)?;
+        let ca = s.f64()?;
+
+        let out = match (order, central) {
+            (0, _) => Some(1.0),
+            (1, true) => Some(0.0),
+            (_, false) => ca.apply_values(|v| v.powi(order as i32)).mean(),
+            (2..=4, true) => {
+                if !bias && n <= order {
+                    return Ok(None);
+                }
+                let (n, [m2, m3, m4]) = central_moments_single_pass(ca);
+                let out = match (order, bias) {
+                    (2, true) => m2,
+                    (3, true) => m3,
+                    (_, true) => m4,
+                    (2, false) => m2 * n / (n - 1.0),
+                    (3, false) => m3 * n * n / ((n - 1.0) * (n - 2.0)),
+                    (_, false) => {
+                        (3.0 * (3.0 - 2.0 * n) * n * m2 * m2 + n * (n * n - 2.0 * n + 3.0) * m4)
+                            / ((n - 1.0) * (n - 2.0) * (n - 3.0))
+                    },
+                };
+                Some(out)
+            },
+            (_, true) => moment_precomputed_mean(&s, order, s.mean().unwrap())?,
+        };
+        Ok(out)
+    }
+
     /// Compute the sample skewness of a data set.
     ///
     /// For normally distributed data, the skewness should be about zero. For
@@ -50,9 +126,19 @@ pub trait MomentSeries: SeriesSealed {
     /// function `skewtest` can be used to determine if the skewness value
     /// is close enough to zero, statistically speaking.
     ///
+    /// With `m_k` the `k`-th central moment of the `n` non-null values, the biased
+    /// estimator is `g1 = m3 / m2^1.5`. If `bias` is `false` the adjusted
+    /// Fisher-Pearson coefficient `G1 = g1 * sqrt(n * (n - 1)) / (n - 2)` is returned.
+    ///
+    /// Returns `None` if there are fewer than 3 non-null values or if the variance is zero.
+    ///
     /// see: [scipy](https://github.com/scipy/scipy/blob/47bb6febaa10658c72962b9615d5d5aa2513fa3a/scipy/stats/stats.py#L1024)
     fn skew(&self, bias: bool) -> PolarsResult<Option<f64>> {
         let s = self.as_series();
+        let n = (s.len() - s.null_count()) as f64;
+        if n < 3.0 {
+            return Ok(None);
+        }
 
         let mean = match s.mean() {
             Some(mean) => mean,
@@ -60,17 +146,15 @@ pub trait MomentSeries: SeriesSealed {
         };
         // we can unwrap because if it were None, we already return None above
         let m2 = moment_precomputed_mean(s, 2, mean)?.unwrap();
+        if m2 <= (f64::EPSILON * mean).powf(2.0) {
+            return Ok(None);
+        }
         let m3 = moment_precomputed_mean(s, 3, mean)?.unwrap();
-        let zero = m2 <= (f64::EPSILON * mean).powf(2.0);
-        let vals = match zero {
-            true => f64::NAN,
-            false => m3 / m2.powf(1.5),
-        };
-        let n = (s.len() - s.null_count()) as f64;
-        let out = if !bias && !zero && n > 3.0 {
-            ((n - 1.0) * n).sqrt() / (n - 2.0) * vals
+        let g1 = m3 / m2.powf(1.5);
+        let out = if bias {
+            g1
         } else {
-            vals
+            ((n - 1.0) * n).sqrt() / (n - 2.0) * g1
         };
         Ok(Some(out))
     }
@@ -83,9 +167,19 @@ pub trait MomentSeries: SeriesSealed {
     /// If bias is `false` then the kurtosis is calculated using k statistics to
     /// eliminate bias coming from biased moment estimators
     ///
+    /// With `m_k` the `k`-th central moment of the `n` non-null values, the biased
+    /// estimator is `b2 = m4 / m2^2`. If `bias` is `false` this becomes
+    /// `3 + ((n^2 - 1) * b2 - 3 * (n - 1)^2) / ((n - 2) * (n - 3))`.
+    ///
+    /// Returns `None` if there are fewer than 4 non-null values or if the variance is zero.
+    ///
     /// see: [scipy](https://github.com/scipy/scipy/blob/47bb6febaa10658c72962b9615d5d5aa2513fa3a/scipy/stats/stats.py#L1027)
     fn kurtosis(&self, fisher: bool, bias: bool) -> PolarsResult<Option<f64>> {
         let s = self.as_series();
+        let n = (s.len() - s.null_count()) as f64;
+        if n < 4.0 {
+            return Ok(None);
+        }
 
         let mean = match s.mean() {
             Some(mean) => mean,
@@ -93,18 +187,16 @@ pub trait MomentSeries: SeriesSealed {
         };
         // we can unwrap because if it were None, we already return None above
         let m2 = moment_precomputed_mean(s, 2, mean)?.unwrap();
+        if m2 <= (f64::EPSILON * mean).powf(2.0) {
+            return Ok(None);
+        }
         let m4 = moment_precomputed_mean(s, 4, mean)?.unwrap();
-        let zero = m2 <= (f64::EPSILON * mean).powf(2.0);
-        let vals = match zero {
-            true => f64::NAN,
-            false => m4 / m2.powf(2.0),
-        };
-        let n = (s.len() - s.null_count()) as f64;
-        let out = if !bias && !zero && n > 3.0 {
-            3.0 + 1.0 / (n - 2.0) / (n - 3.0)
-                * ((n.powf(2.0) - 1.0) * vals - 3.0 * (n - 1.0).powf(2.0))
+        let b2 = m4 / m2.powf(2.0);
+        let out = if bias {
+            b2
         } else {
-            vals
+            3.0 + 1.0 / (n - 2.0) / (n - 3.0)
+                * ((n.powf(2.0) - 1.0) * b2 - 3.0 * (n - 1.0).powf(2.0))
         };
         if fisher {
             Ok(Some(out - 3.0))
@@ -139,6 +231,42 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_moment() -> PolarsResult<()> {
+        let s = Series::new(
+            "",
+            &[Some(1), Some(2), None, Some(3), Some(4), Some(5), Some(23)],
+        );
+
+        assert_eq!(s.moment(0, true, true)?, Some(1.0));
+        assert_eq!(s.moment(1, true, true)?, Some(0.0));
+        assert!((s.moment(1, false, true)?.unwrap() - 38.0 / 6.0).abs() < 1e-12);
+        assert!((s.moment(2, false, true)?.unwrap() - 584.0 / 6.0).abs() < 1e-12);
+
+        // The single pass agrees with the two-pass computation.
+        let mean = s.mean().unwrap();
+        for order in 2..=6 {
+            let expected = moment_precomputed_mean(&s, order, mean)?.unwrap();
+            let out = s.moment(order, true, true)?.unwrap();
+            assert!(
+                (out - expected).abs() < 1e-9 * expected.abs(),
+                "order {order}"
+            );
+        }
+
+        let var = s.var(1).unwrap();
+        assert!((s.moment(2, true, false)?.unwrap() - var).abs() < 1e-9);
+        assert!((s.moment(3, true, false)?.unwrap() - 724.0740740740742 * 1.8).abs() < 1e-9);
+        assert!(s.moment(5, true, false).is_err());
+        assert_eq!(s.moment(5, false, false)?, s.moment(5, false, true)?);
+
+        let s = Series::new("", &[1.0, 2.0, 4.0]);
+        assert_eq!(s.moment(4, true, false)?, None);
+        let s = Series::new("", &[None::<f64>]);
+        assert_eq!(s.moment(0, true, true)?, None);
+        Ok(())
+    }
+
     #[test]
     fn test_skew() -> PolarsResult<()> {
         let s = Series::new("", &[1, 2, 3, 4, 5, 23]);
@@ -173,4 +301,23 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_moments_degenerate() -> PolarsResult<()> {
+        // The sample-size correction of skew is applied from three values on.
+        let s = Series::new("", &[1.0, 2.0, 4.0]);
+        let biased = s.skew(true)?.unwrap();
+        let expected = biased * 6.0f64.sqrt();
+        assert!((s.skew(false)?.unwrap() - expected).abs() < 1e-12);
+        assert_eq!(s.kurtosis(true, false)?, None);
+
+        let s = Series::new("", &[Some(1.0), None, Some(2.0)]);
+        assert_eq!(s.skew(true)?, None);
+
+        let s = Series::new("", &[3.0; 5]);
+        assert_eq!(s.skew(false)?, None);
+        assert_eq!(s.kurtosis(true, true)?, None);
+
+        Ok(())
+    }
 }
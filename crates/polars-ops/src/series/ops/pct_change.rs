@@ -3,23 +3,79 @@ use polars_core::series::ops::NullBehavior;
 
 use crate::prelude::diff;
 
-pub fn pct_change(s: &Series, n: &Series) -> PolarsResult<Series> {
+/// Compute `x[i] / x[i - n] - 1` for every element.
+///
+/// `n` is either a single value or holds one period per row. Rows without a value `n`
+/// periods back, or with a null `n`, are null. Nulls in `s` are first filled with
+/// `fill_null` if given. Division by zero is not an error and follows IEEE 754, producing
+/// `inf`, `-inf` or `NaN`.
+pub fn pct_change(
+    s: &Series,
+    n: &Series,
+    fill_null: Option<FillNullStrategy>,
+) -> PolarsResult<Series> {
     polars_ensure!(
-        n.len() == 1,
-        ComputeError: "n must be a single value."
+        n.len() == 1 || n.len() == s.len(),
+        ShapeMismatch: "`n` must be a single value or have the same length as the series ({}), got {}",
+        s.len(), n.len()
     );
 
     match s.dtype() {
         DataType::Float64 | DataType::Float32 => {},
-        _ => return pct_change(&s.cast(&DataType::Float64)?, n),
+        _ => return pct_change(&s.cast(&DataType::Float64)?, n, fill_null),
     }
 
-    let fill_null_s = s.fill_null(FillNullStrategy::Forward(None))?;
+    let filled = match fill_null {
+        Some(strategy) => s.fill_null(strategy)?,
+        None => s.clone(),
+    };
 
     let n_s = n.cast(&DataType::Int64)?;
-    if let Some(n) = n_s.i64()?.get(0) {
-        diff(&fill_null_s, n, NullBehavior::Ignore)?.divide(&fill_null_s.shift(n))
-    } else {
-        Ok(Series::full_null(s.name(), s.len(), s.dtype()))
+    let n_ca = n_s.i64()?;
+    if n_ca.len() == 1 {
+        return if let Some(n) = n_ca.get(0) {
+            diff(&filled, n, NullBehavior::Ignore)?.divide(&filled.shift(n))
+        } else {
+            Ok(Series::full_null(s.name(), s.len(), s.dtype()))
+        };
+    }
+
+    let len = s.len() as i64;
+    let idx: IdxCa = n_ca
+        .iter()
+        .enumerate()
+        .map(|(i, n)| {
+            let prev = i as i64 - n?;
+            (0..len).contains(&prev).then_some(prev as IdxSize)
+        })
+        .collect();
+    let prev = filled.take(&idx)?;
+    (&filled - &prev).divide(&prev)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pct_change_per_row_n() -> PolarsResult<()> {
+        let s = Series::new("a", [1.0, 2.0, 0.0, 4.0, -2.0]);
+        let n = Series::new("n", [Some(1), Some(1), Some(2), None, Some(2)]);
+        let out = pct_change(&s, &n, None)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[None, Some(1.0), Some(-1.0), None, Some(f64::NEG_INFINITY)]
+        );
+
+        let s = Series::new("a", [Some(1i32), None, Some(3)]);
+        let n = Series::new("n", [1]);
+        let out = pct_change(&s, &n, Some(FillNullStrategy::Forward(None)))?;
+        assert_eq!(Vec::from(out.f64()?), &[None, Some(0.0), Some(2.0)]);
+        let out = pct_change(&s, &n, None)?;
+        assert_eq!(Vec::from(out.f64()?), &[None, None, None]);
+
+        let n = Series::new("n", [1, 2]);
+        assert!(pct_change(&s, &n, None).is_err());
+        Ok(())
     }
 }
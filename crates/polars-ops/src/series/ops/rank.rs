@@ -10,21 +10,29 @@ use crate::prelude::SeriesSealed;
 #[derive(Copy, Clone, Debug, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RankMethod {
+    /// The mean of the ranks the tied values would have received.
     Average,
+    /// The lowest rank of the tie group ("competition" ranking).
     Min,
+    /// The highest rank of the tie group.
     Max,
+    /// Like `Min`, but the next group continues at the following rank, leaving no gaps.
     Dense,
+    /// Distinct ranks, with ties broken by order of appearance (pandas' `first`).
     Ordinal,
     #[cfg(feature = "random")]
     Random,
 }
 
-// We might want to add a `nulls_last` or `null_behavior` field.
 #[derive(Copy, Clone, Debug, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RankOptions {
     pub method: RankMethod,
     pub descending: bool,
+    /// Where to place nulls in the ranking. `None` keeps them null, otherwise they are
+    /// ranked as a single tie group after (`Some(true)`) or before (`Some(false)`) all
+    /// other values, regardless of `descending`.
+    pub nulls_last: Option<bool>,
 }
 
 impl Default for RankOptions {
@@ -32,6 +40,7 @@ impl Default for RankOptions {
         Self {
             method: RankMethod::Dense,
             descending: false,
+            nulls_last: None,
         }
     }
 }
@@ -62,11 +71,17 @@ unsafe fn rank_impl<F: FnMut(&mut [IdxSize])>(idxs: &IdxCa, neq: &BooleanArray,
     flush_ties(&mut ties_indices);
 }
 
-fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) -> Series {
+fn rank(
+    s: &Series,
+    method: RankMethod,
+    descending: bool,
+    nulls_last: Option<bool>,
+    seed: Option<u64>,
+) -> Series {
     let len = s.len();
     let null_count = s.null_count();
     match len {
-        1 => {
+        1 if null_count == 0 || nulls_last.is_some() => {
             return match method {
                 Average => Series::new(s.name(), &[1.0f64]),
                 _ => Series::new(s.name(), &[1 as IdxSize]),
@@ -81,7 +96,7 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
         _ => {},
     }
 
-    if null_count == len {
+    if null_count == len && nulls_last.is_none() {
         return match method {
             Average => Float64Chunked::full_null(s.name(), len).into_series(),
             _ => IdxCa::full_null(s.name(), len).into_series(),
@@ -96,8 +111,31 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
         })
         .slice(0, len - null_count);
 
-    let chunk_refs: Vec<_> = s.chunks().iter().map(|c| &**c).collect();
-    let validity = concatenate_validities(&chunk_refs);
+    let (sort_idx_ca, validity) = match nulls_last {
+        None => {
+            let chunk_refs: Vec<_> = s.chunks().iter().map(|c| &**c).collect();
+            (sort_idx_ca, concatenate_validities(&chunk_refs))
+        },
+        // The nulls form one tie group, kept in order of appearance.
+        Some(nulls_last) => {
+            let null_idx = s
+                .is_null()
+                .into_no_null_iter()
+                .enumerate()
+                .filter_map(|(i, is_null)| is_null.then_some(i as IdxSize))
+                .collect();
+            let mut null_idx = IdxCa::from_vec(s.name(), null_idx);
+            let sort_idx_ca = if nulls_last {
+                let mut sort_idx_ca = sort_idx_ca;
+                sort_idx_ca.append(&null_idx);
+                sort_idx_ca
+            } else {
+                null_idx.append(&sort_idx_ca);
+                null_idx
+            };
+            (sort_idx_ca, None)
+        },
+    };
 
     use RankMethod::*;
     if let Ordinal = method {
@@ -114,7 +152,7 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
         let sorted_values = unsafe { s.take_unchecked(&sort_idx_ca) };
         let not_consecutive_same = sorted_values
             .slice(1, sorted_values.len() - 1)
-            .not_equal(&sorted_values.slice(0, sorted_values.len() - 1))
+            .not_equal_missing(&sorted_values.slice(0, sorted_values.len() - 1))
             .unwrap()
             .rechunk();
         let neq = not_consecutive_same.downcast_iter().next().unwrap();
@@ -184,7 +222,13 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
 
 pub trait SeriesRank: SeriesSealed {
     fn rank(&self, options: RankOptions, seed: Option<u64>) -> Series {
-        rank(self.as_series(), options.method, options.descending, seed)
+        rank(
+            self.as_series(),
+            options.method,
+            options.descending,
+            options.nulls_last,
+            seed,
+        )
     }
 }
 
@@ -198,7 +242,7 @@ mod test {
     fn test_rank() -> PolarsResult<()> {
         let s = Series::new("a", &[1, 2, 3, 2, 2, 3, 0]);
 
-        let out = rank(&s, RankMethod::Ordinal, false, None)
+        let out = rank(&s, RankMethod::Ordinal, false, None, None)
             .idx()?
             .into_no_null_iter()
             .collect::<Vec<_>>();
@@ -206,7 +250,7 @@ mod test {
 
         #[cfg(feature = "random")]
         {
-            let out = rank(&s, RankMethod::Random, false, None)
+            let out = rank(&s, RankMethod::Random, false, None, None)
                 .idx()?
                 .into_no_null_iter()
                 .collect::<Vec<_>>();
@@ -219,25 +263,25 @@ mod test {
             assert_ne!(out[3], out[4]);
         }
 
-        let out = rank(&s, RankMethod::Dense, false, None)
+        let out = rank(&s, RankMethod::Dense, false, None, None)
             .idx()?
             .into_no_null_iter()
             .collect::<Vec<_>>();
         assert_eq!(out, &[2, 3, 4, 3, 3, 4, 1]);
 
-        let out = rank(&s, RankMethod::Max, false, None)
+        let out = rank(&s, RankMethod::Max, false, None, None)
             .idx()?
             .into_no_null_iter()
             .collect::<Vec<_>>();
         assert_eq!(out, &[2, 5, 7, 5, 5, 7, 1]);
 
-        let out = rank(&s, RankMethod::Min, false, None)
+        let out = rank(&s, RankMethod::Min, false, None, None)
             .idx()?
             .into_no_null_iter()
             .collect::<Vec<_>>();
         assert_eq!(out, &[2, 3, 6, 3, 3, 6, 1]);
 
-        let out = rank(&s, RankMethod::Average, false, None)
+        let out = rank(&s, RankMethod::Average, false, None, None)
             .f64()?
             .into_no_null_iter()
             .collect::<Vec<_>>();
@@ -248,7 +292,7 @@ mod test {
             &[Some(1), Some(2), Some(3), Some(2), None, None, Some(0)],
         );
 
-        let out = rank(&s, RankMethod::Average, false, None)
+        let out = rank(&s, RankMethod::Average, false, None, None)
             .f64()?
             .into_iter()
             .collect::<Vec<_>>();
@@ -278,7 +322,7 @@ mod test {
                 Some(8),
             ],
         );
-        let out = rank(&s, RankMethod::Max, false, None)
+        let out = rank(&s, RankMethod::Max, false, None, None)
             .idx()?
             .into_iter()
             .collect::<Vec<_>>();
@@ -302,12 +346,12 @@ mod test {
     #[test]
     fn test_rank_all_null() -> PolarsResult<()> {
         let s = UInt32Chunked::new("", &[None, None, None]).into_series();
-        let out = rank(&s, RankMethod::Average, false, None)
+        let out = rank(&s, RankMethod::Average, false, None, None)
             .f64()?
             .into_iter()
             .collect::<Vec<_>>();
         assert_eq!(out, &[None, None, None]);
-        let out = rank(&s, RankMethod::Dense, false, None)
+        let out = rank(&s, RankMethod::Dense, false, None, None)
             .idx()?
             .into_iter()
             .collect::<Vec<_>>();
@@ -318,16 +362,16 @@ mod test {
     #[test]
     fn test_rank_empty() {
         let s = UInt32Chunked::from_slice("", &[]).into_series();
-        let out = rank(&s, RankMethod::Average, false, None);
+        let out = rank(&s, RankMethod::Average, false, None, None);
         assert_eq!(out.dtype(), &DataType::Float64);
-        let out = rank(&s, RankMethod::Max, false, None);
+        let out = rank(&s, RankMethod::Max, false, None, None);
         assert_eq!(out.dtype(), &IDX_DTYPE);
     }
 
     #[test]
     fn test_rank_reverse() -> PolarsResult<()> {
         let s = Series::new("", &[None, Some(1), Some(1), Some(5), None]);
-        let out = rank(&s, RankMethod::Dense, true, None)
+        let out = rank(&s, RankMethod::Dense, true, None, None)
             .idx()?
             .into_iter()
             .collect::<Vec<_>>();
@@ -335,4 +379,53 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_rank_nulls_last() -> PolarsResult<()> {
+        let s = Series::new(
+            "",
+            &[Some(2), None, Some(1), Some(2), None, Some(1), Some(3)],
+        );
+        let ranks = |method, descending, nulls_last| {
+            rank(&s, method, descending, nulls_last, None)
+                .cast(&DataType::Float64)
+                .unwrap()
+                .f64()
+                .unwrap()
+                .into_no_null_iter()
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ranks(RankMethod::Ordinal, false, Some(true)),
+            &[3.0, 6.0, 1.0, 4.0, 7.0, 2.0, 5.0]
+        );
+        assert_eq!(
+            ranks(RankMethod::Ordinal, true, Some(true)),
+            &[2.0, 6.0, 4.0, 3.0, 7.0, 5.0, 1.0]
+        );
+        assert_eq!(
+            ranks(RankMethod::Dense, false, Some(false)),
+            &[3.0, 1.0, 2.0, 3.0, 1.0, 2.0, 4.0]
+        );
+        assert_eq!(
+            ranks(RankMethod::Min, false, Some(true)),
+            &[3.0, 6.0, 1.0, 3.0, 6.0, 1.0, 5.0]
+        );
+        assert_eq!(
+            ranks(RankMethod::Max, true, Some(false)),
+            &[5.0, 2.0, 7.0, 5.0, 2.0, 7.0, 3.0]
+        );
+        assert_eq!(
+            ranks(RankMethod::Average, false, Some(false)),
+            &[5.5, 1.5, 3.5, 5.5, 1.5, 3.5, 7.0]
+        );
+
+        let out = rank(&s, RankMethod::Dense, false, None, None);
+        assert_eq!(
+            Vec::from(out.idx()?),
+            &[Some(2), None, Some(1), Some(2), None, Some(1), Some(3)]
+        );
+        Ok(())
+    }
 }
@@ -1,5 +1,3 @@
-use std::ops::BitOr;
-
 use polars_core::prelude::*;
 use polars_core::utils::try_get_supertype;
 use polars_error::polars_ensure;
@@ -41,14 +39,7 @@ pub fn replace(
         return Ok(out);
     }
 
-    let old = match (s.dtype(), old.dtype()) {
-        #[cfg(feature = "dtype-categorical")]
-        (DataType::Categorical(_, ord), DataType::String) => {
-            let dt = DataType::Categorical(None, *ord);
-            old.strict_cast(&dt)?
-        },
-        _ => old.strict_cast(s.dtype())?,
-    };
+    let old = cast_old_to_input_dtype(s, old)?;
     let new = new.cast(&return_dtype)?;
 
     if new.len() == 1 {
@@ -58,24 +49,140 @@ pub fn replace(
     }
 }
 
-// Fast path for replacing by a single value
-fn replace_by_single(
+/// Replace every value by looking it up in the `old` -> `new` mapping.
+///
+/// Values that are not in `old` are set to `default`. Without a default, such values raise
+/// an error instead, except for nulls, which stay null. The result dtype is `return_dtype`
+/// if set, otherwise the supertype of `new` and `default`.
+pub fn replace_strict(
     s: &Series,
     old: &Series,
     new: &Series,
-    default: &Series,
+    default: Option<&Series>,
+    return_dtype: Option<DataType>,
 ) -> PolarsResult<Series> {
-    let mask = if old.null_count() == old.len() {
-        s.is_null()
+    if let Some(default) = default {
+        return replace(s, old, new, default, return_dtype);
+    }
+
+    let cast_old = cast_old_to_input_dtype(s, old)?;
+    let unmapped = if cast_old.len() == 0 {
+        s.is_not_null()
     } else {
-        let mask = is_in(s, old)?;
+        !is_in(s, &cast_old, false)? & s.is_not_null()
+    };
+    if unmapped.any() {
+        let first = s.filter(&unmapped)?.get(0)?.into_static()?;
+        polars_bail!(
+            ComputeError: "incomplete mapping specified for `replace_strict`: {} value(s) were not \
+            found in `old`, such as {}\n\nHint: pass a `default` value to set unmapped values",
+            unmapped.sum().unwrap_or_default(), first
+        );
+    }
 
-        if old.null_count() == 0 {
-            mask
-        } else {
-            mask.bitor(s.is_null())
-        }
+    let return_dtype = return_dtype.unwrap_or_else(|| new.dtype().clone());
+    let default = Series::full_null(s.name(), 1, &return_dtype);
+    replace(s, old, new, &default, Some(return_dtype))
+}
+
+/// Replace every numeric value by the label of the range it falls in.
+///
+/// `breaks` holds the sorted edges of `breaks.len() - 1` consecutive ranges, where `labels[i]`
+/// is used for values between `breaks[i]` and `breaks[i + 1]`. The ranges are right-closed
+/// unless `left_closed` is set, as in `cut`. Values outside of all ranges and NaN are set to
+/// `default`, nulls stay null. The result dtype is `return_dtype` if set, otherwise the
+/// supertype of `labels` and `default`.
+pub fn replace_ranges(
+    s: &Series,
+    breaks: &Series,
+    labels: &Series,
+    default: Option<&Series>,
+    left_closed: bool,
+    return_dtype: Option<DataType>,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.dtype().is_numeric(),
+        InvalidOperation: "`replace_ranges` operation not supported for dtype `{}`", s.dtype()
+    );
+    polars_ensure!(
+        breaks.null_count() == 0,
+        ComputeError: "`breaks` input for `replace_ranges` must not contain null values"
+    );
+    polars_ensure!(
+        breaks.len() == labels.len() + 1,
+        ComputeError: "`labels` input for `replace_ranges` must have exactly one value less than `breaks`, \
+        got {} labels for {} breaks", labels.len(), breaks.len()
+    );
+
+    let breaks = breaks.cast(&DataType::Float64)?;
+    let breaks = breaks.f64()?.rechunk();
+    let breaks = breaks.cont_slice().unwrap();
+    polars_ensure!(
+        breaks.windows(2).all(|w| w[0] < w[1]),
+        ComputeError: "`breaks` input for `replace_ranges` must be sorted and unique"
+    );
+
+    let return_dtype = match (return_dtype, default) {
+        (Some(dtype), _) => dtype,
+        (None, Some(default)) => try_get_supertype(labels.dtype(), default.dtype())?,
+        (None, None) => labels.dtype().clone(),
     };
+    let default = match default {
+        Some(default) => {
+            polars_ensure!(
+                default.len() == s.len() || default.len() == 1,
+                ComputeError: "`default` input for `replace_ranges` must have the same length as the input or have length 1"
+            );
+            default.cast(&return_dtype)?
+        },
+        None => Series::full_null(s.name(), 1, &return_dtype),
+    };
+    let labels = labels.cast(&return_dtype)?;
+
+    let op = if left_closed {
+        PartialOrd::ge
+    } else {
+        PartialOrd::gt
+    };
+    let s_f64 = s.cast(&DataType::Float64)?;
+    let idx: IdxCa = s_f64
+        .f64()?
+        .into_iter()
+        .map(|opt_v| {
+            let v = opt_v.filter(|v| !v.is_nan())?;
+            let idx = breaks.partition_point(|b| op(&v, b)).checked_sub(1)?;
+            (idx < labels.len()).then_some(idx as IdxSize)
+        })
+        .collect();
+
+    let mut out = labels.take(&idx)?;
+    out.rename(s.name());
+    if default.null_count() == default.len() {
+        return Ok(out);
+    }
+    let mask = idx.is_not_null() | s.is_null();
+    out.zip_with(&mask, &default)
+}
+
+fn cast_old_to_input_dtype(s: &Series, old: &Series) -> PolarsResult<Series> {
+    match (s.dtype(), old.dtype()) {
+        #[cfg(feature = "dtype-categorical")]
+        (DataType::Categorical(_, ord), DataType::String) => {
+            let dt = DataType::Categorical(None, *ord);
+            old.strict_cast(&dt)
+        },
+        _ => old.strict_cast(s.dtype()),
+    }
+}
+
+// Fast path for replacing by a single value
+fn replace_by_single(
+    s: &Series,
+    old: &Series,
+    new: &Series,
+    default: &Series,
+) -> PolarsResult<Series> {
+    let mask = is_in(s, old, true)?;
     new.zip_with(&mask, default)
 }
 
@@ -137,3 +244,65 @@ fn create_replacer(mut old: Series, mut new: Series) -> PolarsResult<DataFrame>
     let out = unsafe { DataFrame::new_no_checks(cols) };
     Ok(out)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_replace_ranges() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            &[
+                Some(-1),
+                Some(0),
+                Some(5),
+                Some(10),
+                Some(50),
+                None,
+                Some(100),
+                Some(101),
+            ],
+        );
+        let breaks = Series::new("", &[0i32, 10, 100]);
+        let labels = Series::new("", &["low", "high"]);
+        let default = Series::new("", &["other"]);
+
+        let out = replace_ranges(&s, &breaks, &labels, Some(&default), false, None)?;
+        let expected = Series::new(
+            "a",
+            &[
+                Some("other"),
+                Some("other"),
+                Some("low"),
+                Some("low"),
+                Some("high"),
+                None,
+                Some("high"),
+                Some("other"),
+            ],
+        );
+        assert!(out.equals_missing(&expected));
+
+        let out = replace_ranges(&s, &breaks, &labels, None, true, None)?;
+        let expected = Series::new(
+            "a",
+            &[
+                None,
+                Some("low"),
+                Some("low"),
+                Some("high"),
+                Some("high"),
+                None,
+                None,
+                None,
+            ],
+        );
+        assert!(out.equals_missing(&expected));
+
+        let unsorted = Series::new("", &[10i32, 0, 100]);
+        assert!(replace_ranges(&s, &unsorted, &labels, None, false, None).is_err());
+        assert!(replace_ranges(&s, &breaks, &default, None, false, None).is_err());
+        Ok(())
+    }
+}
@@ -1,25 +1,33 @@
 use polars_core::prelude::*;
 
 /// Get the lengths of runs of identical values.
+///
+/// Returns a `Struct` with fields `len` (`UInt32`) and `value`. Consecutive nulls form a
+/// single run.
 pub fn rle(s: &Series) -> PolarsResult<Series> {
-    let (s1, s2) = (s.slice(0, s.len() - 1), s.slice(1, s.len()));
-    let s_neq = s1.not_equal_missing(&s2)?;
-    let n_runs = s_neq.sum().unwrap() + 1;
-    let mut lengths = Vec::with_capacity(n_runs as usize);
-    lengths.push(1);
-    let mut vals = Series::new_empty("values", s.dtype());
-    let vals = vals.extend(&s.head(Some(1)))?.extend(&s2.filter(&s_neq)?)?;
-    let mut idx = 0;
-    for v in s_neq.into_iter() {
-        if v.unwrap() {
-            idx += 1;
-            lengths.push(1);
-        } else {
-            lengths[idx] += 1;
+    let mut lengths = Vec::<u32>::new();
+    let values = if s.is_empty() {
+        s.clear()
+    } else {
+        let s_neq = run_starts(s)?;
+        let mut values = s.head(Some(1));
+        values.append(&s.slice(1, s.len()).filter(&s_neq)?)?;
+
+        lengths.reserve(values.len());
+        lengths.push(1);
+        for arr in s_neq.downcast_iter() {
+            for new_run in arr.values_iter() {
+                if new_run {
+                    lengths.push(1);
+                } else {
+                    *lengths.last_mut().unwrap() += 1;
+                }
+            }
         }
-    }
+        values
+    };
 
-    let outvals = vec![Series::from_vec("lengths", lengths), vals.to_owned()];
+    let outvals = vec![Series::from_vec("len", lengths), values.with_name("value")];
     Ok(StructChunked::new("rle", &outvals)?.into_series())
 }
 
@@ -28,8 +36,7 @@ pub fn rle_id(s: &Series) -> PolarsResult<Series> {
     if s.len() == 0 {
         return Ok(Series::new_empty("id", &DataType::UInt32));
     }
-    let (s1, s2) = (s.slice(0, s.len() - 1), s.slice(1, s.len()));
-    let s_neq = s1.not_equal_missing(&s2)?;
+    let s_neq = run_starts(s)?;
 
     let mut out = Vec::with_capacity(s.len());
     let mut last = 0;
@@ -42,3 +49,48 @@ pub fn rle_id(s: &Series) -> PolarsResult<Series> {
     }
     Ok(Series::from_vec("id", out))
 }
+
+/// Whether every value but the first differs from its predecessor, where nulls are equal.
+fn run_starts(s: &Series) -> PolarsResult<BooleanChunked> {
+    let (s1, s2) = (s.slice(0, s.len() - 1), s.slice(1, s.len()));
+    s1.not_equal_missing(&s2)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rle() -> PolarsResult<()> {
+        let s = Series::new("a", &[None, None, Some(1), Some(1), None, Some(2)]);
+        let out = rle(&s)?;
+        let out = out.struct_()?;
+        assert_eq!(
+            Vec::from(out.field_by_name("len")?.u32()?),
+            &[Some(2), Some(2), Some(1), Some(1)]
+        );
+        assert_eq!(
+            Vec::from(out.field_by_name("value")?.i32()?),
+            &[None, Some(1), None, Some(2)]
+        );
+
+        let out = rle_id(&s)?;
+        assert_eq!(
+            Vec::from(out.u32()?),
+            &[Some(0), Some(0), Some(1), Some(1), Some(2), Some(3)]
+        );
+
+        let empty = Series::new_empty("a", &DataType::Int32);
+        let out = rle(&empty)?;
+        assert_eq!(out.len(), 0);
+        assert_eq!(
+            out.dtype(),
+            &DataType::Struct(vec![
+                Field::new("len", DataType::UInt32),
+                Field::new("value", DataType::Int32),
+            ])
+        );
+        assert_eq!(rle_id(&empty)?.len(), 0);
+        Ok(())
+    }
+}
@@ -1,60 +1,321 @@
-use polars_core::prelude::*;
 #[cfg(feature = "moment")]
-use {
-    crate::series::ops::moment::MomentSeries,
-    polars_core::export::num::{self, Float, FromPrimitive},
-    polars_core::utils::with_unstable_series,
-    std::ops::SubAssign,
-};
+use polars_core::export::num::{Float, NumCast};
+use polars_core::prelude::*;
 
 use crate::series::ops::SeriesSealed;
 
+/// The central moments of a sliding window, updated online as values enter and leave.
+///
+/// see: [Pébay, 2008](https://www.osti.gov/biblio/1028931)
+#[cfg(feature = "moment")]
+#[derive(Default)]
+struct MomentState {
+    n: f64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+}
+
 #[cfg(feature = "moment")]
-fn rolling_skew<T>(
+impl MomentState {
+    fn insert(&mut self, x: f64) {
+        let n1 = self.n;
+        self.n += 1.0;
+        let n = self.n;
+        let delta = x - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term1 = delta * delta_n * n1;
+
+        self.mean += delta_n;
+        self.m4 += term1 * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+        self.m3 += term1 * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term1;
+    }
+
+    /// The exact inverse of `insert`.
+    fn remove(&mut self, x: f64) {
+        let n = self.n;
+        if n <= 1.0 {
+            *self = Self::default();
+            return;
+        }
+        self.mean -= (x - self.mean) / (n - 1.0);
+        self.n -= 1.0;
+        let delta = x - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term1 = delta * delta_n * (n - 1.0);
+
+        self.m2 -= term1;
+        self.m3 -= term1 * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m4 -= term1 * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+    }
+
+    /// The biased variance, or `None` if it is (numerically) zero.
+    fn variance(&self) -> Option<f64> {
+        let var = self.m2 / self.n;
+        (var > (f64::EPSILON * self.mean).powi(2)).then_some(var)
+    }
+
+    fn skew(&self, bias: bool) -> Option<f64> {
+        let n = self.n;
+        let g1 = self.m3 / n / self.variance()?.powf(1.5);
+        if !bias && n > 2.0 {
+            Some(((n - 1.0) * n).sqrt() / (n - 2.0) * g1)
+        } else {
+            Some(g1)
+        }
+    }
+
+    fn kurtosis(&self, fisher: bool, bias: bool) -> Option<f64> {
+        let n = self.n;
+        let g2 = self.m4 / n / self.variance()?.powi(2);
+        let out = if !bias && n > 3.0 {
+            3.0 + 1.0 / (n - 2.0) / (n - 3.0) * ((n * n - 1.0) * g2 - 3.0 * (n - 1.0).powi(2))
+        } else {
+            g2
+        };
+        Some(if fisher { out - 3.0 } else { out })
+    }
+}
+
+/// Apply `f` to the moments of every window of `window_size` values ending at each row. Windows
+/// with fewer than `min_periods` non-null values are null.
+///
+/// The moments are updated in O(1) per row. To bound the accumulated rounding error of the
+/// removals, they are recomputed from the window once every `window_size` rows, which keeps
+/// the total work O(n).
+#[cfg(feature = "moment")]
+fn rolling_moment<T, F>(
     ca: &ChunkedArray<T>,
     window_size: usize,
-    bias: bool,
+    min_periods: usize,
+    f: F,
 ) -> PolarsResult<ChunkedArray<T>>
 where
-    ChunkedArray<T>: IntoSeries,
     T: PolarsFloatType,
-    T::Native: Float + SubAssign + num::pow::Pow<T::Native, Output = T::Native>,
+    T::Native: Float,
+    F: Fn(&MomentState) -> Option<f64>,
 {
-    with_unstable_series(ca.dtype(), |us| {
-        ca.rolling_map_float(window_size, |arr| {
-            let arr = unsafe { arr.chunks_mut().get_mut(0).unwrap() };
-
-            us.with_array(arr, |us| {
-                us.as_ref()
-                    .skew(bias)
-                    .unwrap()
-                    .map(|flt| T::Native::from_f64(flt).unwrap())
-            })
+    polars_ensure!(window_size > 0, InvalidOperation: "`window_size` should be positive");
+    polars_ensure!(
+        min_periods <= window_size,
+        InvalidOperation: "`min_periods` should be <= `window_size`"
+    );
+
+    let values: Vec<Option<f64>> = ca.iter().map(|v| v.and_then(NumCast::from)).collect();
+    let mut state = MomentState::default();
+    let mut since_rebuild = 0;
+
+    let out = (0..values.len()).map(|i| {
+        if let Some(x) = values[i] {
+            state.insert(x);
+        }
+        if i >= window_size {
+            since_rebuild += 1;
+            let removed = values[i - window_size];
+            if since_rebuild == window_size || removed.is_some_and(|x| !x.is_finite()) {
+                state = MomentState::default();
+                values[i + 1 - window_size..=i]
+                    .iter()
+                    .flatten()
+                    .for_each(|&x| state.insert(x));
+                since_rebuild = 0;
+            } else if let Some(x) = removed {
+                state.remove(x);
+            }
+        }
+
+        if (state.n as usize) < min_periods.max(1) {
+            None
+        } else {
+            f(&state).and_then(NumCast::from)
+        }
+    });
+    Ok(ChunkedArray::from_iter_options(ca.name(), out))
+}
+
+#[cfg(feature = "moment")]
+fn rolling_moment_series<F>(
+    s: &Series,
+    window_size: usize,
+    min_periods: usize,
+    f: F,
+) -> PolarsResult<Series>
+where
+    F: Fn(&MomentState) -> Option<f64>,
+{
+    match s.dtype() {
+        DataType::Float64 => {
+            rolling_moment(s.f64().unwrap(), window_size, min_periods, f).map(|ca| ca.into_series())
+        },
+        DataType::Float32 => {
+            rolling_moment(s.f32().unwrap(), window_size, min_periods, f).map(|ca| ca.into_series())
+        },
+        dt if dt.is_numeric() => {
+            let s = s.cast(&DataType::Float64).unwrap();
+            rolling_moment_series(&s, window_size, min_periods, f)
+        },
+        dt => polars_bail!(InvalidOperation: "rolling moments are not supported for dtype {}", dt),
+    }
+}
+
+/// Apply `f` to every window of `s`, where the window at a row holds the row itself and the
+/// `window_size - 1` rows before it (fewer at the start of `s`). Windows with fewer than
+/// `min_periods` non-null values are null and are not passed to `f`.
+///
+/// Every window is materialized as a [`Series`] and `f` is called once per row, so this is
+/// much slower than the specialized rolling kernels; prefer those where one exists. The output
+/// dtype is inferred from the first non-null value returned by `f`, and all other results must
+/// be of that dtype.
+pub fn rolling_map<F>(
+    s: &Series,
+    window_size: usize,
+    min_periods: usize,
+    f: F,
+) -> PolarsResult<Series>
+where
+    F: Fn(&Series) -> AnyValue<'static>,
+{
+    polars_ensure!(window_size > 0, InvalidOperation: "`window_size` should be positive");
+    polars_ensure!(
+        min_periods <= window_size,
+        InvalidOperation: "`min_periods` should be <= `window_size`"
+    );
+
+    let s = s.rechunk();
+    let values = (0..s.len())
+        .map(|idx| {
+            let start = idx.saturating_sub(window_size - 1);
+            let window = s.slice(start as i64, idx + 1 - start);
+            if window.len() - window.null_count() < min_periods.max(1) {
+                AnyValue::Null
+            } else {
+                f(&window)
+            }
         })
-    })
+        .collect::<Vec<_>>();
+    Series::from_any_values(s.name(), &values, true)
 }
 
 pub trait RollingSeries: SeriesSealed {
+    /// Compute the skewness over a rolling window, following scipy's `bias` convention.
+    ///
+    /// Windows with fewer than `min_periods` non-null values, or with zero variance, are null.
     #[cfg(feature = "moment")]
-    fn rolling_skew(&self, window_size: usize, bias: bool) -> PolarsResult<Series> {
-        let s = self.as_series();
-
-        match s.dtype() {
-            DataType::Float64 => {
-                let ca = s.f64().unwrap();
-                rolling_skew(ca, window_size, bias).map(|ca| ca.into_series())
-            },
-            DataType::Float32 => {
-                let ca = s.f32().unwrap();
-                rolling_skew(ca, window_size, bias).map(|ca| ca.into_series())
-            },
-            dt if dt.is_numeric() => {
-                let s = s.cast(&DataType::Float64).unwrap();
-                s.rolling_skew(window_size, bias)
-            },
-            dt => polars_bail!(opq = rolling_skew, dt),
-        }
+    fn rolling_skew(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+        bias: bool,
+    ) -> PolarsResult<Series> {
+        rolling_moment_series(self.as_series(), window_size, min_periods, |state| {
+            state.skew(bias)
+        })
+    }
+
+    /// Compute the kurtosis over a rolling window, following scipy's `fisher` and `bias`
+    /// conventions.
+    ///
+    /// Windows with fewer than `min_periods` non-null values, or with zero variance, are null.
+    #[cfg(feature = "moment")]
+    fn rolling_kurtosis(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+        fisher: bool,
+        bias: bool,
+    ) -> PolarsResult<Series> {
+        rolling_moment_series(self.as_series(), window_size, min_periods, |state| {
+            state.kurtosis(fisher, bias)
+        })
     }
 }
 
 impl RollingSeries for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "moment")]
+    use crate::series::ops::moment::MomentSeries;
+
+    #[test]
+    fn test_rolling_map() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(1i32), Some(2), None, Some(4), Some(5)]);
+        let out = rolling_map(&s, 3, 2, |window| {
+            AnyValue::Float64(window.sum::<f64>().unwrap() / 10.0)
+        })?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[None, Some(0.3), Some(0.3), Some(0.6), Some(0.9)]
+        );
+
+        // The dtype comes from the first non-null result.
+        let out = rolling_map(&s, 2, 1, |window| {
+            if window.len() < 2 {
+                AnyValue::Null
+            } else {
+                AnyValue::StringOwned(format!("{}", window.len()).into())
+            }
+        })?;
+        assert_eq!(out.dtype(), &DataType::String);
+        assert_eq!(out.null_count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "moment")]
+    fn test_rolling_moments_match_scalar() -> PolarsResult<()> {
+        let s = Series::new(
+            "",
+            &[1.0, 2.0, 3.0, 3.0, 2.0, 10.0, 8.0, -4.0, 0.5, 7.0, 7.0],
+        );
+        let window_size = 5;
+
+        for bias in [true, false] {
+            let skew = s.rolling_skew(window_size, window_size, bias)?;
+            let kurt = s.rolling_kurtosis(window_size, window_size, true, bias)?;
+            for i in window_size - 1..s.len() {
+                let window = s.slice((i + 1 - window_size) as i64, window_size);
+                let expected = window.kurtosis(true, bias)?.unwrap();
+                let got = kurt.f64()?.get(i).unwrap();
+                assert!((got - expected).abs() < 1e-10, "{got} != {expected}");
+                let expected = window.skew(true)?.unwrap();
+                if bias {
+                    let got = skew.f64()?.get(i).unwrap();
+                    assert!((got - expected).abs() < 1e-10, "{got} != {expected}");
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "moment")]
+    fn test_rolling_moments_min_periods_and_zero_variance() -> PolarsResult<()> {
+        let s = Series::new(
+            "",
+            &[Some(1.0), None, Some(1.0), Some(1.0), Some(2.0), Some(4.0)],
+        );
+        let out = s.rolling_skew(3, 2, true)?;
+        let out = out.f64()?;
+        assert_eq!(out.get(0), None);
+        assert_eq!(out.get(1), None);
+        // Zero variance.
+        assert_eq!(out.get(2), None);
+        assert_eq!(out.get(3), None);
+        assert!(out.get(4).is_some());
+        assert!(out.get(5).is_some());
+
+        let s = Series::new("", &[1.0f32, 2.0, 4.0]);
+        let out = s.rolling_kurtosis(3, 3, false, true)?;
+        assert_eq!(out.dtype(), &DataType::Float32);
+        Ok(())
+    }
+}
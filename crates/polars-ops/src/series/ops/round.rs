@@ -5,7 +5,7 @@ use polars_core::with_match_physical_numeric_polars_type;
 use crate::series::ops::SeriesSealed;
 
 pub trait RoundSeries: SeriesSealed {
-    /// Round underlying floating point array to given decimal.
+    /// Round underlying floating point or decimal array to given decimal.
     fn round(&self, decimals: u32) -> PolarsResult<Series> {
         let s = self.as_series();
 
@@ -35,9 +35,27 @@ pub trait RoundSeries: SeriesSealed {
                 Ok(s)
             };
         }
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = s.decimal() {
+            let scale = ca.scale() as u32;
+            if decimals >= scale {
+                return Ok(s.clone());
+            }
+            // Round half away from zero on the fixed-point representation.
+            let multiplier = 10i128.pow(scale - decimals);
+            let half = multiplier / 2;
+            let out = ca.as_ref().apply_values(|val| {
+                let rounded = if val >= 0 { val + half } else { val - half };
+                rounded / multiplier * multiplier
+            });
+            return Ok(out
+                .into_decimal_unchecked(ca.precision(), ca.scale())
+                .into_series());
+        }
         polars_bail!(opq = round, s.dtype());
     }
 
+    /// Round to the given number of significant figures, keeping the dtype.
     fn round_sig_figs(&self, digits: i32) -> PolarsResult<Series> {
         let s = self.as_series();
         polars_ensure!(digits >= 1, InvalidOperation: "digits must be an integer >= 1");
@@ -45,12 +63,7 @@ pub trait RoundSeries: SeriesSealed {
         with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
             let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
             let s = ca.apply_values(|value| {
-                let value = value as f64;
-                if value == 0.0 {
-                    return value as <$T as PolarsNumericType>::Native;
-                }
-                let magnitude = 10.0_f64.powi(digits - 1 - value.abs().log10().floor() as i32);
-                ((value * magnitude).round() / magnitude) as <$T as PolarsNumericType>::Native
+                round_sig_figs_f64(value as f64, digits) as <$T as PolarsNumericType>::Native
             }).into_series();
             return Ok(s);
         });
@@ -89,6 +102,27 @@ pub trait RoundSeries: SeriesSealed {
 
 impl RoundSeries for Series {}
 
+fn round_sig_figs_f64(value: f64, digits: i32) -> f64 {
+    if value == 0.0 || !value.is_finite() {
+        return value;
+    }
+    let exp = digits - 1 - value.abs().log10().floor() as i32;
+    // Powers of ten up to 1e22 are exact in f64. Beyond that, scaling would introduce
+    // rounding errors, so we round through the decimal representation instead.
+    if exp.abs() <= 22 {
+        let magnitude = 10.0_f64.powi(exp.abs());
+        if exp >= 0 {
+            (value * magnitude).round() / magnitude
+        } else {
+            (value / magnitude).round() * magnitude
+        }
+    } else {
+        format!("{:.*e}", (digits - 1) as usize, value)
+            .parse()
+            .unwrap()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -100,4 +134,66 @@ mod test {
         let ca = out.f64().unwrap();
         assert_eq!(ca.get(0), Some(1.0));
     }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_round_decimal() -> PolarsResult<()> {
+        let ca = Int128Chunked::from_iter_options(
+            "a",
+            [Some(12345i128), Some(-12355), None, Some(-4)].into_iter(),
+        );
+        let s = ca.into_decimal_unchecked(Some(10), 3).into_series();
+
+        let out = s.round(2)?;
+        assert_eq!(out.dtype(), s.dtype());
+        let out = out.decimal()?;
+        assert_eq!(
+            Vec::from(out.as_ref()),
+            &[Some(12350), Some(-12360), None, Some(0)]
+        );
+        let out = s.round(0)?;
+        assert_eq!(
+            Vec::from(out.decimal()?.as_ref()),
+            &[Some(12000), Some(-12000), None, Some(0)]
+        );
+        assert!(s.round(5)?.equals_missing(&s));
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_sig_figs() {
+        let series = Series::new(
+            "a",
+            &[
+                1234.5,
+                0.012345,
+                -987.6,
+                0.0,
+                1.2345e300,
+                1.2345e-300,
+                f64::INFINITY,
+            ],
+        );
+        let out = series.round_sig_figs(2).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[
+                Some(1200.0),
+                Some(0.012),
+                Some(-990.0),
+                Some(0.0),
+                Some(1.2e300),
+                Some(1.2e-300),
+                Some(f64::INFINITY)
+            ]
+        );
+
+        let series = Series::new("a", &[1.2345e-320f64]);
+        let out = series.round_sig_figs(2).unwrap();
+        assert_eq!(out.f64().unwrap().get(0), Some(1.2e-320));
+
+        let series = Series::new("a", &[1234.5f32]);
+        let out = series.round_sig_figs(3).unwrap();
+        assert_eq!(out.f32().unwrap().get(0), Some(1230.0));
+    }
 }
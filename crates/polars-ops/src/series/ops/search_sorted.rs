@@ -96,6 +96,12 @@ fn search_sorted_bin_array(
     out
 }
 
+/// Find the indices where `search_values` should be inserted into `s` to maintain order.
+///
+/// `s` must already be sorted (in the order given by `descending`); this is not checked and
+/// the result is unspecified otherwise. All `search_values` are looked up in a single pass
+/// over them. With [`SearchSortedSide::Left`] and [`SearchSortedSide::Right`] ties resolve
+/// like numpy's `searchsorted`, giving the first and last valid insertion index respectively.
 pub fn search_sorted(
     s: &Series,
     search_values: &Series,
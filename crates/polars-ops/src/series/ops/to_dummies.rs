@@ -11,18 +11,43 @@ type DummyType = i32;
 type DummyCa = Int32Chunked;
 
 pub trait ToDummies {
-    fn to_dummies(&self, separator: Option<&str>, drop_first: bool) -> PolarsResult<DataFrame>;
+    /// Create an indicator column named `{name}{separator}{value}` for every unique value.
+    ///
+    /// Null values get a `{name}{separator}null` indicator column, unless `drop_nulls` is
+    /// set, in which case rows holding a null are zero in every column. If `drop_first` is
+    /// set, the column of the first non-null value in order of appearance is dropped.
+    fn to_dummies(
+        &self,
+        separator: Option<&str>,
+        drop_first: bool,
+        drop_nulls: bool,
+    ) -> PolarsResult<DataFrame>;
 }
 
 impl ToDummies for Series {
-    fn to_dummies(&self, separator: Option<&str>, drop_first: bool) -> PolarsResult<DataFrame> {
+    fn to_dummies(
+        &self,
+        separator: Option<&str>,
+        drop_first: bool,
+        drop_nulls: bool,
+    ) -> PolarsResult<DataFrame> {
         let sep = separator.unwrap_or("_");
         let col_name = self.name();
         let groups = self.group_tuples(true, drop_first)?;
 
         // SAFETY: groups are in bounds
         let columns = unsafe { self.agg_first(&groups) };
-        let columns = columns.iter().zip(groups.iter()).skip(drop_first as usize);
+        let mut skip_first = drop_first;
+        let columns = columns.iter().zip(groups.iter()).filter(|(av, _)| {
+            if av.is_null() {
+                !drop_nulls
+            } else if skip_first {
+                skip_first = false;
+                false
+            } else {
+                true
+            }
+        });
         let columns = columns
             .map(|(av, group)| {
                 // strings are formatted with extra \" \" in polars, so we
@@ -81,3 +106,26 @@ fn sort_columns(mut columns: Vec<Series>) -> Vec<Series> {
     columns.sort_by(|a, b| a.name().partial_cmp(b.name()).unwrap());
     columns
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_to_dummies_nulls() -> PolarsResult<()> {
+        let s = Series::new("a", [None, Some("b"), Some("a"), Some("b")]);
+
+        let out = s.to_dummies(None, false, false)?;
+        assert_eq!(out.get_column_names(), &["a_a", "a_b", "a_null"]);
+        let out = s.to_dummies(Some(":"), false, true)?;
+        assert_eq!(out.get_column_names(), &["a:a", "a:b"]);
+        assert_eq!(out.get_row(0)?.0, &[AnyValue::UInt8(0), AnyValue::UInt8(0)]);
+
+        // The null indicator is never the dropped category.
+        let out = s.to_dummies(None, true, false)?;
+        assert_eq!(out.get_column_names(), &["a_a", "a_null"]);
+        let out = s.to_dummies(None, true, true)?;
+        assert_eq!(out.get_column_names(), &["a_a"]);
+        Ok(())
+    }
+}
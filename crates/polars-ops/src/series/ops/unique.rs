@@ -26,6 +26,11 @@ where
 }
 
 /// Returns a count of the unique values in the order of appearance.
+///
+/// The counts align element-for-element with the values of `unique` with `maintain_order`.
+/// Keeping this order is cheap for numeric, string, binary and boolean data, whose counts
+/// are stored in insertion order while hashing. Other dtypes are grouped first, after which
+/// the groups have to be sorted by their first occurrence.
 pub fn unique_counts(s: &Series) -> PolarsResult<Series> {
     if s.dtype().to_physical().is_numeric() {
         let s_physical = s.to_physical_repr();
@@ -47,9 +52,43 @@ pub fn unique_counts(s: &Series) -> PolarsResult<Series> {
                 };
                 Ok(ca.into_series())
             },
-            dt => {
-                polars_bail!(opq = unique_counts, dt)
+            DataType::Binary => {
+                Ok(unique_counts_helper(s.binary().unwrap().into_iter()).into_series())
+            },
+            DataType::Boolean => {
+                Ok(unique_counts_helper(s.bool().unwrap().into_iter()).into_series())
+            },
+            _ => {
+                // Groups sorted by their first index are in the order of appearance.
+                let mut counts = s.group_tuples(true, true)?.group_count();
+                counts.rename(s.name());
+                Ok(counts.into_series())
             },
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unique_counts_order_of_appearance() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            [Some(false), None, Some(true), Some(true), None, Some(true)],
+        );
+        let out = unique_counts(&s)?;
+        assert_eq!(Vec::from(out.idx()?), &[Some(1), Some(2), Some(3)]);
+
+        let two = Series::new("", [2i32]);
+        let one_two = Series::new("", [1i32, 2]);
+        let s = Series::new(
+            "a",
+            [two.clone(), one_two.clone(), two.clone(), one_two, two],
+        );
+        let out = unique_counts(&s)?;
+        assert_eq!(Vec::from(out.idx()?), &[Some(3), Some(2)]);
+        Ok(())
+    }
+}
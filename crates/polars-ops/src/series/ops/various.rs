@@ -6,25 +6,44 @@ use polars_core::series::IsSorted;
 use crate::series::ops::SeriesSealed;
 
 pub trait SeriesMethods: SeriesSealed {
-    /// Create a [`DataFrame`] with the unique `values` of this [`Series`] and a column `"counts"`
-    /// with dtype [`IdxType`]
-    fn value_counts(&self, sort: bool, parallel: bool) -> PolarsResult<DataFrame> {
+    /// Create a [`DataFrame`] with the unique `values` of this [`Series`] and a column `"count"`
+    /// with dtype [`IdxType`]. Null values are counted as their own category.
+    ///
+    /// If `normalize` is set, the counts are divided by the length of the [`Series`] and
+    /// returned as a [`Float64`](DataType::Float64) column `"proportion"` instead.
+    /// If `sort` is set, the output is ordered by frequency, most frequent first unless
+    /// `descending` is disabled.
+    fn value_counts(
+        &self,
+        sort: bool,
+        descending: bool,
+        parallel: bool,
+        normalize: bool,
+    ) -> PolarsResult<DataFrame> {
         let s = self.as_series();
+        let name = if normalize { "proportion" } else { "count" };
         polars_ensure!(
-            s.name() != "count",
-            Duplicate: "using `value_counts` on a column named 'count' would lead to duplicate column names"
+            s.name() != name,
+            Duplicate: "using `value_counts` on a column named '{}' would lead to duplicate column names", name
         );
         // we need to sort here as well in case of `maintain_order` because duplicates behavior is undefined
         let groups = s.group_tuples(parallel, sort)?;
         let values = unsafe { s.agg_first(&groups) };
-        let counts = groups.group_count().with_name("count");
-        let cols = vec![values, counts.into_series()];
+        let counts = groups.group_count().into_series();
+        let counts = if normalize {
+            let len = s.len() as f64;
+            (counts.cast(&DataType::Float64)? / len).with_name(name)
+        } else {
+            counts.with_name(name)
+        };
+        let cols = vec![values, counts];
         let df = unsafe { DataFrame::new_no_checks(cols) };
         if sort {
             df.sort(
-                ["count"],
+                [name],
                 SortMultipleOptions::default()
-                    .with_order_descending(true)
+                    .with_order_descending(descending)
+                    .with_maintain_order(true)
                     .with_multithreaded(parallel),
             )
         } else {
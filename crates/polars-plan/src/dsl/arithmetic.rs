@@ -52,6 +52,20 @@ impl Neg for Expr {
 }
 
 impl Expr {
+    /// Compute `self * multiplier + addend`.
+    ///
+    /// Floats use a fused multiply-add, which rounds once and doesn't materialize the
+    /// product. Other numeric types fall back to a separate multiplication and addition.
+    #[cfg(feature = "fused")]
+    pub fn mul_add(self, multiplier: Expr, addend: Expr) -> Self {
+        self.map_many_private(
+            FunctionExpr::Fused(FusedOperator::MulAdd),
+            &[multiplier, addend],
+            false,
+            true,
+        )
+    }
+
     /// Floor divide `self` by `rhs`.
     pub fn floor_div(self, rhs: Self) -> Self {
         binary_expr(self, Operator::FloorDivide, rhs)
@@ -25,6 +25,96 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Offset by an offset such as `"1mo5bd"`, where the `bd` unit counts business days.
+    ///
+    /// The calendar part of the offset is applied first, then the business days are
+    /// added to the date it lands on, with `roll` deciding what happens if that isn't a
+    /// business day.
+    #[cfg(feature = "business")]
+    pub fn offset_by_business(
+        self,
+        by: Expr,
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+        roll: Roll,
+    ) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::Business(BusinessFunction::OffsetByBusiness {
+                week_mask,
+                holidays,
+                roll,
+            }),
+            &[by],
+            false,
+            false,
+        )
+    }
+
+    /// Get the `n`-th business day on or after each date, counting from 1.
+    #[cfg(feature = "business")]
+    pub fn nth_business_day(self, n: Expr, week_mask: [bool; 7], holidays: Vec<i32>) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::Business(BusinessFunction::NthBusinessDay {
+                week_mask,
+                holidays,
+            }),
+            &[n],
+            false,
+            false,
+        )
+    }
+
+    /// Count the number of business days in the month of each date.
+    #[cfg(feature = "business")]
+    pub fn business_days_in_month(self, week_mask: [bool; 7], holidays: Vec<i32>) -> Expr {
+        self.0.map_private(FunctionExpr::Business(
+            BusinessFunction::BusinessDaysInMonth {
+                week_mask,
+                holidays,
+            },
+        ))
+    }
+
+    /// Roll each date to a business day.
+    #[cfg(feature = "business")]
+    pub fn roll_business_day(self, week_mask: [bool; 7], holidays: Vec<i32>, roll: Roll) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Business(BusinessFunction::RollBusinessDay {
+                week_mask,
+                holidays,
+                roll,
+            }))
+    }
+
+    /// Count the number of business days in the trailing `window` ending at each date.
+    ///
+    /// `window` must consist of whole days or weeks, e.g. `Duration::parse("30d")`.
+    #[cfg(feature = "business")]
+    pub fn rolling_business_day_count(
+        self,
+        window: Duration,
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+    ) -> Expr {
+        self.0.map_private(FunctionExpr::Business(
+            BusinessFunction::RollingBusinessDayCount {
+                window,
+                week_mask,
+                holidays,
+            },
+        ))
+    }
+
+    /// Determine whether each date is a business day.
+    #[cfg(feature = "business")]
+    pub fn is_business_day(self, week_mask: [bool; 7], holidays: Vec<i32>) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Business(BusinessFunction::IsBusinessDay {
+                week_mask,
+                holidays,
+            }))
+    }
+
     /// Convert from Date/Time/Datetime into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     pub fn to_string(self, format: &str) -> Expr {
@@ -114,11 +204,15 @@ impl DateLikeNameSpace {
     /// Extract the week from the underlying Date representation.
     /// Can be performed on Date and Datetime
 
-    /// Returns the ISO week number starting from 1.
-    /// The return value ranges from 1 to 53. (The last week of year differs by years.)
-    pub fn week(self) -> Expr {
+    /// With `iso`, returns the ISO week number starting from 1, which belongs to the year
+    /// given by [`DateLikeNameSpace::iso_year`]. The return value ranges from 1 to 53.
+    /// (The last week of year differs by years.)
+    ///
+    /// Otherwise, week 1 is the week containing January 1st, weeks start on Monday, and the
+    /// week belongs to the calendar year. The return value ranges from 1 to 54.
+    pub fn week(self, iso: bool) -> Expr {
         self.0
-            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::Week))
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::Week { iso }))
     }
 
     /// Extract the ISO week day from the underlying Date representation.
@@ -275,9 +369,12 @@ impl DateLikeNameSpace {
     }
 
     /// Combine an existing Date/Datetime with a Time, creating a new Datetime value.
-    pub fn combine(self, time: Expr, tu: TimeUnit) -> Expr {
+    ///
+    /// The wall-clock date and time are localized in `time_zone` if given, and otherwise in
+    /// the time zone of the existing Datetime, if any.
+    pub fn combine(self, time: Expr, tu: TimeUnit, time_zone: Option<TimeZone>) -> Expr {
         self.0.map_many_private(
-            FunctionExpr::TemporalExpr(TemporalFunction::Combine(tu)),
+            FunctionExpr::TemporalExpr(TemporalFunction::Combine(tu, time_zone)),
             &[time],
             false,
             false,
@@ -90,6 +90,7 @@ pub enum Expr {
         expr: Arc<Expr>,
         idx: Arc<Expr>,
         returns_scalar: bool,
+        oob: OutOfBounds,
     },
     SortBy {
         expr: Arc<Expr>,
@@ -224,10 +225,12 @@ impl Hash for Expr {
                 expr,
                 idx,
                 returns_scalar,
+                oob,
             } => {
                 expr.hash(state);
                 idx.hash(state);
                 returns_scalar.hash(state);
+                oob.hash(state);
             },
             // already hashed by discriminant
             Expr::Wildcard | Expr::Len => {},
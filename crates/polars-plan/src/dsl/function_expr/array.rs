@@ -9,6 +9,7 @@ pub enum ArrayFunction {
     Min,
     Max,
     Sum,
+    Mean,
     ToList,
     Unique(bool),
     NUnique,
@@ -30,6 +31,9 @@ pub enum ArrayFunction {
     #[cfg(feature = "array_count")]
     CountMatches,
     Shift,
+    MaxHorizontal,
+    MinHorizontal,
+    SumHorizontal,
 }
 
 impl ArrayFunction {
@@ -38,6 +42,7 @@ impl ArrayFunction {
         match self {
             Min | Max => mapper.map_to_list_and_array_inner_dtype(),
             Sum => mapper.nested_sum_type(),
+            Mean => mapper.map_to_float_dtype(),
             ToList => mapper.try_map_dtype(map_array_dtype_to_list_dtype),
             Unique(_) => mapper.try_map_dtype(map_array_dtype_to_list_dtype),
             NUnique => mapper.with_dtype(IDX_DTYPE),
@@ -56,6 +61,7 @@ impl ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => mapper.with_dtype(IDX_DTYPE),
             Shift => mapper.with_same_dtype(),
+            MaxHorizontal | MinHorizontal | SumHorizontal => mapper.map_to_supertype(),
         }
     }
 }
@@ -75,6 +81,7 @@ impl Display for ArrayFunction {
             Min => "min",
             Max => "max",
             Sum => "sum",
+            Mean => "mean",
             ToList => "to_list",
             Unique(_) => "unique",
             NUnique => "n_unique",
@@ -96,6 +103,9 @@ impl Display for ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => "count_matches",
             Shift => "shift",
+            MaxHorizontal => "max_horizontal",
+            MinHorizontal => "min_horizontal",
+            SumHorizontal => "sum_horizontal",
         };
         write!(f, "arr.{name}")
     }
@@ -108,6 +118,7 @@ impl From<ArrayFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Min => map!(min),
             Max => map!(max),
             Sum => map!(sum),
+            Mean => map!(mean),
             ToList => map!(to_list),
             Unique(stable) => map!(unique, stable),
             NUnique => map!(n_unique),
@@ -129,6 +140,9 @@ impl From<ArrayFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "array_count")]
             CountMatches => map_as_slice!(count_matches),
             Shift => map_as_slice!(shift),
+            MaxHorizontal => map_as_slice!(max_horizontal),
+            MinHorizontal => map_as_slice!(min_horizontal),
+            SumHorizontal => map_as_slice!(sum_horizontal),
         }
     }
 }
@@ -145,6 +159,10 @@ pub(super) fn sum(s: &Series) -> PolarsResult<Series> {
     s.array()?.array_sum()
 }
 
+pub(super) fn mean(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_mean()
+}
+
 pub(super) fn std(s: &Series, ddof: u8) -> PolarsResult<Series> {
     s.array()?.array_std(ddof)
 }
@@ -221,7 +239,9 @@ pub(super) fn contains(s: &[Series]) -> PolarsResult<Series> {
     polars_ensure!(matches!(array.dtype(), DataType::Array(_, _)),
         SchemaMismatch: "invalid series dtype: expected `Array`, got `{}`", array.dtype(),
     );
-    Ok(is_in(item, array)?.with_name(array.name()).into_series())
+    Ok(is_in(item, array, true)?
+        .with_name(array.name())
+        .into_series())
 }
 
 #[cfg(feature = "array_count")]
@@ -243,3 +263,15 @@ pub(super) fn shift(s: &[Series]) -> PolarsResult<Series> {
 
     ca.array_shift(n)
 }
+
+pub(super) fn max_horizontal(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::chunked_array::array::arr_max_horizontal(s)
+}
+
+pub(super) fn min_horizontal(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::chunked_array::array::arr_min_horizontal(s)
+}
+
+pub(super) fn sum_horizontal(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::chunked_array::array::arr_sum_horizontal(s)
+}
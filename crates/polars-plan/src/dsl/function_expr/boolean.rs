@@ -33,7 +33,9 @@ pub enum BooleanFunction {
         closed: ClosedInterval,
     },
     #[cfg(feature = "is_in")]
-    IsIn,
+    IsIn {
+        nulls_equal: bool,
+    },
     AllHorizontal,
     AnyHorizontal,
     // Also bitwise negate
@@ -81,7 +83,7 @@ impl Display for BooleanFunction {
             #[cfg(feature = "is_between")]
             IsBetween { .. } => "is_between",
             #[cfg(feature = "is_in")]
-            IsIn => "is_in",
+            IsIn { .. } => "is_in",
             AnyHorizontal => "any_horizontal",
             AllHorizontal => "all_horizontal",
             Not => "not",
@@ -113,7 +115,7 @@ impl From<BooleanFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "is_between")]
             IsBetween { closed } => map_as_slice!(is_between, closed),
             #[cfg(feature = "is_in")]
-            IsIn => wrap!(is_in),
+            IsIn { nulls_equal } => wrap!(is_in, nulls_equal),
             Not => map!(not),
             AllHorizontal | AnyHorizontal => unreachable!(),
         }
@@ -197,10 +199,10 @@ fn is_between(s: &[Series], closed: ClosedInterval) -> PolarsResult<Series> {
 }
 
 #[cfg(feature = "is_in")]
-fn is_in(s: &mut [Series]) -> PolarsResult<Option<Series>> {
+fn is_in(s: &mut [Series], nulls_equal: bool) -> PolarsResult<Option<Series>> {
     let left = &s[0];
     let other = &s[1];
-    polars_ops::prelude::is_in(left, other).map(|ca| Some(ca.into_series()))
+    polars_ops::prelude::is_in(left, other, nulls_equal).map(|ca| Some(ca.into_series()))
 }
 
 fn not(s: &Series) -> PolarsResult<Series> {
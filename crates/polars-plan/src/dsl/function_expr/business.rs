@@ -1,13 +1,15 @@
 use std::fmt::{Display, Formatter};
 
 use polars_core::prelude::*;
-use polars_ops::prelude::Roll;
+use polars_ops::prelude::{ClosedInterval, Roll};
+#[cfg(feature = "business")]
+use polars_time::Duration;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::dsl::SpecialEq;
-use crate::map_as_slice;
 use crate::prelude::SeriesUdf;
+use crate::{map, map_as_slice};
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
@@ -16,6 +18,8 @@ pub enum BusinessFunction {
     BusinessDayCount {
         week_mask: [bool; 7],
         holidays: Vec<i32>,
+        closed: ClosedInterval,
+        output_dtype: Option<DataType>,
     },
     #[cfg(feature = "business")]
     AddBusinessDay {
@@ -23,6 +27,45 @@ pub enum BusinessFunction {
         holidays: Vec<i32>,
         roll: Roll,
     },
+    #[cfg(feature = "business")]
+    IsBusinessDay {
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+    },
+    #[cfg(feature = "business")]
+    NthBusinessDay {
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+    },
+    #[cfg(feature = "business")]
+    BusinessDaysInMonth {
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+    },
+    #[cfg(feature = "business")]
+    RollBusinessDay {
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+        roll: Roll,
+    },
+    #[cfg(feature = "business")]
+    RollingBusinessDayCount {
+        window: Duration,
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+    },
+    #[cfg(feature = "business")]
+    OffsetByBusiness {
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+        roll: Roll,
+    },
+    #[cfg(feature = "business")]
+    BusinessDateRange {
+        interval: i32,
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+    },
 }
 
 impl Display for BusinessFunction {
@@ -33,6 +76,20 @@ impl Display for BusinessFunction {
             &BusinessDayCount { .. } => "business_day_count",
             #[cfg(feature = "business")]
             &AddBusinessDay { .. } => "add_business_days",
+            #[cfg(feature = "business")]
+            &IsBusinessDay { .. } => "is_business_day",
+            #[cfg(feature = "business")]
+            &NthBusinessDay { .. } => "nth_business_day",
+            #[cfg(feature = "business")]
+            &RollBusinessDay { .. } => "roll_business_day",
+            #[cfg(feature = "business")]
+            &BusinessDaysInMonth { .. } => "business_days_in_month",
+            #[cfg(feature = "business")]
+            &RollingBusinessDayCount { .. } => "rolling_business_day_count",
+            #[cfg(feature = "business")]
+            &OffsetByBusiness { .. } => "offset_by_business",
+            #[cfg(feature = "business")]
+            &BusinessDateRange { .. } => "business_date_range",
         };
         write!(f, "{s}")
     }
@@ -45,8 +102,16 @@ impl From<BusinessFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             BusinessDayCount {
                 week_mask,
                 holidays,
+                closed,
+                output_dtype,
             } => {
-                map_as_slice!(business_day_count, week_mask, &holidays)
+                map_as_slice!(
+                    business_day_count,
+                    week_mask,
+                    &holidays,
+                    closed,
+                    output_dtype.clone()
+                )
             },
             #[cfg(feature = "business")]
             AddBusinessDay {
@@ -56,6 +121,59 @@ impl From<BusinessFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             } => {
                 map_as_slice!(add_business_days, week_mask, &holidays, roll)
             },
+            #[cfg(feature = "business")]
+            IsBusinessDay {
+                week_mask,
+                holidays,
+            } => {
+                map!(is_business_day, week_mask, &holidays)
+            },
+            #[cfg(feature = "business")]
+            NthBusinessDay {
+                week_mask,
+                holidays,
+            } => {
+                map_as_slice!(nth_business_day, week_mask, &holidays)
+            },
+            #[cfg(feature = "business")]
+            BusinessDaysInMonth {
+                week_mask,
+                holidays,
+            } => {
+                map!(business_days_in_month, week_mask, &holidays)
+            },
+            #[cfg(feature = "business")]
+            RollBusinessDay {
+                week_mask,
+                holidays,
+                roll,
+            } => {
+                map!(roll_business_day, week_mask, &holidays, roll)
+            },
+            #[cfg(feature = "business")]
+            RollingBusinessDayCount {
+                window,
+                week_mask,
+                holidays,
+            } => {
+                map!(rolling_business_day_count, window, week_mask, &holidays)
+            },
+            #[cfg(feature = "business")]
+            OffsetByBusiness {
+                week_mask,
+                holidays,
+                roll,
+            } => {
+                map_as_slice!(offset_by_business, week_mask, &holidays, roll)
+            },
+            #[cfg(feature = "business")]
+            BusinessDateRange {
+                interval,
+                week_mask,
+                holidays,
+            } => {
+                map_as_slice!(business_date_range, interval, week_mask, &holidays)
+            },
         }
     }
 }
@@ -65,10 +183,12 @@ pub(super) fn business_day_count(
     s: &[Series],
     week_mask: [bool; 7],
     holidays: &[i32],
+    closed: ClosedInterval,
+    output_dtype: Option<DataType>,
 ) -> PolarsResult<Series> {
     let start = &s[0];
     let end = &s[1];
-    polars_ops::prelude::business_day_count(start, end, week_mask, holidays)
+    polars_ops::prelude::business_day_count(start, end, week_mask, holidays, closed, output_dtype)
 }
 
 #[cfg(feature = "business")]
@@ -82,3 +202,157 @@ pub(super) fn add_business_days(
     let n = &s[1];
     polars_ops::prelude::add_business_days(start, n, week_mask, holidays, roll)
 }
+
+#[cfg(feature = "business")]
+pub(super) fn is_business_day(
+    s: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    polars_ops::prelude::is_business_day(s, week_mask, holidays)
+}
+
+#[cfg(feature = "business")]
+pub(super) fn nth_business_day(
+    s: &[Series],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    let start = &s[0];
+    let n = &s[1];
+    polars_ops::prelude::nth_business_day(start, n, week_mask, holidays)
+}
+
+#[cfg(feature = "business")]
+pub(super) fn roll_business_day(
+    s: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    roll: Roll,
+) -> PolarsResult<Series> {
+    polars_ops::prelude::roll_business_day(s, week_mask, holidays, roll)
+}
+
+#[cfg(feature = "business")]
+pub(super) fn business_days_in_month(
+    s: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    polars_ops::prelude::business_days_in_month(s, week_mask, holidays)
+}
+
+#[cfg(feature = "business")]
+pub(super) fn rolling_business_day_count(
+    s: &Series,
+    window: Duration,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        window.months() == 0 && window.is_full_days() && !window.negative(),
+        InvalidOperation: "`window` must be a positive number of days or weeks, got {}", window
+    );
+    let window_days = i32::try_from(window.weeks() * 7 + window.days())
+        .map_err(|_| polars_err!(ComputeError: "`window` {} is out of range", window))?;
+    polars_ops::prelude::rolling_business_day_count(s, window_days, week_mask, holidays)
+}
+
+#[cfg(feature = "business")]
+pub(super) fn business_date_range(
+    s: &[Series],
+    interval: i32,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    let start = &s[0];
+    let end = &s[1];
+    polars_ops::prelude::business_date_range(start, end, interval, week_mask, holidays)
+}
+
+/// Split an offset such as `"1mo5bd"` into its calendar part, `"1mo"`, and its number of
+/// business days, `5`. A leading minus sign applies to both parts.
+#[cfg(feature = "business")]
+fn split_business_days(offset: &str) -> PolarsResult<(String, i32)> {
+    let (negative, mut rest) = match offset.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, offset),
+    };
+    let mut calendar = String::new();
+    let mut business_days: i32 = 0;
+    while !rest.is_empty() {
+        let n_end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let unit_end = rest[n_end..]
+            .find(|c: char| c.is_ascii_digit())
+            .map_or(rest.len(), |i| n_end + i);
+        let (n, unit) = (&rest[..n_end], &rest[n_end..unit_end]);
+        polars_ensure!(
+            !n.is_empty() && !unit.is_empty(),
+            InvalidOperation: "invalid offset {:?}, expected e.g. \"1mo5bd\"", offset
+        );
+        if unit == "bd" {
+            business_days = n
+                .parse::<i32>()
+                .ok()
+                .and_then(|n| business_days.checked_add(n))
+                .ok_or_else(
+                    || polars_err!(InvalidOperation: "offset {:?} is out of range", offset),
+                )?;
+        } else {
+            calendar.push_str(&rest[..unit_end]);
+        }
+        rest = &rest[unit_end..];
+    }
+    if negative {
+        business_days = -business_days;
+        if !calendar.is_empty() {
+            calendar.insert(0, '-');
+        }
+    }
+    Ok((calendar, business_days))
+}
+
+#[cfg(feature = "business")]
+pub(super) fn offset_by_business(
+    s: &[Series],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    roll: Roll,
+) -> PolarsResult<Series> {
+    let start = &s[0];
+    let offsets = s[1].str()?;
+
+    let mut calendar = StringChunkedBuilder::new(offsets.name(), offsets.len());
+    let mut business_days = Vec::with_capacity(offsets.len());
+    let mut has_calendar = false;
+    for opt_offset in offsets.iter() {
+        match opt_offset {
+            Some(offset) => {
+                let (calendar_part, n) = split_business_days(offset)?;
+                has_calendar |= !calendar_part.is_empty();
+                calendar.append_value(if calendar_part.is_empty() {
+                    "0d"
+                } else {
+                    calendar_part.as_str()
+                });
+                business_days.push(Some(n));
+            },
+            None => {
+                calendar.append_null();
+                business_days.push(None);
+            },
+        }
+    }
+
+    // Apply the calendar part first, so that the business days are counted from the
+    // date it lands on.
+    let shifted = if has_calendar {
+        super::temporal::date_offset(&[start.clone(), calendar.finish().into_series()])?
+    } else {
+        start.clone()
+    };
+    let n = Int32Chunked::from_iter_options(offsets.name(), business_days.into_iter());
+    polars_ops::prelude::add_business_days(&shifted, &n.into_series(), week_mask, holidays, roll)
+}
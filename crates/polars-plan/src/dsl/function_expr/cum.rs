@@ -1,15 +1,15 @@
 use super::*;
 
-pub(super) fn cum_count(s: &Series, reverse: bool) -> PolarsResult<Series> {
-    polars_ops::prelude::cum_count(s, reverse)
+pub(super) fn cum_count(s: &Series, reverse: bool, count_nulls: bool) -> PolarsResult<Series> {
+    polars_ops::prelude::cum_count(s, reverse, count_nulls)
 }
 
 pub(super) fn cum_sum(s: &Series, reverse: bool) -> PolarsResult<Series> {
     polars_ops::prelude::cum_sum(s, reverse)
 }
 
-pub(super) fn cum_prod(s: &Series, reverse: bool) -> PolarsResult<Series> {
-    polars_ops::prelude::cum_prod(s, reverse)
+pub(super) fn cum_prod(s: &Series, reverse: bool, strict: bool) -> PolarsResult<Series> {
+    polars_ops::prelude::cum_prod(s, reverse, strict)
 }
 
 pub(super) fn cum_min(s: &Series, reverse: bool) -> PolarsResult<Series> {
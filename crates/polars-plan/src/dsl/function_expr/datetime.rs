@@ -20,7 +20,9 @@ pub enum TemporalFunction {
     IsoYear,
     Quarter,
     Month,
-    Week,
+    Week {
+        iso: bool,
+    },
     WeekDay,
     Day,
     OrdinalDay,
@@ -59,7 +61,7 @@ pub enum TemporalFunction {
     Round(String),
     #[cfg(feature = "timezones")]
     ReplaceTimeZone(Option<TimeZone>, NonExistent),
-    Combine(TimeUnit),
+    Combine(TimeUnit, Option<TimeZone>),
     DatetimeFunction {
         time_unit: TimeUnit,
         time_zone: Option<TimeZone>,
@@ -73,7 +75,7 @@ impl TemporalFunction {
             Millennium | Century => mapper.with_dtype(DataType::Int8),
             Year | IsoYear => mapper.with_dtype(DataType::Int32),
             OrdinalDay => mapper.with_dtype(DataType::Int16),
-            Month | Quarter | Week | WeekDay | Day | Hour | Minute | Second => {
+            Month | Quarter | Week { .. } | WeekDay | Day | Hour | Minute | Second => {
                 mapper.with_dtype(DataType::Int8)
             },
             Millisecond | Microsecond | Nanosecond => mapper.with_dtype(DataType::Int32),
@@ -119,9 +121,11 @@ impl TemporalFunction {
                 "datetime",
                 DataType::Datetime(*time_unit, time_zone.clone()),
             )),
-            Combine(tu) => mapper.try_map_dtype(|dt| match dt {
-                DataType::Datetime(_, tz) => Ok(DataType::Datetime(*tu, tz.clone())),
-                DataType::Date => Ok(DataType::Datetime(*tu, None)),
+            Combine(tu, time_zone) => mapper.try_map_dtype(|dt| match dt {
+                DataType::Datetime(_, tz) => {
+                    Ok(DataType::Datetime(*tu, time_zone.clone().or(tz.clone())))
+                },
+                DataType::Date => Ok(DataType::Datetime(*tu, time_zone.clone())),
                 dtype => {
                     polars_bail!(ComputeError: "expected Date or Datetime, got {}", dtype)
                 },
@@ -141,7 +145,7 @@ impl Display for TemporalFunction {
             IsoYear => "iso_year",
             Quarter => "quarter",
             Month => "month",
-            Week => "week",
+            Week { .. } => "week",
             WeekDay => "weekday",
             Day => "day",
             OrdinalDay => "ordinal_day",
@@ -181,7 +185,7 @@ impl Display for TemporalFunction {
             #[cfg(feature = "timezones")]
             ReplaceTimeZone(_, _) => "replace_time_zone",
             DatetimeFunction { .. } => return write!(f, "dt.datetime"),
-            Combine(_) => "combine",
+            Combine(_, _) => "combine",
         };
         write!(f, "dt.{s}")
     }
@@ -208,8 +212,12 @@ pub(super) fn month(s: &Series) -> PolarsResult<Series> {
 pub(super) fn quarter(s: &Series) -> PolarsResult<Series> {
     s.quarter().map(|ca| ca.into_series())
 }
-pub(super) fn week(s: &Series) -> PolarsResult<Series> {
-    s.week().map(|ca| ca.into_series())
+pub(super) fn week(s: &Series, iso: bool) -> PolarsResult<Series> {
+    if iso {
+        s.week().map(|ca| ca.into_series())
+    } else {
+        s.week_of_year().map(|ca| ca.into_series())
+    }
 }
 pub(super) fn weekday(s: &Series) -> PolarsResult<Series> {
     s.weekday().map(|ca| ca.into_series())
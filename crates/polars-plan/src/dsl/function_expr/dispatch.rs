@@ -5,8 +5,8 @@ pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
 }
 
 #[cfg(feature = "approx_unique")]
-pub(super) fn approx_n_unique(s: &Series) -> PolarsResult<Series> {
-    polars_ops::prelude::approx_n_unique(s)
+pub(super) fn approx_n_unique(s: &Series, precision: u8) -> PolarsResult<Series> {
+    polars_ops::prelude::approx_n_unique_with_precision(s, precision)
 }
 
 #[cfg(feature = "diff")]
@@ -15,13 +15,46 @@ pub(super) fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsRes
 }
 
 #[cfg(feature = "pct_change")]
-pub(super) fn pct_change(s: &[Series]) -> PolarsResult<Series> {
-    polars_ops::prelude::pct_change(&s[0], &s[1])
+pub(super) fn pct_change(
+    s: &[Series],
+    fill_null: Option<FillNullStrategy>,
+) -> PolarsResult<Series> {
+    polars_ops::prelude::pct_change(&s[0], &s[1], fill_null)
 }
 
 #[cfg(feature = "interpolate")]
-pub(super) fn interpolate(s: &Series, method: InterpolationMethod) -> PolarsResult<Series> {
-    Ok(polars_ops::prelude::interpolate(s, method))
+pub(super) fn interpolate(
+    s: &Series,
+    method: InterpolationMethod,
+    max_gap: Option<IdxSize>,
+) -> PolarsResult<Series> {
+    Ok(polars_ops::prelude::interpolate(s, method, max_gap))
+}
+
+#[cfg(feature = "gcd_lcm")]
+pub(super) fn gcd(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::prelude::gcd(&s[0], &s[1])
+}
+
+#[cfg(feature = "gcd_lcm")]
+pub(super) fn lcm(s: &[Series], saturate: bool) -> PolarsResult<Series> {
+    polars_ops::prelude::lcm(&s[0], &s[1], saturate)
+}
+
+#[cfg(feature = "is_close")]
+pub(super) fn is_close(
+    s: &[Series],
+    rel_tol: f64,
+    abs_tol: f64,
+    nans_equal: bool,
+) -> PolarsResult<Series> {
+    polars_ops::prelude::is_close(&s[0], &s[1], rel_tol, abs_tol, nans_equal)
+        .map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "interpolate_by")]
+pub(super) fn interpolate_by(s: &[Series], method: InterpolationMethod) -> PolarsResult<Series> {
+    polars_ops::prelude::interpolate_by(&s[0], &s[1], method, s.get(2))
 }
 
 pub(super) fn to_physical(s: &Series) -> PolarsResult<Series> {
@@ -47,8 +80,14 @@ pub(super) fn replace_time_zone(
 }
 
 #[cfg(feature = "dtype-struct")]
-pub(super) fn value_counts(s: &Series, sort: bool, parallel: bool) -> PolarsResult<Series> {
-    s.value_counts(sort, parallel)
+pub(super) fn value_counts(
+    s: &Series,
+    sort: bool,
+    descending: bool,
+    parallel: bool,
+    normalize: bool,
+) -> PolarsResult<Series> {
+    s.value_counts(sort, descending, parallel, normalize)
         .map(|df| df.into_struct(s.name()).into_series())
 }
 
@@ -61,6 +100,11 @@ pub(super) fn reshape(s: &Series, dimensions: Vec<i64>) -> PolarsResult<Series>
     s.reshape(&dimensions)
 }
 
+#[cfg(feature = "dtype-array")]
+pub(super) fn reshape_array(s: &Series, dimensions: Vec<i64>) -> PolarsResult<Series> {
+    s.reshape_array(&dimensions)
+}
+
 #[cfg(feature = "repeat_by")]
 pub(super) fn repeat_by(s: &[Series]) -> PolarsResult<Series> {
     let by = &s[1];
@@ -98,8 +142,13 @@ pub(super) fn drop_nulls(s: &Series) -> PolarsResult<Series> {
 }
 
 #[cfg(feature = "mode")]
-pub(super) fn mode(s: &Series) -> PolarsResult<Series> {
-    mode::mode(s)
+pub(super) fn mode(s: &Series, maintain_order: bool, ignore_nulls: bool) -> PolarsResult<Series> {
+    mode::mode(s, maintain_order, ignore_nulls)
+}
+
+#[cfg(feature = "mode")]
+pub(super) fn mode_first(s: &Series, ignore_nulls: bool) -> PolarsResult<Series> {
+    mode::mode_first(s, ignore_nulls)
 }
 
 #[cfg(feature = "moment")]
@@ -113,6 +162,12 @@ pub(super) fn kurtosis(s: &Series, fisher: bool, bias: bool) -> PolarsResult<Ser
         .map(|opt_v| Series::new(s.name(), &[opt_v]))
 }
 
+#[cfg(feature = "moment")]
+pub(super) fn moment(s: &Series, order: usize, central: bool, bias: bool) -> PolarsResult<Series> {
+    s.moment(order, central, bias)
+        .map(|opt_v| Series::new(s.name(), &[opt_v]))
+}
+
 pub(super) fn arg_unique(s: &Series) -> PolarsResult<Series> {
     s.arg_unique().map(|ok| ok.into_series())
 }
@@ -128,6 +183,7 @@ pub(super) fn hist(
     bin_count: Option<usize>,
     include_category: bool,
     include_breakpoint: bool,
+    density: bool,
 ) -> PolarsResult<Series> {
     let bins = if s.len() == 2 {
         Some(s[1].clone())
@@ -135,7 +191,14 @@ pub(super) fn hist(
         None
     };
     let s = &s[0];
-    hist_series(s, bin_count, bins, include_category, include_breakpoint)
+    hist_series(
+        s,
+        bin_count,
+        bins,
+        include_category,
+        include_breakpoint,
+        density,
+    )
 }
 
 #[cfg(feature = "replace")]
@@ -144,6 +207,20 @@ pub(super) fn replace(s: &[Series], return_dtype: Option<DataType>) -> PolarsRes
     polars_ops::series::replace(&s[0], &s[1], &s[2], default, return_dtype)
 }
 
+#[cfg(feature = "replace")]
+pub(super) fn replace_strict(s: &[Series], return_dtype: Option<DataType>) -> PolarsResult<Series> {
+    polars_ops::series::replace_strict(&s[0], &s[1], &s[2], s.get(3), return_dtype)
+}
+
+#[cfg(feature = "replace")]
+pub(super) fn replace_ranges(
+    s: &[Series],
+    left_closed: bool,
+    return_dtype: Option<DataType>,
+) -> PolarsResult<Series> {
+    polars_ops::series::replace_ranges(&s[0], &s[1], &s[2], s.get(3), left_closed, return_dtype)
+}
+
 pub(super) fn fill_null_with_strategy(
     s: &Series,
     strategy: FillNullStrategy,
@@ -165,7 +242,7 @@ pub(super) fn negate(s: &Series) -> PolarsResult<Series> {
     polars_ops::series::negate(s)
 }
 
-pub(super) fn extend_constant(s: &[Series]) -> PolarsResult<Series> {
+pub(super) fn extend_constant(s: &[Series], prepend: bool) -> PolarsResult<Series> {
     let value = &s[1];
     let n = &s[2];
     polars_ensure!(value.len() == 1 && n.len() == 1, ComputeError: "value and n should have unit length.");
@@ -173,9 +250,20 @@ pub(super) fn extend_constant(s: &[Series]) -> PolarsResult<Series> {
     let v = value.get(0)?;
     let s = &s[0];
     match n.u64()?.get(0) {
+        Some(n) if prepend => s.prepend_constant(v, n as usize),
         Some(n) => s.extend_constant(v, n as usize),
         None => {
             polars_bail!(ComputeError: "n can not be None for extend_constant.")
         },
     }
 }
+
+pub(super) fn arg_sort_by(
+    s: &[Series],
+    descending: &[bool],
+    nulls_last: &[bool],
+    multithreaded: bool,
+) -> PolarsResult<Series> {
+    polars_ops::series::arg_sort_by(s, descending, nulls_last, multithreaded)
+        .map(|ca| ca.into_series())
+}
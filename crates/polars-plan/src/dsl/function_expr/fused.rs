@@ -9,6 +9,8 @@ pub enum FusedOperator {
     MultiplyAdd,
     SubMultiply,
     MultiplySub,
+    /// `a * b + c` with a single rounding step for floats, broadcasting scalar operands.
+    MulAdd,
 }
 
 impl Display for FusedOperator {
@@ -17,6 +19,7 @@ impl Display for FusedOperator {
             FusedOperator::MultiplyAdd => "fma",
             FusedOperator::SubMultiply => "fsm",
             FusedOperator::MultiplySub => "fms",
+            FusedOperator::MulAdd => "mul_add",
         };
         write!(f, "{s}")
     }
@@ -30,5 +33,6 @@ pub(super) fn fused(input: &[Series], op: FusedOperator) -> PolarsResult<Series>
         FusedOperator::MultiplyAdd => Ok(fma_series(s0, s1, s2)),
         FusedOperator::SubMultiply => Ok(fsm_series(s0, s1, s2)),
         FusedOperator::MultiplySub => Ok(fms_series(s0, s1, s2)),
+        FusedOperator::MulAdd => mul_add(s0, s1, s2),
     }
 }
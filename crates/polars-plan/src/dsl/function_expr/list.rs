@@ -18,9 +18,11 @@ pub enum ListFunction {
         with_replacement: bool,
         shuffle: bool,
         seed: Option<u64>,
+        clamp: bool,
     },
     Slice,
     Shift,
+    Filter,
     Get(bool),
     #[cfg(feature = "list_gather")]
     Gather(bool),
@@ -71,6 +73,7 @@ impl ListFunction {
             Sample { .. } => mapper.with_same_dtype(),
             Slice => mapper.with_same_dtype(),
             Shift => mapper.with_same_dtype(),
+            Filter => mapper.with_same_dtype(),
             Get(_) => mapper.map_to_list_and_array_inner_dtype(),
             #[cfg(feature = "list_gather")]
             Gather(_) => mapper.with_same_dtype(),
@@ -88,7 +91,11 @@ impl ListFunction {
             ArgMin => mapper.with_dtype(IDX_DTYPE),
             ArgMax => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "diff")]
-            Diff { .. } => mapper.with_same_dtype(),
+            Diff { .. } => mapper.map_dtype(|dt| match dt {
+                DataType::List(inner) => diff_signed_dtype(inner)
+                    .map_or_else(|| dt.clone(), |inner| DataType::List(Box::new(inner))),
+                dt => dt.clone(),
+            }),
             Sort(_) => mapper.with_same_dtype(),
             Reverse => mapper.with_same_dtype(),
             Unique(_) => mapper.with_same_dtype(),
@@ -136,6 +143,7 @@ impl Display for ListFunction {
             },
             Slice => "slice",
             Shift => "shift",
+            Filter => "filter",
             Get(_) => "get",
             #[cfg(feature = "list_gather")]
             Gather(_) => "gather",
@@ -194,15 +202,17 @@ impl From<ListFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
                 with_replacement,
                 shuffle,
                 seed,
+                clamp,
             } => {
                 if is_fraction {
-                    map_as_slice!(sample_fraction, with_replacement, shuffle, seed)
+                    map_as_slice!(sample_fraction, with_replacement, shuffle, seed, clamp)
                 } else {
-                    map_as_slice!(sample_n, with_replacement, shuffle, seed)
+                    map_as_slice!(sample_n, with_replacement, shuffle, seed, clamp)
                 }
             },
             Slice => wrap!(slice),
             Shift => map_as_slice!(shift),
+            Filter => map_as_slice!(filter),
             Get(null_on_oob) => wrap!(get, null_on_oob),
             #[cfg(feature = "list_gather")]
             Gather(null_on_oob) => map_as_slice!(gather, null_on_oob),
@@ -246,7 +256,7 @@ pub(super) fn contains(args: &mut [Series]) -> PolarsResult<Option<Series>> {
     polars_ensure!(matches!(list.dtype(), DataType::List(_)),
         SchemaMismatch: "invalid series dtype: expected `List`, got `{}`", list.dtype(),
     );
-    polars_ops::prelude::is_in(item, list).map(|mut ca| {
+    polars_ops::prelude::is_in(item, list, true).map(|mut ca| {
         ca.rename(list.name());
         Some(ca.into_series())
     })
@@ -265,10 +275,11 @@ pub(super) fn sample_n(
     with_replacement: bool,
     shuffle: bool,
     seed: Option<u64>,
+    clamp: bool,
 ) -> PolarsResult<Series> {
     let list = s[0].list()?;
     let n = &s[1];
-    list.lst_sample_n(n, with_replacement, shuffle, seed)
+    list.lst_sample_n(n, with_replacement, shuffle, seed, clamp)
         .map(|ok| ok.into_series())
 }
 
@@ -278,10 +289,11 @@ pub(super) fn sample_fraction(
     with_replacement: bool,
     shuffle: bool,
     seed: Option<u64>,
+    clamp: bool,
 ) -> PolarsResult<Series> {
     let list = s[0].list()?;
     let fraction = &s[1];
-    list.lst_sample_fraction(fraction, with_replacement, shuffle, seed)
+    list.lst_sample_fraction(fraction, with_replacement, shuffle, seed, clamp)
         .map(|ok| ok.into_series())
 }
 
@@ -302,6 +314,13 @@ pub(super) fn shift(s: &[Series]) -> PolarsResult<Series> {
     list.lst_shift(periods).map(|ok| ok.into_series())
 }
 
+pub(super) fn filter(s: &[Series]) -> PolarsResult<Series> {
+    let list = s[0].list()?;
+    let mask = s[1].list()?;
+
+    list.lst_filter(mask).map(|ok| ok.into_series())
+}
+
 pub(super) fn slice(args: &mut [Series]) -> PolarsResult<Option<Series>> {
     let s = &args[0];
     let list_ca = s.list()?;
@@ -501,13 +520,8 @@ pub(super) fn gather_every(args: &[Series]) -> PolarsResult<Series> {
 pub(super) fn count_matches(args: &[Series]) -> PolarsResult<Series> {
     let s = &args[0];
     let element = &args[1];
-    polars_ensure!(
-        element.len() == 1,
-        ComputeError: "argument expression in `list.count_matches` must produce exactly one element, got {}",
-        element.len()
-    );
     let ca = s.list()?;
-    list_count_matches(ca, element.get(0).unwrap())
+    list_count_matches(ca, element)
 }
 
 pub(super) fn sum(s: &Series) -> PolarsResult<Series> {
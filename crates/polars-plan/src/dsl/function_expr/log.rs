@@ -14,6 +14,10 @@ pub(super) fn log(s: &Series, base: f64) -> PolarsResult<Series> {
     Ok(s.log(base))
 }
 
+pub(super) fn log_with_base(s: &[Series]) -> PolarsResult<Series> {
+    s[0].log_with_base(&s[1])
+}
+
 pub(super) fn log1p(s: &Series) -> PolarsResult<Series> {
     Ok(s.log1p())
 }
@@ -127,12 +127,31 @@ pub enum FunctionExpr {
     Business(BusinessFunction),
     #[cfg(feature = "abs")]
     Abs,
+    #[cfg(feature = "bitwise")]
+    BitCount,
+    #[cfg(feature = "bitwise")]
+    LeadingZeros,
+    #[cfg(feature = "bitwise")]
+    TrailingZeros,
+    #[cfg(feature = "gcd_lcm")]
+    Gcd,
+    #[cfg(feature = "gcd_lcm")]
+    Lcm {
+        saturate: bool,
+    },
+    #[cfg(feature = "is_close")]
+    IsClose {
+        rel_tol: f64,
+        abs_tol: f64,
+        nans_equal: bool,
+    },
     Negate,
     #[cfg(feature = "hist")]
     Hist {
         bin_count: Option<usize>,
         include_category: bool,
         include_breakpoint: bool,
+        density: bool,
     },
     NullCount,
     Pow(PowFunction),
@@ -161,12 +180,27 @@ pub enum FunctionExpr {
     DropNans,
     DropNulls,
     #[cfg(feature = "mode")]
-    Mode,
+    Mode {
+        maintain_order: bool,
+        ignore_nulls: bool,
+    },
+    #[cfg(feature = "mode")]
+    ModeFirst {
+        ignore_nulls: bool,
+    },
     #[cfg(feature = "moment")]
     Skew(bool),
     #[cfg(feature = "moment")]
     Kurtosis(bool, bool),
+    #[cfg(feature = "moment")]
+    Moment {
+        order: usize,
+        central: bool,
+        bias: bool,
+    },
     Reshape(Vec<i64>),
+    #[cfg(feature = "dtype-array")]
+    ReshapeArray(Vec<i64>),
     #[cfg(feature = "repeat_by")]
     RepeatBy,
     ArgUnique,
@@ -193,6 +227,7 @@ pub enum FunctionExpr {
     #[cfg(feature = "cum_agg")]
     CumCount {
         reverse: bool,
+        count_nulls: bool,
     },
     #[cfg(feature = "cum_agg")]
     CumSum {
@@ -201,6 +236,7 @@ pub enum FunctionExpr {
     #[cfg(feature = "cum_agg")]
     CumProd {
         reverse: bool,
+        strict: bool,
     },
     #[cfg(feature = "cum_agg")]
     CumMin {
@@ -214,20 +250,29 @@ pub enum FunctionExpr {
     #[cfg(feature = "dtype-struct")]
     ValueCounts {
         sort: bool,
+        descending: bool,
         parallel: bool,
+        normalize: bool,
     },
     #[cfg(feature = "unique_counts")]
     UniqueCounts,
     #[cfg(feature = "approx_unique")]
-    ApproxNUnique,
+    ApproxNUnique {
+        precision: u8,
+    },
     Coalesce,
     ShrinkType,
     #[cfg(feature = "diff")]
     Diff(i64, NullBehavior),
     #[cfg(feature = "pct_change")]
-    PctChange,
+    PctChange(Option<FillNullStrategy>),
     #[cfg(feature = "interpolate")]
-    Interpolate(InterpolationMethod),
+    Interpolate {
+        method: InterpolationMethod,
+        max_gap: Option<IdxSize>,
+    },
+    #[cfg(feature = "interpolate_by")]
+    InterpolateBy(InterpolationMethod),
     #[cfg(feature = "log")]
     Entropy {
         base: f64,
@@ -238,6 +283,8 @@ pub enum FunctionExpr {
         base: f64,
     },
     #[cfg(feature = "log")]
+    LogWithBase,
+    #[cfg(feature = "log")]
     Log1p,
     #[cfg(feature = "log")]
     Exp,
@@ -265,9 +312,9 @@ pub enum FunctionExpr {
         ddof: u8,
     },
     #[cfg(feature = "peaks")]
-    PeakMin,
+    PeakMin(PeakOptions),
     #[cfg(feature = "peaks")]
-    PeakMax,
+    PeakMax(PeakOptions),
     #[cfg(feature = "cutqcut")]
     Cut {
         breaks: Vec<f64>,
@@ -280,7 +327,7 @@ pub enum FunctionExpr {
         probs: Vec<f64>,
         labels: Option<Vec<String>>,
         left_closed: bool,
-        allow_duplicates: bool,
+        duplicates: QuantileDuplicates,
         include_breaks: bool,
     },
     #[cfg(feature = "rle")]
@@ -336,13 +383,29 @@ pub enum FunctionExpr {
     Replace {
         return_dtype: Option<DataType>,
     },
+    #[cfg(feature = "replace")]
+    ReplaceStrict {
+        return_dtype: Option<DataType>,
+    },
+    #[cfg(feature = "replace")]
+    ReplaceRanges {
+        left_closed: bool,
+        return_dtype: Option<DataType>,
+    },
     GatherEvery {
         n: usize,
         offset: usize,
     },
     #[cfg(feature = "reinterpret")]
     Reinterpret(bool),
-    ExtendConstant,
+    ExtendConstant {
+        prepend: bool,
+    },
+    ArgSortBy {
+        descending: Vec<bool>,
+        nulls_last: Vec<bool>,
+        multithreaded: bool,
+    },
 }
 
 impl Hash for FunctionExpr {
@@ -384,7 +447,12 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "diff")]
             Diff(_, null_behavior) => null_behavior.hash(state),
             #[cfg(feature = "interpolate")]
-            Interpolate(f) => f.hash(state),
+            Interpolate { method, max_gap } => {
+                method.hash(state);
+                max_gap.hash(state);
+            },
+            #[cfg(feature = "interpolate_by")]
+            InterpolateBy(f) => f.hash(state),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 lib,
@@ -398,9 +466,30 @@ impl Hash for FunctionExpr {
             MaxHorizontal | MinHorizontal | SumHorizontal | MeanHorizontal | DropNans
             | DropNulls | Reverse | ArgUnique | Shift | ShiftAndFill => {},
             #[cfg(feature = "mode")]
-            Mode => {},
+            Mode {
+                maintain_order,
+                ignore_nulls,
+            } => (maintain_order, ignore_nulls).hash(state),
+            #[cfg(feature = "mode")]
+            ModeFirst { ignore_nulls } => ignore_nulls.hash(state),
             #[cfg(feature = "abs")]
             Abs => {},
+            #[cfg(feature = "bitwise")]
+            BitCount | LeadingZeros | TrailingZeros => {},
+            #[cfg(feature = "gcd_lcm")]
+            Gcd => {},
+            #[cfg(feature = "gcd_lcm")]
+            Lcm { saturate } => saturate.hash(state),
+            #[cfg(feature = "is_close")]
+            IsClose {
+                rel_tol,
+                abs_tol,
+                nans_equal,
+            } => {
+                rel_tol.to_bits().hash(state);
+                abs_tol.to_bits().hash(state);
+                nans_equal.hash(state);
+            },
             Negate => {},
             NullCount => {},
             #[cfg(feature = "date_offset")]
@@ -427,6 +516,16 @@ impl Hash for FunctionExpr {
                 a.hash(state);
                 b.hash(state);
             },
+            #[cfg(feature = "moment")]
+            Moment {
+                order,
+                central,
+                bias,
+            } => {
+                order.hash(state);
+                central.hash(state);
+                bias.hash(state);
+            },
             #[cfg(feature = "rank")]
             Rank { options, seed } => {
                 options.hash(state);
@@ -440,28 +539,44 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "top_k")]
             TopK { sort_options } => sort_options.hash(state),
             #[cfg(feature = "cum_agg")]
-            CumCount { reverse } => reverse.hash(state),
+            CumCount {
+                reverse,
+                count_nulls,
+            } => {
+                reverse.hash(state);
+                count_nulls.hash(state);
+            },
             #[cfg(feature = "cum_agg")]
             CumSum { reverse } => reverse.hash(state),
             #[cfg(feature = "cum_agg")]
-            CumProd { reverse } => reverse.hash(state),
+            CumProd { reverse, strict } => {
+                reverse.hash(state);
+                strict.hash(state);
+            },
             #[cfg(feature = "cum_agg")]
             CumMin { reverse } => reverse.hash(state),
             #[cfg(feature = "cum_agg")]
             CumMax { reverse } => reverse.hash(state),
             #[cfg(feature = "dtype-struct")]
-            ValueCounts { sort, parallel } => {
+            ValueCounts {
+                sort,
+                descending,
+                parallel,
+                normalize,
+            } => {
                 sort.hash(state);
+                descending.hash(state);
                 parallel.hash(state);
+                normalize.hash(state);
             },
             #[cfg(feature = "unique_counts")]
             UniqueCounts => {},
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => {},
+            ApproxNUnique { precision } => precision.hash(state),
             Coalesce => {},
             ShrinkType => {},
             #[cfg(feature = "pct_change")]
-            PctChange => {},
+            PctChange(fill_null) => fill_null.hash(state),
             #[cfg(feature = "log")]
             Entropy { base, normalize } => {
                 base.to_bits().hash(state);
@@ -470,6 +585,8 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "log")]
             Log { base } => base.to_bits().hash(state),
             #[cfg(feature = "log")]
+            LogWithBase => {},
+            #[cfg(feature = "log")]
             Log1p => {},
             #[cfg(feature = "log")]
             Exp => {},
@@ -486,9 +603,10 @@ impl Hash for FunctionExpr {
             LowerBound => {},
             ConcatExpr(a) => a.hash(state),
             #[cfg(feature = "peaks")]
-            PeakMin => {},
-            #[cfg(feature = "peaks")]
-            PeakMax => {},
+            PeakMin(options) | PeakMax(options) => {
+                options.plateau.hash(state);
+                options.min_prominence.map(f64::to_bits).hash(state);
+            },
             #[cfg(feature = "cutqcut")]
             Cut {
                 breaks,
@@ -505,6 +623,10 @@ impl Hash for FunctionExpr {
             Reshape(dims) => {
                 dims.hash(state);
             },
+            #[cfg(feature = "dtype-array")]
+            ReshapeArray(dims) => {
+                dims.hash(state);
+            },
             #[cfg(feature = "repeat_by")]
             RepeatBy => {},
             #[cfg(feature = "cutqcut")]
@@ -512,14 +634,14 @@ impl Hash for FunctionExpr {
                 probs,
                 labels,
                 left_closed,
-                allow_duplicates,
+                duplicates,
                 include_breaks,
             } => {
                 let slice = bytemuck::cast_slice::<_, u64>(probs);
                 slice.hash(state);
                 labels.hash(state);
                 left_closed.hash(state);
-                allow_duplicates.hash(state);
+                duplicates.hash(state);
                 include_breaks.hash(state);
             },
             #[cfg(feature = "rle")]
@@ -545,18 +667,35 @@ impl Hash for FunctionExpr {
                 bin_count,
                 include_category,
                 include_breakpoint,
+                density,
             } => {
                 bin_count.hash(state);
                 include_category.hash(state);
                 include_breakpoint.hash(state);
+                density.hash(state);
             },
             #[cfg(feature = "replace")]
             Replace { return_dtype } => return_dtype.hash(state),
+            #[cfg(feature = "replace")]
+            ReplaceStrict { return_dtype } => return_dtype.hash(state),
+            #[cfg(feature = "replace")]
+            ReplaceRanges {
+                left_closed,
+                return_dtype,
+            } => {
+                left_closed.hash(state);
+                return_dtype.hash(state);
+            },
             FillNullWithStrategy(strategy) => strategy.hash(state),
             GatherEvery { n, offset } => (n, offset).hash(state),
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => signed.hash(state),
-            ExtendConstant => {},
+            ExtendConstant { prepend } => prepend.hash(state),
+            ArgSortBy {
+                descending,
+                nulls_last,
+                multithreaded,
+            } => (descending, nulls_last, multithreaded).hash(state),
             #[cfg(feature = "top_k")]
             TopKBy { sort_options } => sort_options.hash(state),
         }
@@ -587,6 +726,18 @@ impl Display for FunctionExpr {
             Business(func) => return write!(f, "{func}"),
             #[cfg(feature = "abs")]
             Abs => "abs",
+            #[cfg(feature = "bitwise")]
+            BitCount => "bit_count",
+            #[cfg(feature = "bitwise")]
+            LeadingZeros => "leading_zeros",
+            #[cfg(feature = "bitwise")]
+            TrailingZeros => "trailing_zeros",
+            #[cfg(feature = "gcd_lcm")]
+            Gcd => "gcd",
+            #[cfg(feature = "gcd_lcm")]
+            Lcm { .. } => "lcm",
+            #[cfg(feature = "is_close")]
+            IsClose { .. } => "is_close",
             Negate => "negate",
             NullCount => "null_count",
             Pow(func) => return write!(f, "{func}"),
@@ -613,11 +764,15 @@ impl Display for FunctionExpr {
             DropNans => "drop_nans",
             DropNulls => "drop_nulls",
             #[cfg(feature = "mode")]
-            Mode => "mode",
+            Mode { .. } => "mode",
+            #[cfg(feature = "mode")]
+            ModeFirst { .. } => "mode_first",
             #[cfg(feature = "moment")]
             Skew(_) => "skew",
             #[cfg(feature = "moment")]
             Kurtosis(..) => "kurtosis",
+            #[cfg(feature = "moment")]
+            Moment { .. } => "moment",
             ArgUnique => "arg_unique",
             #[cfg(feature = "rank")]
             Rank { .. } => "rank",
@@ -659,20 +814,24 @@ impl Display for FunctionExpr {
             UniqueCounts => "unique_counts",
             Reverse => "reverse",
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => "approx_n_unique",
+            ApproxNUnique { .. } => "approx_n_unique",
             Coalesce => "coalesce",
             ShrinkType => "shrink_dtype",
             #[cfg(feature = "diff")]
             Diff(_, _) => "diff",
             #[cfg(feature = "pct_change")]
-            PctChange => "pct_change",
+            PctChange(_) => "pct_change",
             #[cfg(feature = "interpolate")]
-            Interpolate(_) => "interpolate",
+            Interpolate { .. } => "interpolate",
+            #[cfg(feature = "interpolate_by")]
+            InterpolateBy(_) => "interpolate_by",
             #[cfg(feature = "log")]
             Entropy { .. } => "entropy",
             #[cfg(feature = "log")]
             Log { .. } => "log",
             #[cfg(feature = "log")]
+            LogWithBase => "log",
+            #[cfg(feature = "log")]
             Log1p => "log1p",
             #[cfg(feature = "log")]
             Exp => "exp",
@@ -699,14 +858,16 @@ impl Display for FunctionExpr {
             #[cfg(feature = "cov")]
             Correlation { method, .. } => return Display::fmt(method, f),
             #[cfg(feature = "peaks")]
-            PeakMin => "peak_min",
+            PeakMin(_) => "peak_min",
             #[cfg(feature = "peaks")]
-            PeakMax => "peak_max",
+            PeakMax(_) => "peak_max",
             #[cfg(feature = "cutqcut")]
             Cut { .. } => "cut",
             #[cfg(feature = "cutqcut")]
             QCut { .. } => "qcut",
             Reshape(_) => "reshape",
+            #[cfg(feature = "dtype-array")]
+            ReshapeArray(_) => "reshape",
             #[cfg(feature = "repeat_by")]
             RepeatBy => "repeat_by",
             #[cfg(feature = "rle")]
@@ -737,11 +898,16 @@ impl Display for FunctionExpr {
             Hist { .. } => "hist",
             #[cfg(feature = "replace")]
             Replace { .. } => "replace",
+            #[cfg(feature = "replace")]
+            ReplaceStrict { .. } => "replace_strict",
+            #[cfg(feature = "replace")]
+            ReplaceRanges { .. } => "replace_ranges",
             FillNullWithStrategy(_) => "fill_null_with_strategy",
             GatherEvery { .. } => "gather_every",
             #[cfg(feature = "reinterpret")]
             Reinterpret(_) => "reinterpret",
-            ExtendConstant => "extend_constant",
+            ExtendConstant { .. } => "extend_constant",
+            ArgSortBy { .. } => "arg_sort_by",
         };
         write!(f, "{s}")
     }
@@ -853,6 +1019,22 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Business(func) => func.into(),
             #[cfg(feature = "abs")]
             Abs => map!(abs::abs),
+            #[cfg(feature = "bitwise")]
+            BitCount => map!(polars_ops::prelude::bit_count),
+            #[cfg(feature = "bitwise")]
+            LeadingZeros => map!(polars_ops::prelude::leading_zeros),
+            #[cfg(feature = "bitwise")]
+            TrailingZeros => map!(polars_ops::prelude::trailing_zeros),
+            #[cfg(feature = "gcd_lcm")]
+            Gcd => map_as_slice!(dispatch::gcd),
+            #[cfg(feature = "gcd_lcm")]
+            Lcm { saturate } => map_as_slice!(dispatch::lcm, saturate),
+            #[cfg(feature = "is_close")]
+            IsClose {
+                rel_tol,
+                abs_tol,
+                nans_equal,
+            } => map_as_slice!(dispatch::is_close, rel_tol, abs_tol, nans_equal),
             Negate => map!(dispatch::negate),
             NullCount => {
                 let f = |s: &mut [Series]| {
@@ -923,7 +1105,17 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                     Std(options) => map!(rolling::rolling_std, options.clone()),
                     StdBy(options) => map_as_slice!(rolling::rolling_std_by, options.clone()),
                     #[cfg(feature = "moment")]
-                    Skew(window_size, bias) => map!(rolling::rolling_skew, window_size, bias),
+                    Skew(window_size, min_periods, bias) => {
+                        map!(rolling::rolling_skew, window_size, min_periods, bias)
+                    },
+                    #[cfg(feature = "moment")]
+                    Kurtosis(window_size, min_periods, fisher, bias) => map!(
+                        rolling::rolling_kurtosis,
+                        window_size,
+                        min_periods,
+                        fisher,
+                        bias
+                    ),
                 }
             },
             #[cfg(feature = "hist")]
@@ -931,12 +1123,14 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                 bin_count,
                 include_category,
                 include_breakpoint,
+                density,
             } => {
                 map_as_slice!(
                     dispatch::hist,
                     bin_count,
                     include_category,
-                    include_breakpoint
+                    include_breakpoint,
+                    density
                 )
             },
             ShiftAndFill => {
@@ -949,11 +1143,22 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                 map_as_slice!(clip::clip, has_min, has_max)
             },
             #[cfg(feature = "mode")]
-            Mode => map!(dispatch::mode),
+            Mode {
+                maintain_order,
+                ignore_nulls,
+            } => map!(dispatch::mode, maintain_order, ignore_nulls),
+            #[cfg(feature = "mode")]
+            ModeFirst { ignore_nulls } => map!(dispatch::mode_first, ignore_nulls),
             #[cfg(feature = "moment")]
             Skew(bias) => map!(dispatch::skew, bias),
             #[cfg(feature = "moment")]
             Kurtosis(fisher, bias) => map!(dispatch::kurtosis, fisher, bias),
+            #[cfg(feature = "moment")]
+            Moment {
+                order,
+                central,
+                bias,
+            } => map!(dispatch::moment, order, central, bias),
             ArgUnique => map!(dispatch::arg_unique),
             #[cfg(feature = "rank")]
             Rank { options, seed } => map!(dispatch::rank, options, seed),
@@ -969,37 +1174,57 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             TopKBy { sort_options } => map_as_slice!(top_k_by, sort_options.clone()),
             Shift => map_as_slice!(shift_and_fill::shift),
             #[cfg(feature = "cum_agg")]
-            CumCount { reverse } => map!(cum::cum_count, reverse),
+            CumCount {
+                reverse,
+                count_nulls,
+            } => map!(cum::cum_count, reverse, count_nulls),
             #[cfg(feature = "cum_agg")]
             CumSum { reverse } => map!(cum::cum_sum, reverse),
             #[cfg(feature = "cum_agg")]
-            CumProd { reverse } => map!(cum::cum_prod, reverse),
+            CumProd { reverse, strict } => map!(cum::cum_prod, reverse, strict),
             #[cfg(feature = "cum_agg")]
             CumMin { reverse } => map!(cum::cum_min, reverse),
             #[cfg(feature = "cum_agg")]
             CumMax { reverse } => map!(cum::cum_max, reverse),
             #[cfg(feature = "dtype-struct")]
-            ValueCounts { sort, parallel } => map!(dispatch::value_counts, sort, parallel),
+            ValueCounts {
+                sort,
+                descending,
+                parallel,
+                normalize,
+            } => map!(
+                dispatch::value_counts,
+                sort,
+                descending,
+                parallel,
+                normalize
+            ),
             #[cfg(feature = "unique_counts")]
             UniqueCounts => map!(dispatch::unique_counts),
             Reverse => map!(dispatch::reverse),
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => map!(dispatch::approx_n_unique),
+            ApproxNUnique { precision } => map!(dispatch::approx_n_unique, precision),
             Coalesce => map_as_slice!(fill_null::coalesce),
             ShrinkType => map_owned!(shrink_type::shrink),
             #[cfg(feature = "diff")]
             Diff(n, null_behavior) => map!(dispatch::diff, n, null_behavior),
             #[cfg(feature = "pct_change")]
-            PctChange => map_as_slice!(dispatch::pct_change),
+            PctChange(fill_null) => map_as_slice!(dispatch::pct_change, fill_null),
             #[cfg(feature = "interpolate")]
-            Interpolate(method) => {
-                map!(dispatch::interpolate, method)
+            Interpolate { method, max_gap } => {
+                map!(dispatch::interpolate, method, max_gap)
+            },
+            #[cfg(feature = "interpolate_by")]
+            InterpolateBy(method) => {
+                map_as_slice!(dispatch::interpolate_by, method)
             },
             #[cfg(feature = "log")]
             Entropy { base, normalize } => map!(log::entropy, base, normalize),
             #[cfg(feature = "log")]
             Log { base } => map!(log::log, base),
             #[cfg(feature = "log")]
+            LogWithBase => map_as_slice!(log::log_with_base),
+            #[cfg(feature = "log")]
             Log1p => map!(log::log1p),
             #[cfg(feature = "log")]
             Exp => map!(log::exp),
@@ -1020,12 +1245,14 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "cov")]
             Correlation { method, ddof } => map_as_slice!(correlation::corr, ddof, method),
             #[cfg(feature = "peaks")]
-            PeakMin => map!(peaks::peak_min),
+            PeakMin(options) => map!(peaks::peak_min, options),
             #[cfg(feature = "peaks")]
-            PeakMax => map!(peaks::peak_max),
+            PeakMax(options) => map!(peaks::peak_max, options),
             #[cfg(feature = "repeat_by")]
             RepeatBy => map_as_slice!(dispatch::repeat_by),
             Reshape(dims) => map!(dispatch::reshape, dims.clone()),
+            #[cfg(feature = "dtype-array")]
+            ReshapeArray(dims) => map!(dispatch::reshape_array, dims.clone()),
             #[cfg(feature = "cutqcut")]
             Cut {
                 breaks,
@@ -1044,14 +1271,14 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                 probs,
                 labels,
                 left_closed,
-                allow_duplicates,
+                duplicates,
                 include_breaks,
             } => map!(
                 qcut,
                 probs.clone(),
                 labels.clone(),
                 left_closed,
-                allow_duplicates,
+                duplicates,
                 include_breaks
             ),
             #[cfg(feature = "rle")]
@@ -1112,11 +1339,32 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Replace { return_dtype } => {
                 map_as_slice!(dispatch::replace, return_dtype.clone())
             },
+            #[cfg(feature = "replace")]
+            ReplaceStrict { return_dtype } => {
+                map_as_slice!(dispatch::replace_strict, return_dtype.clone())
+            },
+            #[cfg(feature = "replace")]
+            ReplaceRanges {
+                left_closed,
+                return_dtype,
+            } => {
+                map_as_slice!(dispatch::replace_ranges, left_closed, return_dtype.clone())
+            },
             FillNullWithStrategy(strategy) => map!(dispatch::fill_null_with_strategy, strategy),
             GatherEvery { n, offset } => map!(dispatch::gather_every, n, offset),
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => map!(dispatch::reinterpret, signed),
-            ExtendConstant => map_as_slice!(dispatch::extend_constant),
+            ExtendConstant { prepend } => map_as_slice!(dispatch::extend_constant, prepend),
+            ArgSortBy {
+                descending,
+                nulls_last,
+                multithreaded,
+            } => map_as_slice!(
+                dispatch::arg_sort_by,
+                descending.as_slice(),
+                nulls_last.as_slice(),
+                multithreaded
+            ),
         }
     }
 }
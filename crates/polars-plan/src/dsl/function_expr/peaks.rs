@@ -3,32 +3,54 @@ use polars_ops::chunked_array::peaks::{peak_max as pmax, peak_min as pmin};
 
 use super::*;
 
-pub(super) fn peak_min(s: &Series) -> PolarsResult<Series> {
+/// Validate `options` and express the prominence in units of the physical representation.
+fn physical_options(dtype: &DataType, options: PeakOptions) -> PolarsResult<PeakOptions> {
+    let Some(min_prominence) = options.min_prominence else {
+        return Ok(options);
+    };
+    polars_ensure!(
+        min_prominence >= 0.0,
+        ComputeError: "`min_prominence` must be non-negative, got {}", min_prominence
+    );
+    let min_prominence = match dtype {
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(_, Some(scale)) => min_prominence * 10f64.powi(*scale as i32),
+        _ => min_prominence,
+    };
+    Ok(PeakOptions {
+        min_prominence: Some(min_prominence),
+        ..options
+    })
+}
+
+pub(super) fn peak_min(s: &Series, options: PeakOptions) -> PolarsResult<Series> {
+    let options = physical_options(s.dtype(), options)?;
     let s = s.to_physical_repr();
     let s = match s.dtype() {
         DataType::Boolean => polars_bail!(opq = peak_min, DataType::Boolean),
         #[cfg(feature = "dtype-decimal")]
-        DataType::Decimal(_, _) => pmin(s.decimal()?).into_series(),
+        DataType::Decimal(_, _) => pmin(s.decimal()?, options).into_series(),
         dt => {
             with_match_physical_numeric_polars_type!(dt, |$T| {
                 let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
-                pmin(ca).into_series()
+                pmin(ca, options).into_series()
             })
         },
     };
     Ok(s)
 }
 
-pub(super) fn peak_max(s: &Series) -> PolarsResult<Series> {
+pub(super) fn peak_max(s: &Series, options: PeakOptions) -> PolarsResult<Series> {
+    let options = physical_options(s.dtype(), options)?;
     let s = s.to_physical_repr();
     let s = match s.dtype() {
         DataType::Boolean => polars_bail!(opq = peak_max, DataType::Boolean),
         #[cfg(feature = "dtype-decimal")]
-        DataType::Decimal(_, _) => pmax(s.decimal()?).into_series(),
+        DataType::Decimal(_, _) => pmax(s.decimal()?, options).into_series(),
         dt => {
             with_match_physical_numeric_polars_type!(dt, |$T| {
                 let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
-                pmax(ca).into_series()
+                pmax(ca, options).into_series()
             })
         },
     };
@@ -20,7 +20,9 @@ pub enum RollingFunction {
     Std(RollingOptions),
     StdBy(RollingOptions),
     #[cfg(feature = "moment")]
-    Skew(usize, bool),
+    Skew(usize, usize, bool),
+    #[cfg(feature = "moment")]
+    Kurtosis(usize, usize, bool, bool),
 }
 
 impl Display for RollingFunction {
@@ -44,6 +46,8 @@ impl Display for RollingFunction {
             StdBy(_) => "rolling_std_by",
             #[cfg(feature = "moment")]
             Skew(..) => "rolling_skew",
+            #[cfg(feature = "moment")]
+            Kurtosis(..) => "rolling_kurtosis",
         };
 
         write!(f, "{name}")
@@ -57,8 +61,16 @@ impl Hash for RollingFunction {
         std::mem::discriminant(self).hash(state);
         match self {
             #[cfg(feature = "moment")]
-            Skew(window_size, bias) => {
+            Skew(window_size, min_periods, bias) => {
                 window_size.hash(state);
+                min_periods.hash(state);
+                bias.hash(state)
+            },
+            #[cfg(feature = "moment")]
+            Kurtosis(window_size, min_periods, fisher, bias) => {
+                window_size.hash(state);
+                min_periods.hash(state);
+                fisher.hash(state);
                 bias.hash(state)
             },
             _ => {},
@@ -190,6 +202,22 @@ pub(super) fn rolling_std_by(s: &[Series], options: RollingOptions) -> PolarsRes
 }
 
 #[cfg(feature = "moment")]
-pub(super) fn rolling_skew(s: &Series, window_size: usize, bias: bool) -> PolarsResult<Series> {
-    s.rolling_skew(window_size, bias)
+pub(super) fn rolling_skew(
+    s: &Series,
+    window_size: usize,
+    min_periods: usize,
+    bias: bool,
+) -> PolarsResult<Series> {
+    s.rolling_skew(window_size, min_periods, bias)
+}
+
+#[cfg(feature = "moment")]
+pub(super) fn rolling_kurtosis(
+    s: &Series,
+    window_size: usize,
+    min_periods: usize,
+    fisher: bool,
+    bias: bool,
+) -> PolarsResult<Series> {
+    s.rolling_kurtosis(window_size, min_periods, fisher, bias)
 }
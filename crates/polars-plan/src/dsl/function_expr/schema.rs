@@ -31,11 +31,28 @@ impl FunctionExpr {
             Boolean(func) => func.get_field(mapper),
             #[cfg(feature = "business")]
             Business(func) => match func {
-                BusinessFunction::BusinessDayCount { .. } => mapper.with_dtype(DataType::Int32),
+                BusinessFunction::BusinessDayCount { output_dtype, .. } => {
+                    mapper.with_dtype(output_dtype.clone().unwrap_or(DataType::Int32))
+                },
                 BusinessFunction::AddBusinessDay { .. } => mapper.with_same_dtype(),
+                BusinessFunction::OffsetByBusiness { .. } => mapper.with_same_dtype(),
+                BusinessFunction::IsBusinessDay { .. } => mapper.with_dtype(DataType::Boolean),
+                BusinessFunction::NthBusinessDay { .. } => mapper.with_dtype(DataType::Date),
+                BusinessFunction::RollBusinessDay { .. } => mapper.with_dtype(DataType::Date),
+                BusinessFunction::BusinessDaysInMonth { .. } => mapper.with_dtype(DataType::Int32),
+                BusinessFunction::RollingBusinessDayCount { .. } => {
+                    mapper.with_dtype(DataType::Int32)
+                },
+                BusinessFunction::BusinessDateRange { .. } => mapper.with_dtype(DataType::Date),
             },
             #[cfg(feature = "abs")]
             Abs => mapper.with_same_dtype(),
+            #[cfg(feature = "bitwise")]
+            BitCount | LeadingZeros | TrailingZeros => mapper.with_dtype(DataType::UInt32),
+            #[cfg(feature = "gcd_lcm")]
+            Gcd | Lcm { .. } => mapper.map_to_supertype(),
+            #[cfg(feature = "is_close")]
+            IsClose { .. } => mapper.with_dtype(DataType::Boolean),
             Negate => mapper.with_same_dtype(),
             NullCount => mapper.with_dtype(IDX_DTYPE),
             Pow(pow_function) => match pow_function {
@@ -70,7 +87,7 @@ impl FunctionExpr {
                     Mean(_) | MeanBy(_) | Quantile(_) | QuantileBy(_) | Var(_) | VarBy(_)
                     | Std(_) | StdBy(_) => mapper.map_to_float_dtype(),
                     #[cfg(feature = "moment")]
-                    Skew(..) => mapper.map_to_float_dtype(),
+                    Skew(..) | Kurtosis(..) => mapper.map_to_float_dtype(),
                 }
             },
             ShiftAndFill => mapper.with_same_dtype(),
@@ -79,11 +96,13 @@ impl FunctionExpr {
             #[cfg(feature = "round_series")]
             Clip { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "mode")]
-            Mode => mapper.with_same_dtype(),
+            Mode { .. } | ModeFirst { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "moment")]
             Skew(_) => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "moment")]
             Kurtosis(..) => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "moment")]
+            Moment { .. } => mapper.with_dtype(DataType::Float64),
             ArgUnique => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "rank")]
             Rank { options, .. } => mapper.with_dtype(match options.method {
@@ -100,10 +119,15 @@ impl FunctionExpr {
             #[cfg(feature = "top_k")]
             TopKBy { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-struct")]
-            ValueCounts { .. } => mapper.map_dtype(|dt| {
+            ValueCounts { normalize, .. } => mapper.map_dtype(|dt| {
+                let count = if *normalize {
+                    Field::new("proportion", DataType::Float64)
+                } else {
+                    Field::new("count", IDX_DTYPE)
+                };
                 DataType::Struct(vec![
                     Field::new(fields[0].name().as_str(), dt.clone()),
-                    Field::new("count", IDX_DTYPE),
+                    count,
                 ])
             }),
             #[cfg(feature = "unique_counts")]
@@ -120,15 +144,16 @@ impl FunctionExpr {
             #[cfg(feature = "cum_agg")]
             CumMax { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => mapper.with_dtype(IDX_DTYPE),
+            ApproxNUnique { .. } => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "hist")]
             Hist {
                 include_category,
                 include_breakpoint,
+                density,
                 ..
             } => {
-                if *include_breakpoint || *include_category {
-                    let mut fields = Vec::with_capacity(3);
+                if *include_breakpoint || *include_category || *density {
+                    let mut fields = Vec::with_capacity(4);
                     if *include_breakpoint {
                         fields.push(Field::new("break_point", DataType::Float64));
                     }
@@ -139,6 +164,9 @@ impl FunctionExpr {
                         ));
                     }
                     fields.push(Field::new("count", IDX_DTYPE));
+                    if *density {
+                        fields.push(Field::new("density", DataType::Float64));
+                    }
                     mapper.with_dtype(DataType::Struct(fields))
                 } else {
                     mapper.with_dtype(IDX_DTYPE)
@@ -158,12 +186,17 @@ impl FunctionExpr {
                 dt => dt.clone(),
             }),
             #[cfg(feature = "pct_change")]
-            PctChange => mapper.map_dtype(|dt| match dt {
+            PctChange(_) => mapper.map_dtype(|dt| match dt {
                 DataType::Float64 | DataType::Float32 => dt.clone(),
                 _ => DataType::Float64,
             }),
             #[cfg(feature = "interpolate")]
-            Interpolate(method) => match method {
+            Interpolate { method, .. } => match method {
+                InterpolationMethod::Linear => mapper.map_numeric_to_float_dtype(),
+                InterpolationMethod::Nearest => mapper.with_same_dtype(),
+            },
+            #[cfg(feature = "interpolate_by")]
+            InterpolateBy(method) => match method {
                 InterpolationMethod::Linear => mapper.map_numeric_to_float_dtype(),
                 InterpolationMethod::Nearest => mapper.with_same_dtype(),
             },
@@ -192,6 +225,8 @@ impl FunctionExpr {
             },
             #[cfg(feature = "log")]
             Entropy { .. } | Log { .. } | Log1p | Exp => mapper.map_to_float_dtype(),
+            #[cfg(feature = "log")]
+            LogWithBase => mapper.with_dtype(DataType::Float64),
             Unique(_) => mapper.with_same_dtype(),
             #[cfg(feature = "round_series")]
             Round { .. } | RoundSF { .. } | Floor | Ceil => mapper.with_same_dtype(),
@@ -202,14 +237,12 @@ impl FunctionExpr {
             #[cfg(feature = "cov")]
             Correlation { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "peaks")]
-            PeakMin => mapper.with_same_dtype(),
-            #[cfg(feature = "peaks")]
-            PeakMax => mapper.with_same_dtype(),
+            PeakMin(_) | PeakMax(_) => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "cutqcut")]
             Cut {
                 include_breaks: false,
                 ..
-            } => mapper.with_dtype(DataType::Categorical(None, Default::default())),
+            } => mapper.with_dtype(DataType::Categorical(None, CategoricalOrdering::Physical)),
             #[cfg(feature = "cutqcut")]
             Cut {
                 include_breaks: true,
@@ -221,7 +254,7 @@ impl FunctionExpr {
                     Field::new("brk", DataType::Float64),
                     Field::new(
                         name_bin.as_str(),
-                        DataType::Categorical(None, Default::default()),
+                        DataType::Categorical(None, CategoricalOrdering::Physical),
                     ),
                 ]);
                 mapper.with_dtype(struct_dt)
@@ -230,17 +263,22 @@ impl FunctionExpr {
             RepeatBy => mapper.map_dtype(|dt| DataType::List(dt.clone().into())),
             Reshape(dims) => mapper.map_dtype(|dt| {
                 let dtype = dt.inner_dtype().unwrap_or(dt).clone();
-                if dims.len() == 1 {
-                    dtype
-                } else {
-                    DataType::List(Box::new(dtype))
-                }
+                dims.iter()
+                    .skip(1)
+                    .fold(dtype, |dtype, _| DataType::List(Box::new(dtype)))
+            }),
+            #[cfg(feature = "dtype-array")]
+            ReshapeArray(dims) => mapper.map_dtype(|dt| {
+                let dtype = dt.inner_dtype().unwrap_or(dt).clone();
+                dims.iter().skip(1).rev().fold(dtype, |dtype, width| {
+                    DataType::Array(Box::new(dtype), *width as usize)
+                })
             }),
             #[cfg(feature = "cutqcut")]
             QCut {
                 include_breaks: false,
                 ..
-            } => mapper.with_dtype(DataType::Categorical(None, Default::default())),
+            } => mapper.with_dtype(DataType::Categorical(None, CategoricalOrdering::Physical)),
             #[cfg(feature = "cutqcut")]
             QCut {
                 include_breaks: true,
@@ -252,7 +290,7 @@ impl FunctionExpr {
                     Field::new("brk", DataType::Float64),
                     Field::new(
                         name_bin.as_str(),
-                        DataType::Categorical(None, Default::default()),
+                        DataType::Categorical(None, CategoricalOrdering::Physical),
                     ),
                 ]);
                 mapper.with_dtype(struct_dt)
@@ -260,8 +298,8 @@ impl FunctionExpr {
             #[cfg(feature = "rle")]
             RLE => mapper.map_dtype(|dt| {
                 DataType::Struct(vec![
-                    Field::new("lengths", DataType::Int32),
-                    Field::new("values", dt.clone()),
+                    Field::new("len", DataType::UInt32),
+                    Field::new("value", dt.clone()),
                 ])
             }),
             #[cfg(feature = "rle")]
@@ -298,6 +336,10 @@ impl FunctionExpr {
             EwmVar { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "replace")]
             Replace { return_dtype } => mapper.replace_dtype(return_dtype.clone()),
+            #[cfg(feature = "replace")]
+            ReplaceStrict { return_dtype } => mapper.replace_strict_dtype(return_dtype.clone()),
+            #[cfg(feature = "replace")]
+            ReplaceRanges { return_dtype, .. } => mapper.replace_strict_dtype(return_dtype.clone()),
             FillNullWithStrategy(_) => mapper.with_same_dtype(),
             GatherEvery { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "reinterpret")]
@@ -309,7 +351,8 @@ impl FunctionExpr {
                 };
                 mapper.with_dtype(dt)
             },
-            ExtendConstant => mapper.with_same_dtype(),
+            ExtendConstant { .. } => mapper.with_same_dtype(),
+            ArgSortBy { .. } => mapper.with_dtype(IDX_DTYPE),
         }
     }
 
@@ -521,6 +564,19 @@ impl<'a> FieldsMapper<'a> {
         };
         self.with_dtype(dtype)
     }
+
+    #[cfg(feature = "replace")]
+    pub fn replace_strict_dtype(&self, return_dtype: Option<DataType>) -> PolarsResult<Field> {
+        let dtype = match (return_dtype, self.fields.get(3)) {
+            (Some(dtype), _) => dtype,
+            // Supertype of `new` and `default`
+            (None, Some(default)) => {
+                try_get_supertype(self.fields[2].data_type(), default.data_type())?
+            },
+            (None, None) => self.fields[2].data_type().clone(),
+        };
+        self.with_dtype(dtype)
+    }
 }
 
 pub(crate) fn args_to_supertype<D: AsRef<DataType>>(dtypes: &[D]) -> PolarsResult<DataType> {
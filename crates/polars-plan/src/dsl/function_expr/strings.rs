@@ -39,6 +39,8 @@ pub enum StringFunction {
     },
     CountMatches(bool),
     EndsWith,
+    #[cfg(feature = "regex")]
+    EscapeRegex,
     Explode,
     Extract(usize),
     ExtractAll,
@@ -71,8 +73,16 @@ pub enum StringFunction {
         n: i64,
         literal: bool,
     },
+    #[cfg(feature = "string_normalize")]
+    Normalize {
+        form: UnicodeForm,
+    },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_distance")]
+    Levenshtein,
+    #[cfg(feature = "string_distance")]
+    JaroWinkler,
     #[cfg(feature = "string_pad")]
     PadStart {
         length: usize,
@@ -84,6 +94,7 @@ pub enum StringFunction {
         fill_char: char,
     },
     Slice,
+    SliceBytes,
     Head,
     Tail,
     #[cfg(feature = "string_encoding")]
@@ -110,9 +121,12 @@ pub enum StringFunction {
     #[cfg(feature = "temporal")]
     Strptime(DataType, StrptimeOptions),
     Split(bool),
+    #[cfg(feature = "regex")]
+    SplitRegex {
+        inclusive: bool,
+    },
     #[cfg(feature = "dtype-decimal")]
     ToDecimal(usize),
-    #[cfg(feature = "nightly")]
     Titlecase,
     Uppercase,
     #[cfg(feature = "string_pad")]
@@ -137,6 +151,8 @@ impl StringFunction {
             Contains { .. } => mapper.with_dtype(DataType::Boolean),
             CountMatches(_) => mapper.with_dtype(DataType::UInt32),
             EndsWith | StartsWith => mapper.with_dtype(DataType::Boolean),
+            #[cfg(feature = "regex")]
+            EscapeRegex => mapper.with_same_dtype(),
             Explode => mapper.with_same_dtype(),
             Extract(_) => mapper.with_same_dtype(),
             ExtractAll => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
@@ -154,12 +170,19 @@ impl StringFunction {
             LenChars => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "regex")]
             Replace { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "string_normalize")]
+            Normalize { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "string_distance")]
+            Levenshtein => mapper.with_dtype(DataType::UInt32),
+            #[cfg(feature = "string_distance")]
+            JaroWinkler => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "temporal")]
             Strptime(dtype, _) => mapper.with_dtype(dtype.clone()),
             Split(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
-            #[cfg(feature = "nightly")]
+            #[cfg(feature = "regex")]
+            SplitRegex { .. } => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
             Titlecase => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(_) => mapper.with_dtype(DataType::Decimal(None, None)),
@@ -172,7 +195,7 @@ impl StringFunction {
             #[cfg(feature = "binary_encoding")]
             Base64Decode(_) => mapper.with_dtype(DataType::Binary),
             Uppercase | Lowercase | StripChars | StripCharsStart | StripCharsEnd | StripPrefix
-            | StripSuffix | Slice | Head | Tail => mapper.with_same_dtype(),
+            | StripSuffix | Slice | SliceBytes | Head | Tail => mapper.with_same_dtype(),
             #[cfg(feature = "string_pad")]
             PadStart { .. } | PadEnd { .. } | ZFill => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-struct")]
@@ -203,6 +226,8 @@ impl Display for StringFunction {
             Contains { .. } => "contains",
             CountMatches(_) => "count_matches",
             EndsWith { .. } => "ends_with",
+            #[cfg(feature = "regex")]
+            EscapeRegex => "escape_regex",
             Extract(_) => "extract",
             #[cfg(feature = "concat_str")]
             ConcatHorizontal { .. } => "concat_horizontal",
@@ -231,8 +256,14 @@ impl Display for StringFunction {
             PadStart { .. } => "pad_start",
             #[cfg(feature = "regex")]
             Replace { .. } => "replace",
+            #[cfg(feature = "string_normalize")]
+            Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_distance")]
+            Levenshtein => "levenshtein",
+            #[cfg(feature = "string_distance")]
+            JaroWinkler => "jaro_winkler",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -242,6 +273,7 @@ impl Display for StringFunction {
             #[cfg(feature = "binary_encoding")]
             Base64Decode(_) => "base64_decode",
             Slice => "slice",
+            SliceBytes => "slice_bytes",
             StartsWith { .. } => "starts_with",
             StripChars => "strip_chars",
             StripCharsStart => "strip_chars_start",
@@ -267,7 +299,14 @@ impl Display for StringFunction {
                     "split"
                 }
             },
-            #[cfg(feature = "nightly")]
+            #[cfg(feature = "regex")]
+            SplitRegex { inclusive } => {
+                if *inclusive {
+                    "split_regex_inclusive"
+                } else {
+                    "split_regex"
+                }
+            },
             Titlecase => "titlecase",
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(_) => "to_decimal",
@@ -293,6 +332,8 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
                 map_as_slice!(strings::count_matches, literal)
             },
             EndsWith { .. } => map_as_slice!(strings::ends_with),
+            #[cfg(feature = "regex")]
+            EscapeRegex => map!(strings::escape_regex),
             StartsWith { .. } => map_as_slice!(strings::starts_with),
             Extract(group_index) => map_as_slice!(strings::extract, group_index),
             ExtractAll => {
@@ -325,6 +366,10 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Split(inclusive) => {
                 map_as_slice!(strings::split, inclusive)
             },
+            #[cfg(feature = "regex")]
+            SplitRegex { inclusive } => {
+                map_as_slice!(strings::split_regex, inclusive)
+            },
             #[cfg(feature = "dtype-struct")]
             SplitExact { n, inclusive } => map_as_slice!(strings::split_exact, n, inclusive),
             #[cfg(feature = "dtype-struct")]
@@ -341,11 +386,16 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             } => map_as_slice!(strings::concat_hor, &delimiter, ignore_nulls),
             #[cfg(feature = "regex")]
             Replace { n, literal } => map_as_slice!(strings::replace, literal, n),
+            #[cfg(feature = "string_normalize")]
+            Normalize { form } => map!(strings::normalize, form),
             #[cfg(feature = "string_reverse")]
             Reverse => map!(strings::reverse),
+            #[cfg(feature = "string_distance")]
+            Levenshtein => map_as_slice!(strings::levenshtein),
+            #[cfg(feature = "string_distance")]
+            JaroWinkler => map_as_slice!(strings::jaro_winkler),
             Uppercase => map!(uppercase),
             Lowercase => map!(lowercase),
-            #[cfg(feature = "nightly")]
             Titlecase => map!(strings::titlecase),
             StripChars => map_as_slice!(strings::strip_chars),
             StripCharsStart => map_as_slice!(strings::strip_chars_start),
@@ -355,6 +405,7 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "string_to_integer")]
             ToInteger(strict) => map_as_slice!(strings::to_integer, strict),
             Slice => map_as_slice!(strings::str_slice),
+            SliceBytes => map_as_slice!(strings::str_slice_bytes),
             Head => map_as_slice!(strings::str_head),
             Tail => map_as_slice!(strings::str_tail),
             #[cfg(feature = "string_encoding")]
@@ -423,7 +474,6 @@ fn lowercase(s: &Series) -> PolarsResult<Series> {
     Ok(ca.to_lowercase().into_series())
 }
 
-#[cfg(feature = "nightly")]
 pub(super) fn titlecase(s: &Series) -> PolarsResult<Series> {
     let ca = s.str()?;
     Ok(ca.to_titlecase().into_series())
@@ -455,6 +505,12 @@ pub(super) fn find(s: &[Series], literal: bool, strict: bool) -> PolarsResult<Se
         .map(|ok| ok.into_series())
 }
 
+#[cfg(feature = "regex")]
+pub(super) fn escape_regex(s: &Series) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(ca.str_escape_regex().into_series())
+}
+
 pub(super) fn ends_with(s: &[Series]) -> PolarsResult<Series> {
     let ca = &s[0].str()?.as_binary();
     let suffix = &s[1].str()?.as_binary();
@@ -623,6 +679,14 @@ pub(super) fn split(s: &[Series], inclusive: bool) -> PolarsResult<Series> {
     }
 }
 
+#[cfg(feature = "regex")]
+pub(super) fn split_regex(s: &[Series], inclusive: bool) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let by = s[1].str()?;
+
+    ca.split_regex(by, inclusive).map(|ca| ca.into_series())
+}
+
 #[cfg(feature = "dtype-date")]
 fn to_date(s: &Series, options: &StrptimeOptions) -> PolarsResult<Series> {
     let ca = s.str()?;
@@ -895,12 +959,30 @@ pub(super) fn replace(s: &[Series], literal: bool, n: i64) -> PolarsResult<Serie
     .map(|ca| ca.into_series())
 }
 
+#[cfg(feature = "string_normalize")]
+pub(super) fn normalize(s: &Series, form: UnicodeForm) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(ca.str_normalize(form).into_series())
+}
+
 #[cfg(feature = "string_reverse")]
 pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
     let ca = s.str()?;
     Ok(ca.str_reverse().into_series())
 }
 
+#[cfg(feature = "string_distance")]
+pub(super) fn levenshtein(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::chunked_array::strings::str_levenshtein(s[0].str()?, s[1].str()?)
+        .map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "string_distance")]
+pub(super) fn jaro_winkler(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::chunked_array::strings::str_jaro_winkler(s[0].str()?, s[1].str()?)
+        .map(|ca| ca.into_series())
+}
+
 #[cfg(feature = "string_to_integer")]
 pub(super) fn to_integer(s: &[Series], strict: bool) -> PolarsResult<Series> {
     let ca = s[0].str()?;
@@ -932,6 +1014,17 @@ pub(super) fn str_slice(s: &[Series]) -> PolarsResult<Series> {
     Ok(ca.str_slice(offset, length)?.into_series())
 }
 
+pub(super) fn str_slice_bytes(s: &[Series]) -> PolarsResult<Series> {
+    polars_ensure!(
+        _ensure_lengths(s),
+        ComputeError: "all series in `str_slice_bytes` should have equal or unit length",
+    );
+    let ca = s[0].str()?;
+    let offset = &s[1];
+    let length = &s[2];
+    Ok(ca.str_slice_bytes(offset, length)?.into_series())
+}
+
 pub(super) fn str_head(s: &[Series]) -> PolarsResult<Series> {
     polars_ensure!(
         _ensure_lengths(s),
@@ -19,7 +19,7 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             IsoYear => map!(datetime::iso_year),
             Month => map!(datetime::month),
             Quarter => map!(datetime::quarter),
-            Week => map!(datetime::week),
+            Week { iso } => map!(datetime::week, iso),
             WeekDay => map!(datetime::weekday),
             Duration(tu) => map_as_slice!(datetime::duration, tu),
             Day => map!(datetime::day),
@@ -62,7 +62,7 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             ReplaceTimeZone(tz, non_existent) => {
                 map_as_slice!(dispatch::replace_time_zone, tz.as_deref(), non_existent)
             },
-            Combine(tu) => map_as_slice!(temporal::combine, tu),
+            Combine(tu, tz) => map_as_slice!(temporal::combine, tu, tz.as_deref()),
             DatetimeFunction {
                 time_unit,
                 time_zone,
@@ -294,19 +294,41 @@ pub(super) fn date_offset(s: &[Series]) -> PolarsResult<Series> {
     }
 }
 
-pub(super) fn combine(s: &[Series], tu: TimeUnit) -> PolarsResult<Series> {
+/// Combine the (local) dates of `s[0]` with the times of `s[1]` into datetimes.
+///
+/// The result is in `time_zone` if given, and otherwise in the time zone of `s[0]`. Either
+/// input may have length 1, and a null in either input yields null.
+pub(super) fn combine(s: &[Series], tu: TimeUnit, time_zone: Option<&str>) -> PolarsResult<Series> {
     let date = &s[0];
     let time = &s[1];
+    polars_ensure!(
+        date.len() == time.len() || date.len() == 1 || time.len() == 1,
+        ShapeMismatch: "`combine` expects inputs of equal length or a scalar, got {} and {}",
+        date.len(), time.len()
+    );
 
-    let tz = match date.dtype() {
-        DataType::Date => None,
-        DataType::Datetime(_, tz) => tz.as_ref(),
+    let (date, tz) = match date.dtype() {
+        DataType::Date => (date.clone(), time_zone),
+        #[cfg(feature = "timezones")]
+        DataType::Datetime(_, Some(tz)) => {
+            // Take the wall-clock date in the time zone of the data, not in UTC.
+            let naive = polars_ops::prelude::replace_time_zone(
+                date.datetime().unwrap(),
+                None,
+                &StringChunked::from_iter(std::iter::once("raise")),
+                NonExistent::Raise,
+            )?;
+            (
+                naive.cast(&DataType::Date)?,
+                time_zone.or(Some(tz.as_str())),
+            )
+        },
+        DataType::Datetime(_, _) => (date.cast(&DataType::Date)?, time_zone),
         _dtype => {
             polars_bail!(ComputeError: format!("expected Date or Datetime, got {}", _dtype))
         },
     };
 
-    let date = date.cast(&DataType::Date)?;
     let datetime = date.cast(&DataType::Datetime(tu, None)).unwrap();
 
     let duration = time.cast(&DataType::Duration(tu))?;
@@ -320,6 +342,10 @@ pub(super) fn combine(s: &[Series], tu: TimeUnit) -> PolarsResult<Series> {
             NonExistent::Raise,
         )?
         .into()),
-        _ => Ok(result_naive),
+        #[cfg(not(feature = "timezones"))]
+        Some(_) => polars_bail!(
+            InvalidOperation: "`combine` with a time zone requires the `timezones` feature"
+        ),
+        None => Ok(result_naive),
     }
 }
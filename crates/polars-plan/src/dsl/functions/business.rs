@@ -6,6 +6,8 @@ pub fn business_day_count(
     end: Expr,
     week_mask: [bool; 7],
     holidays: Vec<i32>,
+    closed: ClosedInterval,
+    output_dtype: Option<DataType>,
 ) -> Expr {
     let input = vec![start, end];
 
@@ -14,6 +16,8 @@ pub fn business_day_count(
         function: FunctionExpr::Business(BusinessFunction::BusinessDayCount {
             week_mask,
             holidays,
+            closed,
+            output_dtype,
         }),
         options: FunctionOptions {
             allow_rename: true,
@@ -21,3 +25,37 @@ pub fn business_day_count(
         },
     }
 }
+
+/// Count the business days between `start` and `end` like Excel's `NETWORKDAYS`: both
+/// endpoints are counted, and the count is negative if `start` is after `end`.
+#[cfg(feature = "dtype-date")]
+pub fn networkdays(start: Expr, end: Expr, week_mask: [bool; 7], holidays: Vec<i32>) -> Expr {
+    business_day_count(start, end, week_mask, holidays, ClosedInterval::Both, None)
+}
+
+/// Generate every `interval`-th business day from `start` through `end`, both inclusive,
+/// starting at the first business day on or after `start`.
+#[cfg(feature = "dtype-date")]
+pub fn business_date_range(
+    start: Expr,
+    end: Expr,
+    interval: i32,
+    week_mask: [bool; 7],
+    holidays: Vec<i32>,
+) -> Expr {
+    let input = vec![start, end];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Business(BusinessFunction::BusinessDateRange {
+            interval,
+            week_mask,
+            holidays,
+        }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            allow_rename: true,
+            ..Default::default()
+        },
+    }
+}
@@ -281,6 +281,48 @@ pub fn sum_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
     })
 }
 
+#[cfg(feature = "dtype-array")]
+fn arr_horizontal<E: AsRef<[Expr]>>(exprs: E, function: ArrayFunction) -> PolarsResult<Expr> {
+    let exprs = exprs.as_ref().to_vec();
+    polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
+
+    Ok(Expr::Function {
+        input: exprs,
+        function: FunctionExpr::ArrayExpr(function),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            input_wildcard_expansion: true,
+            returns_scalar: false,
+            allow_rename: true,
+            ..Default::default()
+        },
+    })
+}
+
+/// Create a new array column with the element-wise maximum of equal-width array columns.
+///
+/// Null values are ignored per position; the name of the resulting column is that of the first input.
+#[cfg(feature = "dtype-array")]
+pub fn arr_max_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+    arr_horizontal(exprs, ArrayFunction::MaxHorizontal)
+}
+
+/// Create a new array column with the element-wise minimum of equal-width array columns.
+///
+/// Null values are ignored per position; the name of the resulting column is that of the first input.
+#[cfg(feature = "dtype-array")]
+pub fn arr_min_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+    arr_horizontal(exprs, ArrayFunction::MinHorizontal)
+}
+
+/// Create a new array column with the element-wise sum of equal-width numeric array columns.
+///
+/// Null values are ignored per position; the name of the resulting column is that of the first input.
+#[cfg(feature = "dtype-array")]
+pub fn arr_sum_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+    arr_horizontal(exprs, ArrayFunction::SumHorizontal)
+}
+
 /// Compute the mean of all values horizontally across columns.
 pub fn mean_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
     let exprs = exprs.as_ref().to_vec();
@@ -13,6 +13,32 @@ pub fn arg_sort_by<E: AsRef<[Expr]>>(by: E, sort_options: SortMultipleOptions) -
         .alias(name.as_ref())
 }
 
+/// Find the indexes that would sort the rows by these keys, where every key has its own
+/// sort direction and null placement.
+///
+/// A single `descending` or `nulls_last` flag is applied to all keys. Rows that compare equal
+/// on all keys keep their original order.
+pub fn arg_sort_by_keys<E: AsRef<[Expr]>>(
+    by: E,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+    multithreaded: bool,
+) -> Expr {
+    Expr::Function {
+        input: by.as_ref().to_vec(),
+        function: FunctionExpr::ArgSortBy {
+            descending,
+            nulls_last,
+            multithreaded,
+        },
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            input_wildcard_expansion: true,
+            ..Default::default()
+        },
+    }
+}
+
 #[cfg(feature = "arg_where")]
 /// Get the indices where `condition` evaluates `true`.
 pub fn arg_where<E: Into<Expr>>(condition: E) -> Expr {
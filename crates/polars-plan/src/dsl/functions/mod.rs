@@ -10,7 +10,6 @@ mod concat;
 #[cfg(feature = "cov")]
 mod correlation;
 pub(crate) mod horizontal;
-#[cfg(any(feature = "range", feature = "arg_where"))]
 mod index;
 #[cfg(feature = "range")]
 mod range;
@@ -28,7 +27,6 @@ pub use concat::*;
 #[cfg(feature = "cov")]
 pub use correlation::*;
 pub use horizontal::*;
-#[cfg(any(feature = "range", feature = "arg_where"))]
 pub use index::*;
 #[cfg(feature = "dtype-struct")]
 use polars_core::utils::get_supertype;
@@ -43,9 +41,7 @@ pub use selectors::*;
 pub use syntactic_sugar::*;
 pub use temporal::*;
 
-#[cfg(feature = "arg_where")]
-use crate::dsl::function_expr::FunctionExpr;
-use crate::dsl::function_expr::ListFunction;
 #[cfg(all(feature = "concat_str", feature = "strings"))]
 use crate::dsl::function_expr::StringFunction;
+use crate::dsl::function_expr::{FunctionExpr, ListFunction};
 use crate::dsl::*;
@@ -39,6 +39,7 @@ impl ListNameSpace {
         with_replacement: bool,
         shuffle: bool,
         seed: Option<u64>,
+        clamp: bool,
     ) -> Expr {
         self.0.map_many_private(
             FunctionExpr::ListExpr(ListFunction::Sample {
@@ -46,6 +47,7 @@ impl ListNameSpace {
                 with_replacement,
                 shuffle,
                 seed,
+                clamp,
             }),
             &[n],
             false,
@@ -60,6 +62,7 @@ impl ListNameSpace {
         with_replacement: bool,
         shuffle: bool,
         seed: Option<u64>,
+        clamp: bool,
     ) -> Expr {
         self.0.map_many_private(
             FunctionExpr::ListExpr(ListFunction::Sample {
@@ -67,6 +70,7 @@ impl ListNameSpace {
                 with_replacement,
                 shuffle,
                 seed,
+                clamp,
             }),
             &[fraction],
             false,
@@ -239,6 +243,16 @@ impl ListNameSpace {
         )
     }
 
+    /// Keep the elements of every sublist where the `mask` sublist is `true`.
+    pub fn filter(self, mask: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ListExpr(ListFunction::Filter),
+            &[mask],
+            false,
+            false,
+        )
+    }
+
     /// Slice every sublist.
     pub fn slice(self, offset: Expr, length: Expr) -> Expr {
         self.0.map_many_private(
@@ -270,11 +284,13 @@ impl ListNameSpace {
     #[allow(clippy::wrong_self_convention)]
     /// Convert this `List` to a `Series` of type `Struct`. The width will be determined according to
     /// `ListToStructWidthStrategy` and the names of the fields determined by the given `name_generator`.
+    /// Sublists shorter than the width are padded with nulls.
     ///
     /// # Schema
     ///
     /// A polars `LazyFrame` needs to know the schema at all time. The caller therefore must provide
-    /// an `upper_bound` of struct fields that will be set.
+    /// an `upper_bound` of struct fields that will be set, unless the width is
+    /// [`ListToStructWidthStrategy::Fixed`].
     /// If this is incorrectly downstream operation may fail. For instance an `all().sum()` expression
     /// will look in the current schema to determine which columns to select.
     pub fn to_struct(
@@ -285,6 +301,11 @@ impl ListNameSpace {
     ) -> Expr {
         // heap allocate the output type and fill it later
         let out_dtype = Arc::new(RwLock::new(None::<DataType>));
+        let upper_bound = match n_fields {
+            ListToStructWidthStrategy::Fixed(n) => n,
+            _ => upper_bound,
+        };
+        let schema_name_generator = name_generator.clone();
 
         self.0
             .map(
@@ -305,11 +326,11 @@ impl ListNameSpace {
                             let mut lock = out_dtype.write().unwrap();
 
                             let inner = dt.inner_dtype().unwrap();
+                            let name_generator = schema_name_generator
+                                .as_deref()
+                                .unwrap_or(&_default_struct_name_gen);
                             let fields = (0..upper_bound)
-                                .map(|i| {
-                                    let name = _default_struct_name_gen(i);
-                                    Field::from_owned(name, inner.clone())
-                                })
+                                .map(|i| Field::from_owned(name_generator(i), inner.clone()))
                                 .collect();
                             let dt = DataType::Struct(fields);
 
@@ -357,6 +357,8 @@ impl Expr {
 
     #[cfg(feature = "search_sorted")]
     /// Find indices where elements should be inserted to maintain order.
+    ///
+    /// The expression must already be sorted in ascending order; this is not checked.
     pub fn search_sorted<E: Into<Expr>>(self, element: E, side: SearchSortedSide) -> Expr {
         let element = element.into();
         Expr::Function {
@@ -393,10 +395,17 @@ impl Expr {
 
     /// Take the values by idx.
     pub fn gather<E: Into<Expr>>(self, idx: E) -> Self {
+        self.gather_with(idx, OutOfBounds::Raise)
+    }
+
+    /// Take the values by idx. Negative indices count from the end, and indices that are
+    /// still out of bounds are treated according to `oob`.
+    pub fn gather_with<E: Into<Expr>>(self, idx: E, oob: OutOfBounds) -> Self {
         Expr::Gather {
             expr: Arc::new(self),
             idx: Arc::new(idx.into()),
             returns_scalar: false,
+            oob,
         }
     }
 
@@ -406,6 +415,7 @@ impl Expr {
             expr: Arc::new(self),
             idx: Arc::new(idx.into()),
             returns_scalar: true,
+            oob: OutOfBounds::Raise,
         }
     }
 
@@ -456,6 +466,9 @@ impl Expr {
     /// Returns the `k` largest rows by given column.
     ///
     /// For single column, use [`Expr::top_k`].
+    ///
+    /// This has time complexity `O(n + k log(k))`. Rows with equal values in `by` are
+    /// returned in their original order.
     #[cfg(feature = "top_k")]
     pub fn top_k_by<K: Into<Expr>, E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
         self,
@@ -486,6 +499,9 @@ impl Expr {
     /// Returns the `k` smallest rows by given column.
     ///
     /// For single column, use [`Expr::bottom_k`].
+    ///
+    /// This has time complexity `O(n + k log(k))`. Rows with equal values in `by` are
+    /// returned in their original order.
     // #[cfg(feature = "top_k")]
     #[cfg(feature = "top_k")]
     pub fn bottom_k_by<K: Into<Expr>, E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
@@ -759,10 +775,13 @@ impl Expr {
         )
     }
 
-    /// Cumulatively count values from 0 to len.
+    /// Cumulatively count the non-null values, or all values if `count_nulls` is set.
     #[cfg(feature = "cum_agg")]
-    pub fn cum_count(self, reverse: bool) -> Self {
-        self.apply_private(FunctionExpr::CumCount { reverse })
+    pub fn cum_count(self, reverse: bool, count_nulls: bool) -> Self {
+        self.apply_private(FunctionExpr::CumCount {
+            reverse,
+            count_nulls,
+        })
     }
 
     /// Get an array with the cumulative sum computed at every element.
@@ -772,9 +791,11 @@ impl Expr {
     }
 
     /// Get an array with the cumulative product computed at every element.
+    ///
+    /// If `strict` is set, integer overflow raises an error instead of wrapping around.
     #[cfg(feature = "cum_agg")]
-    pub fn cum_prod(self, reverse: bool) -> Self {
-        self.apply_private(FunctionExpr::CumProd { reverse })
+    pub fn cum_prod(self, reverse: bool, strict: bool) -> Self {
+        self.apply_private(FunctionExpr::CumProd { reverse, strict })
     }
 
     /// Get an array with the cumulative min computed at every element.
@@ -901,6 +922,58 @@ impl Expr {
         self.map_private(FunctionExpr::Abs)
     }
 
+    /// Count the number of set bits of every integer.
+    #[cfg(feature = "bitwise")]
+    pub fn bit_count(self) -> Self {
+        self.map_private(FunctionExpr::BitCount)
+    }
+
+    /// Count the number of leading zero bits of every integer.
+    #[cfg(feature = "bitwise")]
+    pub fn leading_zeros(self) -> Self {
+        self.map_private(FunctionExpr::LeadingZeros)
+    }
+
+    /// Count the number of trailing zero bits of every integer.
+    #[cfg(feature = "bitwise")]
+    pub fn trailing_zeros(self) -> Self {
+        self.map_private(FunctionExpr::TrailingZeros)
+    }
+
+    /// Element-wise greatest common divisor with another integer expression.
+    #[cfg(feature = "gcd_lcm")]
+    pub fn gcd(self, other: Expr) -> Self {
+        self.map_many_private(FunctionExpr::Gcd, &[other], false, true)
+    }
+
+    /// Element-wise least common multiple with another integer expression.
+    ///
+    /// If the result overflows, this raises an error, or saturates at the maximum value of
+    /// the dtype if `saturate` is set.
+    #[cfg(feature = "gcd_lcm")]
+    pub fn lcm(self, other: Expr, saturate: bool) -> Self {
+        self.map_many_private(FunctionExpr::Lcm { saturate }, &[other], false, true)
+    }
+
+    /// Check whether the values are approximately equal to those of `other`, i.e. whether
+    /// `|a - b| <= max(rel_tol * max(|a|, |b|), abs_tol)`.
+    ///
+    /// NaN is only considered close to NaN if `nans_equal` is set.
+    #[allow(clippy::wrong_self_convention)]
+    #[cfg(feature = "is_close")]
+    pub fn is_close(self, other: Expr, rel_tol: f64, abs_tol: f64, nans_equal: bool) -> Self {
+        self.map_many_private(
+            FunctionExpr::IsClose {
+                rel_tol,
+                abs_tol,
+                nans_equal,
+            },
+            &[other],
+            false,
+            false,
+        )
+    }
+
     /// Apply window function over a subgroup.
     /// This is similar to a group_by + aggregation + self join.
     /// Or similar to [window functions in Postgres](https://www.postgresql.org/docs/9.1/tutorial-window.html).
@@ -1055,7 +1128,17 @@ impl Expr {
     /// Get the approximate count of unique values.
     #[cfg(feature = "approx_unique")]
     pub fn approx_n_unique(self) -> Self {
-        self.apply_private(FunctionExpr::ApproxNUnique)
+        self.approx_n_unique_with_precision(HLL_DEFAULT_PRECISION)
+    }
+
+    /// Get the approximate count of unique values, using `2^precision` registers.
+    ///
+    /// A higher precision uses more memory but gives a more accurate estimate. The relative
+    /// standard error is about `1.04 / sqrt(2^precision)`, and `precision` must be between
+    /// 4 and 18.
+    #[cfg(feature = "approx_unique")]
+    pub fn approx_n_unique_with_precision(self, precision: u8) -> Self {
+        self.apply_private(FunctionExpr::ApproxNUnique { precision })
             .with_function_options(|mut options| {
                 options.returns_scalar = true;
                 options
@@ -1102,9 +1185,12 @@ impl Expr {
     }
 
     /// Check if the values of the left expression are in the lists of the right expr.
+    ///
+    /// If `nulls_equal` is set, a null value is in `other` if `other` contains a null, otherwise
+    /// it results in null.
     #[allow(clippy::wrong_self_convention)]
     #[cfg(feature = "is_in")]
-    pub fn is_in<E: Into<Expr>>(self, other: E) -> Self {
+    pub fn is_in<E: Into<Expr>>(self, other: E, nulls_equal: bool) -> Self {
         let other = other.into();
         let has_literal = has_leaf_literal(&other);
 
@@ -1115,14 +1201,14 @@ impl Expr {
         // we don't have to apply on groups, so this is faster
         if has_literal {
             self.map_many_private(
-                BooleanFunction::IsIn.into(),
+                BooleanFunction::IsIn { nulls_equal }.into(),
                 arguments,
                 returns_scalar,
                 true,
             )
         } else {
             self.apply_many_private(
-                BooleanFunction::IsIn.into(),
+                BooleanFunction::IsIn { nulls_equal }.into(),
                 arguments,
                 returns_scalar,
                 true,
@@ -1207,7 +1293,30 @@ impl Expr {
     #[cfg(feature = "mode")]
     /// Compute the mode(s) of this column. This is the most occurring value.
     pub fn mode(self) -> Expr {
-        self.apply_private(FunctionExpr::Mode)
+        self.mode_with(false, false)
+    }
+
+    #[cfg(feature = "mode")]
+    /// Compute the mode(s) of this column.
+    ///
+    /// If `maintain_order` is set, tied modes are returned in order of first appearance.
+    /// Nulls are counted as a value unless `ignore_nulls` is set.
+    pub fn mode_with(self, maintain_order: bool, ignore_nulls: bool) -> Expr {
+        self.apply_private(FunctionExpr::Mode {
+            maintain_order,
+            ignore_nulls,
+        })
+    }
+
+    #[cfg(feature = "mode")]
+    /// Compute the single most occurring value of this column. Ties are broken by first
+    /// appearance.
+    pub fn mode_first(self, ignore_nulls: bool) -> Expr {
+        self.apply_private(FunctionExpr::ModeFirst { ignore_nulls })
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
     }
 
     /// Exclude a column from a wildcard/regex selection.
@@ -1233,8 +1342,26 @@ impl Expr {
 
     #[cfg(feature = "interpolate")]
     /// Fill null values using interpolation.
-    pub fn interpolate(self, method: InterpolationMethod) -> Expr {
-        self.apply_private(FunctionExpr::Interpolate(method))
+    ///
+    /// Runs of more than `max_gap` consecutive nulls are left null.
+    pub fn interpolate(self, method: InterpolationMethod, max_gap: Option<IdxSize>) -> Expr {
+        self.apply_private(FunctionExpr::Interpolate { method, max_gap })
+    }
+
+    #[cfg(feature = "interpolate_by")]
+    /// Fill null values using interpolation against the x-coordinates in `by`.
+    ///
+    /// Gaps whose neighbours are more than `max_gap` apart in `by` are left null. For temporal
+    /// `by` this must be a duration.
+    pub fn interpolate_by(
+        self,
+        by: Expr,
+        method: InterpolationMethod,
+        max_gap: Option<Expr>,
+    ) -> Expr {
+        let mut input = vec![by];
+        input.extend(max_gap);
+        self.apply_many_private(FunctionExpr::InterpolateBy(method), &input, false, false)
     }
 
     #[cfg(feature = "rolling_window")]
@@ -1336,11 +1463,34 @@ impl Expr {
     }
 
     /// Apply a rolling skew.
+    ///
+    /// `min_periods` defaults to `window_size`.
     #[cfg(feature = "rolling_window")]
     #[cfg(feature = "moment")]
-    pub fn rolling_skew(self, window_size: usize, bias: bool) -> Expr {
+    pub fn rolling_skew(self, window_size: usize, min_periods: Option<usize>, bias: bool) -> Expr {
         self.apply_private(FunctionExpr::RollingExpr(RollingFunction::Skew(
             window_size,
+            min_periods.unwrap_or(window_size),
+            bias,
+        )))
+    }
+
+    /// Apply a rolling kurtosis.
+    ///
+    /// `min_periods` defaults to `window_size`.
+    #[cfg(feature = "rolling_window")]
+    #[cfg(feature = "moment")]
+    pub fn rolling_kurtosis(
+        self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        fisher: bool,
+        bias: bool,
+    ) -> Expr {
+        self.apply_private(FunctionExpr::RollingExpr(RollingFunction::Kurtosis(
+            window_size,
+            min_periods.unwrap_or(window_size),
+            fisher,
             bias,
         )))
     }
@@ -1399,14 +1549,22 @@ impl Expr {
         .with_fmt("rolling_map_float")
     }
 
+    /// Get a boolean mask of the local minimum peaks.
+    ///
+    /// `options` control which elements of a plateau are marked and can filter out peaks
+    /// with a small prominence.
     #[cfg(feature = "peaks")]
-    pub fn peak_min(self) -> Expr {
-        self.apply_private(FunctionExpr::PeakMin)
+    pub fn peak_min(self, options: PeakOptions) -> Expr {
+        self.apply_private(FunctionExpr::PeakMin(options))
     }
 
+    /// Get a boolean mask of the local maximum peaks.
+    ///
+    /// `options` control which elements of a plateau are marked and can filter out peaks
+    /// with a small prominence.
     #[cfg(feature = "peaks")]
-    pub fn peak_max(self) -> Expr {
-        self.apply_private(FunctionExpr::PeakMax)
+    pub fn peak_max(self, options: PeakOptions) -> Expr {
+        self.apply_private(FunctionExpr::PeakMax(options))
     }
 
     #[cfg(feature = "rank")]
@@ -1441,6 +1599,71 @@ impl Expr {
         }
     }
 
+    #[cfg(feature = "replace")]
+    /// Replace all values by looking them up in the `old` -> `new` mapping.
+    ///
+    /// Values that are not found are set to `default`, or raise an error if no default is
+    /// given. Unlike [`Expr::replace`], the result dtype does not depend on the input dtype.
+    pub fn replace_strict<E: Into<Expr>>(
+        self,
+        old: E,
+        new: E,
+        default: Option<E>,
+        return_dtype: Option<DataType>,
+    ) -> Expr {
+        let old = old.into();
+        let new = new.into();
+        // If we search and replace by literals, we can run on batches.
+        let literal_searchers = matches!(&old, Expr::Literal(_)) & matches!(&new, Expr::Literal(_));
+
+        let mut args = vec![old, new];
+        if let Some(default) = default {
+            args.push(default.into())
+        }
+
+        let function = FunctionExpr::ReplaceStrict { return_dtype };
+        if literal_searchers {
+            self.map_many_private(function, &args, false, false)
+        } else {
+            self.apply_many_private(function, &args, false, false)
+        }
+    }
+
+    #[cfg(feature = "replace")]
+    /// Replace all numeric values by the label of the range they fall in.
+    ///
+    /// `breaks` holds the sorted edges of the ranges and `labels` one value per range. Values
+    /// outside of all ranges are set to `default`, or null if no default is given.
+    pub fn replace_ranges<E: Into<Expr>>(
+        self,
+        breaks: E,
+        labels: E,
+        default: Option<E>,
+        left_closed: bool,
+        return_dtype: Option<DataType>,
+    ) -> Expr {
+        let breaks = breaks.into();
+        let labels = labels.into();
+        // If the ranges are given as literals, we can run on batches.
+        let literal_ranges =
+            matches!(&breaks, Expr::Literal(_)) & matches!(&labels, Expr::Literal(_));
+
+        let mut args = vec![breaks, labels];
+        if let Some(default) = default {
+            args.push(default.into())
+        }
+
+        let function = FunctionExpr::ReplaceRanges {
+            left_closed,
+            return_dtype,
+        };
+        if literal_ranges {
+            self.map_many_private(function, &args, false, false)
+        } else {
+            self.apply_many_private(function, &args, false, false)
+        }
+    }
+
     #[cfg(feature = "cutqcut")]
     /// Bin continuous values into discrete categories.
     pub fn cut(
@@ -1469,14 +1692,14 @@ impl Expr {
         probs: Vec<f64>,
         labels: Option<Vec<String>>,
         left_closed: bool,
-        allow_duplicates: bool,
+        duplicates: QuantileDuplicates,
         include_breaks: bool,
     ) -> Expr {
         self.apply_private(FunctionExpr::QCut {
             probs,
             labels,
             left_closed,
-            allow_duplicates,
+            duplicates,
             include_breaks,
         })
         .with_function_options(|mut opt| {
@@ -1492,7 +1715,7 @@ impl Expr {
         n_bins: usize,
         labels: Option<Vec<String>>,
         left_closed: bool,
-        allow_duplicates: bool,
+        duplicates: QuantileDuplicates,
         include_breaks: bool,
     ) -> Expr {
         let probs = (1..n_bins).map(|b| b as f64 / n_bins as f64).collect();
@@ -1500,7 +1723,7 @@ impl Expr {
             probs,
             labels,
             left_closed,
-            allow_duplicates,
+            duplicates,
             include_breaks,
         })
         .with_function_options(|mut opt| {
@@ -1528,9 +1751,12 @@ impl Expr {
     }
 
     #[cfg(feature = "pct_change")]
-    /// Computes percentage change between values.
-    pub fn pct_change(self, n: Expr) -> Expr {
-        self.apply_many_private(FunctionExpr::PctChange, &[n], false, false)
+    /// Computes percentage change between values, `n` periods apart.
+    ///
+    /// `n` is either a scalar or holds one period per row. Nulls are first filled with
+    /// `fill_null` if given. Division by zero follows IEEE 754 instead of raising.
+    pub fn pct_change(self, n: Expr, fill_null: Option<FillNullStrategy>) -> Expr {
+        self.apply_many_private(FunctionExpr::PctChange(fill_null), &[n], false, false)
     }
 
     #[cfg(feature = "moment")]
@@ -1567,6 +1793,24 @@ impl Expr {
             })
     }
 
+    #[cfg(feature = "moment")]
+    /// Compute the `order`-th moment, about the mean if `central` is set and about zero otherwise.
+    ///
+    /// The moment of order `0` is `1`; the first central moment is `0` and the first raw moment
+    /// is the mean. If `bias` is `false`, central moments of order 2 to 4 use unbiased
+    /// estimators.
+    pub fn moment(self, order: usize, central: bool, bias: bool) -> Expr {
+        self.apply_private(FunctionExpr::Moment {
+            order,
+            central,
+            bias,
+        })
+        .with_function_options(|mut options| {
+            options.returns_scalar = true;
+            options
+        })
+    }
+
     /// Get maximal value that could be hold by this dtype.
     pub fn upper_bound(self) -> Expr {
         self.map_private(FunctionExpr::UpperBound)
@@ -1582,6 +1826,13 @@ impl Expr {
         self.apply_private(FunctionExpr::Reshape(dimensions))
     }
 
+    #[cfg(feature = "dtype-array")]
+    /// Reshape into (nested) fixed-size arrays. Only the first dimension can be inferred with `-1`.
+    pub fn reshape_array(self, dimensions: &[i64]) -> Self {
+        let dimensions = dimensions.to_vec();
+        self.apply_private(FunctionExpr::ReshapeArray(dimensions))
+    }
+
     #[cfg(feature = "ewma")]
     /// Calculate the exponentially-weighted moving average.
     pub fn ewm_mean(self, options: EWMOptions) -> Self {
@@ -1652,20 +1903,36 @@ impl Expr {
     }
 
     #[cfg(feature = "dtype-struct")]
-    /// Count all unique values and create a struct mapping value to count.
+    /// Count all unique values and create a struct mapping value to count, or to its
+    /// proportion of the total if `normalize` is set. Nulls are counted as their own value.
+    /// If `sort` is set, the output is ordered by frequency (descending unless `descending`
+    /// is disabled).
     /// (Note that it is better to turn parallel off in the aggregation context).
-    pub fn value_counts(self, sort: bool, parallel: bool) -> Self {
-        self.apply_private(FunctionExpr::ValueCounts { sort, parallel })
-            .with_function_options(|mut opts| {
-                opts.pass_name_to_apply = true;
-                opts
-            })
+    pub fn value_counts(
+        self,
+        sort: bool,
+        descending: bool,
+        parallel: bool,
+        normalize: bool,
+    ) -> Self {
+        self.apply_private(FunctionExpr::ValueCounts {
+            sort,
+            descending,
+            parallel,
+            normalize,
+        })
+        .with_function_options(|mut opts| {
+            opts.pass_name_to_apply = true;
+            opts
+        })
     }
 
     #[cfg(feature = "unique_counts")]
     /// Returns a count of the unique values in the order of appearance.
     /// This method differs from [`Expr::value_counts]` in that it does not return the
     /// values, only the counts and might be faster.
+    ///
+    /// The counts align element-for-element with [`Expr::unique_stable`].
     pub fn unique_counts(self) -> Self {
         self.apply_private(FunctionExpr::UniqueCounts)
     }
@@ -1676,6 +1943,12 @@ impl Expr {
         self.map_private(FunctionExpr::Log { base })
     }
 
+    #[cfg(feature = "log")]
+    /// Compute the logarithm to the base given per element by `base`, which may be a scalar.
+    pub fn log_with_base(self, base: Expr) -> Self {
+        self.map_many_private(FunctionExpr::LogWithBase, &[base], false, false)
+    }
+
     #[cfg(feature = "log")]
     /// Compute the natural logarithm of all elements plus one in the input array.
     pub fn log1p(self) -> Self {
@@ -1718,7 +1991,10 @@ impl Expr {
     }
 
     #[cfg(feature = "row_hash")]
-    /// Compute the hash of every element.
+    /// Compute the hash of every element, seeded with the four words `k0..k3`.
+    ///
+    /// The hashes are reproducible for the same seeds, dtype and Polars version, but the
+    /// hash is not cryptographic.
     pub fn hash(self, k0: u64, k1: u64, k2: u64, k3: u64) -> Expr {
         self.map_private(FunctionExpr::Hash(k0, k1, k2, k3))
     }
@@ -1737,7 +2013,22 @@ impl Expr {
     }
 
     pub fn extend_constant(self, value: Expr, n: Expr) -> Expr {
-        self.apply_many_private(FunctionExpr::ExtendConstant, &[value, n], false, false)
+        self.apply_many_private(
+            FunctionExpr::ExtendConstant { prepend: false },
+            &[value, n],
+            false,
+            false,
+        )
+    }
+
+    /// Prepend `n` copies of `value`, which must be a unit expression.
+    pub fn prepend_constant(self, value: Expr, n: Expr) -> Expr {
+        self.apply_many_private(
+            FunctionExpr::ExtendConstant { prepend: true },
+            &[value, n],
+            false,
+            false,
+        )
     }
 
     #[cfg(feature = "strings")]
@@ -70,6 +70,7 @@ impl Expr {
         bin_count: Option<usize>,
         include_category: bool,
         include_breakpoint: bool,
+        density: bool,
     ) -> Self {
         let mut input = vec![self];
         if let Some(bins) = bins {
@@ -82,6 +83,7 @@ impl Expr {
                 bin_count,
                 include_category,
                 include_breakpoint,
+                density,
             },
             options: FunctionOptions {
                 collect_groups: ApplyOptions::GroupWise,
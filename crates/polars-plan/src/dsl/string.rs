@@ -82,6 +82,14 @@ impl StringNameSpace {
         )
     }
 
+    /// Escape all regular expression meta characters in the string values, so that
+    /// they can be used to build a pattern that matches them literally.
+    #[cfg(feature = "regex")]
+    pub fn escape_regex(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::EscapeRegex))
+    }
+
     /// Check if a string value starts with the `sub` string.
     pub fn starts_with(self, sub: Expr) -> Expr {
         self.0.map_many_private(
@@ -129,7 +137,9 @@ impl StringNameSpace {
     }
 
     #[cfg(feature = "extract_groups")]
-    // Extract all captures groups from a regex pattern as a struct
+    /// Extract all capture groups from a regex pattern as a struct, with one `String` field
+    /// per group. Named groups keep their name, other groups are named by their position.
+    /// Values that are null or don't match get a struct of null fields.
     pub fn extract_groups(self, pat: &str) -> PolarsResult<Expr> {
         // regex will be compiled twice, because it doesn't support serde
         // and we need to compile it here to determine the output datatype
@@ -185,10 +195,11 @@ impl StringNameSpace {
 
     /// Pad the start of the string with zeros until it reaches the given length.
     ///
-    /// A sign prefix (`-`) is handled by inserting the padding after the sign
+    /// A sign prefix (`-` or `+`) is handled by inserting the padding after the sign
     /// character rather than before.
     /// Strings with length equal to or greater than the given length are
-    /// returned as-is.
+    /// returned as-is. The length may differ per row, or be a single value that is
+    /// broadcast to all rows.
     #[cfg(feature = "string_pad")]
     pub fn zfill(self, length: Expr) -> Expr {
         self.0
@@ -196,6 +207,7 @@ impl StringNameSpace {
     }
 
     /// Find the index of a literal substring within another string value.
+    /// The index is counted in characters, like the offsets of [`Self::slice`].
     #[cfg(feature = "regex")]
     pub fn find_literal(self, pat: Expr) -> Expr {
         self.0.map_many_private(
@@ -210,6 +222,7 @@ impl StringNameSpace {
     }
 
     /// Find the index of a substring defined by a regular expressons within another string value.
+    /// The index is counted in characters, like the offsets of [`Self::slice`].
     #[cfg(feature = "regex")]
     pub fn find(self, pat: Expr, strict: bool) -> Expr {
         self.0.map_many_private(
@@ -333,6 +346,21 @@ impl StringNameSpace {
             .map_many_private(StringFunction::Split(true).into(), &[by], false, false)
     }
 
+    #[cfg(feature = "regex")]
+    /// Split the string at every match of the regex pattern `by`. The resulting dtype is
+    /// `List<String>`.
+    ///
+    /// Consecutive matches produce empty strings. If `inclusive`, the matched separators are
+    /// kept at the end of the preceding piece.
+    pub fn split_regex(self, by: Expr, inclusive: bool) -> Expr {
+        self.0.map_many_private(
+            StringFunction::SplitRegex { inclusive }.into(),
+            &[by],
+            false,
+            false,
+        )
+    }
+
     #[cfg(feature = "dtype-struct")]
     /// Split exactly `n` times by a given substring. The resulting dtype is [`DataType::Struct`].
     pub fn split_exact(self, by: Expr, n: usize) -> Expr {
@@ -401,6 +429,13 @@ impl StringNameSpace {
         )
     }
 
+    #[cfg(feature = "string_normalize")]
+    /// Normalize each string to the given Unicode normalization form.
+    pub fn normalize(self, form: UnicodeForm) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::Normalize { form }))
+    }
+
     #[cfg(feature = "string_reverse")]
     /// Reverse each string
     pub fn reverse(self) -> Expr {
@@ -412,6 +447,28 @@ impl StringNameSpace {
         )
     }
 
+    /// Levenshtein edit distance to the strings of `other`, counted in characters.
+    #[cfg(feature = "string_distance")]
+    pub fn levenshtein(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::Levenshtein),
+            &[other],
+            false,
+            false,
+        )
+    }
+
+    /// Jaro-Winkler similarity in `[0, 1]` to the strings of `other`.
+    #[cfg(feature = "string_distance")]
+    pub fn jaro_winkler(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::JaroWinkler),
+            &[other],
+            false,
+            false,
+        )
+    }
+
     /// Remove leading and trailing characters, or whitespace if matches is None.
     pub fn strip_chars(self, matches: Expr) -> Expr {
         self.0.map_many_private(
@@ -474,8 +531,7 @@ impl StringNameSpace {
             .map_private(FunctionExpr::StringExpr(StringFunction::Uppercase))
     }
 
-    /// Convert all characters to titlecase.
-    #[cfg(feature = "nightly")]
+    /// Convert the first character of every word to uppercase and the rest to lowercase.
     pub fn to_titlecase(self) -> Expr {
         self.0
             .map_private(FunctionExpr::StringExpr(StringFunction::Titlecase))
@@ -517,7 +573,7 @@ impl StringNameSpace {
             .map_private(FunctionExpr::StringExpr(StringFunction::LenChars))
     }
 
-    /// Slice the string values.
+    /// Slice the string values, with `offset` and `length` counted in characters.
     pub fn slice(self, offset: Expr, length: Expr) -> Expr {
         self.0.map_many_private(
             FunctionExpr::StringExpr(StringFunction::Slice),
@@ -527,6 +583,17 @@ impl StringNameSpace {
         )
     }
 
+    /// Slice the string values, with `offset` and `length` counted in bytes.
+    /// Errors if a slice doesn't fall on character boundaries.
+    pub fn slice_bytes(self, offset: Expr, length: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::SliceBytes),
+            &[offset, length],
+            false,
+            false,
+        )
+    }
+
     /// Take the first `n` characters of the string values.
     pub fn head(self, n: Expr) -> Expr {
         self.0.map_many_private(
@@ -146,6 +146,7 @@ pub enum AExpr {
         expr: Node,
         idx: Node,
         returns_scalar: bool,
+        oob: OutOfBounds,
     },
     SortBy {
         expr: Node,
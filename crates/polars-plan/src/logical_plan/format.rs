@@ -325,6 +325,7 @@ impl Debug for Expr {
                 expr,
                 idx,
                 returns_scalar,
+                ..
             } => {
                 if *returns_scalar {
                     write!(f, "{expr:?}.get({idx:?})")
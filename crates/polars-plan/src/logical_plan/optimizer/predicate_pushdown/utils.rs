@@ -155,7 +155,7 @@ fn check_and_extend_predicate_pd_nodes(
         match ae {
             #[cfg(feature = "is_in")]
             AExpr::Function {
-                function: FunctionExpr::Boolean(BooleanFunction::IsIn),
+                function: FunctionExpr::Boolean(BooleanFunction::IsIn { .. }),
                 input,
                 ..
             } => {
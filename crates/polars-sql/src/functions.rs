@@ -292,7 +292,6 @@ pub(crate) enum PolarsSQLFunctions {
     /// ```sql
     /// SELECT INITCAP(column_1) from df;
     /// ```
-    #[cfg(feature = "nightly")]
     InitCap,
     /// SQL 'left' function
     /// Returns the first (leftmost) `n` characters.
@@ -729,7 +728,6 @@ impl PolarsSQLFunctions {
             "concat" => Self::Concat,
             "concat_ws" => Self::ConcatWS,
             "ends_with" => Self::EndsWith,
-            #[cfg(feature = "nightly")]
             "initcap" => Self::InitCap,
             "length" | "char_length" | "character_length" => Self::Length,
             "left" => Self::Left,
@@ -905,7 +903,6 @@ impl SQLFunctionVisitor<'_> {
                 })
             },
             EndsWith => self.visit_binary(|e, s| e.str().ends_with(s)),
-            #[cfg(feature = "nightly")]
             InitCap => self.visit_unary(|e| e.str().to_titlecase()),
             Left => self.try_visit_binary(|e, length| {
                 Ok(match length {
@@ -475,8 +475,8 @@ impl SQLExprVisitor<'_> {
             BinaryOperator::Lt => Ok(left.lt(right.max())),
             BinaryOperator::GtEq => Ok(left.gt_eq(right.min())),
             BinaryOperator::LtEq => Ok(left.lt_eq(right.max())),
-            BinaryOperator::Eq => Ok(left.is_in(right)),
-            BinaryOperator::NotEq => Ok(left.is_in(right).not()),
+            BinaryOperator::Eq => Ok(left.is_in(right, false)),
+            BinaryOperator::NotEq => Ok(left.is_in(right, false).not()),
             _ => polars_bail!(ComputeError: "invalid comparison operator"),
         }
     }
@@ -699,9 +699,9 @@ impl SQLExprVisitor<'_> {
         let s = Series::from_any_values("", &list, true)?;
 
         if negated {
-            Ok(expr.is_in(lit(s)).not())
+            Ok(expr.is_in(lit(s), false).not())
         } else {
-            Ok(expr.is_in(lit(s)))
+            Ok(expr.is_in(lit(s), false))
         }
     }
 
@@ -715,9 +715,9 @@ impl SQLExprVisitor<'_> {
         let subquery_result = self.visit_subquery(subquery, SubqueryRestriction::SingleColumn)?;
         let expr = self.visit_expr(expr)?;
         if negated {
-            Ok(expr.is_in(subquery_result).not())
+            Ok(expr.is_in(subquery_result, false).not())
         } else {
-            Ok(expr.is_in(subquery_result))
+            Ok(expr.is_in(subquery_result, false))
         }
     }
 
@@ -982,8 +982,8 @@ fn parse_extract(expr: Expr, field: &DateTimeField) -> PolarsResult<Expr> {
         DateTimeField::Year => expr.dt().year(),
         DateTimeField::Quarter => expr.dt().quarter(),
         DateTimeField::Month => expr.dt().month(),
-        DateTimeField::Week => expr.dt().week(),
-        DateTimeField::IsoWeek => expr.dt().week(),
+        DateTimeField::Week => expr.dt().week(true),
+        DateTimeField::IsoWeek => expr.dt().week(true),
         DateTimeField::DayOfYear | DateTimeField::Doy => expr.dt().ordinal_day(),
         DateTimeField::DayOfWeek | DateTimeField::Dow => {
             let w = expr.dt().weekday();
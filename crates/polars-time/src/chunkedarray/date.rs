@@ -63,6 +63,14 @@ pub trait DateMethods: AsDate {
         ca.apply_kernel_cast::<Int8Type>(&date_to_iso_week)
     }
 
+    /// Returns the week of the calendar year, where week 1 is the week containing
+    /// January 1st and weeks start on Monday.
+    /// The return value ranges from 1 to 54.
+    fn week_of_year(&self) -> Int8Chunked {
+        let ca = self.as_date();
+        ca.apply_kernel_cast::<Int8Type>(&date_to_week_of_year)
+    }
+
     /// Extract day from underlying NaiveDate representation.
     /// Returns the day of month starting from 1.
     ///
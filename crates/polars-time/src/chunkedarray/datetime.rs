@@ -47,14 +47,9 @@ pub trait DatetimeMethods: AsDatetime {
         ca.apply_kernel_cast::<BooleanType>(&f)
     }
 
+    /// Extract the ISO week-based year, in the time zone of the data if it has one.
     fn iso_year(&self) -> Int32Chunked {
-        let ca = self.as_datetime();
-        let f = match ca.time_unit() {
-            TimeUnit::Nanoseconds => datetime_to_iso_year_ns,
-            TimeUnit::Microseconds => datetime_to_iso_year_us,
-            TimeUnit::Milliseconds => datetime_to_iso_year_ms,
-        };
-        ca.apply_kernel_cast::<Int32Type>(&f)
+        cast_and_apply(self.as_datetime(), temporal::iso_year)
     }
 
     /// Extract quarter from underlying NaiveDateTime representation.
@@ -84,6 +79,13 @@ pub trait DatetimeMethods: AsDatetime {
         cast_and_apply(self.as_datetime(), temporal::iso_week)
     }
 
+    /// Returns the week of the calendar year, where week 1 is the week containing
+    /// January 1st and weeks start on Monday, in the time zone of the data if it has one.
+    /// The return value ranges from 1 to 54.
+    fn week_of_year(&self) -> Int8Chunked {
+        cast_and_apply(self.as_datetime(), temporal::week_of_year)
+    }
+
     /// Extract day from underlying NaiveDateTime representation.
     /// Returns the day of month starting from 1.
     ///
@@ -188,4 +190,79 @@ mod test {
             dt.cont_slice().unwrap()
         );
     }
+
+    #[test]
+    fn iso_year_and_quarter() {
+        let datetimes: Vec<_> = [
+            "2020-12-31 12:00:00",
+            "2021-01-03 20:00:00",
+            "2024-12-30 00:00:00",
+            "2027-01-01 00:00:00",
+        ]
+        .iter()
+        .map(|s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap())
+        .collect();
+        let dt = DatetimeChunked::from_naive_datetime(
+            "name",
+            datetimes.iter().copied(),
+            TimeUnit::Microseconds,
+        );
+
+        assert_eq!(
+            Vec::from(&dt.iso_year()),
+            &[Some(2020), Some(2020), Some(2025), Some(2026)]
+        );
+        assert_eq!(
+            Vec::from(&dt.quarter()),
+            &[Some(4), Some(1), Some(4), Some(1)]
+        );
+
+        #[cfg(feature = "timezones")]
+        {
+            // 2021-01-03 20:00 UTC is Monday 2021-01-04 in Tokyo, in ISO week 1 of 2021.
+            let mut dt = dt;
+            dt.set_time_zone("Asia/Tokyo".to_string()).unwrap();
+            assert_eq!(dt.iso_year().get(1), dt.year().get(1));
+            assert_eq!(dt.week().get(1), Some(1));
+        }
+    }
+
+    #[test]
+    fn week_of_year_and_iso_week() {
+        let datetimes: Vec<_> = [
+            "2020-12-31 12:00:00",
+            "2021-01-03 20:00:00",
+            "2024-12-30 00:00:00",
+            "2027-01-01 00:00:00",
+            "2012-12-31 00:00:00",
+        ]
+        .iter()
+        .map(|s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap())
+        .collect();
+        let dt = DatetimeChunked::from_naive_datetime(
+            "name",
+            datetimes.iter().copied(),
+            TimeUnit::Milliseconds,
+        );
+
+        // The ISO week belongs to the ISO year, the week of year to the calendar year.
+        assert_eq!(
+            Vec::from(&dt.week()),
+            &[Some(53), Some(53), Some(1), Some(53), Some(1)]
+        );
+        assert_eq!(
+            Vec::from(&dt.iso_year()),
+            &[Some(2020), Some(2020), Some(2025), Some(2026), Some(2013)]
+        );
+        assert_eq!(
+            Vec::from(&dt.week_of_year()),
+            &[Some(53), Some(1), Some(53), Some(1), Some(54)]
+        );
+
+        let date = dt.cast(&DataType::Date).unwrap();
+        assert_eq!(
+            Vec::from(&date.date().unwrap().week_of_year()),
+            &[Some(53), Some(1), Some(53), Some(1), Some(54)]
+        );
+    }
 }
@@ -1,12 +1,18 @@
 use arrow::temporal_conversions::{
     MICROSECONDS, MILLISECONDS, MILLISECONDS_IN_DAY, NANOSECONDS, SECONDS_IN_DAY,
 };
+use polars_core::prelude::arity::unary_elementwise;
 
 use super::*;
 
 const NANOSECONDS_IN_MILLISECOND: i64 = 1_000_000;
 const SECONDS_IN_HOUR: i64 = 3600;
 
+/// Upscale to a finer time unit, values that overflow become null.
+fn checked_scale(ca: &Int64Chunked, factor: i64) -> Int64Chunked {
+    unary_elementwise(ca, |opt_v| opt_v.and_then(|v| v.checked_mul(factor)))
+}
+
 pub trait DurationMethods {
     /// Extract the hours from a `Duration`
     fn hours(&self) -> Int64Chunked;
@@ -59,7 +65,7 @@ impl DurationMethods for DurationChunked {
         }
     }
 
-    /// Extract the seconds from a `Duration`
+    /// Extract the minutes from a `Duration`
     fn minutes(&self) -> Int64Chunked {
         match self.time_unit() {
             TimeUnit::Milliseconds => (&self.0).wrapping_trunc_div_scalar(MILLISECONDS * 60),
@@ -91,7 +97,7 @@ impl DurationMethods for DurationChunked {
     /// Extract the microseconds from a `Duration`
     fn microseconds(&self) -> Int64Chunked {
         match self.time_unit() {
-            TimeUnit::Milliseconds => &self.0 * 1000,
+            TimeUnit::Milliseconds => checked_scale(&self.0, 1000),
             TimeUnit::Microseconds => self.0.clone(),
             TimeUnit::Nanoseconds => (&self.0).wrapping_trunc_div_scalar(1000),
         }
@@ -100,9 +106,45 @@ impl DurationMethods for DurationChunked {
     /// Extract the nanoseconds from a `Duration`
     fn nanoseconds(&self) -> Int64Chunked {
         match self.time_unit() {
-            TimeUnit::Milliseconds => &self.0 * NANOSECONDS_IN_MILLISECOND,
-            TimeUnit::Microseconds => &self.0 * 1000,
+            TimeUnit::Milliseconds => checked_scale(&self.0, NANOSECONDS_IN_MILLISECOND),
+            TimeUnit::Microseconds => checked_scale(&self.0, 1000),
             TimeUnit::Nanoseconds => self.0.clone(),
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_duration_truncates_toward_zero() {
+        for (tu, per_second) in [
+            (TimeUnit::Milliseconds, MILLISECONDS),
+            (TimeUnit::Microseconds, MICROSECONDS),
+            (TimeUnit::Nanoseconds, NANOSECONDS),
+        ] {
+            // -1.5 seconds and 90.5 minutes.
+            let values = [Some(-3 * per_second / 2), Some(5430 * per_second), None];
+            let ca = Int64Chunked::new("d", &values).into_duration(tu);
+            assert_eq!(Vec::from(&ca.seconds()), &[Some(-1), Some(5430), None]);
+            assert_eq!(Vec::from(&ca.minutes()), &[Some(0), Some(90), None]);
+            assert_eq!(Vec::from(&ca.hours()), &[Some(0), Some(1), None]);
+            assert_eq!(
+                Vec::from(&ca.milliseconds()),
+                &[Some(-1500), Some(5430000), None]
+            );
+        }
+    }
+
+    #[test]
+    fn test_duration_upscale_overflow_is_null() {
+        let ca = Int64Chunked::new("d", &[Some(i64::MAX / 1000), Some(-2)])
+            .into_duration(TimeUnit::Milliseconds);
+        let us = ca.microseconds();
+        assert_eq!(us.get(0), Some(i64::MAX / 1000 * 1000));
+        assert_eq!(us.get(1), Some(-2000));
+        assert_eq!(Vec::from(&ca.nanoseconds()), &[None, Some(-2_000_000)]);
+        assert_eq!(us.name(), "d");
+    }
+}
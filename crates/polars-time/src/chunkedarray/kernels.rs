@@ -17,6 +17,15 @@ trait PolarsIso {
     fn p_weekday(&self) -> i8;
     fn week(&self) -> i8;
     fn iso_year(&self) -> i32;
+    /// The week of the calendar year, where week 1 contains January 1st.
+    fn week_of_year(&self) -> i8;
+}
+
+/// Week 1 is the week containing January 1st, and weeks start on Monday.
+fn week_of_year(date: &impl Datelike) -> i8 {
+    let ordinal0 = date.ordinal0();
+    let jan_1_weekday = (date.weekday().num_days_from_monday() + 7 - ordinal0 % 7) % 7;
+    ((ordinal0 + jan_1_weekday) / 7 + 1).try_into().unwrap()
 }
 
 impl PolarsIso for NaiveDateTime {
@@ -29,6 +38,9 @@ impl PolarsIso for NaiveDateTime {
     fn iso_year(&self) -> i32 {
         self.iso_week().year()
     }
+    fn week_of_year(&self) -> i8 {
+        week_of_year(self)
+    }
 }
 
 impl PolarsIso for NaiveDate {
@@ -41,6 +53,9 @@ impl PolarsIso for NaiveDate {
     fn iso_year(&self) -> i32 {
         self.iso_week().year()
     }
+    fn week_of_year(&self) -> i8 {
+        week_of_year(self)
+    }
 }
 
 macro_rules! to_temporal_unit {
@@ -94,6 +109,15 @@ to_temporal_unit!(
     ArrowDataType::Int8
 );
 #[cfg(feature = "dtype-date")]
+to_temporal_unit!(
+    date_to_week_of_year,
+    week_of_year,
+    date32_to_datetime_opt,
+    i32,
+    i8,
+    ArrowDataType::Int8
+);
+#[cfg(feature = "dtype-date")]
 to_temporal_unit!(
     date_to_iso_year,
     iso_year,
@@ -223,35 +247,6 @@ to_temporal_unit!(
     ArrowDataType::Int16
 );
 
-#[cfg(feature = "dtype-datetime")]
-to_temporal_unit!(
-    datetime_to_iso_year_ns,
-    iso_year,
-    timestamp_ns_to_datetime_opt,
-    i64,
-    i32,
-    ArrowDataType::Int32
-);
-
-#[cfg(feature = "dtype-datetime")]
-to_temporal_unit!(
-    datetime_to_iso_year_us,
-    iso_year,
-    timestamp_us_to_datetime_opt,
-    i64,
-    i32,
-    ArrowDataType::Int32
-);
-
-#[cfg(feature = "dtype-datetime")]
-to_temporal_unit!(
-    datetime_to_iso_year_ms,
-    iso_year,
-    timestamp_ms_to_datetime_opt,
-    i64,
-    i32,
-    ArrowDataType::Int32
-);
 #[cfg(feature = "dtype-datetime")]
 to_boolean_temporal_unit!(
     datetime_to_is_leap_year_ns,
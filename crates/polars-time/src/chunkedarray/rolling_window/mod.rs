@@ -20,7 +20,8 @@ pub struct RollingOptions {
     /// An optional slice with the same length as the window that will be multiplied
     ///              elementwise with the values in the window.
     pub weights: Option<Vec<f64>>,
-    /// Set the labels at the center of the window.
+    /// Set the labels at the center of the window. For an even `window_size` the window holds
+    /// one more element before the label than after it.
     pub center: bool,
     /// Compute the rolling aggregates with a window defined by a time column
     pub by: Option<String>,
@@ -71,7 +72,8 @@ pub struct RollingOptionsImpl<'a> {
     /// An optional slice with the same length as the window that will be multiplied
     ///              elementwise with the values in the window.
     pub weights: Option<Vec<f64>>,
-    /// Set the labels at the center of the window.
+    /// Set the labels at the center of the window. For an even `window_size` the window holds
+    /// one more element before the label than after it.
     pub center: bool,
     pub by: Option<&'a [i64]>,
     pub tu: Option<TimeUnit>,
@@ -106,6 +106,20 @@ pub trait TemporalMethods: AsSeries {
         }
     }
 
+    /// Returns the week of the calendar year, where week 1 is the week containing
+    /// January 1st and weeks start on Monday.
+    /// The return value ranges from 1 to 54.
+    fn week_of_year(&self) -> PolarsResult<Int8Chunked> {
+        let s = self.as_series();
+        match s.dtype() {
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => s.date().map(|ca| ca.week_of_year()),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(_, _) => s.datetime().map(|ca| ca.week_of_year()),
+            dt => polars_bail!(opq = week_of_year, dt),
+        }
+    }
+
     /// Returns the day of year starting from 1.
     ///
     /// The return value ranges from 1 to 366. (The last day of year differs by years.)
@@ -188,6 +188,7 @@
 //! * `timezones` - Activate timezone support.
 //! * `strings` - Extra string utilities for [`StringChunked`] //!     - `string_pad` - `zfill`, `ljust`, `rjust`
 //!     - `string_to_integer` - `parse_int`
+//!     - `string_normalize` - `normalize` to a Unicode normalization form
 //! * `object` - Support for generic ChunkedArrays called [`ObjectChunked<T>`] (generic over `T`).
 //!              These are downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
 //! * Performance related:
@@ -246,6 +247,7 @@
 //!     - `cum_agg` - [`cum_sum`], [`cum_min`], [`cum_max`] aggregation.
 //!     - `rolling_window` - rolling window functions, like [`rolling_mean`]
 //!     - `interpolate` [interpolate None values](polars_ops::chunked_array::interpolate)
+//!     - `interpolate_by` [interpolate None values against another column](polars_ops::series::interpolate_by)
 //!     - `extract_jsonpath` - [Run jsonpath queries on StringChunked](https://goessner.net/articles/JsonPath/)
 //!     - `list` - List utils.
 //!         - `list_gather` take sublist by multiple indices
@@ -253,6 +255,8 @@
 //!     - `moment` - kurtosis and skew statistics
 //!     - `ewma` - Exponential moving average windows
 //!     - `abs` - Get absolute values of [`Series`].
+//!     - `gcd_lcm` - Element-wise greatest common divisor and least common multiple of integers.
+//!     - `is_close` - Element-wise approximate equality of numeric values.
 //!     - `arange` - Range operation on [`Series`].
 //!     - `product` - Compute the product of a [`Series`].
 //!     - `diff` - [`diff`] operation.
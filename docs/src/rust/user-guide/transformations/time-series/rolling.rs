@@ -80,7 +80,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         )
         .agg([
             col("time")
-                .cum_count(true) // python example has false
+                .cum_count(true, false) // python example has false
                 .reverse()
                 .head(Some(3))
                 .alias("day/eom"),
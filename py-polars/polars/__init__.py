@@ -106,6 +106,10 @@ from polars.functions import (
     arctan2d,
     arg_sort_by,
     arg_where,
+    arr_max_horizontal,
+    arr_min_horizontal,
+    arr_sum_horizontal,
+    business_date_range,
     business_day_count,
     coalesce,
     col,
@@ -158,6 +162,7 @@ from polars.functions import (
     min,
     min_horizontal,
     n_unique,
+    networkdays,
     ones,
     quantile,
     reduce,
@@ -331,6 +336,7 @@ __all__ = [
     # polars.functions
     "align_frames",
     "arg_where",
+    "business_date_range",
     "business_day_count",
     "concat",
     "date_range",
@@ -338,6 +344,7 @@ __all__ = [
     "datetime_range",
     "datetime_ranges",
     "element",
+    "networkdays",
     "ones",
     "repeat",
     "time_range",
@@ -353,6 +360,9 @@ __all__ = [
     "sum",
     "all_horizontal",
     "any_horizontal",
+    "arr_max_horizontal",
+    "arr_min_horizontal",
+    "arr_sum_horizontal",
     "cum_sum_horizontal",
     "cumsum_horizontal",
     "max_horizontal",
@@ -8730,6 +8730,7 @@ class DataFrame:
         *,
         separator: str = "_",
         drop_first: bool = False,
+        drop_nulls: bool = False,
     ) -> Self:
         """
         Convert categorical variables into dummy/indicator variables.
@@ -8742,7 +8743,12 @@ class DataFrame:
         separator
             Separator/delimiter used when generating column names.
         drop_first
-            Remove the first category from the variables being encoded.
+            Remove the first category from the variables being encoded. This is the
+            first non-null value of each column in order of appearance.
+        drop_nulls
+            Don't create indicator columns for null values, so that rows holding a
+            null are zero in every column of that variable. By default, nulls get a
+            `{name}{separator}null` column.
 
         Examples
         --------
@@ -8800,7 +8806,9 @@ class DataFrame:
         """
         if columns is not None:
             columns = _expand_selectors(self, columns)
-        return self._from_pydf(self._df.to_dummies(columns, separator, drop_first))
+        return self._from_pydf(
+            self._df.to_dummies(columns, separator, drop_first, drop_nulls)
+        )
 
     def unique(
         self,
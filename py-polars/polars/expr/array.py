@@ -89,6 +89,32 @@ class ExprArrayNameSpace:
         """
         return wrap_expr(self._pyexpr.arr_sum())
 
+    def mean(self) -> Expr:
+        """
+        Compute the mean of the values of the sub-arrays.
+
+        Null values are ignored; a sub-array without any non-null values has a null
+        mean.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     data={"a": [[1, 2], [4, 3]]},
+        ...     schema={"a": pl.Array(pl.Int64, 2)},
+        ... )
+        >>> df.select(pl.col("a").arr.mean())
+        shape: (2, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 1.5 │
+        │ 3.5 │
+        └─────┘
+        """
+        return wrap_expr(self._pyexpr.arr_mean())
+
     def std(self, ddof: int = 1) -> Expr:
         """
         Compute the std of the values of the sub-arrays.
@@ -157,6 +157,88 @@ class ExprDateTimeNameSpace:
             )
         )
 
+    def offset_by_business(
+        self,
+        by: str | Expr,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date] = (),
+        roll: Roll = "raise",
+    ) -> Expr:
+        """
+        Offset by a relative offset that may include business days.
+
+        Parameters
+        ----------
+        by
+            The offset, in the same string language as :meth:`offset_by`, extended
+            with a `bd` unit for business days, e.g. `"5bd"` or `"1mo5bd"`. The
+            calendar part is applied first, and the business days are then counted
+            from the date it lands on. Can also be an expression of strings.
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to exclude from the count.
+        roll
+            What to do when the calendar part of the offset lands on a non-business
+            day. Options are:
+
+            - `'raise'`: raise an error
+            - `'forward'`: move to the next business day
+            - `'backward'`: move to the previous business day
+
+        Returns
+        -------
+        Expr
+            Data type is preserved.
+
+        See Also
+        --------
+        offset_by
+        add_business_days
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame({"start": [date(2024, 1, 31), date(2024, 1, 26)]})
+        >>> df.with_columns(result=pl.col("start").dt.offset_by_business("1mo1bd"))
+        shape: (2, 2)
+        ┌────────────┬────────────┐
+        │ start      ┆ result     │
+        │ ---        ┆ ---        │
+        │ date       ┆ date       │
+        ╞════════════╪════════════╡
+        │ 2024-01-31 ┆ 2024-03-01 │
+        │ 2024-01-26 ┆ 2024-02-27 │
+        └────────────┴────────────┘
+
+        If the calendar part lands on a weekend, `roll` decides where to count from:
+
+        >>> df = pl.DataFrame({"start": [date(2024, 1, 3)]})
+        >>> df.with_columns(
+        ...     result=pl.col("start").dt.offset_by_business("1mo1bd", roll="forward")
+        ... )
+        shape: (1, 2)
+        ┌────────────┬────────────┐
+        │ start      ┆ result     │
+        │ ---        ┆ ---        │
+        │ date       ┆ date       │
+        ╞════════════╪════════════╡
+        │ 2024-01-03 ┆ 2024-02-06 │
+        └────────────┴────────────┘
+        """
+        by = parse_as_expression(by, str_as_lit=True)
+        unix_epoch = dt.date(1970, 1, 1)
+        return wrap_expr(
+            self._pyexpr.dt_offset_by_business(
+                by,
+                week_mask,
+                [(holiday - unix_epoch).days for holiday in holidays],
+                roll,
+            )
+        )
+
     def truncate(
         self,
         every: str | timedelta | Expr,
@@ -491,12 +573,19 @@ class ExprDateTimeNameSpace:
             )
         )
 
-    def combine(self, time: dt.time | Expr, time_unit: TimeUnit = "us") -> Expr:
+    def combine(
+        self,
+        time: dt.time | Expr,
+        time_unit: TimeUnit = "us",
+        *,
+        time_zone: str | None = None,
+    ) -> Expr:
         """
-        Create a naive Datetime from an existing Date/Datetime expression and a Time.
+        Create a Datetime from an existing Date/Datetime expression and a Time.
 
         If the underlying expression is a Datetime then its time component is replaced,
         and if it is a Date then a new Datetime is created by combining the two values.
+        A null in either input results in a null.
 
         Parameters
         ----------
@@ -504,6 +593,11 @@ class ExprDateTimeNameSpace:
             A python time literal or polars expression/column that resolves to a time.
         time_unit : {'ns', 'us', 'ms'}
             Unit of time.
+        time_zone
+            Time zone in which to interpret the combined date and time. By default, the
+            result is in the time zone of the underlying Datetime, or naive if the
+            underlying expression is a Date. For a time zone aware Datetime, the date
+            is taken in its own time zone.
 
         Examples
         --------
@@ -544,12 +638,27 @@ class ExprDateTimeNameSpace:
         │ 2022-12-31 01:02:03.456 ┆ 2022-10-10 01:02:03.456 ┆ 2022-10-10 04:05:06 │
         │ 2023-07-05 07:08:09.101 ┆ 2022-07-05 07:08:09.101 ┆ 2022-07-05 04:05:06 │
         └─────────────────────────┴─────────────────────────┴─────────────────────┘
+
+        Interpret the combined values in a time zone:
+
+        >>> df.select(
+        ...     pl.col("dt").dt.combine(pl.col("tm"), "ms", time_zone="Europe/Amsterdam")
+        ... )
+        shape: (2, 1)
+        ┌────────────────────────────────┐
+        │ dt                             │
+        │ ---                            │
+        │ datetime[ms, Europe/Amsterdam] │
+        ╞════════════════════════════════╡
+        │ 2022-10-10 01:02:03.456 CEST   │
+        │ 2022-07-05 07:08:09.101 CEST   │
+        └────────────────────────────────┘
         """
         if not isinstance(time, (dt.time, pl.Expr)):
             msg = f"expected 'time' to be a Python time or Polars expression, found {type(time).__name__!r}"
             raise TypeError(msg)
         time = parse_as_expression(time)
-        return wrap_expr(self._pyexpr.dt_combine(time, time_unit))
+        return wrap_expr(self._pyexpr.dt_combine(time, time_unit, time_zone))
 
     def to_string(self, format: str) -> Expr:
         """
@@ -941,7 +1050,7 @@ class ExprDateTimeNameSpace:
         """
         return wrap_expr(self._pyexpr.dt_month())
 
-    def week(self) -> Expr:
+    def week(self, *, iso: bool = True) -> Expr:
         """
         Extract the week from the underlying Date representation.
 
@@ -950,11 +1059,26 @@ class ExprDateTimeNameSpace:
         Returns the ISO week number starting from 1.
         The return value ranges from 1 to 53. (The last week of year differs by years.)
 
+        Parameters
+        ----------
+        iso
+            Number the weeks according to ISO 8601, where weeks start on Monday and
+            week 1 is the week containing the first Thursday of the year. The ISO week
+            belongs to the year returned by :func:`iso_year`, which may differ from the
+            calendar year around New Year. If set to `False`, week 1 is the week
+            containing January 1st instead, weeks still start on Monday, and the week
+            always belongs to the calendar year; the return value then ranges from
+            1 to 54.
+
         Returns
         -------
         Expr
             Expression of data type :class:`Int8`.
 
+        See Also
+        --------
+        iso_year
+
         Examples
         --------
         >>> from datetime import date
@@ -972,8 +1096,29 @@ class ExprDateTimeNameSpace:
         │ 2001-06-30 ┆ 26   │
         │ 2001-12-27 ┆ 52   │
         └────────────┴──────┘
-        """
-        return wrap_expr(self._pyexpr.dt_week())
+
+        The two numberings differ around New Year:
+
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2020, 12, 31), date(2021, 1, 3), date(2021, 1, 4)]}
+        ... )
+        >>> df.with_columns(
+        ...     iso_year=pl.col("date").dt.iso_year(),
+        ...     iso_week=pl.col("date").dt.week(),
+        ...     week=pl.col("date").dt.week(iso=False),
+        ... )
+        shape: (3, 4)
+        ┌────────────┬──────────┬──────────┬──────┐
+        │ date       ┆ iso_year ┆ iso_week ┆ week │
+        │ ---        ┆ ---      ┆ ---      ┆ ---  │
+        │ date       ┆ i32      ┆ i8       ┆ i8   │
+        ╞════════════╪══════════╪══════════╪══════╡
+        │ 2020-12-31 ┆ 2020     ┆ 53       ┆ 53   │
+        │ 2021-01-03 ┆ 2020     ┆ 53       ┆ 1    │
+        │ 2021-01-04 ┆ 2021     ┆ 1        ┆ 2    │
+        └────────────┴──────────┴──────────┴──────┘
+        """
+        return wrap_expr(self._pyexpr.dt_week(iso))
 
     def weekday(self) -> Expr:
         """
@@ -1853,6 +1998,8 @@ class ExprDateTimeNameSpace:
         """
         Extract the total days from a Duration type.
 
+        Any remainder smaller than a day is truncated toward zero.
+
         Returns
         -------
         Expr
@@ -1891,6 +2038,8 @@ class ExprDateTimeNameSpace:
         """
         Extract the total hours from a Duration type.
 
+        Any remainder smaller than an hour is truncated toward zero.
+
         Returns
         -------
         Expr
@@ -1930,6 +2079,8 @@ class ExprDateTimeNameSpace:
         """
         Extract the total minutes from a Duration type.
 
+        Any remainder smaller than a minute is truncated toward zero.
+
         Returns
         -------
         Expr
@@ -1969,6 +2120,8 @@ class ExprDateTimeNameSpace:
         """
         Extract the total seconds from a Duration type.
 
+        Any remainder smaller than a second is truncated toward zero.
+
         Returns
         -------
         Expr
@@ -2010,6 +2163,8 @@ class ExprDateTimeNameSpace:
         """
         Extract the total milliseconds from a Duration type.
 
+        Any remainder smaller than a millisecond is truncated toward zero.
+
         Returns
         -------
         Expr
@@ -2052,6 +2207,10 @@ class ExprDateTimeNameSpace:
         """
         Extract the total microseconds from a Duration type.
 
+        Any remainder smaller than a microsecond is truncated toward zero.
+        Durations that can't be represented as an Int64 number of microseconds
+        become null.
+
         Returns
         -------
         Expr
@@ -2094,6 +2253,9 @@ class ExprDateTimeNameSpace:
         """
         Extract the total nanoseconds from a Duration type.
 
+        Durations that can't be represented as an Int64 number of nanoseconds
+        become null.
+
         Returns
         -------
         Expr
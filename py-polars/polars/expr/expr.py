@@ -90,6 +90,8 @@ if TYPE_CHECKING:
         MapElementsStrategy,
         NullBehavior,
         NumericLiteral,
+        OutOfBounds,
+        PlateauBehavior,
         PolarsDataType,
         RankMethod,
         RollingInterpolationMethod,
@@ -1606,7 +1608,7 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.cum_sum(reverse))
 
-    def cum_prod(self, *, reverse: bool = False) -> Self:
+    def cum_prod(self, *, reverse: bool = False, strict: bool = False) -> Self:
         """
         Get an array with the cumulative product computed at every element.
 
@@ -1614,6 +1616,9 @@ class Expr:
         ----------
         reverse
             Reverse the operation.
+        strict
+            Raise an error naming the offending index when an integer product
+            overflows, instead of silently wrapping around. Has no effect on floats.
 
         Notes
         -----
@@ -1639,7 +1644,7 @@ class Expr:
         │ 4   ┆ 24       ┆ 4                │
         └─────┴──────────┴──────────────────┘
         """
-        return self._from_pyexpr(self._pyexpr.cum_prod(reverse))
+        return self._from_pyexpr(self._pyexpr.cum_prod(reverse, strict))
 
     def cum_min(self, *, reverse: bool = False) -> Self:
         """
@@ -1724,14 +1729,17 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.cum_max(reverse))
 
-    def cum_count(self, *, reverse: bool = False) -> Self:
+    def cum_count(self, *, reverse: bool = False, count_nulls: bool = False) -> Self:
         """
         Return the cumulative count of the non-null values in the column.
 
         Parameters
         ----------
         reverse
-            Reverse the operation.
+            Reverse the operation, counting from each value to the end.
+        count_nulls
+            Count null values as well, which turns the result into a running row
+            number.
 
         Examples
         --------
@@ -1752,7 +1760,7 @@ class Expr:
         │ d    ┆ 3         ┆ 1                 │
         └──────┴───────────┴───────────────────┘
         """
-        return self._from_pyexpr(self._pyexpr.cum_count(reverse))
+        return self._from_pyexpr(self._pyexpr.cum_count(reverse, count_nulls))
 
     def floor(self) -> Self:
         """
@@ -1806,6 +1814,9 @@ class Expr:
         """
         Round underlying floating point data by `decimals` digits.
 
+        Decimal data is rounded half away from zero at its own scale, without a
+        round-trip through floating point.
+
         Parameters
         ----------
         decimals
@@ -1885,12 +1896,24 @@ class Expr:
         other = parse_as_expression(other)
         return self._from_pyexpr(self._pyexpr.dot(other))
 
-    def mode(self) -> Self:
+    def mode(self, *, maintain_order: bool = False, ignore_nulls: bool = False) -> Self:
         """
         Compute the most occurring value(s).
 
         Can return multiple Values.
 
+        Parameters
+        ----------
+        maintain_order
+            Return tied values in order of their first appearance. If not set, the
+            order of tied values is not deterministic.
+        ignore_nulls
+            Ignore null values. If not set, null is counted like any other value.
+
+        See Also
+        --------
+        mode_first
+
         Examples
         --------
         >>> df = pl.DataFrame(
@@ -1909,8 +1932,69 @@ class Expr:
         │ 1   ┆ 1   │
         │ 1   ┆ 2   │
         └─────┴─────┘
+
+        Return tied values in order of their first appearance.
+
+        >>> df = pl.DataFrame({"a": [3, None, 1, None, 1, 3]})
+        >>> df.select(pl.col("a").mode(maintain_order=True))
+        shape: (3, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ i64  │
+        ╞══════╡
+        │ 3    │
+        │ null │
+        │ 1    │
+        └──────┘
+        >>> df.select(pl.col("a").mode(maintain_order=True, ignore_nulls=True))
+        shape: (2, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 3   │
+        │ 1   │
+        └─────┘
+        """
+        return self._from_pyexpr(self._pyexpr.mode(maintain_order, ignore_nulls))
+
+    def mode_first(self, *, ignore_nulls: bool = False) -> Self:
+        """
+        Compute the single most occurring value.
+
+        Ties are broken by first appearance, so the result is always one value. This
+        is null if there are no values.
+
+        Parameters
+        ----------
+        ignore_nulls
+            Ignore null values. If not set, null is counted like any other value.
+
+        See Also
+        --------
+        mode
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [1, 1, 2, 3],
+        ...         "b": [2, 1, 1, 2],
+        ...     }
+        ... )
+        >>> df.select(pl.all().mode_first())
+        shape: (1, 2)
+        ┌─────┬─────┐
+        │ a   ┆ b   │
+        │ --- ┆ --- │
+        │ i64 ┆ i64 │
+        ╞═════╪═════╡
+        │ 1   ┆ 2   │
+        └─────┴─────┘
         """
-        return self._from_pyexpr(self._pyexpr.mode())
+        return self._from_pyexpr(self._pyexpr.mode_first(ignore_nulls))
 
     def cast(self, dtype: PolarsDataType | type[Any], *, strict: bool = True) -> Self:
         """
@@ -2062,7 +2146,8 @@ class Expr:
         nulls_last
             Place null values last.
         maintain_order
-            Whether the order should be maintained if elements are equal.
+            Whether the order should be maintained if elements are equal. Rows with
+            equal `by` values are always returned in their original order.
         multithreaded
             Sort using multiple threads.
 
@@ -2228,7 +2313,8 @@ class Expr:
         nulls_last
             Place null values last.
         maintain_order
-            Whether the order should be maintained if elements are equal.
+            Whether the order should be maintained if elements are equal. Rows with
+            equal `by` values are always returned in their original order.
         multithreaded
             Sort using multiple threads.
 
@@ -2481,6 +2567,11 @@ class Expr:
             If 'left', the index of the leftmost suitable location found is given.
             If 'right', return the rightmost suitable location found is given.
 
+        Notes
+        -----
+        The data is assumed to be sorted in ascending order. This is not checked, and
+        the result is undefined if it isn't.
+
         Examples
         --------
         >>> df = pl.DataFrame(
@@ -2652,7 +2743,10 @@ class Expr:
         )
 
     def gather(
-        self, indices: int | list[int] | Expr | Series | np.ndarray[Any, Any]
+        self,
+        indices: int | list[int] | Expr | Series | np.ndarray[Any, Any],
+        *,
+        out_of_bounds: OutOfBounds = "raise",
     ) -> Self:
         """
         Take values by index.
@@ -2660,7 +2754,16 @@ class Expr:
         Parameters
         ----------
         indices
-            An expression that leads to a UInt32 dtyped Series.
+            An expression that leads to an integer dtyped Series. Negative indices
+            count from the end.
+        out_of_bounds : {'raise', 'null', 'wrap'}
+            What to do with indices that are out of bounds, after negative
+            indices have been counted from the end.
+
+            - 'raise': raise an error.
+            - 'null': return a null value.
+            - 'wrap': wrap the index around modulo the length. Indexing into
+              an empty column still raises.
 
         Returns
         -------
@@ -2698,6 +2801,16 @@ class Expr:
         │ one   ┆ [2, 98]   │
         │ two   ┆ [4, 99]   │
         └───────┴───────────┘
+        >>> df.select(pl.col("value").gather([-1, 6], out_of_bounds="null"))
+        shape: (2, 1)
+        ┌───────┐
+        │ value │
+        │ ---   │
+        │ i64   │
+        ╞═══════╡
+        │ 4     │
+        │ null  │
+        └───────┘
         """
         if isinstance(indices, list) or (
             _check_for_numpy(indices) and isinstance(indices, np.ndarray)
@@ -2705,7 +2818,7 @@ class Expr:
             indices_lit = F.lit(pl.Series("", indices, dtype=Int64))._pyexpr
         else:
             indices_lit = parse_as_expression(indices)  # type: ignore[arg-type]
-        return self._from_pyexpr(self._pyexpr.gather(indices_lit))
+        return self._from_pyexpr(self._pyexpr.gather(indices_lit, out_of_bounds))
 
     def get(self, index: int | Expr) -> Self:
         """
@@ -3304,12 +3417,39 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.n_unique())
 
-    def approx_n_unique(self) -> Self:
+    def approx_n_unique(self, *, precision: int = 14) -> Self:
         """
         Approximate count of unique values.
 
         This is done using the HyperLogLog++ algorithm for cardinality estimation.
 
+        Parameters
+        ----------
+        precision
+            Number of bits of the hash used to select one of the `2 ** precision`
+            registers, between 4 and 18. A higher precision uses more memory, one byte
+            per register, but gives a more accurate estimate.
+
+        Notes
+        -----
+        The relative standard error of the estimate is about
+        `1.04 / sqrt(2 ** precision)`:
+
+        =========  =========  ==============
+        precision  registers  standard error
+        =========  =========  ==============
+        4          16         26%
+        8          256        6.5%
+        10         1024       3.3%
+        12         4096       1.6%
+        14         16384      0.81%
+        16         65536      0.41%
+        18         262144     0.20%
+        =========  =========  ==============
+
+        As long as there are at most `2 ** precision` unique values, they are
+        counted exactly.
+
         Examples
         --------
         >>> df = pl.DataFrame({"n": [1, 1, 2]})
@@ -3325,7 +3465,7 @@ class Expr:
         >>> df = pl.DataFrame({"n": range(1000)})
         >>> df.select(
         ...     exact=pl.col("n").n_unique(),
-        ...     approx=pl.col("n").approx_n_unique(),
+        ...     approx=pl.col("n").approx_n_unique(precision=8),
         ... )  # doctest: +SKIP
         shape: (1, 2)
         ┌───────┬────────┐
@@ -3333,10 +3473,10 @@ class Expr:
         │ ---   ┆ ---    │
         │ u32   ┆ u32    │
         ╞═══════╪════════╡
-        │ 1000  ┆ 1005   │
+        │ 1000  ┆ 937    │
         └───────┴────────┘
         """
-        return self._from_pyexpr(self._pyexpr.approx_n_unique())
+        return self._from_pyexpr(self._pyexpr.approx_n_unique(precision))
 
     def null_count(self) -> Self:
         """
@@ -3833,10 +3973,35 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.is_duplicated())
 
-    def peak_max(self) -> Self:
+    def peak_max(
+        self,
+        *,
+        plateau: PlateauBehavior = "none",
+        min_prominence: float | None = None,
+    ) -> Self:
         """
         Get a boolean mask of the local maximum peaks.
 
+        Parameters
+        ----------
+        plateau : {'none', 'all', 'first', 'last'}
+            Which elements of a plateau, a run of equal values that forms a peak, to
+            mark.
+
+            - 'none': only mark peaks that consist of a single value.
+            - 'all': mark every element of the plateau.
+            - 'first': only mark the first element of the plateau.
+            - 'last': only mark the last element of the plateau.
+        min_prominence
+            Only mark peaks that rise at least this much above the higher of the two
+            lowest points passed on either side before reaching a higher value or the
+            end of the data. Use this to ignore small peaks caused by noise.
+
+        Notes
+        -----
+        Values outside of the data are treated as zero when comparing the first and
+        last element to their neighbours.
+
         Examples
         --------
         >>> df = pl.DataFrame({"a": [1, 2, 3, 4, 5]})
@@ -3853,13 +4018,56 @@ class Expr:
         │ false │
         │ true  │
         └───────┘
+
+        Mark only the first element of a plateau:
+
+        >>> df = pl.DataFrame({"a": [1, 3, 3, 1, 2, 1]})
+        >>> df.select(pl.col("a").peak_max(plateau="first"))
+        shape: (6, 1)
+        ┌───────┐
+        │ a     │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ false │
+        │ true  │
+        │ false │
+        │ false │
+        │ true  │
+        │ false │
+        └───────┘
         """
-        return self._from_pyexpr(self._pyexpr.peak_max())
+        return self._from_pyexpr(self._pyexpr.peak_max(plateau, min_prominence))
 
-    def peak_min(self) -> Self:
+    def peak_min(
+        self,
+        *,
+        plateau: PlateauBehavior = "none",
+        min_prominence: float | None = None,
+    ) -> Self:
         """
         Get a boolean mask of the local minimum peaks.
 
+        Parameters
+        ----------
+        plateau : {'none', 'all', 'first', 'last'}
+            Which elements of a plateau, a run of equal values that forms a peak, to
+            mark.
+
+            - 'none': only mark peaks that consist of a single value.
+            - 'all': mark every element of the plateau.
+            - 'first': only mark the first element of the plateau.
+            - 'last': only mark the last element of the plateau.
+        min_prominence
+            Only mark peaks that drop at least this much below the lower of the two
+            highest points passed on either side before reaching a lower value or the
+            end of the data. Use this to ignore small peaks caused by noise.
+
+        Notes
+        -----
+        Values outside of the data are treated as zero when comparing the first and
+        last element to their neighbours.
+
         Examples
         --------
         >>> df = pl.DataFrame({"a": [4, 1, 3, 2, 5]})
@@ -3876,8 +4084,25 @@ class Expr:
         │ true  │
         │ false │
         └───────┘
+
+        Ignore minima that do not stand out by at least 2:
+
+        >>> df = pl.DataFrame({"a": [4, 1, 3, 2, 5]})
+        >>> df.select(pl.col("a").peak_min(min_prominence=2))
+        shape: (5, 1)
+        ┌───────┐
+        │ a     │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ false │
+        │ true  │
+        │ false │
+        │ false │
+        │ false │
+        └───────┘
         """
-        return self._from_pyexpr(self._pyexpr.peak_min())
+        return self._from_pyexpr(self._pyexpr.peak_min(plateau, min_prominence))
 
     def quantile(
         self,
@@ -4151,13 +4376,13 @@ class Expr:
         Compress the column data using run-length encoding.
 
         Run-length encoding (RLE) encodes data by storing each *run* of identical values
-        as a single value and its length.
+        as a single value and its length. Consecutive null values form a single run.
 
         Returns
         -------
         Expr
-            Expression of data type `Struct` with fields `lengths` of data type `Int32`
-            and `values` of the original data type.
+            Expression of data type `Struct` with fields `len` of data type `UInt32`
+            and `value` of the original data type.
 
         See Also
         --------
@@ -4168,18 +4393,18 @@ class Expr:
         >>> df = pl.DataFrame({"a": [1, 1, 2, 1, None, 1, 3, 3]})
         >>> df.select(pl.col("a").rle()).unnest("a")
         shape: (6, 2)
-        ┌─────────┬────────┐
-        │ lengths ┆ values │
-        │ ---     ┆ ---    │
-        │ i32     ┆ i64    │
-        ╞═════════╪════════╡
-        │ 2       ┆ 1      │
-        │ 1       ┆ 2      │
-        │ 1       ┆ 1      │
-        │ 1       ┆ null   │
-        │ 1       ┆ 1      │
-        │ 2       ┆ 3      │
-        └─────────┴────────┘
+        ┌─────┬───────┐
+        │ len ┆ value │
+        │ --- ┆ ---   │
+        │ u32 ┆ i64   │
+        ╞═════╪═══════╡
+        │ 2   ┆ 1     │
+        │ 1   ┆ 2     │
+        │ 1   ┆ 1     │
+        │ 1   ┆ null  │
+        │ 1   ┆ 1     │
+        │ 2   ┆ 3     │
+        └─────┴───────┘
         """
         return self._from_pyexpr(self._pyexpr.rle())
 
@@ -5604,6 +5829,47 @@ class Expr:
         """
         return self.__pow__(exponent)
 
+    def mul_add(
+        self,
+        multiplier: IntoExpr,
+        addend: IntoExpr,
+    ) -> Self:
+        """
+        Compute `expr * multiplier + addend` as a single fused operation.
+
+        For floating point data the result is computed with one rounding step
+        (fused multiply-add), which is both faster and more accurate than
+        evaluating the multiplication and addition separately. Other numeric
+        data types are computed as a regular multiplication followed by an addition.
+
+        Parameters
+        ----------
+        multiplier
+            Value to multiply with; accepts expression input. Must have the same
+            length as this expression or be of length 1.
+        addend
+            Value to add to the product; accepts expression input. Must have the
+            same length as this expression or be of length 1.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"x": [1.0, 2.0, 3.0], "y": [0.5, 1.5, 2.5]})
+        >>> df.with_columns(pl.col("x").mul_add(pl.col("y"), 1.0).alias("x * y + 1"))
+        shape: (3, 3)
+        ┌─────┬─────┬───────────┐
+        │ x   ┆ y   ┆ x * y + 1 │
+        │ --- ┆ --- ┆ ---       │
+        │ f64 ┆ f64 ┆ f64       │
+        ╞═════╪═════╪═══════════╡
+        │ 1.0 ┆ 0.5 ┆ 1.5       │
+        │ 2.0 ┆ 1.5 ┆ 4.0       │
+        │ 3.0 ┆ 2.5 ┆ 8.5       │
+        └─────┴─────┴───────────┘
+        """
+        multiplier = parse_as_expression(multiplier)
+        addend = parse_as_expression(addend)
+        return self._from_pyexpr(self._pyexpr.mul_add(multiplier, addend))
+
     def xor(self, other: Any) -> Self:
         """
         Method equivalent of bitwise exclusive-or operator `expr ^ other`.
@@ -5665,14 +5931,23 @@ class Expr:
         """
         return self.__xor__(other)
 
-    def is_in(self, other: Expr | Collection[Any] | Series) -> Self:
+    def is_in(
+        self, other: Expr | Collection[Any] | Series, *, nulls_equal: bool = False
+    ) -> Self:
         """
         Check if elements of this expression are present in the other Series.
 
+        If `other` is a `List` column, every value is looked up in the list of the
+        same row instead of in a single set of values.
+
         Parameters
         ----------
         other
             Series or sequence of primitive type.
+        nulls_equal
+            Whether a null value is considered to be present if `other` (or the list
+            of the same row) contains a null. By default, null values result in null.
+            A null list always results in null.
 
         Returns
         -------
@@ -5695,6 +5970,24 @@ class Expr:
         │ [1, 2]    ┆ 2                ┆ true     │
         │ [9, 10]   ┆ 3                ┆ false    │
         └───────────┴──────────────────┴──────────┘
+
+        Use `nulls_equal` to match null values against nulls in the set.
+
+        >>> df = pl.DataFrame({"a": [1, None, 3]})
+        >>> df.with_columns(
+        ...     is_in=pl.col("a").is_in([1, None]),
+        ...     is_in_nulls_equal=pl.col("a").is_in([1, None], nulls_equal=True),
+        ... )
+        shape: (3, 3)
+        ┌──────┬───────┬───────────────────┐
+        │ a    ┆ is_in ┆ is_in_nulls_equal │
+        │ ---  ┆ ---   ┆ ---               │
+        │ i64  ┆ bool  ┆ bool              │
+        ╞══════╪═══════╪═══════════════════╡
+        │ 1    ┆ true  ┆ true              │
+        │ null ┆ null  ┆ true              │
+        │ 3    ┆ false ┆ false             │
+        └──────┴───────┴───────────────────┘
         """
         if isinstance(other, Collection) and not isinstance(other, str):
             if isinstance(other, (Set, FrozenSet)):
@@ -5702,7 +5995,7 @@ class Expr:
             other = F.lit(pl.Series(other))._pyexpr
         else:
             other = parse_as_expression(other)
-        return self._from_pyexpr(self._pyexpr.is_in(other))
+        return self._from_pyexpr(self._pyexpr.is_in(other, nulls_equal))
 
     def repeat_by(self, by: pl.Series | Expr | str | int) -> Self:
         """
@@ -5860,6 +6153,72 @@ class Expr:
             self._pyexpr.is_between(lower_bound, upper_bound, closed)
         )
 
+    def is_close(
+        self,
+        other: IntoExpr,
+        *,
+        rel_tol: float = 1e-09,
+        abs_tol: float = 0.0,
+        nans_equal: bool = False,
+    ) -> Self:
+        """
+        Check if this expression is approximately equal to another expression.
+
+        Two values `a` and `b` are considered close if
+        `abs(a - b) <= max(rel_tol * max(abs(a), abs(b)), abs_tol)`.
+
+        Parameters
+        ----------
+        other
+            Numeric expression or scalar to compare with. Accepts expression input.
+            Non-expression inputs are parsed as literals.
+        rel_tol
+            Maximum difference allowed, relative to the larger absolute value of the
+            two inputs. Must be non-negative.
+        abs_tol
+            Minimum absolute difference that is always allowed, which is useful for
+            comparisons near zero. Must be non-negative.
+        nans_equal
+            Consider NaN values to be close to each other.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Notes
+        -----
+        Infinite values are only close to an infinite value of the same sign. Nulls
+        propagate.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [100.0, 100.0, float("nan"), 0.0],
+        ...         "b": [100.5, 102.0, float("nan"), 0.001],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     is_close=pl.col("a").is_close(pl.col("b"), rel_tol=0.01, abs_tol=0.01)
+        ... )
+        shape: (4, 3)
+        ┌───────┬───────┬──────────┐
+        │ a     ┆ b     ┆ is_close │
+        │ ---   ┆ ---   ┆ ---      │
+        │ f64   ┆ f64   ┆ bool     │
+        ╞═══════╪═══════╪══════════╡
+        │ 100.0 ┆ 100.5 ┆ true     │
+        │ 100.0 ┆ 102.0 ┆ false    │
+        │ NaN   ┆ NaN   ┆ false    │
+        │ 0.0   ┆ 0.001 ┆ true     │
+        └───────┴───────┴──────────┘
+        """
+        other = parse_as_expression(other)
+        return self._from_pyexpr(
+            self._pyexpr.is_close(other, rel_tol, abs_tol, nans_equal)
+        )
+
     def hash(
         self,
         seed: int = 0,
@@ -5887,7 +6246,13 @@ class Expr:
         -----
         This implementation of `hash` does not guarantee stable results
         across different Polars versions. Its stability is only guaranteed within a
-        single version.
+        single version: for the same seeds, data type and platform, the hashes are
+        reproducible across sessions, which makes them suitable for deterministic
+        partitioning.
+
+        The hash is not cryptographic. Do not use it to protect sensitive data, as
+        collisions can be constructed deliberately and the input can be recovered
+        for small domains.
 
         Examples
         --------
@@ -5983,14 +6348,21 @@ class Expr:
 
         return self.map_batches(inspect, return_dtype=None, agg_list=True)
 
-    def interpolate(self, method: InterpolationMethod = "linear") -> Self:
+    def interpolate(
+        self, method: InterpolationMethod = "linear", *, max_gap: int | None = None
+    ) -> Self:
         """
         Fill null values using interpolation.
 
         Parameters
         ----------
         method : {'linear', 'nearest'}
-            Interpolation method.
+            Interpolation method. `'nearest'` fills each null with the closest
+            non-null value, preferring the earlier one on ties, and keeps the
+            data type unchanged.
+        max_gap
+            The maximum number of consecutive null values to fill. Longer gaps are
+            left null entirely instead of being filled partially.
 
         Examples
         --------
@@ -6024,7 +6396,7 @@ class Expr:
         │ i64 ┆ f64 │
         ╞═════╪═════╡
         │ 1   ┆ 1.0 │
-        │ 3   ┆ NaN │
+        │ 1   ┆ NaN │
         │ 3   ┆ 3.0 │
         └─────┴─────┘
 
@@ -6057,8 +6429,110 @@ class Expr:
         │ 9           ┆ 18.0   │
         │ 10          ┆ 20.0   │
         └─────────────┴────────┘
+
+        Only fill gaps of at most one null value.
+
+        >>> df = pl.DataFrame({"a": [1, None, 3, None, None, 6]})
+        >>> df.select(pl.col("a").interpolate(max_gap=1))
+        shape: (6, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ 1.0  │
+        │ 2.0  │
+        │ 3.0  │
+        │ null │
+        │ null │
+        │ 6.0  │
+        └──────┘
+        """
+        return self._from_pyexpr(self._pyexpr.interpolate(method, max_gap))
+
+    def interpolate_by(
+        self,
+        by: IntoExpr,
+        method: InterpolationMethod = "linear",
+        *,
+        max_gap: int | float | timedelta | Expr | None = None,
+    ) -> Self:
+        """
+        Fill null values using interpolation based on another column.
+
+        Unlike :meth:`interpolate`, which assumes the values are evenly spaced, the
+        interpolation weights are taken from the x-coordinates in `by`.
+
+        Parameters
+        ----------
+        by
+            Column with the x-coordinates to interpolate against. Must be numeric or
+            temporal, without nulls, and sorted in either ascending or descending
+            order.
+        method : {'linear', 'nearest'}
+            Interpolation method.
+        max_gap
+            The maximum distance in `by` between the non-null values around a gap
+            for the gap to be filled. Wider gaps are left null. Must be a duration
+            if `by` is temporal.
+
+        Notes
+        -----
+        Null values before the first and after the last non-null value are not
+        extrapolated and remain null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "times": [1, 2, 5, 6],
+        ...         "values": [1.0, None, None, 6.0],
+        ...     }
+        ... )
+        >>> df.select(pl.col("values").interpolate_by("times"))
+        shape: (4, 1)
+        ┌────────┐
+        │ values │
+        │ ---    │
+        │ f64    │
+        ╞════════╡
+        │ 1.0    │
+        │ 2.0    │
+        │ 5.0    │
+        │ 6.0    │
+        └────────┘
+
+        Do not bridge gaps of more than two days.
+
+        >>> from datetime import date, timedelta
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": [date(2024, 1, d) for d in (1, 2, 3, 7, 8, 9)],
+        ...         "values": [1.0, None, 3.0, None, None, 9.0],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     pl.col("values").interpolate_by("date", max_gap=timedelta(days=2))
+        ... )
+        shape: (6, 2)
+        ┌────────────┬────────┐
+        │ date       ┆ values │
+        │ ---        ┆ ---    │
+        │ date       ┆ f64    │
+        ╞════════════╪════════╡
+        │ 2024-01-01 ┆ 1.0    │
+        │ 2024-01-02 ┆ 2.0    │
+        │ 2024-01-03 ┆ 3.0    │
+        │ 2024-01-07 ┆ null   │
+        │ 2024-01-08 ┆ null   │
+        │ 2024-01-09 ┆ 9.0    │
+        └────────────┴────────┘
         """
-        return self._from_pyexpr(self._pyexpr.interpolate(method))
+        by = parse_as_expression(by)
+        max_gap_pyexpr = None if max_gap is None else parse_as_expression(max_gap)
+        return self._from_pyexpr(
+            self._pyexpr.interpolate_by(by, method, max_gap_pyexpr)
+        )
 
     @unstable()
     def rolling_min(
@@ -6130,7 +6604,8 @@ class Expr:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
         by
             If the `window_size` is temporal for instance `"5h"` or `"3s"`, you must
             set the column that will be used to determine the windows. This column must
@@ -6342,7 +6817,8 @@ class Expr:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
         by
             If the `window_size` is temporal, for instance `"5h"` or `"3s"`, you must
             set the column that will be used to determine the windows. This column must
@@ -6579,7 +7055,8 @@ class Expr:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
         by
             If the `window_size` is temporal for instance `"5h"` or `"3s"`, you must
             set the column that will be used to determine the windows. This column must
@@ -6826,7 +7303,8 @@ class Expr:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
         by
             If the `window_size` is temporal for instance `"5h"` or `"3s"`, you must
             set the column that will be used to determine the windows. This column must
@@ -7061,7 +7539,8 @@ class Expr:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
         by
             If the `window_size` is temporal for instance `"5h"` or `"3s"`, you must
             set the column that will be used to determine the windows. This column must
@@ -7308,7 +7787,8 @@ class Expr:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
         by
             If the `window_size` is temporal for instance `"5h"` or `"3s"`, you must
             set the column that will be used to determine the windows. This column must
@@ -7555,7 +8035,8 @@ class Expr:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
         by
             If the `window_size` is temporal for instance `"5h"` or `"3s"`, you must
             set the column that will be used to determine the windows. This column must
@@ -7683,7 +8164,7 @@ class Expr:
         quantile
             Quantile between 0.0 and 1.0.
         interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear'}
-            Interpolation method.
+            Interpolation method, the same as for :meth:`quantile`.
         window_size
             The length of the window. Can be a fixed integer size, or a dynamic
             temporal size indicated by a timedelta or the following string language:
@@ -7718,7 +8199,8 @@ class Expr:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
         by
             If the `window_size` is temporal for instance `"5h"` or `"3s"`, you must
             set the column that will be used to determine the windows. This column must
@@ -7846,7 +8328,9 @@ class Expr:
         )
 
     @unstable()
-    def rolling_skew(self, window_size: int, *, bias: bool = True) -> Self:
+    def rolling_skew(
+        self, window_size: int, *, bias: bool = True, min_periods: int | None = None
+    ) -> Self:
         """
         Compute a rolling skew.
 
@@ -7863,6 +8347,10 @@ class Expr:
             Integer size of the rolling window.
         bias
             If False, the calculations are corrected for statistical bias.
+        min_periods
+            The number of values in the window that should be non-null before
+            computing a result. If set to `None` (default), it will be set equal to
+            `window_size`. Windows with zero variance also produce null.
 
         Examples
         --------
@@ -7885,8 +8373,63 @@ class Expr:
         >>> pl.Series([1, 4, 2]).skew(), pl.Series([4, 2, 9]).skew()
         (0.38180177416060584, 0.47033046033698594)
         """
-        return self._from_pyexpr(self._pyexpr.rolling_skew(window_size, bias))
-
+        return self._from_pyexpr(
+            self._pyexpr.rolling_skew(window_size, bias, min_periods)
+        )
+
+    @unstable()
+    def rolling_kurtosis(
+        self,
+        window_size: int,
+        *,
+        fisher: bool = True,
+        bias: bool = True,
+        min_periods: int | None = None,
+    ) -> Self:
+        """
+        Compute a rolling kurtosis.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row includes the row itself and the
+        `window_size - 1` elements before it.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        fisher
+            If True, Fisher's definition is used (normal ==> 0.0). If False,
+            Pearson's definition is used (normal ==> 3.0).
+        bias
+            If False, the calculations are corrected for statistical bias.
+        min_periods
+            The number of values in the window that should be non-null before
+            computing a result. If set to `None` (default), it will be set equal to
+            `window_size`. Windows with zero variance also produce null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 9]})
+        >>> df.select(pl.col("a").rolling_kurtosis(3))
+        shape: (4, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ null │
+        │ null │
+        │ -1.5 │
+        │ -1.5 │
+        └──────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_kurtosis(window_size, fisher, bias, min_periods)
+        )
+
     @unstable()
     def rolling_map(
         self,
@@ -7982,11 +8525,173 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.abs())
 
+    def bit_count(self) -> Self:
+        """
+        Count the number of set bits of every integer, also known as popcount.
+
+        Negative integers count the bits of their two's complement representation.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [0, 1, 7, -1]}, schema={"a": pl.Int8})
+        >>> df.with_columns(
+        ...     bit_count=pl.col("a").bit_count(),
+        ...     leading_zeros=pl.col("a").leading_zeros(),
+        ...     trailing_zeros=pl.col("a").trailing_zeros(),
+        ... )
+        shape: (4, 4)
+        ┌─────┬───────────┬───────────────┬────────────────┐
+        │ a   ┆ bit_count ┆ leading_zeros ┆ trailing_zeros │
+        │ --- ┆ ---       ┆ ---           ┆ ---            │
+        │ i8  ┆ u32       ┆ u32           ┆ u32            │
+        ╞═════╪═══════════╪═══════════════╪════════════════╡
+        │ 0   ┆ 0         ┆ 8             ┆ 8              │
+        │ 1   ┆ 1         ┆ 7             ┆ 0              │
+        │ 7   ┆ 3         ┆ 5             ┆ 0              │
+        │ -1  ┆ 8         ┆ 0             ┆ 0              │
+        └─────┴───────────┴───────────────┴────────────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.bit_count())
+
+    def leading_zeros(self) -> Self:
+        """
+        Count the number of leading zero bits of every integer.
+
+        The count is relative to the width of the data type, so zero has as many
+        leading zeros as the data type has bits.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [0, 1, 255]}, schema={"a": pl.UInt16})
+        >>> df.select(pl.col("a").leading_zeros())
+        shape: (3, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ u32 │
+        ╞═════╡
+        │ 16  │
+        │ 15  │
+        │ 8   │
+        └─────┘
+        """
+        return self._from_pyexpr(self._pyexpr.leading_zeros())
+
+    def trailing_zeros(self) -> Self:
+        """
+        Count the number of trailing zero bits of every integer.
+
+        Zero has as many trailing zeros as the data type has bits.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [0, 1, 8]}, schema={"a": pl.UInt16})
+        >>> df.select(pl.col("a").trailing_zeros())
+        shape: (3, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ u32 │
+        ╞═════╡
+        │ 16  │
+        │ 0   │
+        │ 3   │
+        └─────┘
+        """
+        return self._from_pyexpr(self._pyexpr.trailing_zeros())
+
+    def gcd(self, other: int | IntoExprColumn) -> Self:
+        """
+        Compute the element-wise greatest common divisor with another integer column.
+
+        Parameters
+        ----------
+        other
+            Integer expression or scalar. Accepts expression input. Non-expression
+            inputs are parsed as literals.
+
+        Notes
+        -----
+        The result is non-negative, with `gcd(0, 0) == 0`, and has the supertype of
+        both inputs. Nulls propagate.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [12, -18, 0, 7], "b": [18, 12, 0, -21]})
+        >>> df.with_columns(gcd=pl.col("a").gcd(pl.col("b")))
+        shape: (4, 3)
+        ┌─────┬─────┬─────┐
+        │ a   ┆ b   ┆ gcd │
+        │ --- ┆ --- ┆ --- │
+        │ i64 ┆ i64 ┆ i64 │
+        ╞═════╪═════╪═════╡
+        │ 12  ┆ 18  ┆ 6   │
+        │ -18 ┆ 12  ┆ 6   │
+        │ 0   ┆ 0   ┆ 0   │
+        │ 7   ┆ -21 ┆ 7   │
+        └─────┴─────┴─────┘
+        """
+        other = parse_as_expression(other)
+        return self._from_pyexpr(self._pyexpr.gcd(other))
+
+    def lcm(self, other: int | IntoExprColumn, *, saturate: bool = False) -> Self:
+        """
+        Compute the element-wise least common multiple with another integer column.
+
+        Parameters
+        ----------
+        other
+            Integer expression or scalar. Accepts expression input. Non-expression
+            inputs are parsed as literals.
+        saturate
+            If the result does not fit in the dtype, return its maximum value instead
+            of raising an error.
+
+        Notes
+        -----
+        The result is non-negative, zero if either value is zero, and has the
+        supertype of both inputs. Nulls propagate.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [12, -18, 0, 7], "b": [18, 12, 5, -21]})
+        >>> df.with_columns(lcm=pl.col("a").lcm(pl.col("b")))
+        shape: (4, 3)
+        ┌─────┬─────┬─────┐
+        │ a   ┆ b   ┆ lcm │
+        │ --- ┆ --- ┆ --- │
+        │ i64 ┆ i64 ┆ i64 │
+        ╞═════╪═════╪═════╡
+        │ 12  ┆ 18  ┆ 36  │
+        │ -18 ┆ 12  ┆ 36  │
+        │ 0   ┆ 5   ┆ 0   │
+        │ 7   ┆ -21 ┆ 21  │
+        └─────┴─────┴─────┘
+        """
+        other = parse_as_expression(other)
+        return self._from_pyexpr(self._pyexpr.lcm(other, saturate))
+
     def rank(
         self,
         method: RankMethod = "average",
         *,
         descending: bool = False,
+        nulls_last: bool | None = None,
         seed: int | None = None,
     ) -> Self:
         """
@@ -7994,7 +8699,7 @@ class Expr:
 
         Parameters
         ----------
-        method : {'average', 'min', 'max', 'dense', 'ordinal', 'random'}
+        method : {'average', 'min', 'max', 'dense', 'ordinal', 'first', 'random'}
             The method used to assign ranks to tied elements.
             The following methods are available (default is 'average'):
 
@@ -8010,10 +8715,16 @@ class Expr:
               elements.
             - 'ordinal' : All values are given a distinct rank, corresponding to
               the order that the values occur in the Series.
+            - 'first' : Alias for 'ordinal'.
             - 'random' : Like 'ordinal', but the rank for ties is not dependent
               on the order that the values occur in the Series.
         descending
             Rank in descending order.
+        nulls_last
+            Where to place null values in the ranking. By default nulls are not ranked
+            and stay null. If True, they are ranked as a single tie group after all
+            other values; if False, before them. This does not depend on
+            `descending`.
         seed
             If `method="random"`, use this as seed.
 
@@ -8070,7 +8781,9 @@ class Expr:
         │ 2   ┆ 11  ┆ 2.0  │
         └─────┴─────┴──────┘
         """
-        return self._from_pyexpr(self._pyexpr.rank(method, descending, seed))
+        return self._from_pyexpr(
+            self._pyexpr.rank(method, descending, nulls_last, seed)
+        )
 
     def diff(self, n: int = 1, null_behavior: NullBehavior = "ignore") -> Self:
         """
@@ -8079,9 +8792,16 @@ class Expr:
         Parameters
         ----------
         n
-            Number of slots to shift.
+            Number of slots to shift. A negative value takes the difference with the
+            value that many slots ahead instead.
         null_behavior : {'ignore', 'drop'}
-            How to handle null values.
+            How to handle the positions that have no value to take the difference
+            with: `'ignore'` sets them to null, `'drop'` removes them.
+
+        Notes
+        -----
+        Unsigned integers are cast to a signed integer type, so that negative
+        differences can be represented.
 
         Examples
         --------
@@ -8128,7 +8848,12 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.diff(n, null_behavior))
 
-    def pct_change(self, n: int | IntoExprColumn = 1) -> Self:
+    def pct_change(
+        self,
+        n: int | IntoExprColumn = 1,
+        *,
+        fill_null_strategy: FillNullStrategy | None = "forward",
+    ) -> Self:
         """
         Computes percentage change between values.
 
@@ -8140,7 +8865,17 @@ class Expr:
         Parameters
         ----------
         n
-            periods to shift for forming percent change.
+            periods to shift for forming percent change. This can also be a column
+            with one period per row.
+        fill_null_strategy : {'forward', 'backward', 'min', 'max', 'mean', 'zero', 'one'}
+            Strategy used to fill null values before computing the change. Set to
+            `None` to leave them as they are, in which case the result is null
+            wherever either value is null.
+
+        Notes
+        -----
+        The first `n` rows are null. Division by zero is not an error: it results in
+        `inf`, `-inf` or `NaN`, following IEEE 754.
 
         Examples
         --------
@@ -8164,7 +8899,7 @@ class Expr:
         └──────┴────────────┘
         """
         n = parse_as_expression(n)
-        return self._from_pyexpr(self._pyexpr.pct_change(n))
+        return self._from_pyexpr(self._pyexpr.pct_change(n, fill_null_strategy))
 
     def skew(self, *, bias: bool = True) -> Self:
         r"""
@@ -8204,6 +8939,9 @@ class Expr:
         .. math::
             G_1 = \frac{k_3}{k_2^{3/2}} = \frac{\sqrt{N(N-1)}}{N-2}\frac{m_3}{m_2^{3/2}}
 
+        The result is null if there are fewer than three non-null values, or if
+        the variance is zero.
+
         Examples
         --------
         >>> df = pl.DataFrame({"a": [1, 2, 3, 2, 1]})
@@ -8220,7 +8958,7 @@ class Expr:
         return self._from_pyexpr(self._pyexpr.skew(bias))
 
     def kurtosis(self, *, fisher: bool = True, bias: bool = True) -> Self:
-        """
+        r"""
         Compute the kurtosis (Fisher or Pearson) of a dataset.
 
         Kurtosis is the fourth central moment divided by the square of the
@@ -8239,6 +8977,22 @@ class Expr:
         bias : bool, optional
             If False, the calculations are corrected for statistical bias.
 
+        Notes
+        -----
+        With :math:`m_i` the biased sample :math:`i\texttt{th}` central moment of
+        the :math:`N` non-null values, the biased kurtosis is
+
+        .. math:: b_2=\frac{m_4}{m_2^2}
+
+        If `bias` is False, the value computed is
+
+        .. math::
+            3 + \frac{(N^2-1) b_2 - 3(N-1)^2}{(N-2)(N-3)}
+
+        If `fisher` is True, 3.0 is subtracted from either value. The result is
+        null if there are fewer than four non-null values, or if the variance is
+        zero.
+
         Examples
         --------
         >>> df = pl.DataFrame({"a": [1, 2, 3, 2, 1]})
@@ -8254,6 +9008,58 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.kurtosis(fisher, bias))
 
+    def moment(self, order: int, *, central: bool = True, bias: bool = True) -> Self:
+        r"""
+        Compute the statistical moment of the given order.
+
+        Parameters
+        ----------
+        order
+            The order of the moment.
+        central
+            If True, the moment is taken about the mean, otherwise about zero.
+        bias
+            If False, the central moments are corrected for statistical bias.
+
+        Notes
+        -----
+        With :math:`\bar{x}` the mean of the :math:`N` non-null values, the
+        :math:`k\texttt{th}` central moment is
+
+        .. math:: m_k=\frac{1}{N}\sum_{i=1}^N (x_i - \bar{x})^k
+
+        and the raw moment is the same sum without subtracting the mean. The moment
+        of order 0 is always 1. The first central moment is 0, the first raw moment
+        is the mean.
+
+        If `bias` is False, the central moments of order 2, 3 and 4 are replaced by
+        their unbiased estimators, so that the second central moment equals the
+        sample variance. Unbiased central moments of a higher order are not
+        supported. Raw moments are unbiased already.
+
+        Central moments up to order 4 are computed in a single numerically stable
+        pass, higher orders subtract the mean in a second pass. The result is null
+        if there are no non-null values, or if there are not more values than the
+        order for the unbiased estimators.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 2, 1]})
+        >>> df.select(
+        ...     m3=pl.col("a").moment(3),
+        ...     raw2=pl.col("a").moment(2, central=False),
+        ... )
+        shape: (1, 2)
+        ┌───────┬──────┐
+        │ m3    ┆ raw2 │
+        │ ---   ┆ ---  │
+        │ f64   ┆ f64  │
+        ╞═══════╪══════╡
+        │ 0.144 ┆ 3.8  │
+        └───────┴──────┘
+        """
+        return self._from_pyexpr(self._pyexpr.moment(order, central, bias))
+
     def clip(
         self,
         lower_bound: NumericLiteral | TemporalLiteral | IntoExprColumn | None = None,
@@ -8280,6 +9086,11 @@ class Expr:
         This method only works for numeric and temporal columns. To clip other data
         types, consider writing a `when-then-otherwise` expression. See :func:`when`.
 
+        The bounds can be columns, in which case each element is clipped to the
+        bounds in its own row. A null bound leaves the element unbounded on that
+        side. Bounds are cast to the dtype of the values; a bound whose dtype has no
+        numeric supertype with the values raises a `ComputeError`.
+
         Examples
         --------
         Specifying both a lower and upper bound:
@@ -8771,23 +9582,31 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.radians())
 
-    def reshape(self, dimensions: tuple[int, ...]) -> Self:
+    def reshape(
+        self, dimensions: tuple[int, ...], *, as_array: bool = False
+    ) -> Self:
         """
-        Reshape this Expr to a flat Series or a Series of Lists.
+        Reshape this Expr to a flat Series or a Series of (nested) Lists or Arrays.
 
         Parameters
         ----------
         dimensions
-            Tuple of the dimension sizes. If a -1 is used in any of the dimensions, that
-            dimension is inferred.
+            Tuple of the dimension sizes. If a -1 is used in one of the dimensions,
+            that dimension is inferred. The product of the dimensions must equal the
+            number of elements.
+        as_array
+            Return a (nested) :class:`Array` instead of a :class:`List`. The inner
+            dimensions then determine the array widths, so only the first dimension
+            can be inferred.
 
         Returns
         -------
         Expr
             If a single dimension is given, results in an expression of the original
             data type.
-            If a multiple dimensions are given, results in an expression of data type
-            :class:`List` with shape (rows, cols).
+            If multiple dimensions are given, results in an expression of data type
+            :class:`List` (or :class:`Array`) nested once for every dimension after
+            the first.
 
         Examples
         --------
@@ -8803,12 +9622,23 @@ class Expr:
         │ [4, 5, 6] │
         │ [7, 8, 9] │
         └───────────┘
+        >>> df.select(pl.col("foo").reshape((-1, 3), as_array=True))
+        shape: (3, 1)
+        ┌───────────────┐
+        │ foo           │
+        │ ---           │
+        │ array[i64, 3] │
+        ╞═══════════════╡
+        │ [1, 2, 3]     │
+        │ [4, 5, 6]     │
+        │ [7, 8, 9]     │
+        └───────────────┘
 
         See Also
         --------
         Expr.list.explode : Explode a list column.
         """
-        return self._from_pyexpr(self._pyexpr.reshape(dimensions))
+        return self._from_pyexpr(self._pyexpr.reshape(dimensions, as_array))
 
     def shuffle(self, seed: int | None = None) -> Self:
         """
@@ -9017,11 +9847,14 @@ class Expr:
 
         where :math:`\lambda` equals :math:`\ln(2) / \text{half_life}`.
 
+        Rows where the value or the time is null produce null, and are skipped
+        rather than resetting the average.
+
         Parameters
         ----------
         by
-            Times to calculate average by. Should be ``DateTime``, ``Date``, ``UInt64``,
-            ``UInt32``, ``Int64``, or ``Int32`` data type.
+            Times to calculate average by. Should be ``DateTime``, ``Date``, or an
+            integer data type.
         half_life
             Unit over which observation decays to half its value.
 
@@ -9293,7 +10126,9 @@ class Expr:
             self._pyexpr.ewm_var(alpha, adjust, bias, min_periods, ignore_nulls)
         )
 
-    def extend_constant(self, value: IntoExpr, n: int | IntoExprColumn) -> Self:
+    def extend_constant(
+        self, value: IntoExpr, n: int | IntoExprColumn, *, prepend: bool = False
+    ) -> Self:
         """
         Extremely fast method for extending the Series with 'n' copies of a value.
 
@@ -9301,9 +10136,12 @@ class Expr:
         ----------
         value
             A constant literal value or a unit expressioin with which to extend the
-            expression result Series; can pass None to extend with nulls.
+            expression result Series; can pass None to extend with nulls. The value
+            must be castable to the data type of the expression without loss.
         n
             The number of additional values that will be added.
+        prepend
+            Add the values to the start instead of the end.
 
         Examples
         --------
@@ -9321,33 +10159,60 @@ class Expr:
         │ 99     │
         │ 99     │
         └────────┘
+        >>> df.select(pl.col("values").extend_constant(None, n=1, prepend=True))
+        shape: (4, 1)
+        ┌────────┐
+        │ values │
+        │ ---    │
+        │ i64    │
+        ╞════════╡
+        │ null   │
+        │ 1      │
+        │ 2      │
+        │ 3      │
+        └────────┘
         """
         value = parse_as_expression(value, str_as_lit=True)
         n = parse_as_expression(n)
-        return self._from_pyexpr(self._pyexpr.extend_constant(value, n))
+        return self._from_pyexpr(self._pyexpr.extend_constant(value, n, prepend))
 
     @deprecate_renamed_parameter("multithreaded", "parallel", version="0.19.0")
-    def value_counts(self, *, sort: bool = False, parallel: bool = False) -> Self:
+    def value_counts(
+        self,
+        *,
+        sort: bool = False,
+        descending: bool = True,
+        parallel: bool = False,
+        normalize: bool = False,
+    ) -> Self:
         """
         Count the occurrences of unique values.
 
         Parameters
         ----------
         sort
-            Sort the output by count in descending order.
+            Sort the output by count, in descending order unless `descending` is
+            set to `False`.
             If set to `False` (default), the order of the output is random.
+        descending
+            Sort the counts in descending order. Only used if `sort` is set.
         parallel
             Execute the computation in parallel.
 
             .. note::
                 This option should likely not be enabled in a group by context,
                 as the computation is already parallelized per group.
+        normalize
+            Return the proportion of each value relative to the total length,
+            instead of its count. The count field is then named `proportion` and
+            has data type :class:`Float64`.
 
         Returns
         -------
         Expr
             Expression of data type :class:`Struct` with mapping of unique values to
-            their count.
+            their count (or proportion). Null values are counted as a value of
+            their own.
 
         Examples
         --------
@@ -9379,8 +10244,26 @@ class Expr:
         │ {"red",2}   │
         │ {"green",1} │
         └─────────────┘
+
+        Return the proportions instead of the counts.
+
+        >>> df.select(
+        ...     pl.col("color").value_counts(sort=True, normalize=True)
+        ... ).unnest("color")
+        shape: (3, 2)
+        ┌───────┬────────────┐
+        │ color ┆ proportion │
+        │ ---   ┆ ---        │
+        │ str   ┆ f64        │
+        ╞═══════╪════════════╡
+        │ blue  ┆ 0.5        │
+        │ red   ┆ 0.333333   │
+        │ green ┆ 0.166667   │
+        └───────┴────────────┘
         """
-        return self._from_pyexpr(self._pyexpr.value_counts(sort, parallel))
+        return self._from_pyexpr(
+            self._pyexpr.value_counts(sort, descending, parallel, normalize)
+        )
 
     def unique_counts(self) -> Self:
         """
@@ -9389,6 +10272,13 @@ class Expr:
         This method differs from `value_counts` in that it does not return the
         values, only the counts and might be faster
 
+        Notes
+        -----
+        The counts align element-for-element with `unique(maintain_order=True)`.
+        Keeping this order is cheap for numeric, string, binary and boolean data.
+        Other data types are grouped first, after which the groups are sorted by
+        their first occurrence.
+
         Examples
         --------
         >>> df = pl.DataFrame(
@@ -9414,14 +10304,20 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.unique_counts())
 
-    def log(self, base: float = math.e) -> Self:
+    def log(self, base: float | IntoExpr = math.e) -> Self:
         """
         Compute the logarithm to a given base.
 
         Parameters
         ----------
         base
-            Given base, defaults to `e`
+            Given base, defaults to `e`. Accepts expression input, so every element
+            can use its own base. Non-numeric inputs are parsed as column names.
+
+        Notes
+        -----
+        Negative values result in NaN and zero results in `-inf`. If the base is an
+        expression, the result is always of data type :class:`Float64`.
 
         Examples
         --------
@@ -9437,8 +10333,23 @@ class Expr:
         │ 1.0      │
         │ 1.584963 │
         └──────────┘
+        >>> df = pl.DataFrame({"a": [8, 100, 81], "base": [2, 10, 3]})
+        >>> df.select(pl.col("a").log(pl.col("base")))
+        shape: (3, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 3.0 │
+        │ 2.0 │
+        │ 4.0 │
+        └─────┘
         """
-        return self._from_pyexpr(self._pyexpr.log(base))
+        if isinstance(base, (int, float)):
+            return self._from_pyexpr(self._pyexpr.log(base))
+        base = parse_as_expression(base)
+        return self._from_pyexpr(self._pyexpr.log_with_base(base))
 
     def log1p(self) -> Self:
         """
@@ -9476,6 +10387,12 @@ class Expr:
         normalize
             Normalize pk if it doesn't sum to 1.
 
+        Notes
+        -----
+        Null and zero values of `pk` don't contribute to the entropy. To compute the
+        entropy of the distribution of the values in a column, take it over the
+        counts of its unique values, e.g. `pl.col("a").unique_counts().entropy()`.
+
         Examples
         --------
         >>> df = pl.DataFrame({"a": [1, 2, 3]})
@@ -9497,6 +10414,19 @@ class Expr:
         ╞═══════════╡
         │ -6.754888 │
         └───────────┘
+
+        The entropy of the distribution of the values in a column:
+
+        >>> df = pl.DataFrame({"a": ["x", "x", "y", "z"]})
+        >>> df.select(pl.col("a").unique_counts().entropy(base=2))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 1.5 │
+        └─────┘
         """
         return self._from_pyexpr(self._pyexpr.entropy(base, normalize))
 
@@ -9627,6 +10557,7 @@ class Expr:
         bin_count: int | None = None,
         include_category: bool = False,
         include_breakpoint: bool = False,
+        density: bool = False,
     ) -> Self:
         """
         Bin values into buckets and count their occurrences.
@@ -9647,6 +10578,11 @@ class Expr:
             Include a column that indicates the upper breakpoint.
         include_category
             Include a column that shows the intervals as categories.
+        density
+            Include a `density` column with the count of each bin divided by
+            `total_count * bin_width`, so that the densities integrate to 1.
+            Bins with a width of zero and the unbounded outer bins (when `bins` is
+            given) have a null density.
 
         Returns
         -------
@@ -9689,7 +10625,9 @@ class Expr:
                 bins = pl.Series(bins)
             bins = parse_as_expression(bins)
         return self._from_pyexpr(
-            self._pyexpr.hist(bins, bin_count, include_category, include_breakpoint)
+            self._pyexpr.hist(
+                bins, bin_count, include_category, include_breakpoint, density
+            )
         )
 
     def replace(
@@ -9870,6 +10808,180 @@ class Expr:
 
         return self._from_pyexpr(self._pyexpr.replace(old, new, default, return_dtype))
 
+    def replace_strict(
+        self,
+        old: IntoExpr | Sequence[Any] | Mapping[Any, Any],
+        new: IntoExpr | Sequence[Any] | NoDefault = no_default,
+        *,
+        default: IntoExpr | NoDefault = no_default,
+        return_dtype: PolarsDataType | None = None,
+    ) -> Self:
+        """
+        Replace all values by looking them up in a mapping.
+
+        Unlike :func:`replace`, every value must be mapped: values that are not in
+        `old` are set to `default`, or raise an error if no default is given.
+
+        Parameters
+        ----------
+        old
+            Value or sequence of values to replace.
+            Accepts expression input. Sequences are parsed as Series,
+            other non-expression inputs are parsed as literals.
+            Also accepts a mapping of values to their replacement as syntactic sugar for
+            `replace_strict(old=Series(mapping.keys()), new=Series(mapping.values()))`.
+        new
+            Value or sequence of values to replace by.
+            Accepts expression input. Sequences are parsed as Series,
+            other non-expression inputs are parsed as literals.
+            Length must match the length of `old` or have length 1.
+        default
+            Set values that were not replaced to this value. If not set, a value that
+            is not in `old` raises a `ComputeError`; null values stay null.
+            Accepts expression input. Non-expression inputs are parsed as literals.
+        return_dtype
+            The data type of the resulting expression. If set to `None` (default),
+            the data type is the supertype of `new` and `default`, regardless of the
+            original data type.
+
+        See Also
+        --------
+        replace
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["x", "y", None, "x"]})
+        >>> mapping = {"x": 1, "y": 2}
+        >>> df.with_columns(replaced=pl.col("a").replace_strict(mapping))
+        shape: (4, 2)
+        ┌──────┬──────────┐
+        │ a    ┆ replaced │
+        │ ---  ┆ ---      │
+        │ str  ┆ i64      │
+        ╞══════╪══════════╡
+        │ x    ┆ 1        │
+        │ y    ┆ 2        │
+        │ null ┆ null     │
+        │ x    ┆ 1        │
+        └──────┴──────────┘
+
+        Values that are not mapped raise an error unless a `default` is given.
+
+        >>> df = pl.DataFrame({"a": ["x", "y", "z"]})
+        >>> df.with_columns(replaced=pl.col("a").replace_strict(mapping, default=-1))
+        shape: (3, 2)
+        ┌─────┬──────────┐
+        │ a   ┆ replaced │
+        │ --- ┆ ---      │
+        │ str ┆ i64      │
+        ╞═════╪══════════╡
+        │ x   ┆ 1        │
+        │ y   ┆ 2        │
+        │ z   ┆ -1       │
+        └─────┴──────────┘
+        """
+        if new is no_default and isinstance(old, Mapping):
+            new = pl.Series(old.values())
+            old = pl.Series(old.keys())
+        else:
+            if isinstance(old, Sequence) and not isinstance(old, (str, pl.Series)):
+                old = pl.Series(old)
+            if isinstance(new, Sequence) and not isinstance(new, (str, pl.Series)):
+                new = pl.Series(new)
+
+        old = parse_as_expression(old, str_as_lit=True)  # type: ignore[arg-type]
+        new = parse_as_expression(new, str_as_lit=True)  # type: ignore[arg-type]
+
+        default = (
+            None
+            if default is no_default
+            else parse_as_expression(default, str_as_lit=True)
+        )
+
+        return self._from_pyexpr(
+            self._pyexpr.replace_strict(old, new, default, return_dtype)
+        )
+
+    def replace_ranges(
+        self,
+        breaks: IntoExpr | Sequence[float],
+        labels: IntoExpr | Sequence[Any],
+        *,
+        default: IntoExpr | NoDefault = no_default,
+        left_closed: bool = False,
+        return_dtype: PolarsDataType | None = None,
+    ) -> Self:
+        """
+        Replace numeric values by the label of the range they fall in.
+
+        This is a lighter alternative to :func:`cut` when the bins are only used as a
+        lookup: the labels can be of any data type and no categorical is created.
+
+        Parameters
+        ----------
+        breaks
+            Sorted, unique edges of the ranges. `n` breaks define `n - 1` consecutive
+            ranges. Accepts expression input. Sequences are parsed as Series.
+        labels
+            Value to use for each range. Must have one value less than `breaks`.
+            Accepts expression input. Sequences are parsed as Series.
+        default
+            Set values that fall outside of all ranges, as well as NaN values, to this
+            value. If not set, such values are set to null; null values stay null.
+            Accepts expression input. Non-expression inputs are parsed as literals.
+        left_closed
+            Set the ranges to be left-closed instead of right-closed.
+        return_dtype
+            The data type of the resulting expression. If set to `None` (default),
+            the data type is the supertype of `labels` and `default`.
+
+        See Also
+        --------
+        cut
+        replace_strict
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [-5, 0, 5, 10, 50, 500]})
+        >>> df.with_columns(
+        ...     label=pl.col("a").replace_ranges(
+        ...         [0, 10, 100], ["low", "high"], default="other"
+        ...     )
+        ... )
+        shape: (6, 2)
+        ┌─────┬───────┐
+        │ a   ┆ label │
+        │ --- ┆ ---   │
+        │ i64 ┆ str   │
+        ╞═════╪═══════╡
+        │ -5  ┆ other │
+        │ 0   ┆ other │
+        │ 5   ┆ low   │
+        │ 10  ┆ low   │
+        │ 50  ┆ high  │
+        │ 500 ┆ other │
+        └─────┴───────┘
+        """
+        if isinstance(breaks, Sequence) and not isinstance(breaks, (str, pl.Series)):
+            breaks = pl.Series(breaks)
+        if isinstance(labels, Sequence) and not isinstance(labels, (str, pl.Series)):
+            labels = pl.Series(labels)
+
+        breaks = parse_as_expression(breaks)  # type: ignore[arg-type]
+        labels = parse_as_expression(labels, str_as_lit=True)  # type: ignore[arg-type]
+
+        default = (
+            None
+            if default is no_default
+            else parse_as_expression(default, str_as_lit=True)
+        )
+
+        return self._from_pyexpr(
+            self._pyexpr.replace_ranges(
+                breaks, labels, default, left_closed, return_dtype
+            )
+        )
+
     @deprecate_renamed_function("map_batches", version="0.19.0")
     def map(
         self,
@@ -9976,7 +11088,8 @@ class Expr:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
         """
         return self.rolling_map(
             function, window_size, weights, min_periods, center=center
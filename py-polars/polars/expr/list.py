@@ -145,6 +145,7 @@ class ExprListNameSpace:
         with_replacement: bool = False,
         shuffle: bool = False,
         seed: int | None = None,
+        clamp: bool = False,
     ) -> Expr:
         """
         Sample from this list.
@@ -162,7 +163,11 @@ class ExprListNameSpace:
             Shuffle the order of sampled data points.
         seed
             Seed for the random number generator. If set to None (default), a
-            random seed is generated for each sample operation.
+            random seed is generated for each sample operation. Each row is sampled
+            with its own seed, derived from `seed` and the row index.
+        clamp
+            When sampling without replacement, take at most as many items as a list
+            has instead of raising an error if `n` or `fraction` asks for more.
 
         Examples
         --------
@@ -175,7 +180,7 @@ class ExprListNameSpace:
         │ list[i64] ┆ i64 ┆ list[i64] │
         ╞═══════════╪═════╪═══════════╡
         │ [1, 2, 3] ┆ 2   ┆ [2, 1]    │
-        │ [4, 5]    ┆ 1   ┆ [5]       │
+        │ [4, 5]    ┆ 1   ┆ [4]       │
         └───────────┴─────┴───────────┘
         """
         if n is not None and fraction is not None:
@@ -186,14 +191,16 @@ class ExprListNameSpace:
             fraction = parse_as_expression(fraction)
             return wrap_expr(
                 self._pyexpr.list_sample_fraction(
-                    fraction, with_replacement, shuffle, seed
+                    fraction, with_replacement, shuffle, seed, clamp
                 )
             )
 
         if n is None:
             n = 1
         n = parse_as_expression(n)
-        return wrap_expr(self._pyexpr.list_sample_n(n, with_replacement, shuffle, seed))
+        return wrap_expr(
+            self._pyexpr.list_sample_n(n, with_replacement, shuffle, seed, clamp)
+        )
 
     def sum(self) -> Expr:
         """
@@ -349,16 +356,31 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_var(ddof))
 
-    def sort(self, *, descending: bool = False, nulls_last: bool = False) -> Expr:
+    def sort(
+        self,
+        *,
+        descending: bool = False,
+        nulls_last: bool = False,
+        multithreaded: bool = True,
+        maintain_order: bool = False,
+    ) -> Expr:
         """
         Sort the lists in this column.
 
+        Null values are placed at the start of every list, or at the end if
+        `nulls_last` is set, regardless of the sort direction. Empty lists and
+        lists with a single element are returned unchanged.
+
         Parameters
         ----------
         descending
             Sort in descending order.
         nulls_last
             Place null values last.
+        multithreaded
+            Sort using multiple threads.
+        maintain_order
+            Whether the order should be maintained if elements are equal.
 
         Examples
         --------
@@ -388,7 +410,11 @@ class ExprListNameSpace:
         │ [9, 1, 2] ┆ [9, 2, 1] │
         └───────────┴───────────┘
         """
-        return wrap_expr(self._pyexpr.list_sort(descending, nulls_last))
+        return wrap_expr(
+            self._pyexpr.list_sort(
+                descending, nulls_last, multithreaded, maintain_order
+            )
+        )
 
     def reverse(self) -> Expr:
         """
@@ -711,7 +737,8 @@ class ExprListNameSpace:
         """
         Join all string items in a sublist and place a separator between them.
 
-        This errors if inner type of list `!= String`.
+        This errors if inner type of list `!= String`. Empty sublists result in an
+        empty string.
 
         Parameters
         ----------
@@ -823,6 +850,9 @@ class ExprListNameSpace:
         """
         Calculate the first discrete difference between shifted items of every sublist.
 
+        Unsigned integer lists are cast to a signed integer type that can hold
+        negative differences.
+
         Parameters
         ----------
         n
@@ -916,6 +946,39 @@ class ExprListNameSpace:
         n = parse_as_expression(n)
         return wrap_expr(self._pyexpr.list_shift(n))
 
+    def filter(self, mask: IntoExpr) -> Expr:
+        """
+        Keep the elements of every sublist where the matching `mask` element is true.
+
+        Parameters
+        ----------
+        mask
+            Expression of data type `List(Boolean)`, with sublists of the same lengths
+            as the sublists in this column. Accepts expression input. Strings are
+            parsed as column names. Elements with a null mask value are dropped.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [[1, 2, 3], [4, 5]],
+        ...         "keep": [[True, False, True], [False, None]],
+        ...     }
+        ... )
+        >>> df.select(pl.col("a").list.filter("keep"))
+        shape: (2, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [1, 3]    │
+        │ []        │
+        └───────────┘
+        """
+        mask = parse_as_expression(mask)
+        return wrap_expr(self._pyexpr.list_filter(mask))
+
     def slice(
         self, offset: int | str | Expr, length: int | str | Expr | None = None
     ) -> Expr:
@@ -1039,7 +1102,8 @@ class ExprListNameSpace:
         Parameters
         ----------
         element
-            An expression that produces a single value
+            An expression that produces either a single value, which is counted in
+            every list, or one value per row. Null values count null elements.
 
         Examples
         --------
@@ -1096,7 +1160,7 @@ class ExprListNameSpace:
 
     def to_struct(
         self,
-        n_field_strategy: ToStructStrategy = "first_non_null",
+        n_field_strategy: ToStructStrategy | int = "first_non_null",
         fields: Sequence[str] | Callable[[int], str] | None = None,
         upper_bound: int = 0,
     ) -> Expr:
@@ -1105,21 +1169,27 @@ class ExprListNameSpace:
 
         Parameters
         ----------
-        n_field_strategy : {'first_non_null', 'max_width'}
+        n_field_strategy : {'first_non_null', 'max_width'} or int
             Strategy to determine the number of fields of the struct.
 
             * "first_non_null": set number of fields equal to the length of the
               first non zero-length sublist.
             * "max_width": set number of fields as max length of all sublists.
+            * an integer: use exactly this number of fields; longer sublists are
+              truncated.
+
+            Sublists shorter than the number of fields are padded with nulls.
         fields
             If the name and number of the desired fields is known in advance
-            a list of field names can be given, which will be assigned by index.
+            a list of field names can be given, which will be assigned by index;
+            fields beyond the given names are named `field_i`.
             Otherwise, to dynamically assign field names, a custom function can be
             used; if neither are set, fields will be `field_0, field_1 .. field_n`.
         upper_bound
             A polars `LazyFrame` needs to know the schema at all times, so the
             caller must provide an upper bound of the number of struct fields that
-            will be created; if set incorrectly, subsequent operations may fail.
+            will be created, unless `n_field_strategy` is an integer; if set
+            incorrectly, subsequent operations may fail.
             (For example, an `all().sum()` expression will look in the current
             schema to determine which columns to select).
 
@@ -1184,12 +1254,12 @@ class ExprListNameSpace:
         [{'n': {'one': 0, 'two': 1}}, {'n': {'one': 2, 'two': 3}}]
         """
         if isinstance(fields, Sequence):
-            field_names = list(fields)
-            pyexpr = self._pyexpr.list_to_struct(n_field_strategy, None, upper_bound)
-            return wrap_expr(pyexpr).struct.rename_fields(field_names)
+            pyexpr = self._pyexpr.list_to_struct(
+                n_field_strategy, None, upper_bound, list(fields)
+            )
         else:
             pyexpr = self._pyexpr.list_to_struct(n_field_strategy, fields, upper_bound)
-            return wrap_expr(pyexpr)
+        return wrap_expr(pyexpr)
 
     def eval(self, expr: Expr, *, parallel: bool = False) -> Expr:
         """
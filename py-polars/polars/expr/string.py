@@ -28,6 +28,7 @@ if TYPE_CHECKING:
         PolarsTemporalType,
         TimeUnit,
         TransferEncoding,
+        UnicodeForm,
     )
 
 
@@ -553,6 +554,17 @@ class ExprStringNameSpace:
         """
         Transform to titlecase variant.
 
+        The first character of every word is uppercased and the other characters
+        are lowercased.
+
+        Notes
+        -----
+        Words are determined by the Unicode word boundary rules, so words separated
+        by punctuation are capitalized separately: `"jean-luc"` becomes
+        `"Jean-Luc"`. Apostrophes don't separate words, except after a single
+        letter: `"o'brien"` becomes `"O'Brien"` while `"there's"` becomes
+        `"There's"`.
+
         Examples
         --------
         >>> df = pl.DataFrame(
@@ -932,14 +944,15 @@ class ExprStringNameSpace:
         """
         Pad the start of the string with zeros until it reaches the given length.
 
-        A sign prefix (`-`) is handled by inserting the padding after the sign
+        A sign prefix (`-` or `+`) is handled by inserting the padding after the sign
         character rather than before.
 
         Parameters
         ----------
         length
             Pad the string until it reaches this length. Strings with length equal to
-            or greater than this value are returned as-is.
+            or greater than this value are returned as-is. This can be an expression
+            to pad each row to a different length.
 
         See Also
         --------
@@ -1060,6 +1073,9 @@ class ExprStringNameSpace:
 
         Notes
         -----
+        The index is counted in characters rather than bytes, so it can be used with
+        :func:`slice`, which uses character offsets as well.
+
         To modify regular expression behaviour (such as case-sensitivity) with
         flags, use the inline `(?iLmsuxU)` syntax. For example:
 
@@ -1132,6 +1148,30 @@ class ExprStringNameSpace:
         pattern = parse_as_expression(pattern, str_as_lit=True)
         return wrap_expr(self._pyexpr.str_find(pattern, literal, strict))
 
+    def escape_regex(self) -> Expr:
+        r"""
+        Escape all regular expression meta characters in the string values.
+
+        The result can be embedded in a regular expression pattern to match the
+        original string literally, for example when building patterns from data.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["1.5 (approx)", "a+b", None]})
+        >>> df.with_columns(escaped=pl.col("text").str.escape_regex())
+        shape: (3, 2)
+        ┌──────────────┬─────────────────┐
+        │ text         ┆ escaped         │
+        │ ---          ┆ ---             │
+        │ str          ┆ str             │
+        ╞══════════════╪═════════════════╡
+        │ 1.5 (approx) ┆ 1\.5 \(approx\) │
+        │ a+b          ┆ a\+b            │
+        │ null         ┆ null            │
+        └──────────────┴─────────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_escape_regex())
+
     def ends_with(self, suffix: str | Expr) -> Expr:
         """
         Check if string values end with a substring.
@@ -1611,6 +1651,10 @@ class ExprStringNameSpace:
         If your pattern contains unnamed groups, their numerical position is converted
         to a string.
 
+        The regex is applied once per value, extracting all groups in a single pass.
+        Values that are null or don't match the pattern yield a struct whose fields
+        are all null.
+
         For example, here we access groups 2 and 3 via the names `"2"` and `"3"`::
 
             >>> df = pl.DataFrame({"col": ["foo bar baz"]})
@@ -1738,7 +1782,9 @@ class ExprStringNameSpace:
         pattern = parse_as_expression(pattern, str_as_lit=True)
         return wrap_expr(self._pyexpr.str_count_matches(pattern, literal))
 
-    def split(self, by: IntoExpr, *, inclusive: bool = False) -> Expr:
+    def split(
+        self, by: IntoExpr, *, inclusive: bool = False, regex: bool = False
+    ) -> Expr:
         """
         Split the string by a substring.
 
@@ -1748,6 +1794,10 @@ class ExprStringNameSpace:
             Substring to split by.
         inclusive
             If True, include the split character/string in the results.
+        regex
+            Treat `by` as a regex pattern instead of a literal substring. The pattern
+            is compiled once and reused for all rows. Consecutive matches produce
+            empty strings.
 
         Examples
         --------
@@ -1787,12 +1837,28 @@ class ExprStringNameSpace:
         │ foo*bar*baz ┆ *   ┆ ["foo", "bar", "baz"] ┆ ["foo*", "bar*", "baz"] │
         └─────────────┴─────┴───────────────────────┴─────────────────────────┘
 
+        Split on a regex pattern, for example on variable whitespace and punctuation:
+
+        >>> df = pl.DataFrame({"s": ["foo  bar", "foo, bar;baz"]})
+        >>> df.with_columns(split=pl.col("s").str.split(r"[\s,;]+", regex=True))
+        shape: (2, 2)
+        ┌──────────────┬───────────────────────┐
+        │ s            ┆ split                 │
+        │ ---          ┆ ---                   │
+        │ str          ┆ list[str]             │
+        ╞══════════════╪═══════════════════════╡
+        │ foo  bar     ┆ ["foo", "bar"]        │
+        │ foo, bar;baz ┆ ["foo", "bar", "baz"] │
+        └──────────────┴───────────────────────┘
+
         Returns
         -------
         Expr
             Expression of data type :class:`String`.
         """
         by = parse_as_expression(by, str_as_lit=True)
+        if regex:
+            return wrap_expr(self._pyexpr.str_split_regex(by, inclusive))
         if inclusive:
             return wrap_expr(self._pyexpr.str_split_inclusive(by))
         return wrap_expr(self._pyexpr.str_split(by))
@@ -2122,6 +2188,48 @@ class ExprStringNameSpace:
         value = parse_as_expression(value, str_as_lit=True)
         return wrap_expr(self._pyexpr.str_replace_all(pattern, value, literal))
 
+    def normalize(self, form: UnicodeForm = "NFC") -> Expr:
+        """
+        Returns the Unicode normal form of the string values.
+
+        This uses the forms described in Unicode Standard Annex 15:
+        <https://www.unicode.org/reports/tr15/>.
+
+        Parameters
+        ----------
+        form : {'NFC', 'NFKC', 'NFD', 'NFKD'}
+            Unicode form to use.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["01²", "ﬁne"]})
+        >>> new = df.with_columns(
+        ...     nfc=pl.col("text").str.normalize("NFC"),
+        ...     nfkc=pl.col("text").str.normalize("NFKC"),
+        ... )
+        >>> new
+        shape: (2, 3)
+        ┌──────┬─────┬──────┐
+        │ text ┆ nfc ┆ nfkc │
+        │ ---  ┆ --- ┆ ---  │
+        │ str  ┆ str ┆ str  │
+        ╞══════╪═════╪══════╡
+        │ 01²  ┆ 01² ┆ 012  │
+        │ ﬁne  ┆ ﬁne ┆ fine │
+        └──────┴─────┴──────┘
+        >>> new.select(pl.all().str.len_bytes())
+        shape: (2, 3)
+        ┌──────┬─────┬──────┐
+        │ text ┆ nfc ┆ nfkc │
+        │ ---  ┆ --- ┆ ---  │
+        │ u32  ┆ u32 ┆ u32  │
+        ╞══════╪═════╪══════╡
+        │ 4    ┆ 4   ┆ 3    │
+        │ 5    ┆ 5   ┆ 4    │
+        └──────┴─────┴──────┘
+        """
+        return wrap_expr(self._pyexpr.str_normalize(form))
+
     def reverse(self) -> Expr:
         """
         Returns string values in reversed order.
@@ -2143,6 +2251,100 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_reverse())
 
+    def levenshtein(self, other: IntoExpr) -> Expr:
+        """
+        Compute the Levenshtein edit distance to another string.
+
+        The distance is the minimum number of single-character insertions, deletions
+        and substitutions needed to turn one string into the other. Characters are
+        counted as Unicode scalar values, not bytes.
+
+        Parameters
+        ----------
+        other
+            The string(s) to compare with. Accepts expression input. Strings are
+            parsed as string literals, not as column names.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        See Also
+        --------
+        jaro_winkler
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["kitten", "flaw", "MARTHA", None],
+        ...         "b": ["sitting", "lawn", "MARHTA", "x"],
+        ...     }
+        ... )
+        >>> df.with_columns(pl.col("a").str.levenshtein(pl.col("b")).alias("dist"))
+        shape: (4, 3)
+        ┌────────┬─────────┬──────┐
+        │ a      ┆ b       ┆ dist │
+        │ ---    ┆ ---     ┆ ---  │
+        │ str    ┆ str     ┆ u32  │
+        ╞════════╪═════════╪══════╡
+        │ kitten ┆ sitting ┆ 3    │
+        │ flaw   ┆ lawn    ┆ 2    │
+        │ MARTHA ┆ MARHTA  ┆ 2    │
+        │ null   ┆ x       ┆ null │
+        └────────┴─────────┴──────┘
+        """
+        other = parse_as_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_levenshtein(other))
+
+    def jaro_winkler(self, other: IntoExpr) -> Expr:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        The similarity lies between 0 (no characters in common) and 1 (equal
+        strings). A common prefix of up to four characters is weighted with the
+        standard scaling factor of 0.1.
+
+        Parameters
+        ----------
+        other
+            The string(s) to compare with. Accepts expression input. Strings are
+            parsed as string literals, not as column names.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float64`.
+
+        See Also
+        --------
+        levenshtein
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["kitten", "flaw", "MARTHA", None],
+        ...         "b": ["sitting", "lawn", "MARHTA", "x"],
+        ...     }
+        ... )
+        >>> df.with_columns(pl.col("a").str.jaro_winkler(pl.col("b")).alias("sim"))
+        shape: (4, 3)
+        ┌────────┬─────────┬──────────┐
+        │ a      ┆ b       ┆ sim      │
+        │ ---    ┆ ---     ┆ ---      │
+        │ str    ┆ str     ┆ f64      │
+        ╞════════╪═════════╪══════════╡
+        │ kitten ┆ sitting ┆ 0.746032 │
+        │ flaw   ┆ lawn    ┆ 0.833333 │
+        │ MARTHA ┆ MARHTA  ┆ 0.961111 │
+        │ null   ┆ x       ┆ null     │
+        └────────┴─────────┴──────────┘
+        """
+        other = parse_as_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_jaro_winkler(other))
+
     def slice(
         self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
     ) -> Expr:
@@ -2171,6 +2373,10 @@ class ExprStringNameSpace:
 
         .. _Unicode scalar value: https://www.unicode.org/glossary/#unicode_scalar_value
 
+        See Also
+        --------
+        slice_bytes : Slice using byte offsets.
+
         Examples
         --------
         >>> df = pl.DataFrame({"s": ["pear", None, "papaya", "dragonfruit"]})
@@ -2206,6 +2412,57 @@ class ExprStringNameSpace:
         length = parse_as_expression(length)
         return wrap_expr(self._pyexpr.str_slice(offset, length))
 
+    def slice_bytes(
+        self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
+    ) -> Expr:
+        """
+        Extract a substring from each string value, using byte offsets.
+
+        Parameters
+        ----------
+        offset
+            Start index in bytes. Negative indexing is supported.
+        length
+            Length of the slice in bytes. If set to `None` (default), the slice is
+            taken to the end of the string.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Raises
+        ------
+        ComputeError
+            If a slice does not fall on character boundaries, i.e. if it would cut a
+            multibyte character.
+
+        See Also
+        --------
+        slice : Slice using character offsets.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"s": ["café", "naïve", None]})
+        >>> df.with_columns(
+        ...     chars=pl.col("s").str.slice(0, 5),
+        ...     bytes=pl.col("s").str.slice_bytes(0, 5),
+        ... )
+        shape: (3, 3)
+        ┌───────┬───────┬───────┐
+        │ s     ┆ chars ┆ bytes │
+        │ ---   ┆ ---   ┆ ---   │
+        │ str   ┆ str   ┆ str   │
+        ╞═══════╪═══════╪═══════╡
+        │ café  ┆ café  ┆ café  │
+        │ naïve ┆ naïve ┆ naïv  │
+        │ null  ┆ null  ┆ null  │
+        └───────┴───────┴───────┘
+        """
+        offset = parse_as_expression(offset)
+        length = parse_as_expression(length)
+        return wrap_expr(self._pyexpr.str_slice_bytes(offset, length))
+
     def head(self, n: int | IntoExprColumn) -> Expr:
         """
         Return the first n characters of each string in a String Series.
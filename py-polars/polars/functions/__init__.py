@@ -3,6 +3,9 @@ from polars.functions.aggregation import (
     all_horizontal,
     any,
     any_horizontal,
+    arr_max_horizontal,
+    arr_min_horizontal,
+    arr_sum_horizontal,
     cum_sum,
     cum_sum_horizontal,
     cumsum,
@@ -25,7 +28,11 @@ from polars.functions.as_datatype import (
 from polars.functions.as_datatype import date_ as date
 from polars.functions.as_datatype import datetime_ as datetime
 from polars.functions.as_datatype import time_ as time
-from polars.functions.business import business_day_count
+from polars.functions.business import (
+    business_date_range,
+    business_day_count,
+    networkdays,
+)
 from polars.functions.col import col
 from polars.functions.eager import align_frames, concat
 from polars.functions.lazy import (
@@ -99,6 +106,9 @@ __all__ = [
     "sum",
     "all_horizontal",
     "any_horizontal",
+    "arr_max_horizontal",
+    "arr_min_horizontal",
+    "arr_sum_horizontal",
     "cum_sum_horizontal",
     "cumsum_horizontal",
     "max_horizontal",
@@ -125,6 +135,7 @@ __all__ = [
     "arctan2",
     "arctan2d",
     "arg_sort_by",
+    "business_date_range",
     "business_day_count",
     "coalesce",
     "col",
@@ -162,6 +173,7 @@ __all__ = [
     "mean_horizontal",
     "median",
     "n_unique",
+    "networkdays",
     "quantile",
     "reduce",
     "rolling_corr",
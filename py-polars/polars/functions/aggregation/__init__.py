@@ -1,6 +1,9 @@
 from polars.functions.aggregation.horizontal import (
     all_horizontal,
     any_horizontal,
+    arr_max_horizontal,
+    arr_min_horizontal,
+    arr_sum_horizontal,
     cum_sum_horizontal,
     cumsum_horizontal,
     max_horizontal,
@@ -28,6 +31,9 @@ __all__ = [
     "sum",
     "all_horizontal",
     "any_horizontal",
+    "arr_max_horizontal",
+    "arr_min_horizontal",
+    "arr_sum_horizontal",
     "cum_sum_horizontal",
     "cumsum_horizontal",
     "max_horizontal",
@@ -247,6 +247,123 @@ def mean_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     return wrap_expr(plr.mean_horizontal(pyexprs))
 
 
+def arr_max_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
+    """
+    Get the maximum value element-wise across equal-width `Array` columns.
+
+    Each position of the arrays is reduced separately. Null values, including the
+    values of null arrays, are ignored; the resulting array is only null if the
+    array is null in all inputs.
+
+    Parameters
+    ----------
+    *exprs
+        Column(s) to use in the aggregation. Accepts expression input. Strings are
+        parsed as column names, other non-expression inputs are parsed as literals.
+        All inputs must be arrays of the same width.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "a": [[1, 4], [8, None]],
+    ...         "b": [[3, 2], [5, 7]],
+    ...     },
+    ...     schema={"a": pl.Array(pl.Int64, 2), "b": pl.Array(pl.Int64, 2)},
+    ... )
+    >>> df.with_columns(max=pl.arr_max_horizontal("a", "b"))
+    shape: (2, 3)
+    ┌───────────────┬───────────────┬───────────────┐
+    │ a             ┆ b             ┆ max           │
+    │ ---           ┆ ---           ┆ ---           │
+    │ array[i64, 2] ┆ array[i64, 2] ┆ array[i64, 2] │
+    ╞═══════════════╪═══════════════╪═══════════════╡
+    │ [1, 4]        ┆ [3, 2]        ┆ [3, 4]        │
+    │ [8, null]     ┆ [5, 7]        ┆ [8, 7]        │
+    └───────────────┴───────────────┴───────────────┘
+    """
+    pyexprs = parse_as_list_of_expressions(*exprs)
+    return wrap_expr(plr.arr_max_horizontal(pyexprs))
+
+
+def arr_min_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
+    """
+    Get the minimum value element-wise across equal-width `Array` columns.
+
+    Each position of the arrays is reduced separately. Null values, including the
+    values of null arrays, are ignored; the resulting array is only null if the
+    array is null in all inputs.
+
+    Parameters
+    ----------
+    *exprs
+        Column(s) to use in the aggregation. Accepts expression input. Strings are
+        parsed as column names, other non-expression inputs are parsed as literals.
+        All inputs must be arrays of the same width.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "a": [[1, 4], [8, None]],
+    ...         "b": [[3, 2], [5, 7]],
+    ...     },
+    ...     schema={"a": pl.Array(pl.Int64, 2), "b": pl.Array(pl.Int64, 2)},
+    ... )
+    >>> df.with_columns(min=pl.arr_min_horizontal("a", "b"))
+    shape: (2, 3)
+    ┌───────────────┬───────────────┬───────────────┐
+    │ a             ┆ b             ┆ min           │
+    │ ---           ┆ ---           ┆ ---           │
+    │ array[i64, 2] ┆ array[i64, 2] ┆ array[i64, 2] │
+    ╞═══════════════╪═══════════════╪═══════════════╡
+    │ [1, 4]        ┆ [3, 2]        ┆ [1, 2]        │
+    │ [8, null]     ┆ [5, 7]        ┆ [5, 7]        │
+    └───────────────┴───────────────┴───────────────┘
+    """
+    pyexprs = parse_as_list_of_expressions(*exprs)
+    return wrap_expr(plr.arr_min_horizontal(pyexprs))
+
+
+def arr_sum_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
+    """
+    Get the sum element-wise across equal-width `Array` columns.
+
+    Each position of the arrays is reduced separately. Null values, including the
+    values of null arrays, are ignored; the resulting array is only null if the
+    array is null in all inputs.
+
+    Parameters
+    ----------
+    *exprs
+        Column(s) to use in the aggregation. Accepts expression input. Strings are
+        parsed as column names, other non-expression inputs are parsed as literals.
+        All inputs must be arrays of the same width.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "a": [[1, 4], [8, None]],
+    ...         "b": [[3, 2], [5, 7]],
+    ...     },
+    ...     schema={"a": pl.Array(pl.Int64, 2), "b": pl.Array(pl.Int64, 2)},
+    ... )
+    >>> df.with_columns(sum=pl.arr_sum_horizontal("a", "b"))
+    shape: (2, 3)
+    ┌───────────────┬───────────────┬───────────────┐
+    │ a             ┆ b             ┆ sum           │
+    │ ---           ┆ ---           ┆ ---           │
+    │ array[i64, 2] ┆ array[i64, 2] ┆ array[i64, 2] │
+    ╞═══════════════╪═══════════════╪═══════════════╡
+    │ [1, 4]        ┆ [3, 2]        ┆ [4, 6]        │
+    │ [8, null]     ┆ [5, 7]        ┆ [13, 7]       │
+    └───────────────┴───────────────┴───────────────┘
+    """
+    pyexprs = parse_as_list_of_expressions(*exprs)
+    return wrap_expr(plr.arr_sum_horizontal(pyexprs))
+
+
 def cum_sum_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     """
     Cumulatively sum all values horizontally across columns.
@@ -2,8 +2,9 @@ from __future__ import annotations
 
 import contextlib
 from datetime import date
-from typing import TYPE_CHECKING, Iterable
+from typing import TYPE_CHECKING, Iterable, overload
 
+from polars import functions as F
 from polars._utils.parse_expr_input import parse_as_expression
 from polars._utils.wrap import wrap_expr
 
@@ -11,7 +12,9 @@ with contextlib.suppress(ImportError):  # Module not available when building doc
     import polars.polars as plr
 
 if TYPE_CHECKING:
-    from polars import Expr
+    from typing import Literal
+
+    from polars import Expr, Series
     from polars.type_aliases import IntoExprColumn
 
 
@@ -121,3 +124,186 @@ def business_day_count(
             [(holiday - unix_epoch).days for holiday in holidays],
         )
     )
+
+
+def networkdays(
+    start: date | IntoExprColumn,
+    end: date | IntoExprColumn,
+    week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+    holidays: Iterable[date] = (),
+) -> Expr:
+    """
+    Count business days between `start` and `end` like Excel's `NETWORKDAYS`.
+
+    Unlike :func:`business_day_count`, both `start` and `end` are counted if they
+    are business days. As in Excel, the count is negative if `start` is after `end`.
+
+    Parameters
+    ----------
+    start
+        Start dates.
+    end
+        End dates.
+    week_mask
+        Which days of the week to count. The default is Monday to Friday.
+        If you wanted to count only Monday to Thursday, you would pass
+        `(True, True, True, True, False, False, False)`.
+    holidays
+        Holidays to exclude from the count.
+
+    Returns
+    -------
+    Expr
+
+    Examples
+    --------
+    >>> from datetime import date
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "start": [date(2020, 1, 1), date(2020, 1, 10)],
+    ...         "end": [date(2020, 1, 10), date(2020, 1, 1)],
+    ...     }
+    ... )
+    >>> df.with_columns(networkdays=pl.networkdays("start", "end"))
+    shape: (2, 3)
+    ┌────────────┬────────────┬─────────────┐
+    │ start      ┆ end        ┆ networkdays │
+    │ ---        ┆ ---        ┆ ---         │
+    │ date       ┆ date       ┆ i32         │
+    ╞════════════╪════════════╪═════════════╡
+    │ 2020-01-01 ┆ 2020-01-10 ┆ 8           │
+    │ 2020-01-10 ┆ 2020-01-01 ┆ -8          │
+    └────────────┴────────────┴─────────────┘
+    """
+    start_pyexpr = parse_as_expression(start)
+    end_pyexpr = parse_as_expression(end)
+    unix_epoch = date(1970, 1, 1)
+    return wrap_expr(
+        plr.networkdays(
+            start_pyexpr,
+            end_pyexpr,
+            week_mask,
+            [(holiday - unix_epoch).days for holiday in holidays],
+        )
+    )
+
+
+@overload
+def business_date_range(
+    start: date | IntoExprColumn,
+    end: date | IntoExprColumn,
+    interval: int = ...,
+    *,
+    week_mask: Iterable[bool] = ...,
+    holidays: Iterable[date] = ...,
+    eager: Literal[False] = ...,
+) -> Expr: ...
+
+
+@overload
+def business_date_range(
+    start: date | IntoExprColumn,
+    end: date | IntoExprColumn,
+    interval: int = ...,
+    *,
+    week_mask: Iterable[bool] = ...,
+    holidays: Iterable[date] = ...,
+    eager: Literal[True],
+) -> Series: ...
+
+
+@overload
+def business_date_range(
+    start: date | IntoExprColumn,
+    end: date | IntoExprColumn,
+    interval: int = ...,
+    *,
+    week_mask: Iterable[bool] = ...,
+    holidays: Iterable[date] = ...,
+    eager: bool,
+) -> Series | Expr: ...
+
+
+def business_date_range(
+    start: date | IntoExprColumn,
+    end: date | IntoExprColumn,
+    interval: int = 1,
+    *,
+    week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+    holidays: Iterable[date] = (),
+    eager: bool = False,
+) -> Series | Expr:
+    """
+    Generate a date range of business days.
+
+    The range includes both `start` and `end`, and begins at the first business day
+    on or after `start`.
+
+    Parameters
+    ----------
+    start
+        Lower bound of the date range.
+    end
+        Upper bound of the date range.
+    interval
+        Number of business days between consecutive dates, e.g. `2` for every
+        second business day.
+    week_mask
+        Which days of the week to count. The default is Monday to Friday.
+        If you wanted to count only Monday to Thursday, you would pass
+        `(True, True, True, True, False, False, False)`.
+    holidays
+        Holidays to skip.
+    eager
+        Evaluate immediately and return a `Series`.
+        If set to `False` (default), return an expression instead.
+
+    Returns
+    -------
+    Expr or Series
+        Column of data type :class:`Date`.
+
+    Examples
+    --------
+    >>> from datetime import date
+    >>> pl.business_date_range(
+    ...     date(2024, 1, 5), date(2024, 1, 12), holidays=[date(2024, 1, 9)], eager=True
+    ... ).alias("date")
+    shape: (5,)
+    Series: 'date' [date]
+    [
+        2024-01-05
+        2024-01-08
+        2024-01-10
+        2024-01-11
+        2024-01-12
+    ]
+
+    Every second business day:
+
+    >>> pl.business_date_range(date(2024, 1, 5), date(2024, 1, 12), 2, eager=True)
+    shape: (3,)
+    Series: 'literal' [date]
+    [
+        2024-01-05
+        2024-01-09
+        2024-01-11
+    ]
+    """
+    start_pyexpr = parse_as_expression(start)
+    end_pyexpr = parse_as_expression(end)
+    unix_epoch = date(1970, 1, 1)
+    result = wrap_expr(
+        plr.business_date_range(
+            start_pyexpr,
+            end_pyexpr,
+            interval,
+            week_mask,
+            [(holiday - unix_epoch).days for holiday in holidays],
+        )
+    )
+
+    if eager:
+        return F.select(result).to_series()
+
+    return result
@@ -1537,7 +1537,7 @@ def arg_sort_by(
     exprs: IntoExpr | Iterable[IntoExpr],
     *more_exprs: IntoExpr,
     descending: bool | Sequence[bool] = False,
-    nulls_last: bool = False,
+    nulls_last: bool | Sequence[bool] = False,
     multithreaded: bool = True,
     maintain_order: bool = False,
 ) -> Expr:
@@ -1555,7 +1555,9 @@ def arg_sort_by(
         Sort in descending order. When sorting by multiple columns, can be specified
         per column by passing a sequence of booleans.
     nulls_last
-        Place null values last.
+        Place null values last. When sorting by multiple columns, can be specified
+        per column by passing a sequence of booleans; rows that are equal on all
+        columns then always keep their original order.
     multithreaded
         Sort using multiple threads.
     maintain_order
@@ -1620,6 +1622,22 @@ def arg_sort_by(
     │ 2   │
     │ 3   │
     └─────┘
+
+    Control the placement of nulls per column by passing a sequence of booleans.
+
+    >>> df = pl.DataFrame({"a": [1, None, 1, None], "b": [None, 2, 1, None]})
+    >>> df.select(pl.arg_sort_by("a", "b", nulls_last=[True, False]))
+    shape: (4, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ u32 │
+    ╞═════╡
+    │ 0   │
+    │ 2   │
+    │ 3   │
+    │ 1   │
+    └─────┘
     """
     exprs = parse_as_list_of_expressions(exprs, *more_exprs)
 
@@ -1628,6 +1646,13 @@ def arg_sort_by(
     elif len(exprs) != len(descending):
         msg = f"the length of `descending` ({len(descending)}) does not match the length of `exprs` ({len(exprs)})"
         raise ValueError(msg)
+    if not isinstance(nulls_last, bool):
+        if len(exprs) != len(nulls_last):
+            msg = f"the length of `nulls_last` ({len(nulls_last)}) does not match the length of `exprs` ({len(exprs)})"
+            raise ValueError(msg)
+        return wrap_expr(
+            plr.arg_sort_by_keys(exprs, descending, list(nulls_last), multithreaded)
+        )
     return wrap_expr(
         plr.arg_sort_by(exprs, descending, nulls_last, multithreaded, maintain_order)
     )
@@ -71,6 +71,25 @@ class ArrayNameSpace:
         ]
         """
 
+    def mean(self) -> Series:
+        """
+        Compute the mean of the values of the sub-arrays.
+
+        Null values are ignored; a sub-array without any non-null values has a null
+        mean.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2], [4, 3]], dtype=pl.Array(pl.Int64, 2))
+        >>> s.arr.mean()
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            1.5
+            3.5
+        ]
+        """
+
     def std(self, ddof: int = 1) -> Series:
         """
         Compute the std of the values of the sub-arrays.
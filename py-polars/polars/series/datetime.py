@@ -130,6 +130,60 @@ class DateTimeNameSpace:
         ]
         """
 
+    def offset_by_business(
+        self,
+        by: str | Expr,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date] = (),
+        roll: Roll = "raise",
+    ) -> Series:
+        """
+        Offset by a relative offset that may include business days.
+
+        Parameters
+        ----------
+        by
+            The offset, in the same string language as :meth:`offset_by`, extended
+            with a `bd` unit for business days, e.g. `"5bd"` or `"1mo5bd"`. The
+            calendar part is applied first, and the business days are then counted
+            from the date it lands on. Can also be an expression of strings.
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to exclude from the count.
+        roll
+            What to do when the calendar part of the offset lands on a non-business
+            day. Options are:
+
+            - `'raise'`: raise an error
+            - `'forward'`: move to the next business day
+            - `'backward'`: move to the previous business day
+
+        Returns
+        -------
+        Series
+            Data type is preserved.
+
+        See Also
+        --------
+        offset_by
+        add_business_days
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series("start", [date(2024, 1, 31), date(2024, 1, 26)])
+        >>> s.dt.offset_by_business("1mo1bd")
+        shape: (2,)
+        Series: 'start' [date]
+        [
+                2024-03-01
+                2024-02-27
+        ]
+        """
+
     def min(self) -> dt.date | dt.datetime | dt.timedelta | None:
         """
         Return minimum as Python datetime.
@@ -539,7 +593,7 @@ class DateTimeNameSpace:
         ]
         """
 
-    def week(self) -> Series:
+    def week(self, *, iso: bool = True) -> Series:
         """
         Extract the week from the underlying date representation.
 
@@ -548,6 +602,17 @@ class DateTimeNameSpace:
         Returns the ISO week number starting from 1.
         The return value ranges from 1 to 53. (The last week of year differs by years.)
 
+        Parameters
+        ----------
+        iso
+            Number the weeks according to ISO 8601, where weeks start on Monday and
+            week 1 is the week containing the first Thursday of the year. The ISO week
+            belongs to the year returned by :func:`iso_year`, which may differ from the
+            calendar year around New Year. If set to `False`, week 1 is the week
+            containing January 1st instead, weeks still start on Monday, and the week
+            always belongs to the calendar year; the return value then ranges from
+            1 to 54.
+
         Returns
         -------
         Series
@@ -1338,6 +1403,8 @@ class DateTimeNameSpace:
         """
         Extract the total days from a Duration type.
 
+        Any remainder smaller than a day is truncated toward zero.
+
         Returns
         -------
         Series
@@ -1371,6 +1438,8 @@ class DateTimeNameSpace:
         """
         Extract the total hours from a Duration type.
 
+        Any remainder smaller than an hour is truncated toward zero.
+
         Returns
         -------
         Series
@@ -1406,6 +1475,8 @@ class DateTimeNameSpace:
         """
         Extract the total minutes from a Duration type.
 
+        Any remainder smaller than a minute is truncated toward zero.
+
         Returns
         -------
         Series
@@ -1441,6 +1512,8 @@ class DateTimeNameSpace:
         """
         Extract the total seconds from a Duration type.
 
+        Any remainder smaller than a second is truncated toward zero.
+
         Returns
         -------
         Series
@@ -1478,6 +1551,8 @@ class DateTimeNameSpace:
         """
         Extract the total milliseconds from a Duration type.
 
+        Any remainder smaller than a millisecond is truncated toward zero.
+
         Returns
         -------
         Series
@@ -1514,6 +1589,10 @@ class DateTimeNameSpace:
         """
         Extract the total microseconds from a Duration type.
 
+        Any remainder smaller than a microsecond is truncated toward zero.
+        Durations that can't be represented as an Int64 number of microseconds
+        become null.
+
         Returns
         -------
         Series
@@ -1550,6 +1629,9 @@ class DateTimeNameSpace:
         """
         Extract the total nanoseconds from a Duration type.
 
+        Durations that can't be represented as an Int64 number of nanoseconds
+        become null.
+
         Returns
         -------
         Series
@@ -1935,12 +2017,19 @@ class DateTimeNameSpace:
         ]
         """
 
-    def combine(self, time: dt.time | Series, time_unit: TimeUnit = "us") -> Expr:
+    def combine(
+        self,
+        time: dt.time | Series,
+        time_unit: TimeUnit = "us",
+        *,
+        time_zone: str | None = None,
+    ) -> Expr:
         """
-        Create a naive Datetime from an existing Date/Datetime expression and a Time.
+        Create a Datetime from an existing Date/Datetime expression and a Time.
 
         If the underlying expression is a Datetime then its time component is replaced,
         and if it is a Date then a new Datetime is created by combining the two values.
+        A null in either input results in a null.
 
         Parameters
         ----------
@@ -1948,6 +2037,11 @@ class DateTimeNameSpace:
             A python time literal or Series of the same length as this Series.
         time_unit : {'ns', 'us', 'ms'}
             Unit of time.
+        time_zone
+            Time zone in which to interpret the combined date and time. By default, the
+            result is in the time zone of the underlying Datetime, or naive if the
+            underlying expression is a Date. For a time zone aware Datetime, the date
+            is taken in its own time zone.
 
         Examples
         --------
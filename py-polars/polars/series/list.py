@@ -138,6 +138,7 @@ class ListNameSpace:
         with_replacement: bool = False,
         shuffle: bool = False,
         seed: int | None = None,
+        clamp: bool = False,
     ) -> Series:
         """
         Sample from this list.
@@ -155,7 +156,11 @@ class ListNameSpace:
             Shuffle the order of sampled data points.
         seed
             Seed for the random number generator. If set to None (default), a
-            random seed is generated for each sample operation.
+            random seed is generated for each sample operation. Each row is sampled
+            with its own seed, derived from `seed` and the row index.
+        clamp
+            When sampling without replacement, take at most as many items as a list
+            has instead of raising an error if `n` or `fraction` asks for more.
 
         Examples
         --------
@@ -165,7 +170,7 @@ class ListNameSpace:
         Series: 'values' [list[i64]]
         [
             [2, 1]
-            [5]
+            [4]
         ]
         """
 
@@ -287,10 +292,15 @@ class ListNameSpace:
         descending: bool = False,
         nulls_last: bool = False,
         multithreaded: bool = True,
+        maintain_order: bool = False,
     ) -> Series:
         """
         Sort the arrays in this column.
 
+        Null values are placed at the start of every list, or at the end if
+        `nulls_last` is set, regardless of the sort direction. Empty lists and
+        lists with a single element are returned unchanged.
+
         Parameters
         ----------
         descending
@@ -299,6 +309,8 @@ class ListNameSpace:
             Place null values last.
         multithreaded
             Sort using multiple threads.
+        maintain_order
+            Whether the order should be maintained if elements are equal.
 
         Examples
         --------
@@ -497,7 +509,8 @@ class ListNameSpace:
         """
         Join all string items in a sublist and place a separator between them.
 
-        This errors if inner type of list `!= String`.
+        This errors if inner type of list `!= String`. Empty sublists result in an
+        empty string.
 
         Parameters
         ----------
@@ -637,6 +650,9 @@ class ListNameSpace:
         """
         Calculate the first discrete difference between shifted items of every sublist.
 
+        Unsigned integer lists are cast to a signed integer type that can hold
+        negative differences.
+
         Parameters
         ----------
         n
@@ -712,6 +728,28 @@ class ListNameSpace:
         ]
         """
 
+    def filter(self, mask: IntoExpr) -> Series:
+        """
+        Keep the elements of every sublist where the matching `mask` element is true.
+
+        Parameters
+        ----------
+        mask
+            Series of data type `List(Boolean)`, with sublists of the same lengths as
+            the sublists in this Series. Elements with a null mask value are dropped.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3], [4, 5]])
+        >>> s.list.filter(pl.Series([[True, False, True], [False, None]]))
+        shape: (2,)
+        Series: 'a' [list[i64]]
+        [
+            [1, 3]
+            []
+        ]
+        """
+
     def slice(self, offset: int | Expr, length: int | Expr | None = None) -> Series:
         """
         Slice every sublist.
@@ -814,7 +852,8 @@ class ListNameSpace:
         Parameters
         ----------
         element
-            An expression that produces a single value
+            An expression that produces either a single value, which is counted in
+            every list, or one value per row. Null values count null elements.
 
         Examples
         --------
@@ -859,7 +898,7 @@ class ListNameSpace:
 
     def to_struct(
         self,
-        n_field_strategy: ToStructStrategy = "first_non_null",
+        n_field_strategy: ToStructStrategy | int = "first_non_null",
         fields: Callable[[int], str] | Sequence[str] | None = None,
     ) -> Series:
         """
@@ -867,15 +906,20 @@ class ListNameSpace:
 
         Parameters
         ----------
-        n_field_strategy : {'first_non_null', 'max_width'}
+        n_field_strategy : {'first_non_null', 'max_width'} or int
             Strategy to determine the number of fields of the struct.
 
             * "first_non_null": set number of fields equal to the length of the
               first non zero-length sublist.
             * "max_width": set number of fields as max length of all sublists.
+            * an integer: use exactly this number of fields; longer sublists are
+              truncated.
+
+            Sublists shorter than the number of fields are padded with nulls.
         fields
             If the name and number of the desired fields is known in advance
-            a list of field names can be given, which will be assigned by index.
+            a list of field names can be given, which will be assigned by index;
+            fields beyond the given names are named `field_i`.
             Otherwise, to dynamically assign field names, a custom function can be
             used; if neither are set, fields will be `field_0, field_1 .. field_n`.
 
@@ -135,6 +135,8 @@ if TYPE_CHECKING:
         NullBehavior,
         NumericLiteral,
         OneOrMoreDataTypes,
+        OutOfBounds,
+        PlateauBehavior,
         PolarsDataType,
         PythonLiteral,
         RankMethod,
@@ -1664,10 +1666,21 @@ class Series:
         """
         return self._s.all(ignore_nulls=ignore_nulls)
 
-    def log(self, base: float = math.e) -> Series:
+    def log(self, base: float | IntoExpr = math.e) -> Series:
         """
         Compute the logarithm to a given base.
 
+        Parameters
+        ----------
+        base
+            Given base, defaults to `e`. Accepts a Series or expression, so every
+            element can use its own base.
+
+        Notes
+        -----
+        Negative values result in NaN and zero results in `-inf`. If the base is a
+        Series or an expression, the result is always of data type :class:`Float64`.
+
         Examples
         --------
         >>> s = pl.Series([1, 2, 3])
@@ -1679,6 +1692,14 @@ class Series:
             0.693147
             1.098612
         ]
+        >>> s.log(pl.Series([2, 2, 3]))
+        shape: (3,)
+        Series: '' [f64]
+        [
+            0.0
+            1.0
+            1.0
+        ]
         """
 
     def log1p(self) -> Series:
@@ -1967,6 +1988,36 @@ class Series:
             exponent = Series(exponent)
         return self.to_frame().select_seq(F.col(self.name).pow(exponent)).to_series()
 
+    def mul_add(self, multiplier: IntoExpr, addend: IntoExpr) -> Series:
+        """
+        Compute `self * multiplier + addend` as a single fused operation.
+
+        For floating point data the result is computed with one rounding step
+        (fused multiply-add). Other numeric data types are computed as a regular
+        multiplication followed by an addition.
+
+        Parameters
+        ----------
+        multiplier
+            Value to multiply with; accepts expression input. Must have the same
+            length as this Series or be of length 1.
+        addend
+            Value to add to the product; accepts expression input. Must have the
+            same length as this Series or be of length 1.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1.0, 2.0, 3.0])
+        >>> s.mul_add(2.0, pl.Series([0.5, 0.25, 0.0]))
+        shape: (3,)
+        Series: 'a' [f64]
+        [
+            2.5
+            4.25
+            6.0
+        ]
+        """
+
     def min(self) -> PythonLiteral | None:
         """
         Get the minimal value in this Series.
@@ -2101,7 +2152,11 @@ class Series:
         return self._s.quantile(quantile, interpolation)
 
     def to_dummies(
-        self, *, separator: str = "_", drop_first: bool = False
+        self,
+        *,
+        separator: str = "_",
+        drop_first: bool = False,
+        drop_nulls: bool = False,
     ) -> DataFrame:
         """
         Get dummy/indicator variables.
@@ -2111,7 +2166,13 @@ class Series:
         separator
             Separator/delimiter used when generating column names.
         drop_first
-            Remove the first category from the variable being encoded.
+            Remove the first category from the variable being encoded. This is the
+            first non-null value in order of appearance, i.e.
+            `s.drop_nulls().first()`.
+        drop_nulls
+            Don't create an indicator column for null values, so that rows holding
+            a null are zero in every column. By default, nulls get a
+            `{name}{separator}null` column.
 
         Examples
         --------
@@ -2139,8 +2200,21 @@ class Series:
         │ 1   ┆ 0   │
         │ 0   ┆ 1   │
         └─────┴─────┘
+
+        >>> s = pl.Series("a", [1, None, 2])
+        >>> s.to_dummies(drop_nulls=True)
+        shape: (3, 2)
+        ┌─────┬─────┐
+        │ a_1 ┆ a_2 │
+        │ --- ┆ --- │
+        │ u8  ┆ u8  │
+        ╞═════╪═════╡
+        │ 1   ┆ 0   │
+        │ 0   ┆ 0   │
+        │ 0   ┆ 1   │
+        └─────┴─────┘
         """
-        return wrap_df(self._s.to_dummies(separator, drop_first))
+        return wrap_df(self._s.to_dummies(separator, drop_first, drop_nulls))
 
     @overload
     def cut(
@@ -2558,31 +2632,31 @@ class Series:
         Compress the Series data using run-length encoding.
 
         Run-length encoding (RLE) encodes data by storing each *run* of identical values
-        as a single value and its length.
+        as a single value and its length. Consecutive null values form a single run.
 
         Returns
         -------
         Series
-            Series of data type `Struct` with fields `lengths` of data type `Int32`
-            and `values` of the original data type.
+            Series of data type `Struct` with fields `len` of data type `UInt32`
+            and `value` of the original data type.
 
         Examples
         --------
         >>> s = pl.Series("s", [1, 1, 2, 1, None, 1, 3, 3])
         >>> s.rle().struct.unnest()
         shape: (6, 2)
-        ┌─────────┬────────┐
-        │ lengths ┆ values │
-        │ ---     ┆ ---    │
-        │ i32     ┆ i64    │
-        ╞═════════╪════════╡
-        │ 2       ┆ 1      │
-        │ 1       ┆ 2      │
-        │ 1       ┆ 1      │
-        │ 1       ┆ null   │
-        │ 1       ┆ 1      │
-        │ 2       ┆ 3      │
-        └─────────┴────────┘
+        ┌─────┬───────┐
+        │ len ┆ value │
+        │ --- ┆ ---   │
+        │ u32 ┆ i64   │
+        ╞═════╪═══════╡
+        │ 2   ┆ 1     │
+        │ 1   ┆ 2     │
+        │ 1   ┆ 1     │
+        │ 1   ┆ null  │
+        │ 1   ┆ 1     │
+        │ 2   ┆ 3     │
+        └─────┴───────┘
         """
 
     def rle_id(self) -> Series:
@@ -2632,6 +2706,7 @@ class Series:
         bin_count: int | None = None,
         include_category: bool = True,
         include_breakpoint: bool = True,
+        density: bool = False,
     ) -> DataFrame:
         """
         Bin values into buckets and count their occurrences.
@@ -2652,6 +2727,11 @@ class Series:
             Include a column that indicates the upper breakpoint.
         include_category
             Include a column that shows the intervals as categories.
+        density
+            Include a `density` column with the count of each bin divided by
+            `total_count * bin_width`, so that the densities integrate to 1.
+            Bins with a width of zero and the unbounded outer bins (when `bins` is
+            given) have a null density.
 
         Returns
         -------
@@ -2682,35 +2762,51 @@ class Series:
                     bin_count=bin_count,
                     include_category=include_category,
                     include_breakpoint=include_breakpoint,
+                    density=density,
                 )
             )
             .to_series()
         )
-        if not include_breakpoint and not include_category:
+        if not include_breakpoint and not include_category and not density:
             return out.to_frame()
         else:
             return out.struct.unnest()
 
-    def value_counts(self, *, sort: bool = False, parallel: bool = False) -> DataFrame:
+    def value_counts(
+        self,
+        *,
+        sort: bool = False,
+        descending: bool = True,
+        parallel: bool = False,
+        normalize: bool = False,
+    ) -> DataFrame:
         """
         Count the occurrences of unique values.
 
         Parameters
         ----------
         sort
-            Sort the output by count in descending order.
+            Sort the output by count, in descending order unless `descending` is
+            set to `False`.
             If set to `False` (default), the order of the output is random.
+        descending
+            Sort the counts in descending order. Only used if `sort` is set.
         parallel
             Execute the computation in parallel.
 
             .. note::
                 This option should likely not be enabled in a group by context,
                 as the computation is already parallelized per group.
+        normalize
+            Return the proportion of each value relative to the total length,
+            instead of its count. The count column is then named `proportion` and
+            has data type :class:`Float64`.
 
         Returns
         -------
         DataFrame
-            Mapping of unique values to their count.
+            Mapping of unique values to their count (or proportion). Null values are
+            counted as a value of their own.
 
         Examples
         --------
@@ -2740,15 +2836,38 @@ class Series:
         │ red   ┆ 2     │
         │ green ┆ 1     │
         └───────┴───────┘
+
+        Return the proportions instead of the counts, least frequent first.
+
+        >>> s.value_counts(sort=True, descending=False, normalize=True)
+        shape: (3, 2)
+        ┌───────┬────────────┐
+        │ color ┆ proportion │
+        │ ---   ┆ ---        │
+        │ str   ┆ f64        │
+        ╞═══════╪════════════╡
+        │ green ┆ 0.166667   │
+        │ red   ┆ 0.333333   │
+        │ blue  ┆ 0.5        │
+        └───────┴────────────┘
         """
         return pl.DataFrame._from_pydf(
-            self._s.value_counts(sort=sort, parallel=parallel)
+            self._s.value_counts(
+                sort=sort, descending=descending, parallel=parallel, normalize=normalize
+            )
         )
 
     def unique_counts(self) -> Series:
         """
         Return a count of the unique values in the order of appearance.
 
+        Notes
+        -----
+        The counts align element-for-element with `unique(maintain_order=True)`.
+        Keeping this order is cheap for numeric, string, binary and boolean data.
+        Other data types are grouped first, after which the groups are sorted by
+        their first occurrence.
+
         Examples
         --------
         >>> s = pl.Series("id", ["a", "b", "b", "c", "c", "c"])
@@ -2775,6 +2894,12 @@ class Series:
         normalize
             Normalize pk if it doesn't sum to 1.
 
+        Notes
+        -----
+        Null and zero values of `pk` don't contribute to the entropy. To compute the
+        entropy of the distribution of the values in a column, take it over the
+        counts of its unique values, e.g. `s.unique_counts().entropy(normalize=True)`.
+
         Examples
         --------
         >>> a = pl.Series([0.99, 0.005, 0.005])
@@ -2971,7 +3096,7 @@ class Series:
         ]
         """
 
-    def cum_prod(self, *, reverse: bool = False) -> Series:
+    def cum_prod(self, *, reverse: bool = False, strict: bool = False) -> Series:
         """
         Get an array with the cumulative product computed at every element.
 
@@ -2979,6 +3104,9 @@ class Series:
         ----------
         reverse
             reverse the operation.
+        strict
+            Raise an error naming the offending index when an integer product
+            overflows, instead of silently wrapping around. Has no effect on floats.
 
         Notes
         -----
@@ -3025,14 +3153,17 @@ class Series:
         ]
         """
 
-    def cum_count(self, *, reverse: bool = False) -> Self:
+    def cum_count(self, *, reverse: bool = False, count_nulls: bool = False) -> Self:
         """
         Return the cumulative count of the non-null values in the column.
 
         Parameters
         ----------
         reverse
-            Reverse the operation.
+            Reverse the operation, counting from each value to the end.
+        count_nulls
+            Count null values as well, which turns the result into a running row
+            number.
 
         Examples
         --------
@@ -3567,6 +3698,11 @@ class Series:
             If 'left', the index of the leftmost suitable location found is given.
             If 'right', return the rightmost suitable location found is given.
 
+        Notes
+        -----
+        The data is assumed to be sorted in ascending order. This is not checked, and
+        the result is undefined if it isn't.
+
         Examples
         --------
         >>> s = pl.Series("set", [1, 2, 3, 4, 4, 5, 6, 7])
@@ -3630,7 +3766,10 @@ class Series:
         """
 
     def gather(
-        self, indices: int | list[int] | Expr | Series | np.ndarray[Any, Any]
+        self,
+        indices: int | list[int] | Expr | Series | np.ndarray[Any, Any],
+        *,
+        out_of_bounds: OutOfBounds = "raise",
     ) -> Series:
         """
         Take values by index.
@@ -3638,7 +3777,15 @@ class Series:
         Parameters
         ----------
         indices
-            Index location used for selection.
+            Index location used for selection. Negative indices count from the end.
+        out_of_bounds : {'raise', 'null', 'wrap'}
+            What to do with indices that are out of bounds, after negative
+            indices have been counted from the end.
+
+            - 'raise': raise an error.
+            - 'null': return a null value.
+            - 'wrap': wrap the index around modulo the length. Indexing into
+              an empty Series still raises.
 
         Examples
         --------
@@ -3650,6 +3797,13 @@ class Series:
                 2
                 4
         ]
+        >>> s.gather([-1, 5], out_of_bounds="wrap")
+        shape: (2,)
+        Series: 'a' [i64]
+        [
+                4
+                2
+        ]
         """
 
     def null_count(self) -> int:
@@ -3877,10 +4031,24 @@ class Series:
         ]
         """
 
-    def is_in(self, other: Series | Collection[Any]) -> Series:
+    def is_in(
+        self, other: Series | Collection[Any], *, nulls_equal: bool = False
+    ) -> Series:
         """
         Check if elements of this Series are in the other Series.
 
+        If `other` is a `List` Series, every value is looked up in the list of the
+        same row instead of in a single set of values.
+
+        Parameters
+        ----------
+        other
+            Series or sequence of primitive type.
+        nulls_equal
+            Whether a null value is considered to be present if `other` (or the list
+            of the same row) contains a null. By default, null values result in null.
+            A null list always results in null.
+
         Returns
         -------
         Series
@@ -4306,6 +4474,58 @@ class Series:
 
         return out
 
+    def is_close(
+        self,
+        other: IntoExpr,
+        *,
+        rel_tol: float = 1e-09,
+        abs_tol: float = 0.0,
+        nans_equal: bool = False,
+    ) -> Series:
+        """
+        Check if the values of this Series are approximately equal to another value.
+
+        Two values `a` and `b` are considered close if
+        `abs(a - b) <= max(rel_tol * max(abs(a), abs(b)), abs_tol)`.
+
+        Parameters
+        ----------
+        other
+            Numeric Series or scalar to compare with. Accepts expression input.
+            Non-expression inputs are parsed as literals.
+        rel_tol
+            Maximum difference allowed, relative to the larger absolute value of the
+            two inputs. Must be non-negative.
+        abs_tol
+            Minimum absolute difference that is always allowed, which is useful for
+            comparisons near zero. Must be non-negative.
+        nans_equal
+            Consider NaN values to be close to each other.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Notes
+        -----
+        Infinite values are only close to an infinite value of the same sign. Nulls
+        propagate.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1.0, 1.1, float("nan"), None])
+        >>> s.is_close(1.0, rel_tol=0.2, nans_equal=True)
+        shape: (4,)
+        Series: 'a' [bool]
+        [
+            true
+            true
+            false
+            null
+        ]
+        """
+
     def to_numpy(
         self,
         *,
@@ -4914,6 +5134,9 @@ class Series:
         """
         Round underlying floating point data by `decimals` digits.
 
+        Decimal data is rounded half away from zero at its own scale, without a
+        round-trip through floating point.
+
         Examples
         --------
         >>> s = pl.Series("a", [1.12345, 2.56789, 3.901234])
@@ -4978,12 +5201,24 @@ class Series:
             raise ShapeError(msg)
         return self._s.dot(other._s)
 
-    def mode(self) -> Series:
+    def mode(self, *, maintain_order: bool = False, ignore_nulls: bool = False) -> Series:
         """
         Compute the most occurring value(s).
 
         Can return multiple Values.
 
+        Parameters
+        ----------
+        maintain_order
+            Return tied values in order of their first appearance. If not set, the
+            order of tied values is not deterministic.
+        ignore_nulls
+            Ignore null values. If not set, null is counted like any other value.
+
+        See Also
+        --------
+        mode_first
+
         Examples
         --------
         >>> s = pl.Series("a", [1, 2, 2, 3])
@@ -4993,7 +5228,42 @@ class Series:
         [
                 2
         ]
+        >>> s = pl.Series("a", [3, 1, 2, 1, 3])
+        >>> s.mode(maintain_order=True)
+        shape: (2,)
+        Series: 'a' [i64]
+        [
+                3
+                1
+        ]
+        """
+
+    def mode_first(self, *, ignore_nulls: bool = False) -> PythonLiteral | None:
+        """
+        Compute the single most occurring value.
+
+        Ties are broken by first appearance. Returns `None` if there are no values.
+
+        Parameters
+        ----------
+        ignore_nulls
+            Ignore null values. If not set, null is counted like any other value.
+
+        See Also
+        --------
+        mode
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [3, 1, 2, 1, 3])
+        >>> s.mode_first()
+        3
         """
+        return (
+            self.to_frame()
+            .select_seq(F.col(self.name).mode_first(ignore_nulls=ignore_nulls))
+            .item()
+        )
 
     def sign(self) -> Series:
         """
@@ -5475,7 +5745,8 @@ class Series:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
 
         Examples
         --------
@@ -5538,7 +5809,8 @@ class Series:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
 
         Examples
         --------
@@ -5601,7 +5873,8 @@ class Series:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
 
         Examples
         --------
@@ -5664,7 +5937,8 @@ class Series:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
 
         Examples
         --------
@@ -5728,7 +6002,8 @@ class Series:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
         ddof
             "Delta Degrees of Freedom": The divisor for a length N window is N - ddof
 
@@ -5795,7 +6070,8 @@ class Series:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
         ddof
             "Delta Degrees of Freedom": The divisor for a length N window is N - ddof
 
@@ -5911,7 +6187,8 @@ class Series:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
 
         The window at a given row will include the row itself and the `window_size - 1`
         elements before it.
@@ -5970,7 +6247,7 @@ class Series:
         quantile
             Quantile between 0.0 and 1.0.
         interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear'}
-            Interpolation method.
+            Interpolation method, the same as for :meth:`quantile`.
         window_size
             The length of the window.
         weights
@@ -5983,7 +6260,8 @@ class Series:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
 
         Examples
         --------
@@ -5994,10 +6272,10 @@ class Series:
         [
                 null
                 null
-                1.0
                 2.0
                 3.0
                 4.0
+                6.0
         ]
         >>> s.rolling_quantile(quantile=0.33, interpolation="linear", window_size=3)
         shape: (6,)
@@ -6030,7 +6308,9 @@ class Series:
         )
 
     @unstable()
-    def rolling_skew(self, window_size: int, *, bias: bool = True) -> Series:
+    def rolling_skew(
+        self, window_size: int, *, bias: bool = True, min_periods: int | None = None
+    ) -> Series:
         """
         Compute a rolling skew.
 
@@ -6047,6 +6327,10 @@ class Series:
             Integer size of the rolling window.
         bias
             If False, the calculations are corrected for statistical bias.
+        min_periods
+            The number of values in the window that should be non-null before
+            computing a result. If set to `None` (default), it will be set equal to
+            `window_size`. Windows with zero variance also produce null.
 
         Examples
         --------
@@ -6066,6 +6350,52 @@ class Series:
         (0.38180177416060584, 0.47033046033698594)
         """
 
+    @unstable()
+    def rolling_kurtosis(
+        self,
+        window_size: int,
+        *,
+        fisher: bool = True,
+        bias: bool = True,
+        min_periods: int | None = None,
+    ) -> Series:
+        """
+        Compute a rolling kurtosis.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row includes the row itself and the
+        `window_size - 1` elements before it.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        fisher
+            If True, Fisher's definition is used (normal ==> 0.0). If False,
+            Pearson's definition is used (normal ==> 3.0).
+        bias
+            If False, the calculations are corrected for statistical bias.
+        min_periods
+            The number of values in the window that should be non-null before
+            computing a result. If set to `None` (default), it will be set equal to
+            `window_size`. Windows with zero variance also produce null.
+
+        Examples
+        --------
+        >>> pl.Series([1, 4, 2, 9]).rolling_kurtosis(3)
+        shape: (4,)
+        Series: '' [f64]
+        [
+            null
+            null
+            -1.5
+            -1.5
+        ]
+        """
+
     def sample(
         self,
         n: int | None = None,
@@ -6105,10 +6435,35 @@ class Series:
         ]
         """
 
-    def peak_max(self) -> Self:
+    def peak_max(
+        self,
+        *,
+        plateau: PlateauBehavior = "none",
+        min_prominence: float | None = None,
+    ) -> Self:
         """
         Get a boolean mask of the local maximum peaks.
 
+        Parameters
+        ----------
+        plateau : {'none', 'all', 'first', 'last'}
+            Which elements of a plateau, a run of equal values that forms a peak, to
+            mark.
+
+            - 'none': only mark peaks that consist of a single value.
+            - 'all': mark every element of the plateau.
+            - 'first': only mark the first element of the plateau.
+            - 'last': only mark the last element of the plateau.
+        min_prominence
+            Only mark peaks that rise at least this much above the higher of the two
+            lowest points passed on either side before reaching a higher value or the
+            end of the data. Use this to ignore small peaks caused by noise.
+
+        Notes
+        -----
+        Values outside of the data are treated as zero when comparing the first and
+        last element to their neighbours.
+
         Examples
         --------
         >>> s = pl.Series("a", [1, 2, 3, 4, 5])
@@ -6122,12 +6477,52 @@ class Series:
                 false
                 true
         ]
+
+        Mark every element of a plateau:
+
+        >>> s = pl.Series("a", [1, 3, 3, 1, 2, 1])
+        >>> s.peak_max(plateau="all")
+        shape: (6,)
+        Series: 'a' [bool]
+        [
+            false
+            true
+            true
+            false
+            true
+            false
+        ]
         """
 
-    def peak_min(self) -> Self:
+    def peak_min(
+        self,
+        *,
+        plateau: PlateauBehavior = "none",
+        min_prominence: float | None = None,
+    ) -> Self:
         """
         Get a boolean mask of the local minimum peaks.
 
+        Parameters
+        ----------
+        plateau : {'none', 'all', 'first', 'last'}
+            Which elements of a plateau, a run of equal values that forms a peak, to
+            mark.
+
+            - 'none': only mark peaks that consist of a single value.
+            - 'all': mark every element of the plateau.
+            - 'first': only mark the first element of the plateau.
+            - 'last': only mark the last element of the plateau.
+        min_prominence
+            Only mark peaks that drop at least this much below the lower of the two
+            highest points passed on either side before reaching a lower value or the
+            end of the data. Use this to ignore small peaks caused by noise.
+
+        Notes
+        -----
+        Values outside of the data are treated as zero when comparing the first and
+        last element to their neighbours.
+
         Examples
         --------
         >>> s = pl.Series("a", [4, 1, 3, 2, 5])
@@ -6141,6 +6536,20 @@ class Series:
             true
             false
         ]
+
+        Ignore minima that do not stand out by at least 2:
+
+        >>> s = pl.Series("a", [4, 1, 3, 2, 5])
+        >>> s.peak_min(min_prominence=2)
+        shape: (5,)
+        Series: 'a' [bool]
+        [
+            false
+            true
+            false
+            false
+            false
+        ]
         """
 
     def n_unique(self) -> int:
@@ -6197,7 +6606,13 @@ class Series:
         -----
         This implementation of `hash` does not guarantee stable results
         across different Polars versions. Its stability is only guaranteed within a
-        single version.
+        single version: for the same seeds, data type and platform, the hashes are
+        reproducible across sessions, which makes them suitable for deterministic
+        partitioning.
+
+        The hash is not cryptographic. Do not use it to protect sensitive data, as
+        collisions can be constructed deliberately and the input can be recovered
+        for small domains.
 
         Examples
         --------
@@ -6225,7 +6640,9 @@ class Series:
             If True, reinterpret as `pl.Int64`. Otherwise, reinterpret as `pl.UInt64`.
         """
 
-    def interpolate(self, method: InterpolationMethod = "linear") -> Series:
+    def interpolate(
+        self, method: InterpolationMethod = "linear", *, max_gap: int | None = None
+    ) -> Series:
         """
         Fill null values using interpolation.
 
@@ -6233,6 +6650,9 @@ class Series:
         ----------
         method : {'linear', 'nearest'}
             Interpolation method.
+        max_gap
+            The maximum number of consecutive null values to fill. Longer gaps are
+            left null entirely instead of being filled partially.
 
         Examples
         --------
@@ -6249,6 +6669,51 @@ class Series:
         ]
         """
 
+    def interpolate_by(
+        self,
+        by: IntoExpr,
+        method: InterpolationMethod = "linear",
+        *,
+        max_gap: int | float | timedelta | Expr | None = None,
+    ) -> Series:
+        """
+        Fill null values using interpolation based on another column.
+
+        Unlike :meth:`interpolate`, which assumes the values are evenly spaced, the
+        interpolation weights are taken from the x-coordinates in `by`.
+
+        Parameters
+        ----------
+        by
+            Column with the x-coordinates to interpolate against. Must be numeric or
+            temporal, without nulls, and sorted in either ascending or descending
+            order.
+        method : {'linear', 'nearest'}
+            Interpolation method.
+        max_gap
+            The maximum distance in `by` between the non-null values around a gap
+            for the gap to be filled. Wider gaps are left null. Must be a duration
+            if `by` is temporal.
+
+        Notes
+        -----
+        Null values before the first and after the last non-null value are not
+        extrapolated and remain null.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1.0, None, None, 6.0])
+        >>> s.interpolate_by(pl.Series([1, 2, 5, 6]))
+        shape: (4,)
+        Series: 'a' [f64]
+        [
+            1.0
+            2.0
+            5.0
+            6.0
+        ]
+        """
+
     def abs(self) -> Series:
         """
         Compute absolute values.
@@ -6268,11 +6733,147 @@ class Series:
         ]
         """
 
+    def bit_count(self) -> Series:
+        """
+        Count the number of set bits of every integer, also known as popcount.
+
+        Negative integers count the bits of their two's complement representation.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [0, 1, 7, -1], dtype=pl.Int8)
+        >>> s.bit_count()
+        shape: (4,)
+        Series: 'a' [u32]
+        [
+                0
+                1
+                3
+                8
+        ]
+        """
+
+    def leading_zeros(self) -> Series:
+        """
+        Count the number of leading zero bits of every integer.
+
+        The count is relative to the width of the data type, so zero has as many
+        leading zeros as the data type has bits.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [0, 1, 255], dtype=pl.UInt16)
+        >>> s.leading_zeros()
+        shape: (3,)
+        Series: 'a' [u32]
+        [
+                16
+                15
+                8
+        ]
+        """
+
+    def trailing_zeros(self) -> Series:
+        """
+        Count the number of trailing zero bits of every integer.
+
+        Zero has as many trailing zeros as the data type has bits.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [0, 1, 8], dtype=pl.UInt16)
+        >>> s.trailing_zeros()
+        shape: (3,)
+        Series: 'a' [u32]
+        [
+                16
+                0
+                3
+        ]
+        """
+
+    def gcd(self, other: int | IntoExprColumn) -> Series:
+        """
+        Compute the element-wise greatest common divisor with another integer Series.
+
+        Parameters
+        ----------
+        other
+            Integer Series or scalar. Accepts expression input. Non-expression inputs
+            are parsed as literals.
+
+        Notes
+        -----
+        The result is non-negative, with `gcd(0, 0) == 0`, and has the supertype of
+        both inputs. Nulls propagate.
+
+        Examples
+        --------
+        >>> s = pl.Series([12, -18, 0, 7])
+        >>> s.gcd(pl.Series([18, 12, 0, -21]))
+        shape: (4,)
+        Series: '' [i64]
+        [
+            6
+            6
+            0
+            7
+        ]
+        """
+
+    def lcm(self, other: int | IntoExprColumn, *, saturate: bool = False) -> Series:
+        """
+        Compute the element-wise least common multiple with another integer Series.
+
+        Parameters
+        ----------
+        other
+            Integer Series or scalar. Accepts expression input. Non-expression inputs
+            are parsed as literals.
+        saturate
+            If the result does not fit in the dtype, return its maximum value instead
+            of raising an error.
+
+        Notes
+        -----
+        The result is non-negative, zero if either value is zero, and has the
+        supertype of both inputs. Nulls propagate.
+
+        Examples
+        --------
+        >>> s = pl.Series([12, -18, 0, 7])
+        >>> s.lcm(pl.Series([18, 12, 5, -21]))
+        shape: (4,)
+        Series: '' [i64]
+        [
+            36
+            36
+            0
+            21
+        ]
+        """
+
     def rank(
         self,
         method: RankMethod = "average",
         *,
         descending: bool = False,
+        nulls_last: bool | None = None,
         seed: int | None = None,
     ) -> Series:
         """
@@ -6280,7 +6881,7 @@ class Series:
 
         Parameters
         ----------
-        method : {'average', 'min', 'max', 'dense', 'ordinal', 'random'}
+        method : {'average', 'min', 'max', 'dense', 'ordinal', 'first', 'random'}
             The method used to assign ranks to tied elements.
             The following methods are available (default is 'average'):
 
@@ -6296,10 +6897,16 @@ class Series:
               elements.
             - 'ordinal' : All values are given a distinct rank, corresponding to
               the order that the values occur in the Series.
+            - 'first' : Alias for 'ordinal'.
             - 'random' : Like 'ordinal', but the rank for ties is not dependent
               on the order that the values occur in the Series.
         descending
             Rank in descending order.
+        nulls_last
+            Where to place null values in the ranking. By default nulls are not ranked
+            and stay null. If True, they are ranked as a single tie group after all
+            other values; if False, before them. This does not depend on
+            `descending`.
         seed
             If `method="random"`, use this as seed.
 
@@ -6341,9 +6948,16 @@ class Series:
         Parameters
         ----------
         n
-            Number of slots to shift.
+            Number of slots to shift. A negative value takes the difference with the
+            value that many slots ahead instead.
         null_behavior : {'ignore', 'drop'}
-            How to handle null values.
+            How to handle the positions that have no value to take the difference
+            with: `'ignore'` sets them to null, `'drop'` removes them.
+
+        Notes
+        -----
+        Unsigned integers are cast to a signed integer type, so that negative
+        differences can be represented.
 
         Examples
         --------
@@ -6380,7 +6994,12 @@ class Series:
         ]
         """
 
-    def pct_change(self, n: int | IntoExprColumn = 1) -> Series:
+    def pct_change(
+        self,
+        n: int | IntoExprColumn = 1,
+        *,
+        fill_null_strategy: FillNullStrategy | None = "forward",
+    ) -> Series:
         """
         Computes percentage change between values.
 
@@ -6392,7 +7011,17 @@ class Series:
         Parameters
         ----------
         n
-            periods to shift for forming percent change.
+            periods to shift for forming percent change. This can also be a column
+            with one period per row.
+        fill_null_strategy : {'forward', 'backward', 'min', 'max', 'mean', 'zero', 'one'}
+            Strategy used to fill null values before computing the change. Set to
+            `None` to leave them as they are, in which case the result is null
+            wherever either value is null.
+
+        Notes
+        -----
+        The first `n` rows are null. Division by zero is not an error: it results in
+        `inf`, `-inf` or `NaN`, following IEEE 754.
 
         Examples
         --------
@@ -6467,6 +7096,9 @@ class Series:
         .. math::
             G_1 = \frac{k_3}{k_2^{3/2}} = \frac{\sqrt{N(N-1)}}{N-2}\frac{m_3}{m_2^{3/2}}
 
+        The result is null if there are fewer than three non-null values, or if
+        the variance is zero.
+
         Examples
         --------
         >>> s = pl.Series([1, 2, 2, 4, 5])
@@ -6476,7 +7108,7 @@ class Series:
         return self._s.skew(bias)
 
     def kurtosis(self, *, fisher: bool = True, bias: bool = True) -> float | None:
-        """
+        r"""
         Compute the kurtosis (Fisher or Pearson) of a dataset.
 
         Kurtosis is the fourth central moment divided by the square of the
@@ -6495,6 +7127,22 @@ class Series:
         bias : bool, optional
             If False, the calculations are corrected for statistical bias.
 
+        Notes
+        -----
+        With :math:`m_i` the biased sample :math:`i\texttt{th}` central moment of
+        the :math:`N` non-null values, the biased kurtosis is
+
+        .. math:: b_2=\frac{m_4}{m_2^2}
+
+        If `bias` is False, the value computed is
+
+        .. math::
+            3 + \frac{(N^2-1) b_2 - 3(N-1)^2}{(N-2)(N-3)}
+
+        If `fisher` is True, 3.0 is subtracted from either value. The result is
+        null if there are fewer than four non-null values, or if the variance is
+        zero.
+
         Examples
         --------
         >>> s = pl.Series("grades", [66, 79, 54, 97, 96, 70, 69, 85, 93, 75])
@@ -6507,6 +7155,56 @@ class Series:
         """
         return self._s.kurtosis(fisher, bias)
 
+    def moment(
+        self, order: int, *, central: bool = True, bias: bool = True
+    ) -> float | None:
+        r"""
+        Compute the statistical moment of the given order.
+
+        Parameters
+        ----------
+        order
+            The order of the moment.
+        central
+            If True, the moment is taken about the mean, otherwise about zero.
+        bias
+            If False, the central moments are corrected for statistical bias.
+
+        Notes
+        -----
+        With :math:`\bar{x}` the mean of the :math:`N` non-null values, the
+        :math:`k\texttt{th}` central moment is
+
+        .. math:: m_k=\frac{1}{N}\sum_{i=1}^N (x_i - \bar{x})^k
+
+        and the raw moment is the same sum without subtracting the mean. The moment
+        of order 0 is always 1. The first central moment is 0, the first raw moment
+        is the mean.
+
+        If `bias` is False, the central moments of order 2, 3 and 4 are replaced by
+        their unbiased estimators, so that the second central moment equals the
+        sample variance. Unbiased central moments of a higher order are not
+        supported. Raw moments are unbiased already.
+
+        Central moments up to order 4 are computed in a single numerically stable
+        pass, higher orders subtract the mean in a second pass. The result is null
+        if there are no non-null values, or if there are not more values than the
+        order for the unbiased estimators.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 2, 3, 2, 1])
+        >>> s.moment(2, central=False)
+        3.8
+        >>> s.moment(0)
+        1.0
+        """
+        return (
+            self.to_frame()
+            .select_seq(F.col(self.name).moment(order, central=central, bias=bias))
+            .item()
+        )
+
     def clip(
         self,
         lower_bound: NumericLiteral | TemporalLiteral | IntoExprColumn | None = None,
@@ -6535,6 +7233,11 @@ class Series:
         This method only works for numeric and temporal columns. To clip other data
         types, consider writing a `when-then-otherwise` expression. See :func:`when`.
 
+        The bounds can be columns, in which case each element is clipped to the
+        bounds in its own row. A null bound leaves the element unbounded on that
+        side. Bounds are cast to the dtype of the values; a bound whose dtype has no
+        numeric supertype with the values raises a `ComputeError`.
+
         Examples
         --------
         Specifying both a lower and upper bound:
@@ -6744,23 +7447,139 @@ class Series:
         ]
         """
 
-    def reshape(self, dimensions: tuple[int, ...]) -> Series:
+    def replace_strict(
+        self,
+        old: IntoExpr | Sequence[Any] | Mapping[Any, Any],
+        new: IntoExpr | Sequence[Any] | NoDefault = no_default,
+        *,
+        default: IntoExpr | NoDefault = no_default,
+        return_dtype: PolarsDataType | None = None,
+    ) -> Self:
+        """
+        Replace all values by looking them up in a mapping.
+
+        Unlike :func:`replace`, every value must be mapped: values that are not in
+        `old` are set to `default`, or raise an error if no default is given.
+
+        Parameters
+        ----------
+        old
+            Value or sequence of values to replace.
+            Accepts expression input. Sequences are parsed as Series,
+            other non-expression inputs are parsed as literals.
+            Also accepts a mapping of values to their replacement as syntactic sugar for
+            `replace_strict(old=Series(mapping.keys()), new=Series(mapping.values()))`.
+        new
+            Value or sequence of values to replace by.
+            Accepts expression input. Sequences are parsed as Series,
+            other non-expression inputs are parsed as literals.
+            Length must match the length of `old` or have length 1.
+        default
+            Set values that were not replaced to this value. If not set, a value that
+            is not in `old` raises a `ComputeError`; null values stay null.
+            Accepts expression input. Non-expression inputs are parsed as literals.
+        return_dtype
+            The data type of the resulting Series. If set to `None` (default),
+            the data type is the supertype of `new` and `default`, regardless of the
+            original data type.
+
+        See Also
+        --------
+        replace
+
+        Examples
+        --------
+        >>> s = pl.Series(["x", "y", None, "x"])
+        >>> s.replace_strict({"x": 1, "y": 2})
+        shape: (4,)
+        Series: '' [i64]
+        [
+                1
+                2
+                null
+                1
+        ]
+        """
+
+    def replace_ranges(
+        self,
+        breaks: IntoExpr | Sequence[float],
+        labels: IntoExpr | Sequence[Any],
+        *,
+        default: IntoExpr | NoDefault = no_default,
+        left_closed: bool = False,
+        return_dtype: PolarsDataType | None = None,
+    ) -> Self:
         """
-        Reshape this Series to a flat Series or a Series of Lists.
+        Replace numeric values by the label of the range they fall in.
+
+        This is a lighter alternative to :func:`cut` when the bins are only used as a
+        lookup: the labels can be of any data type and no categorical is created.
+
+        Parameters
+        ----------
+        breaks
+            Sorted, unique edges of the ranges. `n` breaks define `n - 1` consecutive
+            ranges. Accepts expression input. Sequences are parsed as Series.
+        labels
+            Value to use for each range. Must have one value less than `breaks`.
+            Accepts expression input. Sequences are parsed as Series.
+        default
+            Set values that fall outside of all ranges, as well as NaN values, to this
+            value. If not set, such values are set to null; null values stay null.
+            Accepts expression input. Non-expression inputs are parsed as literals.
+        left_closed
+            Set the ranges to be left-closed instead of right-closed.
+        return_dtype
+            The data type of the resulting Series. If set to `None` (default),
+            the data type is the supertype of `labels` and `default`.
+
+        See Also
+        --------
+        cut
+        replace_strict
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [-5, 0, 5, 10, 50, 500])
+        >>> s.replace_ranges([0, 10, 100], ["low", "high"], default="other")
+        shape: (6,)
+        Series: 'a' [str]
+        [
+            "other"
+            "other"
+            "low"
+            "low"
+            "high"
+            "other"
+        ]
+        """
+
+    def reshape(
+        self, dimensions: tuple[int, ...], *, as_array: bool = False
+    ) -> Series:
+        """
+        Reshape this Series to a flat Series or a Series of (nested) Lists or Arrays.
 
         Parameters
         ----------
         dimensions
-            Tuple of the dimension sizes. If a -1 is used in any of the dimensions, that
-            dimension is inferred.
+            Tuple of the dimension sizes. If a -1 is used in one of the dimensions,
+            that dimension is inferred. The product of the dimensions must equal the
+            number of elements.
+        as_array
+            Return a (nested) :class:`Array` instead of a :class:`List`. The inner
+            dimensions then determine the array widths, so only the first dimension
+            can be inferred.
 
         Returns
         -------
         Series
             If a single dimension is given, results in a Series of the original
             data type.
-            If a multiple dimensions are given, results in a Series of data type
-            :class:`List` with shape (rows, cols).
+            If multiple dimensions are given, results in a Series of data type
+            :class:`List` (or :class:`Array`) nested once for every dimension after
+            the first.
 
         See Also
         --------
@@ -6777,6 +7596,14 @@ class Series:
                 [4, 5, 6]
                 [7, 8, 9]
         ]
+        >>> s.reshape((-1, 3), as_array=True)
+        shape: (3,)
+        Series: 'foo' [array[i64, 3]]
+        [
+                [1, 2, 3]
+                [4, 5, 6]
+                [7, 8, 9]
+        ]
         """
 
     def shuffle(self, seed: int | None = None) -> Series:
@@ -6905,11 +7732,14 @@ class Series:
 
         where :math:`\lambda` equals :math:`\ln(2) / \text{half_life}`.
 
+        Rows where the value or the time is null produce null, and are skipped
+        rather than resetting the average.
+
         Parameters
         ----------
         by
-            Times to calculate average by. Should be ``DateTime``, ``Date``, ``UInt64``,
-            ``UInt32``, ``Int64``, or ``Int32`` data type.
+            Times to calculate average by. Should be ``DateTime``, ``Date``, or an
+            integer data type.
         half_life
             Unit over which observation decays to half its value.
 
@@ -7139,7 +7969,9 @@ class Series:
         ]
         """
 
-    def extend_constant(self, value: IntoExpr, n: int | IntoExprColumn) -> Series:
+    def extend_constant(
+        self, value: IntoExpr, n: int | IntoExprColumn, *, prepend: bool = False
+    ) -> Series:
         """
         Extremely fast method for extending the Series with 'n' copies of a value.
 
@@ -7147,9 +7979,12 @@ class Series:
         ----------
         value
             A constant literal value or a unit expressioin with which to extend the
-            expression result Series; can pass None to extend with nulls.
+            expression result Series; can pass None to extend with nulls. The value
+            must be castable to the data type of the Series without loss.
         n
             The number of additional values that will be added.
+        prepend
+            Add the values to the start instead of the end.
 
         Examples
         --------
@@ -7164,6 +7999,15 @@ class Series:
                 99
                 99
         ]
+        >>> s.extend_constant(0, n=1, prepend=True)
+        shape: (4,)
+        Series: '' [i64]
+        [
+                0
+                1
+                2
+                3
+        ]
         """
 
     def set_sorted(self, *, descending: bool = False) -> Self:
@@ -7281,7 +8125,8 @@ class Series:
             - the window size, if `window_size` is a fixed integer
             - 1, if `window_size` is a dynamic temporal size
         center
-            Set the labels at the center of the window
+            Set the labels at the center of the window. For an even `window_size`
+            the window holds one more element before the label than after it.
         """
 
     @deprecate_renamed_function("is_first_distinct", version="0.19.3")
@@ -20,6 +20,7 @@ if TYPE_CHECKING:
         PolarsTemporalType,
         TimeUnit,
         TransferEncoding,
+        UnicodeForm,
     )
 
 
@@ -517,6 +518,9 @@ class StringNameSpace:
 
         Notes
         -----
+        The index is counted in characters rather than bytes, so it can be used with
+        :func:`slice`, which uses character offsets as well.
+
         To modify regular expression behaviour (such as case-sensitivity) with
         flags, use the inline `(?iLmsuxU)` syntax. For example:
 
@@ -582,6 +586,26 @@ class StringNameSpace:
         ]
         """
 
+    def escape_regex(self) -> Series:
+        r"""
+        Escape all regular expression meta characters in the string values.
+
+        The result can be embedded in a regular expression pattern to match the
+        original string literally, for example when building patterns from data.
+
+        Examples
+        --------
+        >>> s = pl.Series(["1.5 (approx)", "a+b", None])
+        >>> s.str.escape_regex()
+        shape: (3,)
+        Series: '' [str]
+        [
+            "1\.5 \(approx\)"
+            "a\+b"
+            null
+        ]
+        """
+
     def ends_with(self, suffix: str | Expr) -> Series:
         """
         Check if string values end with a substring.
@@ -919,6 +943,10 @@ class StringNameSpace:
         If your pattern contains unnamed groups, their numerical position is converted
         to a string.
 
+        The regex is applied once per value, extracting all groups in a single pass.
+        Values that are null or don't match the pattern yield a struct whose fields
+        are all null.
+
         For example, we can access the first group via the string `"1"`::
 
             >>> (
@@ -1003,7 +1031,9 @@ class StringNameSpace:
         ]
         """
 
-    def split(self, by: IntoExpr, *, inclusive: bool = False) -> Series:
+    def split(
+        self, by: IntoExpr, *, inclusive: bool = False, regex: bool = False
+    ) -> Series:
         """
         Split the string by a substring.
 
@@ -1013,6 +1043,10 @@ class StringNameSpace:
             Substring to split by.
         inclusive
             If True, include the split character/string in the results.
+        regex
+            Treat `by` as a regex pattern instead of a literal substring. The pattern
+            is compiled once and reused for all rows. Consecutive matches produce
+            empty strings.
 
         Returns
         -------
@@ -1507,14 +1541,15 @@ class StringNameSpace:
         """
         Pad the start of the string with zeros until it reaches the given length.
 
-        A sign prefix (`-`) is handled by inserting the padding after the sign
+        A sign prefix (`-` or `+`) is handled by inserting the padding after the sign
         character rather than before.
 
         Parameters
         ----------
         length
             Pad the string until it reaches this length. Strings with length equal to
-            or greater than this value are returned as-is.
+            or greater than this value are returned as-is. This can be an expression
+            to pad each row to a different length.
 
         See Also
         --------
@@ -1575,6 +1610,17 @@ class StringNameSpace:
         """
         Modify the strings to their titlecase equivalent.
 
+        The first character of every word is uppercased and the other characters
+        are lowercased.
+
+        Notes
+        -----
+        Words are determined by the Unicode word boundary rules, so words separated
+        by punctuation are capitalized separately: `"jean-luc"` becomes
+        `"Jean-Luc"`. Apostrophes don't separate words, except after a single
+        letter: `"o'brien"` becomes `"O'Brien"` while `"there's"` becomes
+        `"There's"`.
+
         Examples
         --------
         >>> s = pl.Series("sing", ["welcome to my world", "THERE'S NO TURNING BACK"])
@@ -1587,6 +1633,37 @@ class StringNameSpace:
         ]
         """
 
+    def normalize(self, form: UnicodeForm = "NFC") -> Series:
+        """
+        Returns the Unicode normal form of the string values.
+
+        This uses the forms described in Unicode Standard Annex 15:
+        <https://www.unicode.org/reports/tr15/>.
+
+        Parameters
+        ----------
+        form : {'NFC', 'NFKC', 'NFD', 'NFKD'}
+            Unicode form to use.
+
+        Examples
+        --------
+        >>> s = pl.Series(["01²", "ﬁne"])
+        >>> s.str.normalize("NFC")
+        shape: (2,)
+        Series: '' [str]
+        [
+            "01²"
+            "ﬁne"
+        ]
+        >>> s.str.normalize("NFKC")
+        shape: (2,)
+        Series: '' [str]
+        [
+            "012"
+            "fine"
+        ]
+        """
+
     def reverse(self) -> Series:
         """
         Returns string values in reversed order.
@@ -1604,6 +1681,78 @@ class StringNameSpace:
         ]
         """
 
+    def levenshtein(self, other: IntoExpr) -> Series:
+        """
+        Compute the Levenshtein edit distance to another string.
+
+        The distance is the minimum number of single-character insertions, deletions
+        and substitutions needed to turn one string into the other. Characters are
+        counted as Unicode scalar values, not bytes.
+
+        Parameters
+        ----------
+        other
+            The string(s) to compare with. Accepts expression input. Strings are
+            parsed as string literals, not as column names.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        See Also
+        --------
+        jaro_winkler
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["kitten", "flaw", None])
+        >>> s.str.levenshtein(pl.Series(["sitting", "lawn", "x"]))
+        shape: (3,)
+        Series: 'a' [u32]
+        [
+            3
+            2
+            null
+        ]
+        """
+
+    def jaro_winkler(self, other: IntoExpr) -> Series:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        The similarity lies between 0 (no characters in common) and 1 (equal
+        strings). A common prefix of up to four characters is weighted with the
+        standard scaling factor of 0.1.
+
+        Parameters
+        ----------
+        other
+            The string(s) to compare with. Accepts expression input. Strings are
+            parsed as string literals, not as column names.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float64`.
+
+        See Also
+        --------
+        levenshtein
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["MARTHA", "MARHTA", None])
+        >>> s.str.jaro_winkler("MARTHA")
+        shape: (3,)
+        Series: 'a' [f64]
+        [
+            1.0
+            0.961111
+            null
+        ]
+        """
+
     def slice(
         self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
     ) -> Series:
@@ -1632,6 +1781,10 @@ class StringNameSpace:
 
         .. _Unicode scalar value: https://www.unicode.org/glossary/#unicode_scalar_value
 
+        See Also
+        --------
+        slice_bytes : Slice using byte offsets.
+
         Examples
         --------
         >>> s = pl.Series(["pear", None, "papaya", "dragonfruit"])
@@ -1658,6 +1811,48 @@ class StringNameSpace:
         ]
         """
 
+    def slice_bytes(
+        self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
+    ) -> Series:
+        """
+        Extract a substring from each string value, using byte offsets.
+
+        Parameters
+        ----------
+        offset
+            Start index in bytes. Negative indexing is supported.
+        length
+            Length of the slice in bytes. If set to `None` (default), the slice is
+            taken to the end of the string.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Raises
+        ------
+        ComputeError
+            If a slice does not fall on character boundaries, i.e. if it would cut a
+            multibyte character.
+
+        See Also
+        --------
+        slice : Slice using character offsets.
+
+        Examples
+        --------
+        >>> s = pl.Series(["café", "naïve", None])
+        >>> s.str.slice_bytes(-2)
+        shape: (3,)
+        Series: '' [str]
+        [
+            "é"
+            "ve"
+            null
+        ]
+        """
+
     def head(self, n: int | IntoExprColumn) -> Series:
         """
         Return the first n characters of each string in a String Series.
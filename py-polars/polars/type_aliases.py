@@ -98,6 +98,7 @@ Label: TypeAlias = Literal["left", "right", "datapoint"]
 NonExistent: TypeAlias = Literal["raise", "null"]
 NullBehavior: TypeAlias = Literal["ignore", "drop"]
 NullStrategy: TypeAlias = Literal["ignore", "propagate"]
+OutOfBounds: TypeAlias = Literal["raise", "null", "wrap"]
 ParallelStrategy: TypeAlias = Literal["auto", "columns", "row_groups", "none"]
 ParquetCompression: TypeAlias = Literal[
     "lz4", "uncompressed", "snappy", "gzip", "lzo", "brotli", "zstd"
@@ -105,7 +106,10 @@ ParquetCompression: TypeAlias = Literal[
 PivotAgg: TypeAlias = Literal[
     "min", "max", "first", "last", "sum", "mean", "median", "len"
 ]
-RankMethod: TypeAlias = Literal["average", "min", "max", "dense", "ordinal", "random"]
+PlateauBehavior: TypeAlias = Literal["all", "first", "last", "none"]
+RankMethod: TypeAlias = Literal[
+    "average", "min", "max", "dense", "ordinal", "first", "random"
+]
 Roll: TypeAlias = Literal["raise", "forward", "backward"]
 SizeUnit: TypeAlias = Literal[
     "b",
@@ -131,6 +135,7 @@ StartBy: TypeAlias = Literal[
     "sunday",
 ]
 TimeUnit: TypeAlias = Literal["ns", "us", "ms"]
+UnicodeForm: TypeAlias = Literal["NFC", "NFKC", "NFD", "NFKD"]
 UniqueKeepStrategy: TypeAlias = Literal["first", "last", "any", "none"]
 UnstackDirection: TypeAlias = Literal["vertical", "horizontal"]
 MapElementsStrategy: TypeAlias = Literal["thread_local", "threading"]
@@ -748,12 +748,15 @@ impl FromPyObject<'_> for Wrap<Label> {
 
 impl FromPyObject<'_> for Wrap<ListToStructWidthStrategy> {
     fn extract(ob: &PyAny) -> PyResult<Self> {
+        if let Ok(width) = ob.extract::<usize>() {
+            return Ok(Wrap(ListToStructWidthStrategy::Fixed(width)));
+        }
         let parsed = match &*ob.extract::<PyBackedStr>()? {
             "first_non_null" => ListToStructWidthStrategy::FirstNonNull,
             "max_width" => ListToStructWidthStrategy::MaxWidth,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "`n_field_strategy` must be one of {{'first_non_null', 'max_width'}}, got {v}",
+                    "`n_field_strategy` must be one of {{'first_non_null', 'max_width'}} or an integer width, got {v}",
                 )))
             },
         };
@@ -864,11 +867,11 @@ impl FromPyObject<'_> for Wrap<RankMethod> {
             "max" => RankMethod::Max,
             "average" => RankMethod::Average,
             "dense" => RankMethod::Dense,
-            "ordinal" => RankMethod::Ordinal,
+            "ordinal" | "first" => RankMethod::Ordinal,
             "random" => RankMethod::Random,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "rank `method` must be one of {{'min', 'max', 'average', 'dense', 'ordinal', 'random'}}, got {v}",
+                    "rank `method` must be one of {{'min', 'max', 'average', 'dense', 'ordinal', 'first', 'random'}}, got {v}",
                 )))
             }
         };
@@ -876,6 +879,40 @@ impl FromPyObject<'_> for Wrap<RankMethod> {
     }
 }
 
+impl FromPyObject<'_> for Wrap<OutOfBounds> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "raise" => OutOfBounds::Raise,
+            "null" => OutOfBounds::Null,
+            "wrap" => OutOfBounds::Wrap,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`out_of_bounds` must be one of {{'raise', 'null', 'wrap'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+#[cfg(feature = "peaks")]
+impl FromPyObject<'_> for Wrap<PlateauBehavior> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "all" => PlateauBehavior::MarkAll,
+            "first" => PlateauBehavior::MarkFirst,
+            "last" => PlateauBehavior::MarkLast,
+            "none" => PlateauBehavior::MarkNone,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`plateau` must be one of {{'all', 'first', 'last', 'none'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl FromPyObject<'_> for Wrap<Roll> {
     fn extract(ob: &PyAny) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -908,6 +945,23 @@ impl FromPyObject<'_> for Wrap<TimeUnit> {
     }
 }
 
+impl FromPyObject<'_> for Wrap<UnicodeForm> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "NFC" => UnicodeForm::NFC,
+            "NFKC" => UnicodeForm::NFKC,
+            "NFD" => UnicodeForm::NFD,
+            "NFKD" => UnicodeForm::NFKD,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`form` must be one of {{'NFC', 'NFKC', 'NFD', 'NFKD'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl FromPyObject<'_> for Wrap<UniqueKeepStrategy> {
     fn extract(ob: &PyAny) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -20,6 +20,10 @@ impl PyExpr {
         self.inner.clone().arr().sum().into()
     }
 
+    fn arr_mean(&self) -> Self {
+        self.inner.clone().arr().mean().into()
+    }
+
     fn arr_std(&self, ddof: u8) -> Self {
         self.inner.clone().arr().std(ddof).into()
     }
@@ -20,6 +20,20 @@ impl PyExpr {
             .into()
     }
 
+    fn dt_offset_by_business(
+        &self,
+        by: PyExpr,
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+        roll: Wrap<Roll>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .offset_by_business(by.inner, week_mask, holidays, roll.0)
+            .into()
+    }
+
     fn dt_to_string(&self, format: &str) -> Self {
         self.inner.clone().dt().to_string(format).into()
     }
@@ -94,11 +108,17 @@ impl PyExpr {
         self.inner.clone().dt().round(every.inner, offset).into()
     }
 
-    fn dt_combine(&self, time: Self, time_unit: Wrap<TimeUnit>) -> Self {
+    #[pyo3(signature = (time, time_unit, time_zone))]
+    fn dt_combine(
+        &self,
+        time: Self,
+        time_unit: Wrap<TimeUnit>,
+        time_zone: Option<TimeZone>,
+    ) -> Self {
         self.inner
             .clone()
             .dt()
-            .combine(time.inner, time_unit.0)
+            .combine(time.inner, time_unit.0, time_zone)
             .into()
     }
     fn dt_millennium(&self) -> Self {
@@ -122,8 +142,8 @@ impl PyExpr {
     fn dt_month(&self) -> Self {
         self.inner.clone().dt().month().into()
     }
-    fn dt_week(&self) -> Self {
-        self.inner.clone().dt().week().into()
+    fn dt_week(&self, iso: bool) -> Self {
+        self.inner.clone().dt().week(iso).into()
     }
     fn dt_weekday(&self) -> Self {
         self.inner.clone().dt().weekday().into()
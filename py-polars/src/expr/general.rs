@@ -202,9 +202,14 @@ impl PyExpr {
         allow_duplicates: bool,
         include_breaks: bool,
     ) -> Self {
+        let duplicates = if allow_duplicates {
+            QuantileDuplicates::Drop
+        } else {
+            QuantileDuplicates::Raise
+        };
         self.inner
             .clone()
-            .qcut(probs, labels, left_closed, allow_duplicates, include_breaks)
+            .qcut(probs, labels, left_closed, duplicates, include_breaks)
             .into()
     }
     #[pyo3(signature = (n_bins, labels, left_closed, allow_duplicates, include_breaks))]
@@ -217,15 +222,14 @@ impl PyExpr {
         allow_duplicates: bool,
         include_breaks: bool,
     ) -> Self {
+        let duplicates = if allow_duplicates {
+            QuantileDuplicates::Drop
+        } else {
+            QuantileDuplicates::Raise
+        };
         self.inner
             .clone()
-            .qcut_uniform(
-                n_bins,
-                labels,
-                left_closed,
-                allow_duplicates,
-                include_breaks,
-            )
+            .qcut_uniform(n_bins, labels, left_closed, duplicates, include_breaks)
             .into()
     }
 
@@ -247,8 +251,11 @@ impl PyExpr {
     fn len(&self) -> Self {
         self.inner.clone().len().into()
     }
-    fn value_counts(&self, sort: bool, parallel: bool) -> Self {
-        self.inner.clone().value_counts(sort, parallel).into()
+    fn value_counts(&self, sort: bool, descending: bool, parallel: bool, normalize: bool) -> Self {
+        self.inner
+            .clone()
+            .value_counts(sort, descending, parallel, normalize)
+            .into()
     }
     fn unique_counts(&self) -> Self {
         self.inner.clone().unique_counts().into()
@@ -370,13 +377,21 @@ impl PyExpr {
     }
 
     #[cfg(feature = "peaks")]
-    fn peak_min(&self) -> Self {
-        self.inner.clone().peak_min().into()
+    fn peak_min(&self, plateau: Wrap<PlateauBehavior>, min_prominence: Option<f64>) -> Self {
+        let options = PeakOptions {
+            plateau: plateau.0,
+            min_prominence,
+        };
+        self.inner.clone().peak_min(options).into()
     }
 
     #[cfg(feature = "peaks")]
-    fn peak_max(&self) -> Self {
-        self.inner.clone().peak_max().into()
+    fn peak_max(&self, plateau: Wrap<PlateauBehavior>, min_prominence: Option<f64>) -> Self {
+        let options = PeakOptions {
+            plateau: plateau.0,
+            min_prominence,
+        };
+        self.inner.clone().peak_max(options).into()
     }
 
     fn arg_max(&self) -> Self {
@@ -394,8 +409,11 @@ impl PyExpr {
             .search_sorted(element.inner, side.0)
             .into()
     }
-    fn gather(&self, idx: Self) -> Self {
-        self.inner.clone().gather(idx.inner).into()
+    fn gather(&self, idx: Self, out_of_bounds: Wrap<OutOfBounds>) -> Self {
+        self.inner
+            .clone()
+            .gather_with(idx.inner, out_of_bounds.0)
+            .into()
     }
 
     fn get(&self, idx: Self) -> Self {
@@ -490,8 +508,11 @@ impl PyExpr {
             .into()
     }
 
-    fn approx_n_unique(&self) -> Self {
-        self.inner.clone().approx_n_unique().into()
+    fn approx_n_unique(&self, precision: u8) -> Self {
+        self.inner
+            .clone()
+            .approx_n_unique_with_precision(precision)
+            .into()
     }
 
     fn is_first_distinct(&self) -> Self {
@@ -696,8 +717,8 @@ impl PyExpr {
     }
 
     #[cfg(feature = "is_in")]
-    fn is_in(&self, expr: Self) -> Self {
-        self.inner.clone().is_in(expr.inner).into()
+    fn is_in(&self, expr: Self, nulls_equal: bool) -> Self {
+        self.inner.clone().is_in(expr.inner, nulls_equal).into()
     }
 
     #[cfg(feature = "repeat_by")]
@@ -709,6 +730,14 @@ impl PyExpr {
         self.inner.clone().pow(exponent.inner).into()
     }
 
+    #[cfg(feature = "optimizations")]
+    fn mul_add(&self, multiplier: Self, addend: Self) -> Self {
+        self.inner
+            .clone()
+            .mul_add(multiplier.inner, addend.inner)
+            .into()
+    }
+
     fn sqrt(&self) -> Self {
         self.inner.clone().sqrt().into()
     }
@@ -726,11 +755,11 @@ impl PyExpr {
     fn cum_min(&self, reverse: bool) -> Self {
         self.inner.clone().cum_min(reverse).into()
     }
-    fn cum_prod(&self, reverse: bool) -> Self {
-        self.inner.clone().cum_prod(reverse).into()
+    fn cum_prod(&self, reverse: bool, strict: bool) -> Self {
+        self.inner.clone().cum_prod(reverse, strict).into()
     }
-    fn cum_count(&self, reverse: bool) -> Self {
-        self.inner.clone().cum_count(reverse).into()
+    fn cum_count(&self, reverse: bool, count_nulls: bool) -> Self {
+        self.inner.clone().cum_count(reverse, count_nulls).into()
     }
 
     fn cumulative_eval(&self, expr: Self, min_periods: usize, parallel: bool) -> Self {
@@ -766,8 +795,14 @@ impl PyExpr {
     fn reinterpret(&self, signed: bool) -> Self {
         self.inner.clone().reinterpret(signed).into()
     }
-    fn mode(&self) -> Self {
-        self.inner.clone().mode().into()
+    fn mode(&self, maintain_order: bool, ignore_nulls: bool) -> Self {
+        self.inner
+            .clone()
+            .mode_with(maintain_order, ignore_nulls)
+            .into()
+    }
+    fn mode_first(&self, ignore_nulls: bool) -> Self {
+        self.inner.clone().mode_first(ignore_nulls).into()
     }
     fn exclude(&self, columns: Vec<String>) -> Self {
         self.inner.clone().exclude(columns).into()
@@ -776,8 +811,21 @@ impl PyExpr {
         let dtypes = vec_extract_wrapped(dtypes);
         self.inner.clone().exclude_dtype(&dtypes).into()
     }
-    fn interpolate(&self, method: Wrap<InterpolationMethod>) -> Self {
-        self.inner.clone().interpolate(method.0).into()
+    #[pyo3(signature = (method, max_gap=None))]
+    fn interpolate(&self, method: Wrap<InterpolationMethod>, max_gap: Option<IdxSize>) -> Self {
+        self.inner.clone().interpolate(method.0, max_gap).into()
+    }
+    #[pyo3(signature = (by, method, max_gap=None))]
+    fn interpolate_by(
+        &self,
+        by: PyExpr,
+        method: Wrap<InterpolationMethod>,
+        max_gap: Option<PyExpr>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .interpolate_by(by.inner, method.0, max_gap.map(|e| e.inner))
+            .into()
     }
 
     fn lower_bound(&self) -> Self {
@@ -788,10 +836,17 @@ impl PyExpr {
         self.inner.clone().upper_bound().into()
     }
 
-    fn rank(&self, method: Wrap<RankMethod>, descending: bool, seed: Option<u64>) -> Self {
+    fn rank(
+        &self,
+        method: Wrap<RankMethod>,
+        descending: bool,
+        nulls_last: Option<bool>,
+        seed: Option<u64>,
+    ) -> Self {
         let options = RankOptions {
             method: method.0,
             descending,
+            nulls_last,
         };
         self.inner.clone().rank(options, seed).into()
     }
@@ -800,9 +855,36 @@ impl PyExpr {
         self.inner.clone().diff(n, null_behavior.0).into()
     }
 
+    fn bit_count(&self) -> Self {
+        self.inner.clone().bit_count().into()
+    }
+    fn leading_zeros(&self) -> Self {
+        self.inner.clone().leading_zeros().into()
+    }
+    fn trailing_zeros(&self) -> Self {
+        self.inner.clone().trailing_zeros().into()
+    }
+    fn gcd(&self, other: Self) -> Self {
+        self.inner.clone().gcd(other.inner).into()
+    }
+
+    fn lcm(&self, other: Self, saturate: bool) -> Self {
+        self.inner.clone().lcm(other.inner, saturate).into()
+    }
+
+    fn is_close(&self, other: Self, rel_tol: f64, abs_tol: f64, nans_equal: bool) -> Self {
+        self.inner
+            .clone()
+            .is_close(other.inner, rel_tol, abs_tol, nans_equal)
+            .into()
+    }
+
     #[cfg(feature = "pct_change")]
-    fn pct_change(&self, n: Self) -> Self {
-        self.inner.clone().pct_change(n.inner).into()
+    fn pct_change(&self, n: Self, fill_null_strategy: Option<&str>) -> PyResult<Self> {
+        let fill_null = fill_null_strategy
+            .map(|strategy| parse_fill_null_strategy(strategy, None))
+            .transpose()?;
+        Ok(self.inner.clone().pct_change(n.inner, fill_null).into())
     }
 
     fn skew(&self, bias: bool) -> Self {
@@ -811,9 +893,16 @@ impl PyExpr {
     fn kurtosis(&self, fisher: bool, bias: bool) -> Self {
         self.inner.clone().kurtosis(fisher, bias).into()
     }
+    fn moment(&self, order: usize, central: bool, bias: bool) -> Self {
+        self.inner.clone().moment(order, central, bias).into()
+    }
 
-    fn reshape(&self, dims: Vec<i64>) -> Self {
-        self.inner.clone().reshape(&dims).into()
+    fn reshape(&self, dims: Vec<i64>, as_array: bool) -> Self {
+        if as_array {
+            self.inner.clone().reshape_array(&dims).into()
+        } else {
+            self.inner.clone().reshape(&dims).into()
+        }
     }
 
     fn to_physical(&self) -> Self {
@@ -899,11 +988,13 @@ impl PyExpr {
         };
         self.inner.clone().ewm_var(options).into()
     }
-    fn extend_constant(&self, value: PyExpr, n: PyExpr) -> Self {
-        self.inner
-            .clone()
-            .extend_constant(value.inner, n.inner)
-            .into()
+    fn extend_constant(&self, value: PyExpr, n: PyExpr, prepend: bool) -> Self {
+        let expr = self.inner.clone();
+        if prepend {
+            expr.prepend_constant(value.inner, n.inner).into()
+        } else {
+            expr.extend_constant(value.inner, n.inner).into()
+        }
     }
 
     fn any(&self, ignore_nulls: bool) -> Self {
@@ -917,6 +1008,10 @@ impl PyExpr {
         self.inner.clone().log(base).into()
     }
 
+    fn log_with_base(&self, base: Self) -> Self {
+        self.inner.clone().log_with_base(base.inner).into()
+    }
+
     fn log1p(&self) -> Self {
         self.inner.clone().log1p().into()
     }
@@ -940,6 +1035,44 @@ impl PyExpr {
         self.inner.clone().set_sorted_flag(is_sorted).into()
     }
 
+    fn replace_strict(
+        &self,
+        old: PyExpr,
+        new: PyExpr,
+        default: Option<PyExpr>,
+        return_dtype: Option<Wrap<DataType>>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .replace_strict(
+                old.inner,
+                new.inner,
+                default.map(|e| e.inner),
+                return_dtype.map(|dt| dt.0),
+            )
+            .into()
+    }
+
+    fn replace_ranges(
+        &self,
+        breaks: PyExpr,
+        labels: PyExpr,
+        default: Option<PyExpr>,
+        left_closed: bool,
+        return_dtype: Option<Wrap<DataType>>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .replace_ranges(
+                breaks.inner,
+                labels.inner,
+                default.map(|e| e.inner),
+                left_closed,
+                return_dtype.map(|dt| dt.0),
+            )
+            .into()
+    }
+
     fn replace(
         &self,
         old: PyExpr,
@@ -959,18 +1092,25 @@ impl PyExpr {
     }
 
     #[cfg(feature = "hist")]
-    #[pyo3(signature = (bins, bin_count, include_category, include_breakpoint))]
+    #[pyo3(signature = (bins, bin_count, include_category, include_breakpoint, density))]
     fn hist(
         &self,
         bins: Option<PyExpr>,
         bin_count: Option<usize>,
         include_category: bool,
         include_breakpoint: bool,
+        density: bool,
     ) -> Self {
         let bins = bins.map(|e| e.inner);
         self.inner
             .clone()
-            .hist(bins, bin_count, include_category, include_breakpoint)
+            .hist(
+                bins,
+                bin_count,
+                include_category,
+                include_breakpoint,
+                density,
+            )
             .into()
     }
 }
@@ -116,6 +116,10 @@ impl PyExpr {
         self.inner.clone().list().shift(periods.inner).into()
     }
 
+    fn list_filter(&self, mask: PyExpr) -> Self {
+        self.inner.clone().list().filter(mask.inner).into()
+    }
+
     fn list_slice(&self, offset: PyExpr, length: Option<PyExpr>) -> Self {
         let length = match length {
             Some(i) => i.inner,
@@ -128,14 +132,22 @@ impl PyExpr {
         self.inner.clone().list().tail(n.inner).into()
     }
 
-    fn list_sort(&self, descending: bool, nulls_last: bool) -> Self {
+    fn list_sort(
+        &self,
+        descending: bool,
+        nulls_last: bool,
+        multithreaded: bool,
+        maintain_order: bool,
+    ) -> Self {
         self.inner
             .clone()
             .list()
             .sort(
                 SortOptions::default()
                     .with_order_descending(descending)
-                    .with_nulls_last(nulls_last),
+                    .with_nulls_last(nulls_last)
+                    .with_multithreaded(multithreaded)
+                    .with_maintain_order(maintain_order),
             )
             .into()
     }
@@ -156,11 +168,12 @@ impl PyExpr {
         with_replacement: bool,
         shuffle: bool,
         seed: Option<u64>,
+        clamp: bool,
     ) -> Self {
         self.inner
             .clone()
             .list()
-            .sample_n(n.inner, with_replacement, shuffle, seed)
+            .sample_n(n.inner, with_replacement, shuffle, seed, clamp)
             .into()
     }
 
@@ -171,11 +184,12 @@ impl PyExpr {
         with_replacement: bool,
         shuffle: bool,
         seed: Option<u64>,
+        clamp: bool,
     ) -> Self {
         self.inner
             .clone()
             .list()
-            .sample_fraction(fraction.inner, with_replacement, shuffle, seed)
+            .sample_fraction(fraction.inner, with_replacement, shuffle, seed, clamp)
             .into()
     }
 
@@ -201,12 +215,13 @@ impl PyExpr {
         self.inner.clone().list().to_array(width).into()
     }
 
-    #[pyo3(signature = (width_strat, name_gen, upper_bound))]
+    #[pyo3(signature = (width_strat, name_gen, upper_bound, names=None))]
     fn list_to_struct(
         &self,
         width_strat: Wrap<ListToStructWidthStrategy>,
         name_gen: Option<PyObject>,
         upper_bound: usize,
+        names: Option<Vec<String>>,
     ) -> PyResult<Self> {
         let name_gen = name_gen.map(|lambda| {
             Arc::new(move |idx: usize| {
@@ -217,6 +232,7 @@ impl PyExpr {
                 })
             }) as NameGenerator
         });
+        let name_gen = name_gen.or_else(|| names.map(names_struct_name_gen));
 
         Ok(self
             .inner
@@ -213,8 +213,26 @@ impl PyExpr {
             .into()
     }
 
-    fn rolling_skew(&self, window_size: usize, bias: bool) -> Self {
-        self.inner.clone().rolling_skew(window_size, bias).into()
+    #[pyo3(signature = (window_size, bias, min_periods))]
+    fn rolling_skew(&self, window_size: usize, bias: bool, min_periods: Option<usize>) -> Self {
+        self.inner
+            .clone()
+            .rolling_skew(window_size, min_periods, bias)
+            .into()
+    }
+
+    #[pyo3(signature = (window_size, fisher, bias, min_periods))]
+    fn rolling_kurtosis(
+        &self,
+        window_size: usize,
+        fisher: bool,
+        bias: bool,
+        min_periods: Option<usize>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .rolling_kurtosis(window_size, min_periods, fisher, bias)
+            .into()
     }
 
     #[pyo3(signature = (lambda, window_size, weights, min_periods, center))]
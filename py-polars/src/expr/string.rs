@@ -102,6 +102,14 @@ impl PyExpr {
             .into()
     }
 
+    fn str_slice_bytes(&self, offset: Self, length: Self) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .slice_bytes(offset.inner, length.inner)
+            .into()
+    }
+
     fn str_head(&self, n: Self) -> Self {
         self.inner.clone().str().head(n.inner).into()
     }
@@ -122,7 +130,6 @@ impl PyExpr {
         self.inner.clone().str().to_lowercase().into()
     }
 
-    #[cfg(feature = "nightly")]
     fn str_to_titlecase(&self) -> Self {
         self.inner.clone().str().to_titlecase().into()
     }
@@ -153,10 +160,22 @@ impl PyExpr {
             .into()
     }
 
+    fn str_normalize(&self, form: Wrap<UnicodeForm>) -> Self {
+        self.inner.clone().str().normalize(form.0).into()
+    }
+
     fn str_reverse(&self) -> Self {
         self.inner.clone().str().reverse().into()
     }
 
+    fn str_levenshtein(&self, other: Self) -> Self {
+        self.inner.clone().str().levenshtein(other.inner).into()
+    }
+
+    fn str_jaro_winkler(&self, other: Self) -> Self {
+        self.inner.clone().str().jaro_winkler(other.inner).into()
+    }
+
     fn str_pad_start(&self, length: usize, fill_char: char) -> Self {
         self.inner.clone().str().pad_start(length, fill_char).into()
     }
@@ -191,6 +210,11 @@ impl PyExpr {
         self.inner.clone().str().ends_with(sub.inner).into()
     }
 
+    #[cfg(feature = "regex")]
+    fn str_escape_regex(&self) -> Self {
+        self.inner.clone().str().escape_regex().into()
+    }
+
     fn str_starts_with(&self, sub: Self) -> Self {
         self.inner.clone().str().starts_with(sub.inner).into()
     }
@@ -280,6 +304,15 @@ impl PyExpr {
         self.inner.clone().str().split_inclusive(by.inner).into()
     }
 
+    #[cfg(feature = "regex")]
+    fn str_split_regex(&self, by: Self, inclusive: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .split_regex(by.inner, inclusive)
+            .into()
+    }
+
     fn str_split_exact(&self, by: Self, n: usize) -> Self {
         self.inner.clone().str().split_exact(by.inner, n).into()
     }
@@ -46,3 +46,24 @@ pub fn mean_horizontal(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
     let e = dsl::mean_horizontal(exprs).map_err(PyPolarsErr::from)?;
     Ok(e.into())
 }
+
+#[pyfunction]
+pub fn arr_max_horizontal(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let e = dsl::arr_max_horizontal(exprs).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
+
+#[pyfunction]
+pub fn arr_min_horizontal(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let e = dsl::arr_min_horizontal(exprs).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
+
+#[pyfunction]
+pub fn arr_sum_horizontal(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let e = dsl::arr_sum_horizontal(exprs).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
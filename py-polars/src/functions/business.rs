@@ -1,4 +1,5 @@
 use polars::lazy::dsl;
+use polars::prelude::ClosedInterval;
 use pyo3::prelude::*;
 
 use crate::PyExpr;
@@ -12,5 +13,25 @@ pub fn business_day_count(
 ) -> PyExpr {
     let start = start.inner;
     let end = end.inner;
-    dsl::business_day_count(start, end, week_mask, holidays).into()
+    dsl::business_day_count(start, end, week_mask, holidays, ClosedInterval::Left, None).into()
+}
+
+#[pyfunction]
+pub fn networkdays(start: PyExpr, end: PyExpr, week_mask: [bool; 7], holidays: Vec<i32>) -> PyExpr {
+    let start = start.inner;
+    let end = end.inner;
+    dsl::networkdays(start, end, week_mask, holidays).into()
+}
+
+#[pyfunction]
+pub fn business_date_range(
+    start: PyExpr,
+    end: PyExpr,
+    interval: i32,
+    week_mask: [bool; 7],
+    holidays: Vec<i32>,
+) -> PyExpr {
+    let start = start.inner;
+    let end = end.inner;
+    dsl::business_date_range(start, end, interval, week_mask, holidays).into()
 }
@@ -76,6 +76,18 @@ pub fn arg_sort_by(
     )
     .into()
 }
+
+#[pyfunction]
+pub fn arg_sort_by_keys(
+    by: Vec<PyExpr>,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+    multithreaded: bool,
+) -> PyExpr {
+    let by = by.into_iter().map(|e| e.inner).collect::<Vec<Expr>>();
+    dsl::arg_sort_by_keys(by, descending, nulls_last, multithreaded).into()
+}
+
 #[pyfunction]
 pub fn arg_where(condition: PyExpr) -> PyExpr {
     dsl::arg_where(condition.inner).into()
@@ -441,6 +441,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
             expr,
             idx,
             returns_scalar,
+            ..
         } => Gather {
             expr: expr.0,
             idx: idx.0,
@@ -667,9 +668,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     RollingFunction::StdBy(_) => {
                         return Err(PyNotImplementedError::new_err("rolling std by"))
                     },
-                    RollingFunction::Skew(_, _) => {
+                    RollingFunction::Skew(..) => {
                         return Err(PyNotImplementedError::new_err("rolling skew"))
                     },
+                    RollingFunction::Kurtosis(..) => {
+                        return Err(PyNotImplementedError::new_err("rolling kurtosis"))
+                    },
                 },
                 FunctionExpr::ShiftAndFill => {
                     return Err(PyNotImplementedError::new_err("shift and fill"))
@@ -677,12 +681,18 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Shift => ("shift",).to_object(py),
                 FunctionExpr::DropNans => ("dropnan",).to_object(py),
                 FunctionExpr::DropNulls => ("dropnull",).to_object(py),
-                FunctionExpr::Mode => ("mode",).to_object(py),
+                FunctionExpr::Mode { .. } => ("mode",).to_object(py),
+                FunctionExpr::ModeFirst { .. } => {
+                    return Err(PyNotImplementedError::new_err("mode_first"))
+                },
                 FunctionExpr::Skew(_) => return Err(PyNotImplementedError::new_err("skew")),
                 FunctionExpr::Kurtosis(_, _) => {
                     return Err(PyNotImplementedError::new_err("kurtosis"))
                 },
                 FunctionExpr::Reshape(_) => return Err(PyNotImplementedError::new_err("reshape")),
+                FunctionExpr::ReshapeArray(_) => {
+                    return Err(PyNotImplementedError::new_err("reshape"))
+                },
                 FunctionExpr::RepeatBy => return Err(PyNotImplementedError::new_err("repeat by")),
                 FunctionExpr::ArgUnique => ("argunique",).to_object(py),
                 FunctionExpr::Rank {
@@ -697,20 +707,22 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::TopK { sort_options: _ } => {
                     return Err(PyNotImplementedError::new_err("top k"))
                 },
-                FunctionExpr::CumCount { reverse } => ("cumcount", reverse).to_object(py),
+                FunctionExpr::CumCount {
+                    reverse,
+                    count_nulls,
+                } => ("cumcount", reverse, count_nulls).to_object(py),
                 FunctionExpr::CumSum { reverse } => ("cumsum", reverse).to_object(py),
-                FunctionExpr::CumProd { reverse } => ("cumprod", reverse).to_object(py),
+                FunctionExpr::CumProd { reverse, .. } => ("cumprod", reverse).to_object(py),
                 FunctionExpr::CumMin { reverse } => ("cummin", reverse).to_object(py),
                 FunctionExpr::CumMax { reverse } => ("cummax", reverse).to_object(py),
                 FunctionExpr::Reverse => return Err(PyNotImplementedError::new_err("reverse")),
-                FunctionExpr::ValueCounts {
-                    sort: _,
-                    parallel: _,
-                } => return Err(PyNotImplementedError::new_err("value counts")),
+                FunctionExpr::ValueCounts { .. } => {
+                    return Err(PyNotImplementedError::new_err("value counts"))
+                },
                 FunctionExpr::UniqueCounts => {
                     return Err(PyNotImplementedError::new_err("unique counts"))
                 },
-                FunctionExpr::ApproxNUnique => {
+                FunctionExpr::ApproxNUnique { .. } => {
                     return Err(PyNotImplementedError::new_err("approx nunique"))
                 },
                 FunctionExpr::Coalesce => return Err(PyNotImplementedError::new_err("coalesce")),
@@ -718,17 +730,31 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     return Err(PyNotImplementedError::new_err("shrink type"))
                 },
                 FunctionExpr::Diff(_, _) => return Err(PyNotImplementedError::new_err("diff")),
-                FunctionExpr::PctChange => {
+                FunctionExpr::PctChange(_) => {
                     return Err(PyNotImplementedError::new_err("pct change"))
                 },
-                FunctionExpr::Interpolate(_) => {
+                FunctionExpr::Interpolate { .. } => {
                     return Err(PyNotImplementedError::new_err("interpolate"))
                 },
+                FunctionExpr::BitCount => ("bit_count",).to_object(py),
+                FunctionExpr::LeadingZeros => ("leading_zeros",).to_object(py),
+                FunctionExpr::TrailingZeros => ("trailing_zeros",).to_object(py),
+                FunctionExpr::Gcd => return Err(PyNotImplementedError::new_err("gcd")),
+                FunctionExpr::Lcm { saturate: _ } => {
+                    return Err(PyNotImplementedError::new_err("lcm"))
+                },
+                FunctionExpr::IsClose { .. } => {
+                    return Err(PyNotImplementedError::new_err("is_close"))
+                },
+                FunctionExpr::InterpolateBy(_) => {
+                    return Err(PyNotImplementedError::new_err("interpolate_by"))
+                },
                 FunctionExpr::Entropy {
                     base: _,
                     normalize: _,
                 } => return Err(PyNotImplementedError::new_err("entropy")),
                 FunctionExpr::Log { base: _ } => return Err(PyNotImplementedError::new_err("log")),
+                FunctionExpr::LogWithBase => return Err(PyNotImplementedError::new_err("log")),
                 FunctionExpr::Log1p => return Err(PyNotImplementedError::new_err("log1p")),
                 FunctionExpr::Exp => return Err(PyNotImplementedError::new_err("exp")),
                 FunctionExpr::Unique(_) => return Err(PyNotImplementedError::new_err("unique")),
@@ -753,8 +779,8 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Correlation { .. } => {
                     return Err(PyNotImplementedError::new_err("corr"))
                 },
-                FunctionExpr::PeakMin => return Err(PyNotImplementedError::new_err("peak min")),
-                FunctionExpr::PeakMax => return Err(PyNotImplementedError::new_err("peak max")),
+                FunctionExpr::PeakMin(_) => return Err(PyNotImplementedError::new_err("peak min")),
+                FunctionExpr::PeakMax(_) => return Err(PyNotImplementedError::new_err("peak max")),
                 FunctionExpr::Cut { .. } => return Err(PyNotImplementedError::new_err("cut")),
                 FunctionExpr::QCut { .. } => return Err(PyNotImplementedError::new_err("qcut")),
                 FunctionExpr::RLE => return Err(PyNotImplementedError::new_err("rle")),
@@ -807,6 +833,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Replace { return_dtype: _ } => {
                     return Err(PyNotImplementedError::new_err("replace"))
                 },
+                FunctionExpr::ReplaceStrict { return_dtype: _ } => {
+                    return Err(PyNotImplementedError::new_err("replace_strict"))
+                },
                 FunctionExpr::Negate => return Err(PyNotImplementedError::new_err("negate")),
                 FunctionExpr::FillNullWithStrategy(_) => {
                     return Err(PyNotImplementedError::new_err("fill null with strategy"))
@@ -817,9 +846,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Reinterpret(_) => {
                     return Err(PyNotImplementedError::new_err("reinterpret"))
                 },
-                FunctionExpr::ExtendConstant => {
+                FunctionExpr::ExtendConstant { .. } => {
                     return Err(PyNotImplementedError::new_err("extend constant"))
                 },
+                FunctionExpr::ArgSortBy { .. } => {
+                    return Err(PyNotImplementedError::new_err("arg_sort_by"))
+                },
                 FunctionExpr::Business(_) => {
                     return Err(PyNotImplementedError::new_err("business"))
                 },
@@ -200,6 +200,10 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     // Functions - business
     m.add_wrapped(wrap_pyfunction!(functions::business_day_count))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::networkdays))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::business_date_range))
+        .unwrap();
 
     // Functions - aggregation
     m.add_wrapped(wrap_pyfunction!(functions::all_horizontal))
@@ -214,10 +218,18 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::mean_horizontal))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::arr_max_horizontal))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::arr_min_horizontal))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::arr_sum_horizontal))
+        .unwrap();
 
     // Functions - lazy
     m.add_wrapped(wrap_pyfunction!(functions::arg_sort_by))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::arg_sort_by_keys))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::arg_where))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::as_struct))
@@ -583,11 +583,16 @@ impl PySeries {
         Ok(s.into())
     }
 
-    #[pyo3(signature = (separator, drop_first=false))]
-    fn to_dummies(&self, separator: Option<&str>, drop_first: bool) -> PyResult<PyDataFrame> {
+    #[pyo3(signature = (separator, drop_first=false, drop_nulls=false))]
+    fn to_dummies(
+        &self,
+        separator: Option<&str>,
+        drop_first: bool,
+        drop_nulls: bool,
+    ) -> PyResult<PyDataFrame> {
         let df = self
             .series
-            .to_dummies(separator, drop_first)
+            .to_dummies(separator, drop_first, drop_nulls)
             .map_err(PyPolarsErr::from)?;
         Ok(df.into())
     }
@@ -730,10 +735,16 @@ impl PySeries {
         self.series.tail(Some(n)).into()
     }
 
-    fn value_counts(&self, sort: bool, parallel: bool) -> PyResult<PyDataFrame> {
+    fn value_counts(
+        &self,
+        sort: bool,
+        descending: bool,
+        parallel: bool,
+        normalize: bool,
+    ) -> PyResult<PyDataFrame> {
         let out = self
             .series
-            .value_counts(sort, parallel)
+            .value_counts(sort, descending, parallel, normalize)
             .map_err(PyPolarsErr::from)?;
         Ok(out.into())
     }
@@ -1451,6 +1451,23 @@ def test_reproducible_hash_with_seeds() -> None:
         assert_series_equal(expected, result, check_names=False, check_exact=True)
 
 
+
+def test_hash_seeds() -> None:
+    s = pl.Series("s", [1234, None, 5678])
+    result = s.hash(1, 2, 3, 4)
+    assert result.dtype == pl.UInt64
+    assert result.null_count() == 0
+    assert_series_equal(result, s.hash(1, 2, 3, 4))
+    assert_series_equal(
+        pl.select(pl.lit(s).hash(1, 2, 3, 4)).to_series(), result, check_names=False
+    )
+
+    # Every seed word takes part in the hash.
+    for seeds in [(0, 2, 3, 4), (1, 0, 3, 4), (1, 2, 0, 4), (1, 2, 3, 0)]:
+        assert (s.hash(*seeds) != result).all()
+    # Unset seed words default to the first one.
+    assert_series_equal(s.hash(7), s.hash(7, 7, 7, 7))
+
 @pytest.mark.slow()
 @pytest.mark.parametrize(
     "e",
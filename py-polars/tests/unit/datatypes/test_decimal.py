@@ -393,6 +393,27 @@ def test_decimal_unique() -> None:
     }
 
 
+def test_decimal_abs_round_clip() -> None:
+    s = pl.Series("a", [D("1.235"), D("-1.235"), None, D("-0.004")])
+    dtype = pl.Decimal(precision=None, scale=3)
+    assert s.dtype == dtype
+
+    result = s.abs()
+    assert result.dtype == dtype
+    assert result.to_list() == [D("1.235"), D("1.235"), None, D("0.004")]
+
+    result = s.round(2)
+    assert result.dtype == dtype
+    assert result.to_list() == [D("1.240"), D("-1.240"), None, D("0.000")]
+
+    result = s.clip(D("-1.2"), D("0.5"))
+    assert result.dtype == dtype
+    assert result.to_list() == [D("0.500"), D("-1.200"), None, D("-0.004")]
+
+    result = s.clip(lower_bound=0)
+    assert result.to_list() == [D("1.235"), D("0.000"), None, D("0.000")]
+
+
 def test_decimal_write_parquet_12375() -> None:
     df = pl.DataFrame(
         {
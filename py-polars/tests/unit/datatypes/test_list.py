@@ -773,6 +773,43 @@ def test_list_median(data_dispersion: pl.DataFrame) -> None:
     assert_frame_equal(result, expected)
 
 
+@pytest.mark.parametrize("dtype", [pl.Int32, pl.UInt8, pl.Float32, pl.Float64])
+def test_list_dispersion_edge_cases(dtype: pl.PolarsDataType) -> None:
+    s = pl.Series("a", [[3, 1, 2, 4], [], [5], None, [None, None], [1, None, 3]])
+    s = s.cast(pl.List(dtype))
+    out_dtype = pl.Float32 if dtype == pl.Float32 else pl.Float64
+
+    assert_series_equal(
+        s.list.median(),
+        pl.Series("a", [2.5, None, 5.0, None, None, 2.0], dtype=out_dtype),
+    )
+    assert_series_equal(
+        s.list.var(),
+        pl.Series("a", [5 / 3, None, None, None, None, 2.0], dtype=out_dtype),
+    )
+    assert_series_equal(
+        s.list.var(ddof=0),
+        pl.Series("a", [1.25, None, 0.0, None, None, 1.0], dtype=out_dtype),
+    )
+    assert_series_equal(
+        s.list.std(),
+        pl.Series(
+            "a", [(5 / 3) ** 0.5, None, None, None, None, 2.0**0.5], dtype=out_dtype
+        ),
+    )
+
+    # Without inner nulls a faster path is taken, which must agree.
+    s = s.list.drop_nulls()
+    assert_series_equal(
+        s.list.median(),
+        pl.Series("a", [2.5, None, 5.0, None, None, 2.0], dtype=out_dtype),
+    )
+    assert_series_equal(
+        s.list.var(),
+        pl.Series("a", [5 / 3, None, None, None, None, 2.0], dtype=out_dtype),
+    )
+
+
 def test_list_gather_null_struct_14927() -> None:
     df = pl.DataFrame(
         [
@@ -1584,6 +1584,55 @@ def test_iso_year() -> None:
     assert pl.Series([date(2022, 1, 1)]).dt.iso_year()[0] == 2021
 
 
+def test_iso_year_and_quarter_boundaries() -> None:
+    dates = [date(2020, 12, 31), date(2024, 12, 30), date(2027, 1, 1), None]
+    s = pl.Series(dates)
+    assert s.dt.iso_year().to_list() == [2020, 2025, 2026, None]
+    assert s.dt.quarter().to_list() == [4, 4, 1, None]
+    assert_series_equal(s.cast(pl.Datetime("ms")).dt.iso_year(), s.dt.iso_year())
+
+
+def test_iso_year_time_zone() -> None:
+    # 2021-01-03 20:00 UTC is Monday 2021-01-04 in Tokyo, in ISO week 1 of 2021
+    s = pl.Series([datetime(2021, 1, 3, 20)]).dt.replace_time_zone("UTC")
+    s = s.dt.convert_time_zone("Asia/Tokyo")
+    assert s.dt.week().item() == 1
+    assert s.dt.iso_year().item() == 2021
+
+
+def test_week_iso_and_calendar() -> None:
+    dates = [
+        date(2020, 12, 31),
+        date(2021, 1, 3),
+        date(2021, 1, 4),
+        date(2024, 12, 30),
+        date(2012, 12, 31),
+        None,
+    ]
+    s = pl.Series(dates)
+    iso = pl.DataFrame({"iso_year": s.dt.iso_year(), "week": s.dt.week()})
+    assert iso.rows() == [
+        (2020, 53),
+        (2020, 53),
+        (2021, 1),
+        (2025, 1),
+        (2013, 1),
+        (None, None),
+    ]
+    # the ISO (year, week) pairs agree with Python's `isocalendar`
+    assert iso.rows()[:-1] == [d.isocalendar()[:2] for d in dates if d is not None]
+
+    result = s.dt.week(iso=False)
+    assert result.dtype == pl.Int8
+    assert result.to_list() == [53, 1, 2, 53, 54, None]
+
+    assert_series_equal(s.cast(pl.Datetime("ms")).dt.week(iso=False), result)
+    assert_series_equal(
+        pl.DataFrame({"a": s}).select(pl.col("a").dt.week(iso=False)).to_series(),
+        result.alias("a"),
+    )
+
+
 def test_replace_time_zone() -> None:
     ny = ZoneInfo("America/New_York")
     assert pl.DataFrame({"a": [datetime(2022, 9, 25, 14)]}).with_columns(
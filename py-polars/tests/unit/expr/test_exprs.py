@@ -139,6 +139,24 @@ def test_entropy() -> None:
     assert_frame_equal(result, expected)
 
 
+def test_entropy_zero_and_null_probabilities() -> None:
+    s = pl.Series([0.5, 0.0, None, 0.5])
+    assert s.entropy(base=2) == 1.0
+    assert s.entropy(base=2, normalize=True) == 1.0
+    assert pl.Series([0.0, 0.0]).entropy() == 0.0
+
+    # A single probability isn't normalized away unless asked for.
+    assert pl.Series([0.5]).entropy(base=2) == 0.5
+    assert pl.Series([0.5]).entropy(base=2, normalize=True) == 0.0
+
+
+def test_entropy_of_value_distribution() -> None:
+    s = pl.Series("a", ["x", "x", "y", "z", "x", "y", "w", "w"])
+    assert s.unique_counts().entropy(base=2, normalize=True) == pytest.approx(
+        1.9056390622295665
+    )
+
+
 def test_dot_in_group_by() -> None:
     df = pl.DataFrame(
         {
@@ -383,6 +401,29 @@ def test_rank_string_null_11252() -> None:
     assert rank.to_list() == [None, 1.0, 3.0, None, 2.0]
 
 
+def test_rank_ties_and_nulls_last() -> None:
+    df = pl.DataFrame({"a": [2, None, 1, 2, None, 1, 3]})
+
+    out = df.select(
+        pl.col("a").rank("dense").alias("dense"),
+        pl.col("a").rank("first", nulls_last=True).alias("first"),
+        pl.col("a").rank("ordinal", descending=True, nulls_last=True).alias("desc"),
+        pl.col("a").rank("min", nulls_last=True).alias("min"),
+        pl.col("a").rank("max", descending=True, nulls_last=False).alias("max"),
+        pl.col("a").rank("average", nulls_last=False).alias("average"),
+    )
+    assert out.to_dict(as_series=False) == {
+        "dense": [2, None, 1, 2, None, 1, 3],
+        "first": [3, 6, 1, 4, 7, 2, 5],
+        "desc": [2, 6, 4, 3, 7, 5, 1],
+        "min": [3, 6, 1, 3, 6, 1, 5],
+        "max": [5, 2, 7, 5, 2, 7, 3],
+        "average": [5.5, 1.5, 3.5, 5.5, 1.5, 3.5, 7.0],
+    }
+    assert out.schema["first"] == pl.get_index_type()
+    assert out.schema["average"] == pl.Float64
+
+
 def test_search_sorted() -> None:
     for seed in [1, 2, 3]:
         np.random.seed(seed)
@@ -409,6 +450,16 @@ def test_search_sorted() -> None:
     assert a.search_sorted(b, side="right").to_list() == [0, 2, 2, 4, 4]
 
 
+@pytest.mark.parametrize("side", ["left", "right"])
+def test_search_sorted_side_numpy_parity(side: Any) -> None:
+    np.random.seed(0)
+    arr = np.sort(np.random.randint(0, 10, 100))
+    needles = np.arange(-1, 12)
+
+    out = pl.Series(arr).search_sorted(pl.Series(needles), side=side)
+    assert out.to_list() == np.searchsorted(arr, needles, side=side).tolist()
+
+
 def test_logical_boolean() -> None:
     # note, cannot use expressions in logical
     # boolean context (eg: and/or/not operators)
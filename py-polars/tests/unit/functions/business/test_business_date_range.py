@@ -0,0 +1,66 @@
+from datetime import date, datetime
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_series_equal
+
+
+def test_business_date_range() -> None:
+    holidays = [date(2024, 1, 9)]
+    result = pl.business_date_range(
+        date(2024, 1, 6), date(2024, 1, 16), holidays=holidays, eager=True
+    )
+    expected = pl.Series(
+        "literal",
+        [date(2024, 1, d) for d in (8, 10, 11, 12, 15, 16)],
+        pl.Date,
+    )
+    assert_series_equal(result, expected)
+
+    result = pl.business_date_range(
+        date(2024, 1, 6), date(2024, 1, 16), 2, holidays=holidays, eager=True
+    )
+    assert result.to_list() == [date(2024, 1, d) for d in (8, 11, 15)]
+
+    week_mask = (True, False, True, False, True, False, False)
+    result = pl.business_date_range(
+        date(2024, 1, 1), date(2024, 1, 7), week_mask=week_mask, eager=True
+    )
+    assert result.to_list() == [date(2024, 1, 1), date(2024, 1, 3), date(2024, 1, 5)]
+
+
+def test_business_date_range_expr() -> None:
+    df = pl.DataFrame(
+        {
+            "start": [datetime(2024, 1, 5, 12)],
+            "end": [date(2024, 1, 9)],
+        }
+    )
+    result = df.select(
+        pl.business_date_range(pl.col("start").dt.date(), "end").alias("dates")
+    )
+    expected = pl.Series(
+        "dates", [date(2024, 1, 5), date(2024, 1, 8), date(2024, 1, 9)], pl.Date
+    )
+    assert_series_equal(result.to_series(), expected)
+
+
+def test_business_date_range_empty() -> None:
+    result = pl.business_date_range(date(2024, 1, 6), date(2024, 1, 7), eager=True)
+    assert result.dtype == pl.Date
+    assert result.len() == 0
+
+    result = pl.business_date_range(date(2024, 1, 9), date(2024, 1, 1), eager=True)
+    assert result.len() == 0
+
+
+def test_business_date_range_invalid() -> None:
+    with pytest.raises(pl.ComputeError, match="positive number of business days"):
+        pl.business_date_range(date(2024, 1, 1), date(2024, 1, 9), 0, eager=True)
+    with pytest.raises(pl.ComputeError, match="exactly one value"):
+        pl.business_date_range(
+            pl.Series([date(2024, 1, 1), date(2024, 1, 2)]),
+            date(2024, 1, 9),
+            eager=True,
+        )
@@ -86,7 +86,8 @@ def test_business_day_count_w_week_mask_invalid() -> None:
         }
     )
     with pytest.raises(
-        pl.ComputeError, match="`week_mask` must have at least one business day"
+        pl.ComputeError,
+        match=r"`week_mask` must have at least one business day, got \[false, false",
     ):
         df.select(pl.business_day_count("start", "end", week_mask=[False] * 7))
 
@@ -120,3 +121,35 @@ def test_business_day_count_w_holidays() -> None:
     )["business_day_count"]
     expected = pl.Series("business_day_count", [0, 5, 5], pl.Int32)
     assert_series_equal(result, expected)
+
+
+def test_networkdays() -> None:
+    # expected values are Excel's `NETWORKDAYS` with the same arguments
+    holidays = [date(2024, 1, 1), date(2024, 3, 29), date(2024, 4, 1)]
+    df = pl.DataFrame(
+        {
+            "start": [
+                date(2024, 1, 1),
+                date(2024, 3, 25),
+                date(2024, 4, 5),
+                date(2024, 1, 1),
+                date(2024, 1, 6),
+                date(2024, 1, 1),
+            ],
+            "end": [
+                date(2024, 1, 31),
+                date(2024, 4, 5),
+                date(2024, 3, 25),
+                date(2024, 1, 1),
+                date(2024, 1, 7),
+                date(2024, 12, 31),
+            ],
+        }
+    )
+    result = df.select(pl.networkdays("start", "end", holidays=holidays))["start"]
+    expected = pl.Series("start", [22, 8, -8, 0, 0, 259], pl.Int32)
+    assert_series_equal(result, expected)
+
+    result = df.select(pl.networkdays("start", "end"))["start"]
+    expected = pl.Series("start", [23, 10, -10, 1, 0, 262], pl.Int32)
+    assert_series_equal(result, expected)
@@ -0,0 +1,49 @@
+from __future__ import annotations
+
+from datetime import date, datetime
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_series_equal
+
+
+def test_offset_by_business() -> None:
+    df = pl.DataFrame(
+        {
+            "start": [date(2024, 1, 31), date(2024, 1, 26), date(2024, 1, 3)],
+            "by": ["5bd", None, "-1mo2bd"],
+        }
+    )
+    result = df.select(
+        calendar_first=pl.col("start").dt.offset_by_business("1mo1bd", roll="forward"),
+        per_row=pl.col("start").dt.offset_by_business("by", roll="forward"),
+    )
+    assert result["calendar_first"].to_list() == [
+        date(2024, 3, 1),
+        date(2024, 2, 27),
+        date(2024, 2, 6),
+    ]
+    # 2024-02-03 and 2023-12-03 fall on weekends and are rolled forward first.
+    assert result["per_row"].to_list() == [date(2024, 2, 7), None, date(2023, 11, 30)]
+
+
+def test_offset_by_business_holidays_and_datetime() -> None:
+    s = pl.Series("start", [datetime(2024, 12, 20, 9, 30)])
+    result = s.dt.offset_by_business(
+        "3bd", holidays=[date(2024, 12, 25), date(2024, 12, 26)]
+    )
+    expected = pl.Series("start", [datetime(2024, 12, 27, 9, 30)])
+    assert_series_equal(result, expected)
+
+
+def test_offset_by_business_roll_raise() -> None:
+    s = pl.Series([date(2024, 1, 3)])
+    with pytest.raises(pl.ComputeError, match="is not a business date"):
+        s.dt.offset_by_business("1mo")
+
+
+def test_offset_by_business_invalid() -> None:
+    s = pl.Series([date(2024, 1, 3)])
+    with pytest.raises(pl.InvalidOperationError, match="invalid offset"):
+        s.dt.offset_by_business("bd")
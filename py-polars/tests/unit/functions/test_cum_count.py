@@ -24,6 +24,23 @@ def test_cum_count_single_arg(reverse: bool, output: list[int]) -> None:
     assert result.to_series().flags[("SORTED_ASC", "SORTED_DESC")[reverse]]
 
 
+@pytest.mark.parametrize(
+    ("reverse", "count_nulls", "output"),
+    [
+        (False, False, [0, 1, 1, 2, 2]),
+        (True, False, [2, 2, 1, 1, 0]),
+        (False, True, [1, 2, 3, 4, 5]),
+        (True, True, [5, 4, 3, 2, 1]),
+    ],
+)
+def test_cum_count_count_nulls(
+    reverse: bool, count_nulls: bool, output: list[int]
+) -> None:
+    s = pl.Series("a", [None, 1, None, 2, None])
+    result = s.cum_count(reverse=reverse, count_nulls=count_nulls)
+    assert_series_equal(result, pl.Series("a", output, dtype=pl.UInt32))
+
+
 def test_cum_count_multi_arg() -> None:
     df = pl.DataFrame(
         {
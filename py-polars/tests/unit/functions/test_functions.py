@@ -527,3 +527,34 @@ def test_head_tail(fruits_cars: pl.DataFrame) -> None:
     res_expr = fruits_cars.select(pl.tail("A", 2))
     expected = pl.Series("A", [4, 5])
     assert_series_equal(res_expr.to_series(), expected)
+
+
+def test_arr_horizontal() -> None:
+    dtype = pl.Array(pl.Int64, 2)
+    df = pl.DataFrame(
+        {
+            "a": [[1, None], [5, 6], None],
+            "b": [[3, None], None, [9, 9]],
+        },
+        schema={"a": dtype, "b": dtype},
+    )
+    out = df.select(
+        max=pl.arr_max_horizontal("a", "b"),
+        min=pl.arr_min_horizontal("a", "b"),
+        sum=pl.arr_sum_horizontal("a", "b"),
+    )
+    assert out.schema == {"max": dtype, "min": dtype, "sum": dtype}
+    assert out.to_dict(as_series=False) == {
+        "max": [[3, None], [5, 6], [9, 9]],
+        "min": [[1, None], [5, 6], [9, 9]],
+        "sum": [[4, 0], [5, 6], [9, 9]],
+    }
+
+
+def test_arr_horizontal_width_mismatch() -> None:
+    df = pl.DataFrame(
+        {"a": [[1, 2]], "b": [[1, 2, 3]]},
+        schema={"a": pl.Array(pl.Int64, 2), "b": pl.Array(pl.Int64, 3)},
+    )
+    with pytest.raises(InvalidOperationError):
+        df.select(pl.arr_max_horizontal("a", "b"))
@@ -63,6 +63,24 @@ def test_arr_sum(
     assert s.arr.sum().to_list() == expected_sum
 
 
+@pytest.mark.parametrize(
+    ("data", "expected_mean", "dtype"),
+    [
+        ([[1, 2], [4, 3]], [1.5, 3.5], pl.Int64),
+        ([[1, None], [None, 3], [None, None], None], [1.0, 3.0, None, None], pl.Int64),
+        ([[1.0, 2.0], [4.0, 3.0]], [1.5, 3.5], pl.Float32),
+        ([[True, False], [True, True]], [0.5, 1.0], pl.Boolean),
+    ],
+)
+def test_arr_mean(
+    data: list[list[Any]], expected_mean: list[Any], dtype: pl.DataType
+) -> None:
+    s = pl.Series("a", data, dtype=pl.Array(dtype, 2))
+    out = s.arr.mean()
+    assert out.dtype == (pl.Float32 if dtype == pl.Float32 else pl.Float64)
+    assert out.to_list() == expected_mean
+
+
 def test_arr_unique() -> None:
     df = pl.DataFrame(
         {"a": pl.Series("a", [[1, 1], [4, 3]], dtype=pl.Array(pl.Int64, 2))}
@@ -131,6 +149,20 @@ def test_array_arg_min_max() -> None:
     assert_series_equal(s.arr.arg_max(), expected)
 
 
+def test_array_arg_min_max_ties_and_nulls() -> None:
+    s = pl.Series("a", [[5, 1, 5, 1], [2, 2, 2, 2]], dtype=pl.Array(pl.Int64, 4))
+    assert s.arr.arg_min().to_list() == [1, 0]
+    assert s.arr.arg_max().to_list() == [0, 0]
+
+    s = pl.Series(
+        "a",
+        [[None, 3, 1], [None, None, None], None, [2, None, 4]],
+        dtype=pl.Array(pl.Float64, 3),
+    )
+    assert s.arr.arg_min().to_list() == [2, None, None, 0]
+    assert s.arr.arg_max().to_list() == [1, None, None, 2]
+
+
 def test_array_get() -> None:
     s = pl.Series(
         "a",
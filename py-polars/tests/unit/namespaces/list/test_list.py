@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 from datetime import date, datetime
+from typing import Any
 
 import numpy as np
 import pytest
@@ -220,6 +221,13 @@ def test_list_join() -> None:
     assert out.to_dict(as_series=False) == {"a": [None, None, None, "c@d", ""]}
 
 
+
+def test_list_join_wide() -> None:
+    values = [str(i) for i in range(10_000)]
+    s = pl.Series([values, values[:3], [None, *values[:2]]])
+    out = s.list.join(", ")
+    assert out.to_list() == [", ".join(values), "0, 1, 2", "0, 1"]
+
 def test_list_arr_empty() -> None:
     df = pl.DataFrame({"cars": [[1, 2, 3], [2, 3], [4], []]})
 
@@ -281,6 +289,42 @@ def test_list_shift() -> None:
     assert_frame_equal(df, expected_df)
 
 
+def test_list_shift_preserves_length() -> None:
+    s = pl.Series("a", [[1, 2, 3], [], None, [4]])
+    assert s.list.shift(5).to_list() == [[None, None, None], [], None, [None]]
+    assert s.list.shift(-1).to_list() == [[2, 3, None], [], None, [None]]
+
+    with pytest.raises(pl.ShapeError):
+        s.list.shift(pl.Series([1, 2]))
+
+
+def test_list_filter() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2, 3], [4, 5], None, [6]],
+            "keep": [[True, False, True], [None, True], [True], None],
+        }
+    )
+    result = df.select(pl.col("a").list.filter("keep"))
+    expected = pl.DataFrame({"a": [[1, 3], [5], None, None]})
+    assert_frame_equal(result, expected)
+
+    mask = pl.col("a").list.eval(pl.element() > 1)
+    result = df.select(pl.col("a").list.filter(mask))
+    expected = pl.DataFrame({"a": [[2, 3], [4, 5], None, [6]]})
+    assert_frame_equal(result, expected)
+
+
+def test_list_filter_length_mismatch() -> None:
+    df = pl.DataFrame({"a": [[1, 2, 3]], "keep": [[True, False]]})
+    with pytest.raises(pl.ComputeError, match="does not match"):
+        df.select(pl.col("a").list.filter("keep"))
+
+    df = pl.DataFrame({"a": [[1, 2, 3]], "keep": [[1, 0, 1]]})
+    with pytest.raises(pl.ComputeError, match=r"list\[bool\]"):
+        df.select(pl.col("a").list.filter("keep"))
+
+
 def test_list_drop_nulls() -> None:
     s = pl.Series("values", [[1, None, 2, None], [None, None], [1, 2], None])
     expected = pl.Series("values", [[1, 2], [], [1, 2], None])
@@ -295,7 +339,7 @@ def test_list_drop_nulls() -> None:
 def test_list_sample() -> None:
     s = pl.Series("values", [[1, 2, 3, None], [None, None], [1, 2], None])
 
-    expected_sample_n = pl.Series("values", [[3, 1], [None], [2], None])
+    expected_sample_n = pl.Series("values", [[3, 1], [None], [1], None])
     assert_series_equal(
         s.list.sample(n=pl.Series([2, 1, 1, 1]), seed=1), expected_sample_n
     )
@@ -323,12 +367,53 @@ def test_list_sample() -> None:
     assert_frame_equal(df, expected_df)
 
 
+def test_list_sample_per_row_seed() -> None:
+    s = pl.Series("values", [[1, 2, 3]] * 4)
+    out = s.list.sample(n=3, shuffle=True, seed=0)
+    expected = pl.Series("values", [[1, 3, 2], [2, 1, 3], [2, 1, 3], [3, 1, 2]])
+    assert_series_equal(out, expected)
+
+    # The result does not depend on the chunking.
+    chunked = pl.concat([s.slice(0, 2), s.slice(2, 2)], rechunk=False)
+    assert chunked.n_chunks() == 2
+    assert_series_equal(chunked.list.sample(n=3, shuffle=True, seed=0), expected)
+
+
+def test_list_sample_clamp() -> None:
+    s = pl.Series("values", [[1, 2, 3], [], [4], None])
+
+    with pytest.raises(pl.ShapeError):
+        s.list.sample(n=2)
+
+    out = s.list.sample(n=2, clamp=True, seed=0)
+    assert out.list.len().to_list() == [2, 0, 1, None]
+    out = s.list.sample(fraction=2.0, clamp=True, seed=0)
+    assert out.to_list() == [[1, 2, 3], [], [4], None]
+
+
 def test_list_diff() -> None:
     s = pl.Series("a", [[1, 2], [10, 2, 1]])
     expected = pl.Series("a", [[None, 1], [None, -8, -1]])
     assert s.list.diff().to_list() == expected.to_list()
 
 
+def test_list_diff_unsigned() -> None:
+    s = pl.Series("a", [[5, 2, 4], [1], None, []], dtype=pl.List(pl.UInt8))
+
+    result = s.list.diff()
+    expected = pl.Series(
+        "a", [[None, -3, 2], [None], None, []], dtype=pl.List(pl.Int16)
+    )
+    assert_series_equal(result, expected)
+
+    result = s.list.diff(n=-1, null_behavior="drop")
+    expected = pl.Series("a", [[3, -2], [], None, []], dtype=pl.List(pl.Int16))
+    assert_series_equal(result, expected)
+
+    lf = pl.LazyFrame({"a": s})
+    assert lf.select(pl.col("a").list.diff()).schema == {"a": pl.List(pl.Int16)}
+
+
 def test_slice() -> None:
     vals = [[1, 2, 3, 4], [10, 2, 1]]
     s = pl.Series("a", vals)
@@ -655,6 +740,28 @@ def test_list_to_struct() -> None:
     ]
 
 
+def test_list_to_struct_names_and_width() -> None:
+    s = pl.Series("n", [[0, 1], [2, 3, 4], []])
+
+    result = s.list.to_struct("max_width", fields=["a"])
+    assert result.struct.fields == ["a", "field_1", "field_2"]
+    assert result.to_list() == [
+        {"a": 0, "field_1": 1, "field_2": None},
+        {"a": 2, "field_1": 3, "field_2": 4},
+        {"a": None, "field_1": None, "field_2": None},
+    ]
+
+    result = s.list.to_struct(4, fields=["a", "b"])
+    assert result.struct.fields == ["a", "b", "field_2", "field_3"]
+    assert result.struct.field("field_3").to_list() == [None, None, None]
+
+    result = s.list.to_struct(1)
+    assert result.to_list() == [{"field_0": 0}, {"field_0": 2}, {"field_0": None}]
+
+    lf = pl.LazyFrame({"n": s}).select(pl.col("n").list.to_struct(2, fields=["a"]))
+    assert lf.schema == {"n": pl.Struct({"a": pl.Int64, "field_1": pl.Int64})}
+
+
 def test_select_from_list_to_struct_11143() -> None:
     ldf = pl.LazyFrame({"some_col": [[1.0, 2.0], [1.5, 3.0]]})
     ldf = ldf.select(
@@ -697,6 +804,27 @@ def test_list_count_matches_boolean_nulls_9141() -> None:
     assert a.select(pl.col("a").list.count_matches(True))["a"].to_list() == [1]
 
 
+def test_list_count_matches_per_row() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2, 1], [None, 2, None], [3], [], None],
+            "element": [1, None, 4, 1, 1],
+        }
+    )
+    out = df.select(
+        per_row=pl.col("a").list.count_matches(pl.col("element")),
+        nulls=pl.col("a").list.count_matches(None),
+    )
+    expected = pl.DataFrame(
+        {"per_row": [2, 2, 0, 0, None], "nulls": [0, 2, 0, 0, None]},
+        schema={"per_row": pl.UInt32, "nulls": pl.UInt32},
+    )
+    assert_frame_equal(out, expected)
+
+    with pytest.raises(pl.ShapeError):
+        df.select(pl.col("a").list.count_matches(pl.Series([1, 2])))
+
+
 def test_list_gather_oob_10079() -> None:
     df = pl.DataFrame(
         {
@@ -808,6 +936,43 @@ def test_list_ordering() -> None:
     )
 
 
+@pytest.mark.parametrize(
+    ("values", "dtype"),
+    [
+        ([[3, None, 1, None, 2], [None], [], [5], None], pl.Int64),
+        ([[3.0, None, 1.5, None, 2.0], [None], [], [5.0], None], pl.Float64),
+        ([["c", None, "a", None, "b"], [None], [], ["e"], None], pl.String),
+    ],
+)
+@pytest.mark.parametrize("descending", [False, True])
+@pytest.mark.parametrize("nulls_last", [False, True])
+def test_list_sort_nulls_interleaved(
+    values: list[list[Any] | None],
+    dtype: pl.PolarsDataType,
+    descending: bool,
+    nulls_last: bool,
+) -> None:
+    s = pl.Series("a", values, dtype=pl.List(dtype))
+    result = s.list.sort(
+        descending=descending, nulls_last=nulls_last, maintain_order=True
+    )
+
+    first = values[0]
+    assert first is not None
+    valid = sorted((v for v in first if v is not None), reverse=descending)
+    nulls = [None, None]
+    expected_first = valid + nulls if nulls_last else nulls + valid
+    expected = pl.Series(
+        "a", [expected_first, [None], [], values[3], None], dtype=pl.List(dtype)
+    )
+    assert_series_equal(result, expected)
+
+    result = pl.DataFrame({"a": s}).select(
+        pl.col("a").list.sort(descending=descending, nulls_last=nulls_last)
+    )
+    assert_series_equal(result.to_series(), expected)
+
+
 def test_list_get_logical_type() -> None:
     s = pl.Series(
         "a",
@@ -869,6 +1034,11 @@ def test_list_gather_every() -> None:
 
     assert_frame_equal(out, expected)
 
+    with pytest.raises(pl.ComputeError, match="`n` should be positive"):
+        df.select(pl.col("lst").list.gather_every(0))
+    with pytest.raises(pl.ComputeError, match="`n` should be positive"):
+        df.select(pl.col("lst").list.gather_every(pl.col("n") - 1))
+
 
 def test_list_n_unique() -> None:
     df = pl.DataFrame(
@@ -884,6 +1054,26 @@ def test_list_n_unique() -> None:
     assert_frame_equal(out, expected)
 
 
+@pytest.mark.parametrize(
+    ("values", "dtype"),
+    [
+        ([[1.0, None, float("nan"), 1.0, None], [None, None], []], pl.Float64),
+        ([["a", None, "b", "a", None], [None, None], []], pl.String),
+        (
+            [[date(2020, 1, 1), None, date(2020, 1, 2), None], [None, None], []],
+            pl.Date,
+        ),
+    ],
+)
+def test_list_n_unique_nulls(values: list[list[Any]], dtype: pl.PolarsDataType) -> None:
+    s = pl.Series("a", values, dtype=pl.List(dtype))
+
+    assert s.list.n_unique().to_list() == [3, 1, 0]
+    unique = s.list.unique(maintain_order=True)
+    assert unique.list.len().to_list() == [3, 1, 0]
+    assert unique.to_list()[1:] == [[None], []]
+
+
 def test_list_get_with_null() -> None:
     df = pl.DataFrame({"a": [None, [1, 2]], "b": [False, True]})
 
@@ -98,6 +98,23 @@ def test_list_set_operations() -> None:
     assert r2 == exp
 
 
+def test_list_set_operations_order_and_nulls() -> None:
+    df = pl.DataFrame({"a": [[1, 2, 3, 4, None, 2]], "b": [[1, None, 5, None]]})
+
+    assert df.select(pl.col("a").list.set_union("b"))["a"].to_list() == [
+        [1, 2, 3, 4, None, 5]
+    ]
+    assert df.select(pl.col("a").list.set_intersection("b"))["a"].to_list() == [
+        [1, None]
+    ]
+    assert df.select(pl.col("a").list.set_difference("b"))["a"].to_list() == [
+        [2, 3, 4]
+    ]
+    assert df.select(pl.col("a").list.set_symmetric_difference("b"))[
+        "a"
+    ].to_list() == [[2, 3, 4, 5]]
+
+
 def test_list_set_operations_broadcast() -> None:
     df = pl.DataFrame(
         {
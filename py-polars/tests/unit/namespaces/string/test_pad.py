@@ -90,6 +90,15 @@ def test_str_zfill_expr() -> None:
     assert_frame_equal(out, expected)
 
 
+def test_str_zfill_sign_per_row() -> None:
+    s = pl.Series("code", ["+7", "-42", "13", "+", "12345", None])
+    width = pl.Series("width", [4, 5, 1, 3, 4, 8])
+    assert s.str.zfill(width).to_list() == ["+007", "-0042", "13", "+00", "12345", None]
+    assert s.str.zfill(4).to_list() == [
+        x.zfill(4) if x is not None else None for x in s.to_list()
+    ]
+
+
 def test_str_ljust_deprecated() -> None:
     s = pl.Series(["a", "bc", "def"])
 
@@ -301,6 +301,24 @@ def test_str_find(strict: bool) -> None:
         }
 
 
+def test_str_find_char_offsets() -> None:
+    df = pl.DataFrame({"txt": ["naïve café", "日本語のテキスト", "plain"]})
+    result = df.select(
+        regex=pl.col("txt").str.find("caf|テ"),
+        lit=pl.col("txt").str.find("é", literal=True),
+        col=pl.col("txt").str.find(pl.Series(["v", "の", "x"])),
+    )
+    expected = pl.DataFrame(
+        {"regex": [6, 4, None], "lit": [9, None, None], "col": [3, 3, None]},
+        schema={"regex": pl.UInt32, "lit": pl.UInt32, "col": pl.UInt32},
+    )
+    assert_frame_equal(result, expected)
+
+    # The offsets line up with `str.slice`.
+    out = df.select(pl.col("txt").str.slice(pl.col("txt").str.find("caf|テ")))
+    assert out.to_series().to_list() == ["café", "テキスト", None]
+
+
 def test_str_find_invalid_regex() -> None:
     # test behaviour of 'strict' with invalid regular expressions
     df = pl.DataFrame({"txt": ["AbCdEfG"]})
@@ -1158,6 +1176,14 @@ def test_extract_groups() -> None:
     ).to_dict(as_series=False) == {"literal": [{"foo": "foo", "bar": None}]}
 
 
+def test_extract_groups_no_match() -> None:
+    s = pl.Series("s", ["a=1", "nope", None])
+    out = s.str.extract_groups(r"(?<key>\w)=(\d)")
+    assert out.struct.fields == ["key", "2"]
+    assert out.struct.field("key").to_list() == ["a", None, None]
+    assert out.struct.field("2").to_list() == ["1", None, None]
+
+
 def test_starts_ends_with() -> None:
     df = pl.DataFrame(
         {
@@ -1284,6 +1310,31 @@ def test_split_expr() -> None:
     assert_frame_equal(out, expected)
 
 
+
+def test_split_regex() -> None:
+    s = pl.Series("x", ["a  b\tc", "a,;b", None, ""])
+
+    assert s.str.split(r"[\s,;]", regex=True).to_list() == [
+        ["a", "", "b", "c"],
+        ["a", "", "b"],
+        None,
+        [""],
+    ]
+    assert s.str.split(r"[\s,;]+", regex=True, inclusive=True).to_list() == [
+        ["a  ", "b\t", "c"],
+        ["a,;", "b"],
+        None,
+        [],
+    ]
+
+    df = pl.DataFrame({"x": ["a1b22c", "a_b", None], "by": [r"\d+", "_", "x"]})
+    out = df.select(pl.col("x").str.split(pl.col("by"), regex=True))
+    assert out.to_series().to_list() == [["a", "b", "c"], ["a", "b"], None]
+
+    with pytest.raises(pl.ComputeError):
+        s.str.split("(", regex=True)
+
+
 def test_split_exact() -> None:
     df = pl.DataFrame({"x": ["a_a", None, "b", "c_c"]})
     out = df.select([pl.col("x").str.split_exact("_", 2, inclusive=False)]).unnest("x")
@@ -1394,6 +1445,27 @@ def test_titlecase() -> None:
     }
 
 
+def test_titlecase_word_boundaries() -> None:
+    s = pl.Series(
+        [
+            "o'brien and jean-luc",
+            "DON'T STOP",
+            "(nested) words, 1st",
+            "e\u0301cole",
+            "",
+            None,
+        ]
+    )
+    assert s.str.to_titlecase().to_list() == [
+        "O'Brien And Jean-Luc",
+        "Don't Stop",
+        "(Nested) Words, 1st",
+        "E\u0301cole",
+        "",
+        None,
+    ]
+
+
 def test_string_replace_with_nulls_10124() -> None:
     df = pl.DataFrame({"col1": ["S", "S", "S", None, "S", "S", "S", "S"]})
 
@@ -1446,6 +1518,66 @@ def test_string_reverse() -> None:
     assert_frame_equal(result, expected)
 
 
+def test_string_distances() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["kitten", "flaw", "café", "", None, "abc"],
+            "b": ["sitting", "lawn", "cafe", "abc", "x", None],
+        }
+    )
+    out = df.select(
+        lev=pl.col("a").str.levenshtein(pl.col("b")),
+        jw=pl.col("a").str.jaro_winkler(pl.col("b")),
+    )
+    assert out.schema == {"lev": pl.UInt32, "jw": pl.Float64}
+    assert out["lev"].to_list() == [3, 2, 1, 3, None, None]
+    jw = out["jw"].to_list()
+    assert jw[0] == pytest.approx(0.746031746)
+    assert jw[1] == pytest.approx(0.833333333)
+    assert jw[3] == 0.0
+    assert jw[4:] == [None, None]
+
+    s = pl.Series("a", ["MARTHA", "MARHTA", "DIXON"])
+    assert s.str.levenshtein("MARTHA").to_list() == [0, 2, 6]
+    assert s.str.jaro_winkler("MARTHA").to_list()[:2] == pytest.approx(
+        [1.0, 0.961111111]
+    )
+
+    with pytest.raises(pl.ShapeError):
+        s.str.levenshtein(pl.Series(["a", "b"]))
+
+
+@pytest.mark.parametrize(
+    ("form", "expected"),
+    [
+        ("NFC", ["caf\u00e9", "\u00e9", "\ufb01", None]),
+        ("NFD", ["cafe\u0301", "e\u0301", "\ufb01", None]),
+        ("NFKC", ["caf\u00e9", "\u00e9", "fi", None]),
+        ("NFKD", ["cafe\u0301", "e\u0301", "fi", None]),
+    ],
+)
+def test_string_normalize(form: str, expected: list[str | None]) -> None:
+    s = pl.Series("text", ["caf\u00e9", "e\u0301", "\ufb01", None])
+    assert_series_equal(
+        s.str.normalize(form),  # type: ignore[arg-type]
+        pl.Series("text", expected),
+    )
+
+
+def test_string_normalize_makes_representations_equal() -> None:
+    df = pl.DataFrame({"a": ["caf\u00e9"], "b": ["cafe\u0301"]})
+    assert df.select(pl.col("a") == pl.col("b")).item() is False
+    result = df.select(
+        pl.col("a").str.normalize() == pl.col("b").str.normalize()
+    ).item()
+    assert result is True
+
+
+def test_string_normalize_invalid_form() -> None:
+    with pytest.raises(ValueError, match="`form` must be one of"):
+        pl.Series(["a"]).str.normalize("NFX")  # type: ignore[arg-type]
+
+
 @pytest.mark.parametrize(
     ("data", "expected_dat"),
     [
@@ -1463,3 +1595,68 @@ def test_replace_lit_n_char_13385(
     res = s.str.replace("a", "b", literal=True)
     expected_s = pl.Series(expected_dat, dtype=pl.String)
     assert_series_equal(res, expected_s)
+
+
+def test_contains_any() -> None:
+    s = pl.Series("s", ["Hello World", "polars", None, "DataFrame"])
+    assert s.str.contains_any(["world", "frame"]).to_list() == [
+        False,
+        False,
+        None,
+        False,
+    ]
+    assert s.str.contains_any(
+        ["world", "frame"], ascii_case_insensitive=True
+    ).to_list() == [True, False, None, True]
+
+    patterns = [f"pattern{i}" for i in range(1000)] + ["lars"]
+    assert s.str.contains_any(patterns).to_list() == [False, True, None, False]
+
+
+def test_replace_many() -> None:
+    s = pl.Series("s", ["hello world", None, "Hello again"])
+    result = s.str.replace_many(["hello", "world"], ["hi", "earth"])
+    assert result.to_list() == ["hi earth", None, "Hello again"]
+
+    result = s.str.replace_many(["hello", "again"], "X", ascii_case_insensitive=True)
+    assert result.to_list() == ["X world", None, "X X"]
+
+    with pytest.raises(pl.InvalidOperationError, match="same amount of patterns"):
+        s.str.replace_many(["a", "b", "c"], ["x", "y"])
+
+
+def test_str_slice_bytes() -> None:
+    s = pl.Series("s", ["café", "naïve", None, "abc"])
+    assert s.str.slice_bytes(0, 5).to_list() == ["café", "naïv", None, "abc"]
+    assert s.str.slice_bytes(-2).to_list() == ["é", "ve", None, "bc"]
+    assert s.str.slice_bytes(-7, 3).to_list() == ["c", "na", None, ""]
+    assert s.str.slice_bytes(1, 0).to_list() == ["", "", None, ""]
+
+    df = pl.DataFrame({"s": ["abcdef", "ghijkl"], "offset": [1, 2], "len": [2, 3]})
+    result = df.select(pl.col("s").str.slice_bytes("offset", "len"))
+    assert result.to_series().to_list() == ["bc", "ijk"]
+
+
+def test_str_slice_bytes_char_boundary() -> None:
+    s = pl.Series("s", ["naïve"])
+    assert s.str.slice(0, 3).to_list() == ["naï"]
+    with pytest.raises(pl.ComputeError, match="character boundaries"):
+        s.str.slice_bytes(0, 3)
+
+
+def test_escape_regex() -> None:
+    s = pl.Series("s", ["1.5 (approx)", "a+b*c?", "[x]{2}|^$", "plain", None])
+    escaped = s.str.escape_regex()
+    assert escaped.to_list() == [
+        r"1\.5 \(approx\)",
+        r"a\+b\*c\?",
+        r"\[x\]\{2\}\|\^\$",
+        "plain",
+        None,
+    ]
+
+    df = pl.DataFrame({"text": ["cost is 1.5 (approx)", "1x5 approx"]})
+    result = df.select(
+        pl.col("text").str.contains(pl.lit("1.5 (approx)").str.escape_regex())
+    )
+    assert result.to_series().to_list() == [True, False]
@@ -294,6 +294,11 @@ def test_month_end_datetime(
     [
         (date(2022, 3, 15), date(2022, 3, 31)),
         (date(2022, 3, 31), date(2022, 3, 31)),
+        (date(2022, 4, 1), date(2022, 4, 30)),
+        (date(2023, 2, 10), date(2023, 2, 28)),
+        (date(2024, 2, 10), date(2024, 2, 29)),
+        (date(1900, 2, 10), date(1900, 2, 28)),
+        (date(2000, 2, 10), date(2000, 2, 29)),
     ],
 )
 def test_month_end_date(dt: date, expected: date) -> None:
@@ -302,6 +307,13 @@ def test_month_end_date(dt: date, expected: date) -> None:
     assert result == expected
 
 
+def test_month_start_end_nulls() -> None:
+    ser = pl.Series([date(2024, 2, 10), None])
+    assert ser.dt.month_start().to_list() == [date(2024, 2, 1), None]
+    assert ser.dt.month_end().to_list() == [date(2024, 2, 29), None]
+    assert ser.dt.month_end().dtype == pl.Date
+
+
 def test_month_start_end_invalid() -> None:
     ser = pl.Series([time(1, 2, 3)])
     with pytest.raises(
@@ -448,6 +460,37 @@ def test_duration_extract_times(
     assert_series_equal(getattr(duration.dt, unit_attr)(), expected)
 
 
+@pytest.mark.parametrize("time_unit", ["ms", "us", "ns"])
+def test_duration_extract_times_truncate_toward_zero(time_unit: TimeUnit) -> None:
+    s = pl.Series(
+        [timedelta(seconds=-1.5), timedelta(minutes=90, microseconds=500_000), None],
+        dtype=pl.Duration(time_unit),
+    )
+    df = pl.DataFrame({"d": s}).select(
+        pl.col("d").dt.total_hours().alias("h"),
+        pl.col("d").dt.total_minutes().alias("m"),
+        pl.col("d").dt.total_seconds().alias("s"),
+        pl.col("d").dt.total_milliseconds().alias("ms"),
+    )
+    expected = pl.DataFrame(
+        {
+            "h": [0, 1, None],
+            "m": [0, 90, None],
+            "s": [-1, 5400, None],
+            "ms": [-1500, 5_400_500, None],
+        },
+        schema_overrides={"h": pl.Int64, "m": pl.Int64, "s": pl.Int64, "ms": pl.Int64},
+    )
+    assert_frame_equal(df, expected)
+
+
+def test_duration_extract_times_overflow_to_null() -> None:
+    s = pl.Series([2**62, -2], dtype=pl.Duration("ms"))
+    assert_series_equal(
+        s.dt.total_nanoseconds(), pl.Series([None, -2_000_000], dtype=pl.Int64)
+    )
+
+
 @pytest.mark.parametrize(
     ("unit_attr", "expected"),
     [
@@ -733,6 +776,49 @@ def test_date_time_combine(tzinfo: ZoneInfo | None, time_zone: str | None) -> No
     assert df.schema == expected_schema
 
 
+def test_combine_time_zone() -> None:
+    df = pl.DataFrame(
+        {
+            "dt": [date(2022, 10, 10), None, date(2022, 3, 27)],
+            "tm": [time(1, 2, 3), time(4, 5, 6), None],
+        }
+    )
+    result = df.select(
+        pl.col("dt").dt.combine(pl.col("tm"), "ms", time_zone="Europe/Amsterdam")
+    )["dt"]
+    expected = pl.Series(
+        "dt",
+        [datetime(2022, 10, 10, 1, 2, 3), None, None],
+        dtype=pl.Datetime("ms"),
+    ).dt.replace_time_zone("Europe/Amsterdam")
+    assert_series_equal(result, expected)
+
+    # the date of a time zone aware datetime is taken in its own time zone
+    s = pl.Series([datetime(2021, 1, 3, 20)]).dt.replace_time_zone("UTC")
+    s = s.dt.convert_time_zone("Asia/Tokyo")
+    result = s.dt.combine(time(9))
+    assert result.dtype == pl.Datetime("us", "Asia/Tokyo")
+    assert result.dt.replace_time_zone(None).item() == datetime(2021, 1, 4, 9)
+
+    result = s.dt.combine(time(9), time_zone="Europe/Amsterdam")
+    assert result.dtype == pl.Datetime("us", "Europe/Amsterdam")
+    assert result.dt.replace_time_zone(None).item() == datetime(2021, 1, 4, 9)
+
+
+def test_combine_broadcast_and_length_mismatch() -> None:
+    df = pl.DataFrame({"dt": [date(2022, 1, 1), date(2022, 1, 2)]})
+    result = df.select(pl.col("dt").dt.combine(pl.lit(time(12))))["dt"]
+    assert result.to_list() == [datetime(2022, 1, 1, 12), datetime(2022, 1, 2, 12)]
+
+    result = df.select(
+        pl.lit(date(2022, 1, 1)).dt.combine(pl.Series([time(1), time(2)]))
+    ).to_series()
+    assert result.to_list() == [datetime(2022, 1, 1, 1), datetime(2022, 1, 1, 2)]
+
+    with pytest.raises(pl.ShapeError, match="equal length or a scalar"):
+        pl.Series([date(2022, 1, 1)] * 2).dt.combine(pl.Series([time(1)] * 3))
+
+
 def test_combine_unsupported_types() -> None:
     with pytest.raises(ComputeError, match="expected Date or Datetime, got time"):
         pl.Series([time(1, 2)]).dt.combine(time(3, 4))
@@ -789,6 +875,14 @@ def test_is_leap_year() -> None:
     ]
 
 
+@pytest.mark.parametrize("dtype", [pl.Date, pl.Datetime("ms"), pl.Datetime("ns")])
+def test_is_leap_year_century_rule(dtype: pl.PolarsDataType) -> None:
+    s = pl.Series(
+        [date(1900, 3, 1), date(2000, 3, 1), date(2023, 3, 1), None, date(2024, 3, 1)]
+    ).cast(dtype)
+    assert s.dt.is_leap_year().to_list() == [False, True, False, None, True]
+
+
 def test_quarter() -> None:
     assert pl.datetime_range(
         datetime(2022, 1, 1), datetime(2022, 12, 1), "1mo", eager=True
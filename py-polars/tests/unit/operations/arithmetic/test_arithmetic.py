@@ -293,3 +293,35 @@ def test_null_column_arithmetic(op: Any) -> None:
     # test broadcast left
     output_df = df.select(op(pl.Series("a", [None]), pl.col("a")))
     assert_frame_equal(expected_df, output_df)
+
+
+def test_mul_add() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1.0, 2.0, None, 4.0],
+            "b": [0.5, 1.5, 2.0, None],
+            "c": [1, 2, 3, 4],
+        }
+    )
+    out = df.select(
+        fma=pl.col("a").mul_add(pl.col("b"), pl.col("c")),
+        scalar=pl.col("a").mul_add(2.0, 1.0),
+        ints=pl.col("c").mul_add(pl.col("c"), 1),
+    )
+    expected = pl.DataFrame(
+        {
+            "fma": [1.5, 5.0, None, None],
+            "scalar": [3.0, 5.0, None, 9.0],
+            "ints": [2, 5, 10, 17],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    s = pl.Series("a", [1.0, 2.0, 3.0])
+    assert_series_equal(
+        s.mul_add(2.0, pl.Series([0.5, 0.25, 0.0])),
+        pl.Series("a", [2.5, 4.25, 6.0]),
+    )
+
+    with pytest.raises(pl.ShapeError):
+        df.select(pl.col("a").mul_add(pl.Series([1.0, 2.0]), 1.0))
@@ -12,7 +12,12 @@ from polars.exceptions import ComputeError, InvalidOperationError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
-    from polars.type_aliases import ClosedInterval, PolarsDataType, TimeUnit
+    from polars.type_aliases import (
+        ClosedInterval,
+        PolarsDataType,
+        RollingInterpolationMethod,
+        TimeUnit,
+    )
 
 
 @pytest.fixture()
@@ -458,24 +463,49 @@ def test_overlapping_groups_4628() -> None:
 
 def test_rolling_skew_lagging_null_5179() -> None:
     s = pl.Series([None, 3, 4, 1, None, None, None, None, 3, None, 5, 4, 7, 2, 1, None])
-    assert s.rolling_skew(3).fill_nan(-1.0).to_list() == [
-        None,
-        None,
-        0.0,
-        -0.3818017741606059,
-        0.0,
-        -1.0,
-        None,
-        None,
-        -1.0,
-        -1.0,
-        0.0,
-        0.0,
-        0.38180177416060695,
-        0.23906314692954517,
-        0.6309038567106234,
-        0.0,
-    ]
+    assert s.rolling_skew(3, min_periods=1).to_list() == pytest.approx(
+        [
+            None,
+            None,
+            0.0,
+            -0.3818017741606059,
+            0.0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            0.0,
+            0.0,
+            0.38180177416060695,
+            0.23906314692954517,
+            0.6309038567106234,
+            0.0,
+        ]
+    )
+    assert s.rolling_skew(3).to_list() == pytest.approx(
+        [None] * 3
+        + [-0.3818017741606059]
+        + [None] * 8
+        + [0.38180177416060695, 0.23906314692954517, 0.6309038567106234, None]
+    )
+
+
+def test_rolling_kurtosis() -> None:
+    s = pl.Series([1, 2, 3, 3, 2, 10, 8, -4, 0.5, 7, 7])
+    out = s.rolling_kurtosis(5, bias=False)
+    for i in range(4, len(s)):
+        expected = s[i - 4 : i + 1].kurtosis(bias=False)
+        assert out[i] == pytest.approx(expected)
+    assert out[:4].to_list() == [None] * 4
+
+    out = s.rolling_kurtosis(5, fisher=False)
+    assert out[4] == pytest.approx(s[:5].kurtosis(fisher=False))
+
+    # Zero variance windows are null rather than NaN.
+    s = pl.Series([1.0, 1.0, 1.0, 2.0])
+    assert s.rolling_kurtosis(3).to_list() == pytest.approx([None, None, None, -1.5])
+    assert s.rolling_skew(3).to_list()[:3] == [None, None, None]
 
 
 def test_rolling_var_numerical_stability_5197() -> None:
@@ -625,6 +655,31 @@ def test_rolling_weighted_quantile_10031() -> None:
     )
 
 
+@pytest.mark.parametrize(
+    "interpolation", ["nearest", "higher", "lower", "midpoint", "linear"]
+)
+@pytest.mark.parametrize("quantile", [0.1, 0.25, 0.5, 0.7])
+@pytest.mark.parametrize("center", [False, True])
+def test_rolling_quantile_matches_quantile(
+    interpolation: RollingInterpolationMethod, quantile: float, center: bool
+) -> None:
+    s = pl.Series("a", [4.0, None, 1.0, 7.0, 3.0, None, 9.0, 2.0, 5.0])
+    window_size = 4
+
+    result = s.rolling_quantile(
+        quantile, interpolation, window_size, min_periods=1, center=center
+    )
+
+    # windows are `[i - 3, i]`, or `[i - 2, i + 1]` when centered
+    right = (window_size + 1) // 2 if center else 1
+    expected = []
+    for i in range(len(s)):
+        start = max(i + right - window_size, 0)
+        window = s[start : i + right]
+        expected.append(window.quantile(quantile, interpolation))
+    assert result.to_list() == pytest.approx(expected)
+
+
 def test_rolling_meta_eq_10101() -> None:
     assert pl.col("A").rolling_sum(10).meta.eq(pl.col("A").rolling_sum(10)) is True
 
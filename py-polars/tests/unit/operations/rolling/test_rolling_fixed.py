@@ -5,3 +5,37 @@ def test_rolling_var_stability_12905() -> None:
     s1 = pl.Series("a", [36743.6 for _ in range(10)])
     assert s1.rolling_var(window_size=12, min_periods=2).sum() == 0.0
     assert s1.rolling_std(window_size=12, min_periods=2).sum() == 0.0
+
+
+def test_rolling_center() -> None:
+    s = pl.Series("a", [1.0, 2.0, 3.0, 4.0, 5.0])
+
+    # odd windows are symmetric around the label
+    assert s.rolling_sum(3, center=True).to_list() == [None, 6.0, 9.0, 12.0, None]
+    assert s.rolling_mean(3, center=True).to_list() == [None, 2.0, 3.0, 4.0, None]
+    assert s.rolling_var(3, center=True).to_list() == [None, 1.0, 1.0, 1.0, None]
+    assert s.rolling_std(3, center=True).to_list() == [None, 1.0, 1.0, 1.0, None]
+    assert s.rolling_quantile(0.5, window_size=3, center=True).to_list() == [
+        None,
+        2.0,
+        3.0,
+        4.0,
+        None,
+    ]
+
+    # even windows hold one more element before the label than after it
+    assert s.rolling_sum(4, center=True).to_list() == [None, None, 10.0, 14.0, None]
+    assert s.rolling_sum(4, center=True, min_periods=1).to_list() == [
+        3.0,
+        6.0,
+        10.0,
+        14.0,
+        12.0,
+    ]
+    assert s.rolling_mean(4, center=True, min_periods=2).to_list() == [
+        1.5,
+        2.0,
+        2.5,
+        3.5,
+        4.0,
+    ]
@@ -0,0 +1,49 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+@pytest.mark.parametrize(
+    ("dtype", "bits"),
+    [(pl.Int8, 8), (pl.Int16, 16), (pl.Int32, 32), (pl.Int64, 64), (pl.UInt8, 8)],
+)
+def test_bit_counts(dtype: pl.PolarsDataType, bits: int) -> None:
+    df = pl.DataFrame({"a": [0, 1, 6, None]}, schema={"a": dtype})
+    result = df.select(
+        bit_count=pl.col("a").bit_count(),
+        leading_zeros=pl.col("a").leading_zeros(),
+        trailing_zeros=pl.col("a").trailing_zeros(),
+    )
+    expected = pl.DataFrame(
+        {
+            "bit_count": [0, 1, 2, None],
+            "leading_zeros": [bits, bits - 1, bits - 3, None],
+            "trailing_zeros": [bits, 0, 1, None],
+        },
+        schema_overrides={
+            "bit_count": pl.UInt32,
+            "leading_zeros": pl.UInt32,
+            "trailing_zeros": pl.UInt32,
+        },
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_bit_counts_negative() -> None:
+    s = pl.Series("a", [-1, -2, -128], dtype=pl.Int8)
+    assert s.bit_count().to_list() == [8, 7, 1]
+    assert s.leading_zeros().to_list() == [0, 0, 0]
+    assert s.trailing_zeros().to_list() == [0, 1, 7]
+
+
+def test_bit_counts_schema() -> None:
+    lf = pl.LazyFrame({"a": [1]}, schema={"a": pl.UInt64})
+    assert lf.select(pl.col("a").bit_count()).schema["a"] == pl.UInt32
+
+
+def test_bit_counts_non_integer() -> None:
+    with pytest.raises(pl.InvalidOperationError, match="only supports integer"):
+        pl.Series([1.5]).bit_count()
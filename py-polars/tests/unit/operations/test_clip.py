@@ -28,9 +28,9 @@ def test_clip_int(clip_exprs: list[pl.Expr]) -> None:
     result = lf.select(clip_exprs)
     expected = pl.LazyFrame(
         {
-            "clip": [1, 1, 4, None, None],
-            "clip_min": [1, 2, 4, None, 5],
-            "clip_max": [1, 1, 3, 4, None],
+            "clip": [1, 1, 4, 4, 5],
+            "clip_min": [1, 2, 4, 4, 5],
+            "clip_max": [1, 1, 3, 4, 5],
         }
     )
     assert_frame_equal(result, expected)
@@ -47,9 +47,9 @@ def test_clip_float(clip_exprs: list[pl.Expr]) -> None:
     result = lf.select(clip_exprs)
     expected = pl.LazyFrame(
         {
-            "clip": [1.0, 1.0, 4.0, None, None],
-            "clip_min": [1.0, 2.0, 4.0, None, 5.0],
-            "clip_max": [1.0, 1.0, 3.0, 4.0, None],
+            "clip": [1.0, 1.0, 4.0, 4.0, 5.0],
+            "clip_min": [1.0, 2.0, 4.0, 4.0, 5.0],
+            "clip_max": [1.0, 1.0, 3.0, 4.0, 5.0],
         }
     )
     assert_frame_equal(result, expected)
@@ -92,15 +92,15 @@ def test_clip_datetime(clip_exprs: list[pl.Expr]) -> None:
                 datetime(1996, 6, 5),
                 datetime(2023, 9, 20, 18, 30, 6),
                 None,
-                None,
-                None,
+                datetime(1993, 3, 13),
+                datetime(2000, 1, 10),
             ],
             "clip_min": [
                 datetime(1995, 6, 5, 10, 30),
                 datetime(1996, 6, 5),
                 datetime(2023, 10, 20, 18, 30, 6),
                 None,
-                None,
+                datetime(2023, 9, 24),
                 datetime(2000, 1, 10),
             ],
             "clip_max": [
@@ -109,7 +109,7 @@ def test_clip_datetime(clip_exprs: list[pl.Expr]) -> None:
                 datetime(2023, 9, 20, 18, 30, 6),
                 None,
                 datetime(1993, 3, 13),
-                None,
+                datetime(2000, 1, 10),
             ],
         }
     )
@@ -127,7 +127,7 @@ def test_clip_non_numeric_dtype_fails() -> None:
 def test_clip_string_input() -> None:
     df = pl.DataFrame({"a": [0, 1, 2], "min": [1, None, 1]})
     result = df.select(pl.col("a").clip("min"))
-    expected = pl.DataFrame({"a": [1, None, 2]})
+    expected = pl.DataFrame({"a": [1, 1, 2]})
     assert_frame_equal(result, expected)
 
 
@@ -137,6 +137,18 @@ def test_clip_bound_invalid_for_original_dtype() -> None:
         s.clip(-1, 5)
 
 
+def test_clip_null_bound_is_unbounded() -> None:
+    s = pl.Series([-5, 0, 5, None])
+    assert s.clip(pl.lit(None, dtype=pl.Int64), 1).to_list() == [-5, 0, 1, None]
+    assert s.clip(pl.Series([None, 1, None, 0]), 2).to_list() == [-5, 1, 2, None]
+
+
+def test_clip_bound_without_numeric_supertype() -> None:
+    s = pl.Series([1, 2, 3])
+    with pytest.raises(pl.ComputeError, match="cannot clip values of dtype i64"):
+        s.clip(pl.lit("1"))
+
+
 def test_clip_min_max_deprecated() -> None:
     s = pl.Series([-1, 0, 1])
 
@@ -0,0 +1,46 @@
+from __future__ import annotations
+
+import pytest
+from hypothesis import given
+
+import polars as pl
+from polars.testing import assert_series_equal
+from polars.testing.parametric import series
+
+
+@pytest.mark.parametrize("op", ["cum_sum", "cum_min", "cum_max", "cum_prod"])
+@given(
+    s=series(
+        null_probability=0.3,
+        allowed_dtypes=[
+            pl.Int8,
+            pl.Int16,
+            pl.Int32,
+            pl.Int64,
+            pl.UInt8,
+            pl.UInt16,
+            pl.UInt32,
+            pl.UInt64,
+            pl.Float32,
+            pl.Float64,
+        ],
+    )
+)
+def test_cum_agg_reverse_identity(s: pl.Series, op: str) -> None:
+    reversed = getattr(s, op)(reverse=True)
+    reflected = getattr(s.reverse(), op)().reverse()
+    assert_series_equal(reversed, reflected)
+
+
+def test_cum_min_max_leading_nulls_and_infinities() -> None:
+    s = pl.Series([None, None, float("-inf"), 2.0, None, float("inf")])
+
+    assert s.cum_max().to_list() == [None, None, float("-inf"), 2.0, None, float("inf")]
+    assert s.cum_min(reverse=True).to_list() == [
+        None,
+        None,
+        float("-inf"),
+        2.0,
+        None,
+        float("inf"),
+    ]
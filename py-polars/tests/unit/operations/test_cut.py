@@ -27,6 +27,42 @@ def test_cut() -> None:
     assert_series_equal(result, expected, categorical_as_str=True)
 
 
+def test_cut_left_closed() -> None:
+    s = pl.Series("a", [-2, -1, 0, 1, 2])
+
+    result = s.cut([-1, 1], left_closed=True)
+
+    expected = pl.Series(
+        "a",
+        [
+            "[-inf, -1)",
+            "[-1, 1)",
+            "[-1, 1)",
+            "[1, inf)",
+            "[1, inf)",
+        ],
+        dtype=pl.Categorical,
+    )
+    assert_series_equal(result, expected, categorical_as_str=True)
+
+    out = s.cut([-1, 1], left_closed=True, include_breaks=True)
+    assert out.struct.field("break_point").to_list() == [-1.0, 1.0, 1.0, inf, inf]
+
+
+def test_qcut_left_closed_on_breakpoint() -> None:
+    s = pl.Series("a", [0, 10, 10, 10, 20])
+
+    right = s.qcut([0.5], labels=["low", "high"])
+    left = s.qcut([0.5], labels=["low", "high"], left_closed=True)
+
+    assert right.cast(pl.String).to_list() == ["low", "low", "low", "low", "high"]
+    assert left.cast(pl.String).to_list() == ["low", "high", "high", "high", "high"]
+    assert s.qcut([0.5], left_closed=True).cat.get_categories().to_list() == [
+        "[-inf, 10)",
+        "[10, inf)",
+    ]
+
+
 def test_cut_lazy_schema() -> None:
     lf = pl.LazyFrame({"a": [-2, -1, 0, 1, 2]})
 
@@ -81,6 +117,38 @@ def test_cut_null_values() -> None:
     assert_series_equal(result, expected, categorical_as_str=True)
 
 
+def test_cut_sort_follows_bin_order() -> None:
+    s = pl.Series("a", [500, 5, 50, 1, None])
+
+    result = s.cut([2, 10, 100], left_closed=True)
+    assert result.dtype == pl.Categorical(ordering="physical")
+    assert result.cat.get_categories().to_list() == [
+        "[-inf, 2)",
+        "[2, 10)",
+        "[10, 100)",
+        "[100, inf)",
+    ]
+
+    # Lexical order would put "[10, 100)" before "[2, 10)".
+    assert result.sort(nulls_last=True).to_list() == [
+        "[-inf, 2)",
+        "[2, 10)",
+        "[10, 100)",
+        "[100, inf)",
+        None,
+    ]
+
+    df = pl.DataFrame({"a": [500, 5, 50, 1]}).lazy()
+    result_lazy = df.select(pl.col("a").cut([2, 10, 100], left_closed=True))
+    assert result_lazy.schema["a"] == pl.Categorical(ordering="physical")
+    assert result_lazy.sort("a").collect()["a"].to_list() == [
+        "[-inf, 2)",
+        "[2, 10)",
+        "[10, 100)",
+        "[100, inf)",
+    ]
+
+
 def test_cut_deprecated_as_series() -> None:
     a = pl.Series("a", [v / 10 for v in range(-30, 30, 5)])
     with pytest.deprecated_call():
@@ -9,7 +9,7 @@ import polars as pl
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
-    from polars.type_aliases import PolarsIntegerType, TimeUnit
+    from polars.type_aliases import PolarsDataType, PolarsIntegerType, TimeUnit
 
 
 @pytest.mark.parametrize("sort", [True, False])
@@ -203,13 +203,30 @@ def test_ewma_by_invalid() -> None:
         )
     df = pl.DataFrame({"values": [[1, 2], [3, 4]]})
     with pytest.raises(
-        pl.InvalidOperationError, match=r"expected series to be Float64, Float32, .*"
+        pl.InvalidOperationError, match=r"expected series to be a float or integer"
     ):
         df.with_row_index().select(
             pl.col("values").ewm_mean_by("index", half_life="2i"),
         )
 
 
+@pytest.mark.parametrize("values_dtype", [pl.Int8, pl.UInt16, pl.Float32])
+@pytest.mark.parametrize("by_dtype", [pl.Int16, pl.UInt8, pl.Int64])
+def test_ewma_by_integer_dtypes(
+    values_dtype: PolarsDataType, by_dtype: PolarsIntegerType
+) -> None:
+    df = pl.DataFrame(
+        {"values": [1, 2, None, 4], "by": [0, 2, 3, 6]},
+        schema={"values": values_dtype, "by": by_dtype},
+    )
+    result = df.select(pl.col("values").ewm_mean_by("by", half_life="2i"))
+    expected = pl.DataFrame(
+        {"values": [1.0, 1.5, None, 3.375]},
+        schema={"values": pl.Float32 if values_dtype == pl.Float32 else pl.Float64},
+    )
+    assert_frame_equal(result, expected)
+
+
 def test_ewma_by_warn_two_chunks() -> None:
     df = pl.DataFrame({"values": [3.0, 2.0], "by": [3, 1]})
     df = pl.concat([df, df], rechunk=False)
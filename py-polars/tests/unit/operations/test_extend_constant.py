@@ -79,3 +79,26 @@ def test_extend_by_not_uint_expr() -> None:
         s.extend_constant(pl.Series([2, 3]), 3)
     with pytest.raises(pl.ComputeError, match="value and n should have unit length"):
         s.extend_constant(2, pl.Series([3, 4]))
+
+
+def test_extend_constant_prepend() -> None:
+    s = pl.Series("s", [1, 2])
+    assert_series_equal(
+        s.extend_constant(0, 2, prepend=True), pl.Series("s", [0, 0, 1, 2])
+    )
+    assert_series_equal(
+        s.extend_constant(None, 1, prepend=True), pl.Series("s", [None, 1, 2])
+    )
+    assert_series_equal(s.extend_constant(0, 0, prepend=True), s)
+
+    df = pl.DataFrame({"a": [1, 2]})
+    result = df.select(pl.col("a").extend_constant(9, pl.lit(1), prepend=True))
+    assert_frame_equal(result, pl.DataFrame({"a": [9, 1, 2]}))
+
+
+def test_extend_constant_value_not_castable() -> None:
+    s = pl.Series("s", [1, 2])
+    with pytest.raises(pl.InvalidOperationError, match="cannot extend series"):
+        s.extend_constant("abc", 2)
+    with pytest.raises(pl.InvalidOperationError, match="cannot extend series"):
+        s.extend_constant("abc", 2, prepend=True)
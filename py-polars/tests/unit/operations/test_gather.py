@@ -1,4 +1,7 @@
+import pytest
+
 import polars as pl
+from polars.testing import assert_series_equal
 
 
 def test_negative_index() -> None:
@@ -11,6 +14,46 @@ def test_negative_index() -> None:
     ).to_dict(as_series=False) == {"a": [0, 1], "b": [[2, 6], [1, 5]]}
 
 
+def test_gather_out_of_bounds() -> None:
+    s = pl.Series("a", [1, 2, 3, 4])
+    idx = [-1, -5, 2, None, 4]
+    with pytest.raises(pl.OutOfBoundsError, match="index -5"):
+        s.gather(idx)
+    assert s.gather(idx, out_of_bounds="null").to_list() == [4, None, 3, None, None]
+    assert s.gather(idx, out_of_bounds="wrap").to_list() == [4, 4, 3, None, 1]
+
+    # All negative.
+    assert s.gather([-4, -3, -2, -1]).to_list() == [1, 2, 3, 4]
+
+    empty = pl.Series("a", [], dtype=pl.Int64)
+    assert_series_equal(empty.gather([]), empty)
+    assert empty.gather([0, -1], out_of_bounds="null").to_list() == [None, None]
+    with pytest.raises(pl.OutOfBoundsError):
+        empty.gather([0], out_of_bounds="wrap")
+
+    with pytest.raises(ValueError, match="`out_of_bounds` must be one of"):
+        s.gather([0], out_of_bounds="clip")  # type: ignore[arg-type]
+
+
+def test_gather_out_of_bounds_group_by() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3, 4, 5, 6]})
+    result = (
+        df.group_by(pl.col("a") % 2)
+        .agg(
+            null=pl.col("a").gather([-1, 3], out_of_bounds="null"),
+            wrap=pl.col("a").gather([-1, 3], out_of_bounds="wrap"),
+        )
+        .sort("a")
+    )
+    assert result.to_dict(as_series=False) == {
+        "a": [0, 1],
+        "null": [[6, None], [5, None]],
+        "wrap": [[6, 2], [5, 1]],
+    }
+    with pytest.raises(pl.OutOfBoundsError):
+        df.group_by(pl.col("a") % 2).agg(pl.col("a").gather([-1, 3]))
+
+
 def test_gather_agg_schema() -> None:
     df = pl.DataFrame(
         {
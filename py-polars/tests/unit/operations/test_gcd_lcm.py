@@ -0,0 +1,51 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_gcd_lcm() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [12, -18, 0, None, 7, 0],
+            "b": [18, 12, 0, 3, -21, 5],
+        }
+    )
+    result = df.select(
+        gcd=pl.col("a").gcd(pl.col("b")),
+        lcm=pl.col("a").lcm(pl.col("b")),
+        gcd_scalar=pl.col("a").gcd(4),
+    )
+    expected = pl.DataFrame(
+        {
+            "gcd": [6, 6, 0, None, 7, 5],
+            "lcm": [36, 36, 0, None, 21, 0],
+            "gcd_scalar": [4, 2, 4, None, 1, 4],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_gcd_lcm_supertype() -> None:
+    a = pl.Series("a", [4, 6], dtype=pl.Int16)
+    b = pl.Series("b", [6, 4], dtype=pl.Int32)
+    assert_series_equal(a.lcm(b), pl.Series("a", [12, 12], dtype=pl.Int32))
+    assert_series_equal(a.gcd(4), pl.Series("a", [4, 2], dtype=pl.Int16))
+
+
+def test_lcm_overflow() -> None:
+    s = pl.Series("a", [2**31 - 1, 6], dtype=pl.Int32)
+    other = pl.Series("b", [2**31 - 2, 4], dtype=pl.Int32)
+    with pytest.raises(pl.ComputeError, match="overflows"):
+        s.lcm(other)
+    assert_series_equal(
+        s.lcm(other, saturate=True),
+        pl.Series("a", [2**31 - 1, 12], dtype=pl.Int32),
+    )
+
+
+def test_gcd_non_integer_raises() -> None:
+    with pytest.raises(pl.InvalidOperationError, match="only supports integer"):
+        pl.Series([1.5, 2.0]).gcd(2)
@@ -9,7 +9,11 @@ import polars as pl
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
-    from polars.type_aliases import PolarsDataType, PolarsTemporalType
+    from polars.type_aliases import (
+        InterpolationMethod,
+        PolarsDataType,
+        PolarsTemporalType,
+    )
 
 
 @pytest.mark.parametrize(
@@ -94,7 +98,7 @@ def test_interpolate_nearest(input_dtype: PolarsDataType) -> None:
     df = pl.LazyFrame({"a": [1, None, 2, None, 3]}, schema={"a": input_dtype})
     result = df.with_columns(pl.all().interpolate(method="nearest"))
     assert result.schema["a"] == input_dtype
-    expected = pl.DataFrame({"a": [1, 2, 2, 3, 3]}, schema={"a": input_dtype})
+    expected = pl.DataFrame({"a": [1, 1, 2, 2, 3]}, schema={"a": input_dtype})
     assert_frame_equal(result.collect(), expected)
 
 
@@ -104,23 +108,23 @@ def test_interpolate_nearest(input_dtype: PolarsDataType) -> None:
         (
             [date(2020, 1, 1), None, date(2020, 1, 2)],
             pl.Date,
-            [date(2020, 1, 1), date(2020, 1, 2), date(2020, 1, 2)],
+            [date(2020, 1, 1), date(2020, 1, 1), date(2020, 1, 2)],
         ),
         (
             [datetime(2020, 1, 1), None, datetime(2020, 1, 2)],
             pl.Datetime("ms"),
-            [datetime(2020, 1, 1), datetime(2020, 1, 2), datetime(2020, 1, 2)],
+            [datetime(2020, 1, 1), datetime(2020, 1, 1), datetime(2020, 1, 2)],
         ),
         (
             [datetime(2020, 1, 1), None, datetime(2020, 1, 2)],
             pl.Datetime("us", "Asia/Kathmandu"),
-            [datetime(2020, 1, 1), datetime(2020, 1, 2), datetime(2020, 1, 2)],
+            [datetime(2020, 1, 1), datetime(2020, 1, 1), datetime(2020, 1, 2)],
         ),
-        ([time(1), None, time(2)], pl.Time, [time(1), time(2), time(2)]),
+        ([time(1), None, time(2)], pl.Time, [time(1), time(1), time(2)]),
         (
             [timedelta(1), None, timedelta(2)],
             pl.Duration("ms"),
-            [timedelta(1), timedelta(2), timedelta(2)],
+            [timedelta(1), timedelta(1), timedelta(2)],
         ),
     ],
 )
@@ -132,3 +136,19 @@ def test_interpolate_temporal_nearest(
     assert result.schema["a"] == input_dtype
     expected = pl.DataFrame({"a": output}, schema={"a": input_dtype})
     assert_frame_equal(result.collect(), expected)
+
+
+@pytest.mark.parametrize("method", ["linear", "nearest"])
+def test_interpolate_max_gap(method: InterpolationMethod) -> None:
+    df = pl.DataFrame({"a": [None, 1, None, 3, None, None, None, 7, None]})
+
+    result = df.select(pl.col("a").interpolate(method, max_gap=2))
+    assert result["a"].to_list()[:4] == [None, 1, 2, 3]
+    assert result["a"].to_list()[4:] == [None, None, None, 7, None]
+
+    # A gap within the limit is filled completely.
+    result = df.select(pl.col("a").interpolate(method, max_gap=3))
+    assert result["a"].null_count() == 2
+
+    result = df["a"].interpolate(method, max_gap=0)
+    assert result.null_count() == df["a"].null_count()
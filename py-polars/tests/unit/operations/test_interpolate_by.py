@@ -0,0 +1,89 @@
+from __future__ import annotations
+
+from datetime import date, timedelta
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_interpolate_by_linear() -> None:
+    df = pl.DataFrame(
+        {
+            "times": [0, 1, 3, 6, 7],
+            "values": [1, None, None, 4, None],
+        }
+    )
+    result = df.select(pl.col("values").interpolate_by("times"))
+    expected = pl.DataFrame({"values": [1.0, 1.5, 2.5, 4.0, None]})
+    assert_frame_equal(result, expected)
+    assert df.lazy().select(pl.col("values").interpolate_by("times")).schema == {
+        "values": pl.Float64
+    }
+
+
+def test_interpolate_by_temporal() -> None:
+    df = pl.DataFrame(
+        {
+            "times": [date(2024, 1, 1), date(2024, 1, 2), date(2024, 1, 5)],
+            "values": [None, 10, 40],
+            "dates": [date(2024, 1, 1), None, date(2024, 1, 4)],
+        }
+    )
+    result = df.select(
+        pl.col("values").interpolate_by("times"),
+        pl.col("dates").interpolate_by("times"),
+    )
+    expected = pl.DataFrame(
+        {
+            "values": [None, 10.0, 40.0],
+            "dates": [date(2024, 1, 1), date(2024, 1, 2), date(2024, 1, 4)],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_interpolate_by_nearest() -> None:
+    s = pl.Series("a", [1, None, None, None, 5])
+    result = s.interpolate_by(pl.Series([0, 1, 2, 5, 6]), method="nearest")
+    assert_series_equal(result, pl.Series("a", [1, 1, 1, 5, 5]))
+
+
+def test_interpolate_by_descending() -> None:
+    s = pl.Series("a", [None, 10.0, None, 40.0])
+    result = s.interpolate_by(pl.Series([9, 8, 7, 5]))
+    assert_series_equal(result, pl.Series("a", [None, 10.0, 20.0, 40.0]))
+
+
+def test_interpolate_by_invalid() -> None:
+    s = pl.Series("a", [1.0, None, 3.0])
+    with pytest.raises(pl.ComputeError, match="monotonic"):
+        s.interpolate_by(pl.Series([0, 2, 1]))
+    with pytest.raises(pl.ComputeError, match="nulls"):
+        s.interpolate_by(pl.Series([0, None, 2]))
+    with pytest.raises(pl.InvalidOperationError):
+        s.interpolate_by(pl.Series(["a", "b", "c"]))
+
+
+def test_interpolate_by_max_gap() -> None:
+    s = pl.Series("a", [1.0, None, 3.0, None, 5.0])
+    result = s.interpolate_by(pl.Series([0, 1, 2, 10, 12]), max_gap=2)
+    assert_series_equal(result, pl.Series("a", [1.0, 2.0, 3.0, None, 5.0]))
+
+    df = pl.DataFrame(
+        {
+            "dates": [date(2024, 1, d) for d in (1, 2, 3, 7, 8)],
+            "values": [1.0, None, 3.0, None, 8.0],
+        }
+    )
+    for max_gap, expected in [
+        (timedelta(days=2), [1.0, 2.0, 3.0, None, 8.0]),
+        (timedelta(days=5), [1.0, 2.0, 3.0, 7.0, 8.0]),
+        (pl.duration(hours=47), [1.0, None, 3.0, None, 8.0]),
+    ]:
+        result = df.select(pl.col("values").interpolate_by("dates", max_gap=max_gap))
+        assert result["values"].to_list() == expected
+
+    with pytest.raises(pl.InvalidOperationError, match="needs a duration"):
+        df.select(pl.col("values").interpolate_by("dates", max_gap=2))
@@ -0,0 +1,48 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+NAN = float("nan")
+INF = float("inf")
+
+
+def test_is_close() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1.0, 1.0, NAN, INF, INF, None, 0.0],
+            "b": [1.0 + 1e-10, 1.1, NAN, INF, -INF, 1.0, 1e-12],
+        }
+    )
+    result = df.select(
+        default=pl.col("a").is_close(pl.col("b")),
+        tolerant=pl.col("a").is_close(pl.col("b"), abs_tol=1e-9, nans_equal=True),
+    )
+    expected = pl.DataFrame(
+        {
+            "default": [True, False, False, True, False, None, False],
+            "tolerant": [True, False, True, True, False, None, True],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_is_close_broadcast_scalar() -> None:
+    s = pl.Series("a", [1, 2, 3])
+    assert_series_equal(
+        s.is_close(2.1, rel_tol=0.1),
+        pl.Series("a", [False, True, False]),
+    )
+    b = pl.Series("b", [1.9, 2.0, 2.6])
+    result = pl.select(pl.lit(2.0).is_close(b, abs_tol=0.5))
+    assert result.to_series().to_list() == [True, True, False]
+
+
+def test_is_close_invalid() -> None:
+    s = pl.Series("a", [1.0, 2.0])
+    with pytest.raises(pl.ComputeError, match="non-negative"):
+        s.is_close(1.0, rel_tol=-1.0)
+    with pytest.raises(pl.InvalidOperationError, match="numeric"):
+        s.is_close(pl.Series(["x", "y"]))
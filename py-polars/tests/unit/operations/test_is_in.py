@@ -394,3 +394,53 @@ def test_cat_list_is_in_from_single_str(val: str | None, expected: list[bool]) -
     res = df.select(pl.col("li").list.contains(pl.lit(val, dtype=pl.String)))
     expected_df = pl.DataFrame({"li": expected})
     assert_frame_equal(res, expected_df)
+
+
+@pytest.mark.parametrize("nulls_equal", [False, True])
+def test_is_in_nulls_equal_set(nulls_equal: bool) -> None:
+    s = pl.Series("a", [1, None, 3])
+    null = True if nulls_equal else None
+
+    result = s.is_in([1, None], nulls_equal=nulls_equal)
+    assert_series_equal(result, pl.Series("a", [True, null, False]))
+
+    # Without a null in the set, a null value is never present.
+    result = s.is_in([1, 2], nulls_equal=nulls_equal)
+    expected = [True, False if nulls_equal else None, False]
+    assert_series_equal(result, pl.Series("a", expected))
+
+    result = pl.Series("a", ["x", None]).is_in(["x", None], nulls_equal=nulls_equal)
+    assert_series_equal(result, pl.Series("a", [True, null]))
+
+
+@pytest.mark.parametrize("nulls_equal", [False, True])
+def test_is_in_nulls_equal_list(nulls_equal: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, None, None, 3, 4],
+            "lst": [[1, None], [2, None], [2], None, [5]],
+        }
+    )
+    result = df.select(pl.col("a").is_in("lst", nulls_equal=nulls_equal)).to_series()
+    if nulls_equal:
+        expected = [True, True, False, None, False]
+    else:
+        expected = [True, None, None, None, False]
+    assert result.to_list() == expected
+
+    # A scalar value is looked up in the list of every row.
+    result = df.select(
+        pl.lit(None, dtype=pl.Int64).is_in(pl.col("lst"), nulls_equal=nulls_equal)
+    ).to_series()
+    if nulls_equal:
+        expected = [True, True, False, None, False]
+    else:
+        expected = [None] * 5
+    assert result.to_list() == expected
+
+    # The flattened lists form a single set instead.
+    result = df.select(
+        pl.col("a").is_in(pl.col("lst").explode(), nulls_equal=nulls_equal)
+    ).to_series()
+    null = True if nulls_equal else None
+    assert result.to_list() == [True, null, null, False, False]
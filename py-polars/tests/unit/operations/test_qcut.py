@@ -113,6 +113,26 @@ def test_qcut_allow_duplicates() -> None:
     assert_series_equal(result, expected, categorical_as_str=True)
 
 
+def test_qcut_allow_duplicates_heavy_ties() -> None:
+    s = pl.Series("a", [1] * 18 + [2, 3])
+    quantiles = [0.25, 0.5, 0.75, 0.95]
+
+    with pytest.raises(pl.DuplicateError, match="quantile 0.5 has the same edge 1"):
+        s.qcut(quantiles)
+
+    result = s.qcut(quantiles, allow_duplicates=True)
+
+    categories = ["(-inf, 1]", "(1, 2.05]", "(2.05, inf]"]
+    expected = pl.Series(
+        "a", [categories[0]] * 18 + categories[1:], dtype=pl.Categorical
+    )
+    assert_series_equal(result, expected, categorical_as_str=True)
+    assert result.cat.get_categories().to_list() == categories
+
+    with pytest.raises(pl.ShapeError):
+        s.qcut(quantiles, labels=["a", "b", "c", "d", "e"], allow_duplicates=True)
+
+
 def test_qcut_over() -> None:
     df = pl.DataFrame(
         {
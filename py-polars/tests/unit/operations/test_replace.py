@@ -574,3 +574,66 @@ def test_replace_cat_cat(
             s = pl.Series("s", ["a", "b"], dtype=dt)
             s_replaced = s.replace(old, new, default=pl.lit("OTHER", dtype=dt))  # type: ignore[arg-type]
             assert_series_equal(s_replaced, expected.fill_null("OTHER"))
+
+
+def test_replace_strict_str_to_int() -> None:
+    df = pl.DataFrame({"a": ["x", "y", None, "x"]})
+    result = df.select(pl.col("a").replace_strict({"x": 1, "y": 2}))
+    expected = pl.DataFrame({"a": [1, 2, None, 1]})
+    assert_frame_equal(result, expected)
+
+
+def test_replace_strict_unmapped_raises() -> None:
+    s = pl.Series("a", ["x", "z", "w"])
+    with pytest.raises(pl.ComputeError, match="incomplete mapping"):
+        s.replace_strict({"x": 1, "y": 2})
+
+
+def test_replace_strict_default() -> None:
+    df = pl.DataFrame({"a": ["x", "z", None], "b": [10, 20, 30]})
+    result = df.select(
+        lit_default=pl.col("a").replace_strict({"x": 1}, default=-1),
+        expr_default=pl.col("a").replace_strict(["x"], [1], default=pl.col("b")),
+        cast=pl.col("a").replace_strict({"x": 1}, default=0, return_dtype=pl.UInt8),
+    )
+    expected = pl.DataFrame(
+        {
+            "lit_default": [1, -1, -1],
+            "expr_default": [1, 20, 30],
+            "cast": pl.Series([1, 0, 0], dtype=pl.UInt8),
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_replace_strict_null_key() -> None:
+    s = pl.Series("a", [1, None, 2])
+    result = s.replace_strict({1: "one", 2: "two", None: "missing"})
+    assert_series_equal(result, pl.Series("a", ["one", "missing", "two"]))
+
+
+def test_replace_ranges() -> None:
+    s = pl.Series("a", [-5, 0, 5, 10, 50, None, 100, 500])
+
+    result = s.replace_ranges([0, 10, 100], ["low", "high"], default="other")
+    expected = pl.Series(
+        "a", ["other", "other", "low", "low", "high", None, "high", "other"]
+    )
+    assert_series_equal(result, expected)
+
+    result = s.replace_ranges([0, 10, 100], [1, 2], left_closed=True)
+    expected = pl.Series("a", [None, 1, 1, 2, 2, None, None, None])
+    assert_series_equal(result, expected)
+
+    lf = pl.LazyFrame({"a": [1.5, 20.0]})
+    out = lf.select(pl.col("a").replace_ranges([0, 10, 100], [1.0, 2.0]))
+    assert out.schema == {"a": pl.Float64}
+    assert out.collect()["a"].to_list() == [1.0, 2.0]
+
+
+def test_replace_ranges_invalid() -> None:
+    s = pl.Series("a", [1, 2, 3])
+    with pytest.raises(pl.ComputeError, match="sorted"):
+        s.replace_ranges([10, 0, 100], ["low", "high"])
+    with pytest.raises(pl.ComputeError, match="one value less"):
+        s.replace_ranges([0, 10, 100], ["low"])
@@ -7,8 +7,8 @@ def test_rle() -> None:
     lf = pl.LazyFrame({"a": values})
 
     expected = pl.LazyFrame(
-        {"lengths": [2, 1, 1, 1, 1, 2], "values": [1, 2, 1, None, 1, 3]},
-        schema_overrides={"lengths": pl.Int32},
+        {"len": [2, 1, 1, 1, 1, 2], "value": [1, 2, 1, None, 1, 3]},
+        schema_overrides={"len": pl.UInt32},
     )
 
     result_expr = lf.select(pl.col("a").rle()).unnest("a")
@@ -29,3 +29,18 @@ def test_rle_id() -> None:
 
     result_series = lf.collect().to_series().rle_id()
     assert_frame_equal(result_series.to_frame(), expected.collect())
+
+
+def test_rle_nulls_and_empty() -> None:
+    s = pl.Series("a", [None, None, 1, 1, None], dtype=pl.Int64)
+    assert s.rle().struct.unnest().to_dict(as_series=False) == {
+        "len": [2, 2, 1],
+        "value": [None, 1, None],
+    }
+    assert s.rle_id().to_list() == [0, 0, 1, 1, 2]
+
+    empty = pl.Series("a", [], dtype=pl.Int64)
+    out = empty.rle()
+    assert out.len() == 0
+    assert out.dtype == pl.Struct({"len": pl.UInt32, "value": pl.Int64})
+    assert empty.rle_id().len() == 0
@@ -972,6 +972,34 @@ def test_arg_sort_by_descending() -> None:
         df.select(pl.arg_sort_by(["a", "b"], descending=[True]))
 
 
+def test_arg_sort_by_nulls_last_per_key() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, None, 1, 2, None],
+            "b": ["x", "y", None, "z", "x"],
+            "c": [10, 20, 30, 40, 50],
+        }
+    )
+    result = df.select(
+        pl.arg_sort_by("a", "b", descending=[False, True], nulls_last=[True, False])
+    )
+    expected = pl.Series("a", [2, 0, 3, 1, 4], dtype=pl.UInt32).to_frame()
+    assert_frame_equal(result, expected)
+
+    result = df.select(
+        pl.col("c").gather(
+            pl.arg_sort_by("a", "b", descending=True, nulls_last=[False, True])
+        )
+    )
+    assert result["c"].to_list() == [20, 50, 40, 10, 30]
+
+    with pytest.raises(
+        ValueError,
+        match=r"the length of `nulls_last` \(3\) does not match the length of `exprs` \(2\)",
+    ):
+        df.select(pl.arg_sort_by("a", "b", nulls_last=[True, False, True]))
+
+
 def test_arg_sort_struct() -> None:
     df = pl.DataFrame(
         {
@@ -1015,6 +1043,15 @@ def test_top_k_9385() -> None:
     ].to_list() == [False]
 
 
+def test_top_k_by_ties_keep_original_order() -> None:
+    df = pl.DataFrame({"a": list(range(7)), "by": [3, 1, 2, 1, 3, 1, 2]})
+    result = df.select(
+        top=pl.col("a").top_k(3, by="by"),
+        bottom=pl.col("a").bottom_k(3, by="by"),
+    )
+    assert result.to_dict(as_series=False) == {"top": [0, 4, 2], "bottom": [1, 3, 5]}
+
+
 def test_sorted_flag_partition_by() -> None:
     assert (
         pl.DataFrame({"one": [1, 2, 3], "two": ["a", "a", "b"]})
@@ -49,6 +49,33 @@ def test_hist() -> None:
     ).to_series().to_list() == [0, 3, 4]
 
 
+def test_hist_density() -> None:
+    a = pl.Series("a", [1, 3, 8, 8, 2, 1, 3])
+    out = a.hist(bin_count=4, include_category=False, density=True)
+
+    assert out.columns == ["break_point", "count", "density"]
+    assert out["density"].dtype == pl.Float64
+    assert out["density"].to_list() == pytest.approx(
+        [0.0, 3 / 15.75, 2 / 15.75, 0.0, 2 / 15.75]
+    )
+    assert (out["density"] * 2.25).sum() == pytest.approx(1.0)
+
+    # Zero-width and unbounded bins have a null density.
+    b = pl.Series("b", [1, 2, 2, 4])
+    out = b.hist(
+        bins=[0, 2, 2, 5],
+        include_category=False,
+        include_breakpoint=False,
+        density=True,
+    )
+    assert out["count"].to_list() == [0, 3, 0, 1, 0]
+    assert out["density"].to_list() == pytest.approx([None, 0.375, None, 1 / 12, None])
+
+    lf = pl.LazyFrame({"b": [1, 2, 2, 4]})
+    q = lf.select(pl.col("b").hist(bins=[0, 2], density=True))
+    assert q.schema["b"] == q.collect().schema["b"]
+
+
 @pytest.mark.parametrize("values", [[], [None]])
 def test_hist_empty_or_all_null(values: list[None]) -> None:
     ser = pl.Series(values, dtype=pl.Float64)
@@ -182,5 +209,20 @@ def test_count() -> None:
 def test_kurtosis_same_vals() -> None:
     df = pl.DataFrame({"a": [1.0042855193121334] * 11})
     assert_frame_equal(
-        df.select(pl.col("a").kurtosis()), pl.select(a=pl.lit(float("nan")))
+        df.select(pl.col("a").kurtosis()), pl.select(a=pl.lit(None, pl.Float64))
     )
+
+
+def test_skew_kurtosis_degenerate() -> None:
+    s = pl.Series("a", [1.0, 2.0, 4.0])
+    biased = s.skew()
+    assert biased is not None
+    # The sample-size correction applies from three values on.
+    assert s.skew(bias=False) == pytest.approx(biased * 6**0.5)
+    assert s.kurtosis() is None
+    assert s.kurtosis(bias=False) is None
+    assert pl.Series([1.0, None, 2.0]).skew() is None
+
+    df = pl.DataFrame({"a": [5.0] * 4})
+    result = df.select(skew=pl.col("a").skew(), kurt=pl.col("a").kurtosis())
+    assert result.row(0) == (None, None)
@@ -50,11 +50,38 @@ def test_value_counts_expr() -> None:
     ).to_dict(as_series=False) == {"session": [1], "id": [{"id": 2, "count": 2}]}
 
 
+def test_value_counts_normalize() -> None:
+    s = pl.Series("a", [1, 2, 2, None, 2, None, None, None])
+    result = s.value_counts(sort=True, normalize=True)
+    expected = pl.DataFrame(
+        {"a": [None, 2, 1], "proportion": [0.5, 0.375, 0.125]},
+        schema_overrides={"a": pl.Int64},
+    )
+    assert_frame_equal(result, expected)
+
+    out = pl.select(pl.lit(s).value_counts(sort=True, normalize=True)).to_series()
+    assert out.struct.fields == ["a", "proportion"]
+    assert out.struct.field("proportion").dtype == pl.Float64
+    assert_frame_equal(out.struct.unnest(), expected)
+
+
+def test_value_counts_ascending() -> None:
+    s = pl.Series("a", ["x", "y", "y", "z", "z", "z", None])
+    result = s.value_counts(sort=True, descending=False)
+    expected = pl.DataFrame(
+        {"a": ["x", None, "y", "z"], "count": [1, 1, 2, 3]},
+        schema_overrides={"count": pl.UInt32},
+    )
+    assert_frame_equal(result, expected)
+
+
 def test_value_counts_duplicate_name() -> None:
     s = pl.Series("count", [1])
 
     with pytest.raises(pl.DuplicateError, match="count"):
         s.value_counts()
+    with pytest.raises(pl.DuplicateError, match="proportion"):
+        s.alias("proportion").value_counts(normalize=True)
 
     def test_count() -> None:
         assert pl.Series([None, 1, None, 2, 3]).count() == 3
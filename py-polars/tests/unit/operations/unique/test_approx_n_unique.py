@@ -18,3 +18,24 @@ def test_lf_approx_n_unique_deprecated() -> None:
         result = df.approx_n_unique()
     expected = pl.LazyFrame({"a": [2], "b": [1]}).cast(pl.UInt32)
     assert_frame_equal(result, expected)
+
+
+def test_approx_n_unique_precision() -> None:
+    df = pl.DataFrame({"a": list(range(50_000)), "b": [i % 100 for i in range(50_000)]})
+    result = df.select(
+        a_low=pl.col("a").approx_n_unique(precision=10),
+        a_high=pl.col("a").approx_n_unique(precision=18),
+        b=pl.col("b").approx_n_unique(precision=8),
+    ).row(0)
+    assert abs(result[0] - 50_000) / 50_000 < 0.2
+    assert abs(result[1] - 50_000) / 50_000 < 0.02
+    # Small cardinalities are counted exactly.
+    assert result[2] == 100
+
+
+@pytest.mark.parametrize("precision", [3, 19])
+def test_approx_n_unique_invalid_precision(precision: int) -> None:
+    with pytest.raises(pl.ComputeError, match="precision"):
+        pl.Series([1, 2]).to_frame().select(
+            pl.first().approx_n_unique(precision=precision)
+        )
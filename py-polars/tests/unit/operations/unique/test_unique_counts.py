@@ -1,5 +1,7 @@
 from datetime import datetime
 
+import pytest
+
 import polars as pl
 from polars.testing import assert_series_equal
 
@@ -43,3 +45,24 @@ def test_unique_counts_null() -> None:
     s = pl.Series([None, None, None])
     expected = pl.Series([3], dtype=pl.UInt32)
     assert_series_equal(s.unique_counts(), expected)
+
+
+@pytest.mark.parametrize(
+    "values",
+    [
+        [3, 1, 3, None, 1, 3],
+        ["b", "a", "b", None, "a", "b"],
+        [True, None, False, True, None, True],
+        [b"x", b"y", b"x"],
+        [[1, 2], [3], [1, 2], None, [3], [1, 2]],
+        [{"a": 1}, {"a": 2}, {"a": 1}],
+    ],
+)
+def test_unique_counts_align_with_unique(values: list[object]) -> None:
+    df = pl.DataFrame({"x": values})
+    result = df.select(
+        value=pl.col("x").unique(maintain_order=True),
+        count=pl.col("x").unique_counts(),
+    )
+    for value, count in result.iter_rows():
+        assert values.count(value) == count
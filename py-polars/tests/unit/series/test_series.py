@@ -34,7 +34,12 @@ from polars.testing import assert_frame_equal, assert_series_equal
 if TYPE_CHECKING:
     from zoneinfo import ZoneInfo
 
-    from polars.type_aliases import EpochTimeUnit, PolarsDataType, TimeUnit
+    from polars.type_aliases import (
+        EpochTimeUnit,
+        PlateauBehavior,
+        PolarsDataType,
+        TimeUnit,
+    )
 else:
     from polars._utils.convert import string_to_zoneinfo as ZoneInfo
 
@@ -57,6 +62,20 @@ def test_cum_agg_with_nulls() -> None:
     assert_series_equal(s.cum_prod(), pl.Series("a", [None, 2, None, 14, 112, None]))
 
 
+def test_cum_prod_strict() -> None:
+    s = pl.Series("a", [2**40, None, 2**30, 2])
+    with pytest.raises(pl.ComputeError, match="overflowed i64 at index 2"):
+        s.cum_prod(strict=True)
+    with pytest.raises(pl.ComputeError, match="at index 0"):
+        s.cum_prod(reverse=True, strict=True)
+    assert s.cum_prod().len() == 4
+
+    s = pl.Series("a", [None, 3, 4], dtype=pl.UInt8)
+    assert s.cum_prod(strict=True).to_list() == [None, 3, 12]
+    s = pl.Series("a", [1e300, 1e300])
+    assert s.cum_prod(strict=True).to_list() == [1e300, float("inf")]
+
+
 def test_cum_agg_deprecated() -> None:
     # confirm that known series give expected results
     s = pl.Series("a", [1, 2, 3, 2])
@@ -1140,6 +1159,18 @@ def test_round() -> None:
         pytest.param(
             pl.Series([0.0]), 2, pl.Series([0.0]), id="0 should remain the same"
         ),
+        pytest.param(
+            pl.Series([1234.5, 0.012345, -987.6]),
+            2,
+            pl.Series([1200.0, 0.012, -990.0]),
+            id="negatives",
+        ),
+        pytest.param(
+            pl.Series([1.2345e300, 1.2345e-300, float("inf")]),
+            2,
+            pl.Series([1.2e300, 1.2e-300, float("inf")]),
+            id="extreme magnitudes",
+        ),
     ],
 )
 def test_round_sig_figs(
@@ -1183,6 +1214,30 @@ def test_mode() -> None:
 
     # sorted data
     assert pl.int_range(0, 3, eager=True).mode().to_list() == [2, 1, 0]
+    assert pl.Series([1, 1, 2]).sort().mode().to_list() == [1]
+
+
+def test_mode_maintain_order() -> None:
+    s = pl.Series("a", [3, None, 1.5, None, 1.5, 3, 2])
+    assert s.mode(maintain_order=True).to_list() == [3, None, 1.5]
+    assert s.mode(maintain_order=True, ignore_nulls=True).to_list() == [3, 1.5]
+    assert s.mode_first() == 3
+
+    s = pl.Series("a", [None, "x", None, "y", "y"])
+    assert s.mode_first() is None
+    assert s.mode_first(ignore_nulls=True) == "y"
+    assert pl.Series("a", [], dtype=pl.Int64).mode_first() is None
+
+    df = pl.DataFrame({"g": [1, 1, 1, 2, 2, 2], "v": [5, 4, 4, 5, 6, 7]})
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("v").mode_first().alias("first"),
+        pl.col("v").mode(maintain_order=True).alias("modes"),
+    )
+    assert out.to_dict(as_series=False) == {
+        "g": [1, 2],
+        "first": [4, 5],
+        "modes": [[4], [5, 6, 7]],
+    }
 
 
 def test_rank() -> None:
@@ -1216,6 +1271,20 @@ def test_diff() -> None:
     )
 
 
+def test_diff_negative_n_and_unsigned() -> None:
+    s = pl.Series("a", [1, 5, 2, 7], dtype=pl.UInt8)
+
+    result = s.diff(-1)
+    assert result.dtype == pl.Int16
+    assert result.to_list() == [-4, 3, -5, None]
+    assert s.diff(-2, null_behavior="drop").to_list() == [-1, -2]
+    assert s.diff(2, null_behavior="drop").to_list() == [1, 2]
+
+    # Shifting beyond the length leaves nothing to compare with.
+    assert s.diff(5).to_list() == [None] * 4
+    assert s.diff(-5, null_behavior="drop").len() == 0
+
+
 def test_pct_change() -> None:
     s = pl.Series("a", [1, 2, 4, 8, 16, 32, 64])
     expected = pl.Series("a", [None, None, float("inf"), 3.0, 3.0, 3.0, 3.0])
@@ -1231,6 +1300,21 @@ def test_pct_change() -> None:
     ]
 
 
+def test_pct_change_per_row_n_and_fill_null() -> None:
+    df = pl.DataFrame(
+        {"a": [1.0, None, 2.0, 0.0, -4.0], "n": [1, 1, 2, None, 1]},
+    )
+    out = df.select(
+        ffill=pl.col("a").pct_change(pl.col("n")),
+        keep=pl.col("a").pct_change(pl.col("n"), fill_null_strategy=None),
+    )
+    assert out.to_dict(as_series=False) == {
+        "ffill": [None, 0.0, 1.0, None, float("-inf")],
+        "keep": [None, None, 1.0, None, float("-inf")],
+    }
+    assert out.schema["ffill"] == pl.Float64
+
+
 def test_skew() -> None:
     s = pl.Series("a", [1, 2, 3, 2, 2, 3, 0])
 
@@ -1252,6 +1336,33 @@ def test_kurtosis() -> None:
     assert np.isclose(df.select(pl.col("a").kurtosis())["a"][0], expected)
 
 
+def test_moment() -> None:
+    values = [1, 2, 3, 2, 2, 3, 0, None]
+    s = pl.Series("a", values)
+    x = np.array([v for v in values if v is not None], dtype=np.float64)
+    centered = x - x.mean()
+
+    assert s.moment(0) == 1.0
+    assert s.moment(1) == 0.0
+    assert s.moment(1, central=False) == pytest.approx(x.mean())
+    for order in range(2, 7):
+        assert s.moment(order) == pytest.approx(np.mean(centered**order))
+        assert s.moment(order, central=False) == pytest.approx(np.mean(x**order))
+
+    assert s.moment(2, bias=False) == pytest.approx(s.var())
+    assert s.moment(4) / s.moment(2) ** 2 - 3 == pytest.approx(s.kurtosis())  # type: ignore[operator]
+
+    with pytest.raises(pl.InvalidOperationError, match="up to order 4"):
+        s.moment(5, bias=False)
+    assert pl.Series([1.0, 2.0]).moment(2, bias=False) == pytest.approx(0.5)
+    assert pl.Series([1.0, 2.0]).moment(3, bias=False) is None
+    assert pl.Series([None], dtype=pl.Float64).moment(2) is None
+
+    df = pl.DataFrame({"g": [1, 1, 2, 2], "a": [1.0, 3.0, 2.0, 2.0]})
+    out = df.group_by("g", maintain_order=True).agg(pl.col("a").moment(2))
+    assert out["a"].to_list() == [1.0, 0.0]
+
+
 def test_sqrt() -> None:
     s = pl.Series("a", [1, 2])
     assert_series_equal(s.sqrt(), pl.Series("a", [1.0, np.sqrt(2)]))
@@ -1555,6 +1666,27 @@ def test_to_dummies_drop_first() -> None:
     assert_frame_equal(result, expected)
 
 
+def test_to_dummies_null() -> None:
+    s = pl.Series("a", [None, 2, 1, 2])
+    result = s.to_dummies(separator=":")
+    expected = pl.DataFrame(
+        {"a:1": [0, 0, 1, 0], "a:2": [0, 1, 0, 1], "a:null": [1, 0, 0, 0]},
+        schema={"a:1": pl.UInt8, "a:2": pl.UInt8, "a:null": pl.UInt8},
+    )
+    assert_frame_equal(result, expected)
+
+    result = s.to_dummies(drop_nulls=True)
+    expected = pl.DataFrame(
+        {"a_1": [0, 0, 1, 0], "a_2": [0, 1, 0, 1]},
+        schema={"a_1": pl.UInt8, "a_2": pl.UInt8},
+    )
+    assert_frame_equal(result, expected)
+
+    # The dropped category is the first non-null value, not the null indicator.
+    result = s.to_dummies(drop_first=True)
+    assert result.columns == ["a_1", "a_null"]
+
+
 def test_chunk_lengths() -> None:
     s = pl.Series("a", [1, 2, 2, 3])
     # this is a Series with one chunk, of length 4
@@ -1723,6 +1855,34 @@ def test_peak_max_peak_min() -> None:
     assert_series_equal(result, expected)
 
 
+@pytest.mark.parametrize(
+    ("plateau", "expected"),
+    [
+        ("none", [False, False, False, False, False, True, False]),
+        ("all", [False, True, True, True, False, True, False]),
+        ("first", [False, True, False, False, False, True, False]),
+        ("last", [False, False, False, True, False, True, False]),
+    ],
+)
+def test_peak_max_plateau(plateau: PlateauBehavior, expected: list[bool]) -> None:
+    s = pl.Series("a", [1, 3, 3, 3, 1, 2, 1])
+    assert_series_equal(s.peak_max(plateau=plateau), pl.Series("a", expected))
+    assert_series_equal((-s).peak_min(plateau=plateau), pl.Series("a", expected))
+
+
+def test_peak_prominence() -> None:
+    s = pl.Series("a", [0.0, 5.0, 4.5, 4.8, 1.0, 3.0, 0.0])
+    result = s.peak_max(min_prominence=1.0)
+    expected = pl.Series("a", [False, True, False, False, False, True, False])
+    assert_series_equal(result, expected)
+
+    result = pl.select(pl.lit(s).peak_min(min_prominence=1.0)).to_series()
+    assert result.to_list() == [False, False, False, False, True, False, False]
+
+    with pytest.raises(pl.ComputeError, match="non-negative"):
+        s.peak_max(min_prominence=-1.0)
+
+
 def test_shrink_to_fit() -> None:
     s = pl.Series("a", [4, 1, 3, 2, 5])
     sf = s.shrink_to_fit(in_place=True)
@@ -1782,6 +1942,44 @@ def test_reshape() -> None:
         s.reshape(())
 
 
+def test_reshape_nested() -> None:
+    s = pl.Series("a", range(12))
+    out = s.reshape((2, -1, 3))
+    assert out.dtype == pl.List(pl.List(pl.Int64))
+    assert out.to_list() == [
+        [[0, 1, 2], [3, 4, 5]],
+        [[6, 7, 8], [9, 10, 11]],
+    ]
+
+    with pytest.raises(ComputeError, match="cannot reshape len 12"):
+        s.reshape((5, -1))
+    with pytest.raises(ComputeError, match="only one dimension can be inferred"):
+        s.reshape((-1, -1))
+
+
+def test_reshape_as_array() -> None:
+    s = pl.Series("a", range(12))
+    out = s.reshape((-1, 4), as_array=True)
+    assert out.dtype == pl.Array(pl.Int64, 4)
+    assert out.to_list() == [[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11]]
+
+    out = s.reshape((3, 2, 2), as_array=True)
+    assert out.dtype == pl.Array(pl.Array(pl.Int64, 2), 2)
+    assert_series_equal(out.explode().explode(), s)
+
+    # the schema of the lazy result matches the eager result
+    lf = pl.LazyFrame({"a": s}).select(
+        pl.col("a").reshape((3, 2, 2), as_array=True)
+    )
+    assert lf.schema["a"] == pl.Array(pl.Array(pl.Int64, 2), 2)
+    assert_series_equal(lf.collect().to_series(), out)
+
+    with pytest.raises(ComputeError, match="positive inner dimensions"):
+        s.reshape((3, -1), as_array=True)
+    with pytest.raises(ComputeError, match="cannot reshape len 12"):
+        s.reshape((-1, 5), as_array=True)
+
+
 def test_init_categorical() -> None:
     with pl.StringCache():
         for values in [[None], ["foo", "bar"], [None, "foo", "bar"]]:
@@ -1844,6 +2042,31 @@ def test_log_exp() -> None:
     assert_series_equal(a.log1p(), expected)
 
 
+def test_log_with_base() -> None:
+    s = pl.Series("a", [8, 100, 0, -1, None])
+    base = pl.Series([2, 10, 10, 10, 3])
+    result = s.log(base)
+    assert result.dtype == pl.Float64
+    assert result.name == "a"
+    assert result.to_list()[:3] == [3.0, 2.0, float("-inf")]
+    assert math.isnan(result[3])
+    assert result[4] is None
+
+    df = pl.DataFrame(
+        {"a": [8.0, 9.0], "b": [2.0, 3.0]}, schema_overrides={"a": pl.Float32}
+    )
+    result_df = df.select(pl.col("a").log("b"), c=pl.col("a").log(pl.lit(2)))
+    assert result_df.to_dict(as_series=False) == {
+        "a": [3.0, 2.0],
+        "c": [3.0, math.log(9, 2)],
+    }
+    assert result_df.schema == {"a": pl.Float64, "c": pl.Float64}
+    assert df.lazy().select(pl.col("a").log("b")).schema["a"] == pl.Float64
+
+    with pytest.raises(pl.ShapeError):
+        s.log(pl.Series([2, 3]))
+
+
 def test_to_physical() -> None:
     # casting an int result in an int
     s = pl.Series("a", [1, 2, 3])